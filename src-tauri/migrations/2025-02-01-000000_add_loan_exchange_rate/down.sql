@@ -0,0 +1,2 @@
+ALTER TABLE loans DROP COLUMN amount_cup;
+ALTER TABLE loans DROP COLUMN exchange_rate;
@@ -0,0 +1,18 @@
+-- Guardar la tasa aplicada y el equivalente en CUP de cada préstamo
+ALTER TABLE loans ADD COLUMN exchange_rate REAL NOT NULL DEFAULT 1.0;
+ALTER TABLE loans ADD COLUMN amount_cup REAL NOT NULL DEFAULT 0.0;
+
+-- Los préstamos existentes toman la tasa de su moneda en `currencies`; si la moneda aún no está
+-- registrada se usa la tasa con la que la siembra 2025-02-02-000000_seed_currencies
+UPDATE loans SET exchange_rate = COALESCE(
+    (SELECT c.exchange_rate FROM currencies c WHERE c.code = loans.currency),
+    CASE currency
+        WHEN 'USD' THEN 370.0
+        WHEN 'EUR' THEN 400.0
+        WHEN 'MLC' THEN 280.0
+        ELSE 1.0
+    END
+)
+WHERE currency <> 'CUP';
+
+UPDATE loans SET amount_cup = amount * exchange_rate;
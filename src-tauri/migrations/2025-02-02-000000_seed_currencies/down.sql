@@ -0,0 +1 @@
+DELETE FROM currencies WHERE code IN ('CUP', 'USD', 'EUR', 'MLC');
@@ -0,0 +1,6 @@
+-- Monedas con las que opera el negocio, CUP es la moneda base
+INSERT OR IGNORE INTO currencies (code, name, exchange_rate) VALUES
+    ('CUP', 'Peso cubano', 1.0),
+    ('USD', 'Dólar estadounidense', 370.0),
+    ('EUR', 'Euro', 400.0),
+    ('MLC', 'Moneda libremente convertible', 280.0);
@@ -0,0 +1,21 @@
+CREATE TABLE payments_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    amount REAL NOT NULL,
+    currency TEXT NOT NULL,
+    exchange_rate REAL NOT NULL DEFAULT 370.0,
+    amount_cup REAL NOT NULL,
+    borrower_id INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (borrower_id) REFERENCES borrowers (id)
+);
+
+INSERT INTO payments_old (id, amount, currency, exchange_rate, amount_cup, borrower_id, created_at, updated_at)
+SELECT id, amount, currency, exchange_rate, amount_cup, borrower_id, created_at, updated_at FROM payments;
+
+DROP TABLE payments;
+ALTER TABLE payments_old RENAME TO payments;
+CREATE INDEX idx_payments_borrower_id ON payments(borrower_id);
+
+DROP INDEX IF EXISTS idx_exchange_rate_history_lookup;
+DROP TABLE IF EXISTS exchange_rate_history;
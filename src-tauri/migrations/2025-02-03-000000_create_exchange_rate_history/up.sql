@@ -0,0 +1,35 @@
+-- Historial de tasas: cada fila es la tasa vigente desde `effective_from` hasta la siguiente
+CREATE TABLE exchange_rate_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    currency_code TEXT NOT NULL,
+    exchange_rate REAL NOT NULL,
+    effective_from DATETIME NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (currency_code) REFERENCES currencies (code)
+);
+
+CREATE INDEX idx_exchange_rate_history_lookup ON exchange_rate_history(currency_code, effective_from);
+
+-- Las tasas actuales se consideran vigentes desde siempre para no romper registros anteriores
+INSERT INTO exchange_rate_history (currency_code, exchange_rate, effective_from)
+SELECT code, exchange_rate, '1970-01-01 00:00:00' FROM currencies;
+
+-- payments.exchange_rate ya no tiene un valor por defecto fijo, siempre se resuelve desde el historial
+CREATE TABLE payments_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    amount REAL NOT NULL,
+    currency TEXT NOT NULL,
+    exchange_rate REAL NOT NULL,
+    amount_cup REAL NOT NULL,
+    borrower_id INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (borrower_id) REFERENCES borrowers (id)
+);
+
+INSERT INTO payments_new (id, amount, currency, exchange_rate, amount_cup, borrower_id, created_at, updated_at)
+SELECT id, amount, currency, exchange_rate, amount_cup, borrower_id, created_at, updated_at FROM payments;
+
+DROP TABLE payments;
+ALTER TABLE payments_new RENAME TO payments;
+CREATE INDEX idx_payments_borrower_id ON payments(borrower_id);
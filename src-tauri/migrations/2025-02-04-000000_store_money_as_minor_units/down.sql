@@ -0,0 +1,64 @@
+-- Volver a importes REAL en unidades mayores
+CREATE TABLE borrowers_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    phone TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    location TEXT NOT NULL,
+    total_loans REAL NOT NULL DEFAULT 0.0,
+    total_paid REAL NOT NULL DEFAULT 0.0,
+    balance REAL NOT NULL DEFAULT 0.0,
+    status TEXT NOT NULL DEFAULT 'active',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO borrowers_old (id, phone, name, location, total_loans, total_paid, balance, status, created_at, updated_at)
+SELECT id, phone, name, location, total_loans_cents / 100.0, total_paid_cents / 100.0, balance_cents / 100.0,
+       status, created_at, updated_at
+FROM borrowers;
+
+CREATE TABLE loans_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    amount REAL NOT NULL,
+    currency TEXT NOT NULL DEFAULT 'CUP',
+    status TEXT NOT NULL DEFAULT 'active',
+    borrower_id INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    exchange_rate REAL NOT NULL DEFAULT 1.0,
+    amount_cup REAL NOT NULL DEFAULT 0.0,
+    FOREIGN KEY (borrower_id) REFERENCES borrowers (id)
+);
+
+INSERT INTO loans_old (id, amount, currency, status, borrower_id, created_at, updated_at, exchange_rate, amount_cup)
+SELECT id, amount_cents / 100.0, currency, status, borrower_id, created_at, updated_at,
+       exchange_rate, amount_cup_cents / 100.0
+FROM loans;
+
+CREATE TABLE payments_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    amount REAL NOT NULL,
+    currency TEXT NOT NULL,
+    exchange_rate REAL NOT NULL,
+    amount_cup REAL NOT NULL,
+    borrower_id INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (borrower_id) REFERENCES borrowers (id)
+);
+
+INSERT INTO payments_old (id, amount, currency, exchange_rate, amount_cup, borrower_id, created_at, updated_at)
+SELECT id, amount_cents / 100.0, currency, exchange_rate, amount_cup_cents / 100.0, borrower_id, created_at, updated_at
+FROM payments;
+
+DROP TABLE payments;
+DROP TABLE loans;
+DROP TABLE borrowers;
+
+ALTER TABLE borrowers_old RENAME TO borrowers;
+ALTER TABLE loans_old RENAME TO loans;
+ALTER TABLE payments_old RENAME TO payments;
+
+CREATE INDEX idx_borrowers_phone ON borrowers(phone);
+CREATE INDEX idx_loans_borrower_id ON loans(borrower_id);
+CREATE INDEX idx_payments_borrower_id ON payments(borrower_id);
@@ -0,0 +1,69 @@
+-- Los importes pasan de REAL a enteros en centavos para evitar errores de redondeo acumulados
+CREATE TABLE borrowers_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    phone TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    location TEXT NOT NULL,
+    total_loans_cents BIGINT NOT NULL DEFAULT 0,
+    total_paid_cents BIGINT NOT NULL DEFAULT 0,
+    balance_cents BIGINT NOT NULL DEFAULT 0,
+    status TEXT NOT NULL DEFAULT 'active',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO borrowers_new (id, phone, name, location, status, created_at, updated_at)
+SELECT id, phone, name, location, status, created_at, updated_at FROM borrowers;
+
+CREATE TABLE loans_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    amount_cents BIGINT NOT NULL,
+    currency TEXT NOT NULL DEFAULT 'CUP',
+    status TEXT NOT NULL DEFAULT 'active',
+    borrower_id INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    exchange_rate REAL NOT NULL DEFAULT 1.0,
+    amount_cup_cents BIGINT NOT NULL DEFAULT 0,
+    FOREIGN KEY (borrower_id) REFERENCES borrowers (id)
+);
+
+INSERT INTO loans_new (id, amount_cents, currency, status, borrower_id, created_at, updated_at, exchange_rate, amount_cup_cents)
+SELECT id, CAST(ROUND(amount * 100) AS INTEGER), currency, status, borrower_id, created_at, updated_at,
+       exchange_rate, CAST(ROUND(amount_cup * 100) AS INTEGER)
+FROM loans;
+
+CREATE TABLE payments_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    amount_cents BIGINT NOT NULL,
+    currency TEXT NOT NULL,
+    exchange_rate REAL NOT NULL,
+    amount_cup_cents BIGINT NOT NULL,
+    borrower_id INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (borrower_id) REFERENCES borrowers (id)
+);
+
+INSERT INTO payments_new (id, amount_cents, currency, exchange_rate, amount_cup_cents, borrower_id, created_at, updated_at)
+SELECT id, CAST(ROUND(amount * 100) AS INTEGER), currency, exchange_rate,
+       CAST(ROUND(amount_cup * 100) AS INTEGER), borrower_id, created_at, updated_at
+FROM payments;
+
+-- Los totales se recalculan desde los registros ya redondeados para que cuadren al centavo
+UPDATE borrowers_new SET
+    total_loans_cents = COALESCE((SELECT SUM(amount_cup_cents) FROM loans_new WHERE loans_new.borrower_id = borrowers_new.id), 0),
+    total_paid_cents = COALESCE((SELECT SUM(amount_cup_cents) FROM payments_new WHERE payments_new.borrower_id = borrowers_new.id), 0);
+UPDATE borrowers_new SET balance_cents = total_loans_cents - total_paid_cents;
+
+DROP TABLE payments;
+DROP TABLE loans;
+DROP TABLE borrowers;
+
+ALTER TABLE borrowers_new RENAME TO borrowers;
+ALTER TABLE loans_new RENAME TO loans;
+ALTER TABLE payments_new RENAME TO payments;
+
+CREATE INDEX idx_borrowers_phone ON borrowers(phone);
+CREATE INDEX idx_loans_borrower_id ON loans(borrower_id);
+CREATE INDEX idx_payments_borrower_id ON payments(borrower_id);
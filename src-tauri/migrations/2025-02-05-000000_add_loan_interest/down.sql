@@ -0,0 +1,3 @@
+ALTER TABLE loans DROP COLUMN interest_method;
+ALTER TABLE loans DROP COLUMN rate_period;
+ALTER TABLE loans DROP COLUMN interest_rate;
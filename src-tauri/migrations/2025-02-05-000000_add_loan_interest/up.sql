@@ -0,0 +1,4 @@
+-- Condiciones de interés de cada préstamo; los existentes quedan sin interés
+ALTER TABLE loans ADD COLUMN interest_rate DOUBLE NOT NULL DEFAULT 0.0;
+ALTER TABLE loans ADD COLUMN rate_period TEXT NOT NULL DEFAULT 'monthly';
+ALTER TABLE loans ADD COLUMN interest_method TEXT NOT NULL DEFAULT 'flat';
@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_loan_installments_due_date;
+DROP TABLE IF EXISTS loan_installments;
+
+ALTER TABLE loans DROP COLUMN first_due_date;
+ALTER TABLE loans DROP COLUMN installment_count;
+ALTER TABLE loans DROP COLUMN installment_frequency;
@@ -0,0 +1,23 @@
+-- Parámetros del plan de cuotas; NULL si el préstamo no tiene plan
+ALTER TABLE loans ADD COLUMN installment_frequency TEXT;
+ALTER TABLE loans ADD COLUMN installment_count INTEGER;
+ALTER TABLE loans ADD COLUMN first_due_date DATETIME;
+
+-- Cuotas del plan de pagos, importes en centavos de la moneda del préstamo
+CREATE TABLE loan_installments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    loan_id INTEGER NOT NULL,
+    number INTEGER NOT NULL,
+    due_date DATETIME NOT NULL,
+    principal_cents BIGINT NOT NULL,
+    interest_cents BIGINT NOT NULL,
+    paid_cents BIGINT NOT NULL DEFAULT 0,
+    status TEXT NOT NULL DEFAULT 'pending',
+    paid_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (loan_id) REFERENCES loans (id),
+    UNIQUE (loan_id, number)
+);
+
+CREATE INDEX idx_loan_installments_due_date ON loan_installments(due_date);
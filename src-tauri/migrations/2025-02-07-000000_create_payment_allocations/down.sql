@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_payment_allocations_loan_id;
+DROP INDEX IF EXISTS idx_payment_allocations_payment_id;
+DROP TABLE IF EXISTS payment_allocations;
+
+ALTER TABLE payments DROP COLUMN allocation_strategy;
+ALTER TABLE loans DROP COLUMN remaining_cents;
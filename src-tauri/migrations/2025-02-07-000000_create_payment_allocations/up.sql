@@ -0,0 +1,22 @@
+-- Saldo pendiente de cada préstamo en centavos de su moneda; se recalcula al asignar los pagos
+ALTER TABLE loans ADD COLUMN remaining_cents BIGINT NOT NULL DEFAULT 0;
+UPDATE loans SET remaining_cents = amount_cents;
+
+-- Estrategia con la que se reparte cada pago entre los préstamos abiertos del prestatario
+ALTER TABLE payments ADD COLUMN allocation_strategy TEXT NOT NULL DEFAULT 'oldest_first';
+
+-- Parte de un pago aplicada a un préstamo; `amount_cents` en la moneda del préstamo
+-- y `amount_cup_cents` es la porción del pago en CUP que lo cubre
+CREATE TABLE payment_allocations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    payment_id INTEGER NOT NULL,
+    loan_id INTEGER NOT NULL,
+    amount_cents BIGINT NOT NULL,
+    amount_cup_cents BIGINT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (payment_id) REFERENCES payments (id),
+    FOREIGN KEY (loan_id) REFERENCES loans (id)
+);
+
+CREATE INDEX idx_payment_allocations_payment_id ON payment_allocations(payment_id);
+CREATE INDEX idx_payment_allocations_loan_id ON payment_allocations(loan_id);
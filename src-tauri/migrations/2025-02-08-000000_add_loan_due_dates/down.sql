@@ -0,0 +1,5 @@
+UPDATE borrowers SET status = 'active' WHERE status IN ('current', 'late', 'delinquent', 'paid_off');
+UPDATE loans SET status = 'active' WHERE status IN ('overdue', 'defaulted');
+
+DROP INDEX IF EXISTS idx_loans_due_date;
+ALTER TABLE loans DROP COLUMN due_date;
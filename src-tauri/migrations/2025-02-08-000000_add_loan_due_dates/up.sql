@@ -0,0 +1,12 @@
+-- Vencimiento final del préstamo; con plan de cuotas es el de la última cuota
+ALTER TABLE loans ADD COLUMN due_date DATETIME;
+UPDATE loans SET due_date = (
+    SELECT MAX(loan_installments.due_date)
+    FROM loan_installments
+    WHERE loan_installments.loan_id = loans.id
+);
+
+CREATE INDEX idx_loans_due_date ON loans(due_date);
+
+-- Los prestatarios pasan a los estados de cobranza; la tarea diaria los recalcula
+UPDATE borrowers SET status = 'current' WHERE status = 'active';
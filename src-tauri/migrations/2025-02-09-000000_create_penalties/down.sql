@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_penalties_borrower_id;
+DROP INDEX IF EXISTS idx_penalties_loan_id;
+DROP TABLE IF EXISTS penalties;
+DROP TABLE IF EXISTS penalty_rules;
+
+UPDATE borrowers SET balance_cents = balance_cents - total_penalties_cents;
+ALTER TABLE borrowers DROP COLUMN total_penalties_cents;
@@ -0,0 +1,41 @@
+-- Recargos acumulados del prestatario en centavos de CUP; forman parte del saldo
+ALTER TABLE borrowers ADD COLUMN total_penalties_cents BIGINT NOT NULL DEFAULT 0;
+
+-- Reglas de recargo por atraso
+-- `kind`: fixed (monto fijo), percentage (porcentaje de lo vencido) o daily (porcentaje diario de lo vencido)
+-- `amount_cents` y `cap_cents` están en centavos de CUP; `rate` es un porcentaje
+CREATE TABLE penalty_rules (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    amount_cents BIGINT NOT NULL DEFAULT 0,
+    rate DOUBLE NOT NULL DEFAULT 0.0,
+    grace_days INTEGER NOT NULL DEFAULT 0,
+    cap_cents BIGINT,
+    active BOOLEAN NOT NULL DEFAULT 1,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Cada recargo cobrado a un préstamo, en centavos de CUP
+CREATE TABLE penalties (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    loan_id INTEGER NOT NULL,
+    borrower_id INTEGER NOT NULL,
+    rule_id INTEGER NOT NULL,
+    amount_cents BIGINT NOT NULL,
+    days_past_due INTEGER NOT NULL,
+    days_charged INTEGER NOT NULL DEFAULT 0,
+    status TEXT NOT NULL DEFAULT 'charged',
+    waived_reason TEXT,
+    waived_by INTEGER,
+    waived_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (loan_id) REFERENCES loans (id),
+    FOREIGN KEY (borrower_id) REFERENCES borrowers (id),
+    FOREIGN KEY (rule_id) REFERENCES penalty_rules (id)
+);
+
+CREATE INDEX idx_penalties_loan_id ON penalties(loan_id);
+CREATE INDEX idx_penalties_borrower_id ON penalties(borrower_id);
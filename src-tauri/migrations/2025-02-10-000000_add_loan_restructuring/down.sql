@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_loans_restructured_from_id;
+
+ALTER TABLE loans DROP COLUMN carried_over_cup_cents;
+ALTER TABLE loans DROP COLUMN restructured_at;
+ALTER TABLE loans DROP COLUMN restructured_from_id;
@@ -0,0 +1,12 @@
+-- Préstamo original del que proviene una reestructuración; se guarda sin clave foránea
+-- para poder eliminar la columna al revertir
+ALTER TABLE loans ADD COLUMN restructured_from_id INTEGER;
+
+-- Fecha en que el préstamo se cerró por reestructuración
+ALTER TABLE loans ADD COLUMN restructured_at DATETIME;
+
+-- Parte del principal, en centavos de CUP, que pasó al préstamo nuevo
+-- Los totales del prestatario la descuentan para no contar dos veces el mismo principal
+ALTER TABLE loans ADD COLUMN carried_over_cup_cents BIGINT NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_loans_restructured_from_id ON loans(restructured_from_id);
@@ -0,0 +1,10 @@
+UPDATE borrowers SET balance_cents = balance_cents + written_off_total_cents - recovered_total_cents;
+ALTER TABLE borrowers DROP COLUMN recovered_total_cents;
+ALTER TABLE borrowers DROP COLUMN written_off_total_cents;
+
+ALTER TABLE loans DROP COLUMN written_off_by;
+ALTER TABLE loans DROP COLUMN write_off_reason;
+ALTER TABLE loans DROP COLUMN recovered_cup_cents;
+ALTER TABLE loans DROP COLUMN written_off_cup_cents;
+ALTER TABLE loans DROP COLUMN written_off_cents;
+ALTER TABLE loans DROP COLUMN written_off_at;
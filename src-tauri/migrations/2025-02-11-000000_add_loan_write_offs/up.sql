@@ -0,0 +1,15 @@
+-- Castigo de préstamos incobrables
+-- `written_off_cents` es el saldo pendiente al castigar, en la moneda del préstamo
+-- `written_off_cup_cents` es lo que sale del saldo del prestatario, en centavos de CUP
+-- `recovered_cup_cents` son los pagos recibidos después del castigo, en centavos de CUP
+ALTER TABLE loans ADD COLUMN written_off_at DATETIME;
+ALTER TABLE loans ADD COLUMN written_off_cents BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE loans ADD COLUMN written_off_cup_cents BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE loans ADD COLUMN recovered_cup_cents BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE loans ADD COLUMN write_off_reason TEXT;
+ALTER TABLE loans ADD COLUMN written_off_by INTEGER;
+
+-- Totales castigados y recuperados del prestatario en centavos de CUP
+-- El saldo descuenta lo castigado y vuelve a sumar lo recuperado
+ALTER TABLE borrowers ADD COLUMN written_off_total_cents BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE borrowers ADD COLUMN recovered_total_cents BIGINT NOT NULL DEFAULT 0;
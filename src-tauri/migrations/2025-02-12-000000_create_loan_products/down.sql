@@ -0,0 +1,5 @@
+DROP INDEX idx_loans_product_id;
+ALTER TABLE loans DROP COLUMN penalty_rule_id;
+ALTER TABLE loans DROP COLUMN product_id;
+
+DROP TABLE loan_products;
@@ -0,0 +1,29 @@
+-- Productos de préstamo: condiciones por defecto para no repetirlas en cada préstamo
+-- Con `installment_frequency` e `installment_count` el préstamo nace con plan de cuotas;
+-- sin plan, `term_days` fija el vencimiento a partir de la fecha del préstamo
+-- `min_amount_cents` y `max_amount_cents` están en centavos de la moneda del producto
+CREATE TABLE loan_products (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    currency TEXT NOT NULL DEFAULT 'CUP',
+    interest_rate DOUBLE NOT NULL DEFAULT 0.0,
+    rate_period TEXT NOT NULL DEFAULT 'monthly',
+    interest_method TEXT NOT NULL DEFAULT 'flat',
+    installment_frequency TEXT,
+    installment_count INTEGER,
+    term_days INTEGER,
+    penalty_rule_id INTEGER,
+    min_amount_cents BIGINT,
+    max_amount_cents BIGINT,
+    active BOOLEAN NOT NULL DEFAULT 1,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (penalty_rule_id) REFERENCES penalty_rules (id)
+);
+
+-- Producto con que se creó el préstamo y la regla de recargo que le aplica
+-- Sin regla propia el préstamo recibe todas las reglas activas
+ALTER TABLE loans ADD COLUMN product_id INTEGER;
+ALTER TABLE loans ADD COLUMN penalty_rule_id INTEGER;
+
+CREATE INDEX idx_loans_product_id ON loans(product_id);
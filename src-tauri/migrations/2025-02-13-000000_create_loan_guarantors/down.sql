@@ -0,0 +1,4 @@
+ALTER TABLE borrowers DROP COLUMN guarantee_exposure_cents;
+
+DROP INDEX idx_loan_guarantors_borrower_id;
+DROP TABLE loan_guarantors;
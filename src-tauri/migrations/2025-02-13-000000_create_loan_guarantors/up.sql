@@ -0,0 +1,19 @@
+-- Garantes de un préstamo: un prestatario registrado o un contacto sin ficha
+-- `guaranteed_amount_cents` está en centavos de la moneda del préstamo
+CREATE TABLE loan_guarantors (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    loan_id INTEGER NOT NULL,
+    borrower_id INTEGER,
+    contact_name TEXT,
+    contact_phone TEXT,
+    guaranteed_amount_cents BIGINT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (loan_id) REFERENCES loans (id),
+    FOREIGN KEY (borrower_id) REFERENCES borrowers (id),
+    UNIQUE (loan_id, borrower_id)
+);
+
+CREATE INDEX idx_loan_guarantors_borrower_id ON loan_guarantors(borrower_id);
+
+-- Lo que el prestatario garantiza en préstamos ajenos que siguen abiertos, en centavos de CUP
+ALTER TABLE borrowers ADD COLUMN guarantee_exposure_cents BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+ALTER TABLE payments DROP COLUMN loan_id;
+
+DROP INDEX idx_loan_collaterals_loan_id;
+DROP TABLE loan_collaterals;
@@ -0,0 +1,24 @@
+-- Garantías prendarias de un préstamo; el valor estimado está en centavos de `currency`
+-- `status`: held (en custodia), returned (devuelta) o liquidated (vendida para cubrir la deuda)
+-- `payment_id` es el pago con que se registró lo obtenido al liquidarla
+CREATE TABLE loan_collaterals (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    loan_id INTEGER NOT NULL,
+    description TEXT NOT NULL,
+    estimated_value_cents BIGINT NOT NULL,
+    currency TEXT NOT NULL DEFAULT 'CUP',
+    storage_location TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'held',
+    payment_id INTEGER,
+    returned_at DATETIME,
+    liquidated_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (loan_id) REFERENCES loans (id),
+    FOREIGN KEY (payment_id) REFERENCES payments (id)
+);
+
+CREATE INDEX idx_loan_collaterals_loan_id ON loan_collaterals(loan_id);
+
+-- Préstamo al que se aplica primero el pago, antes de repartir el resto con su estrategia
+ALTER TABLE payments ADD COLUMN loan_id INTEGER;
@@ -0,0 +1,4 @@
+ALTER TABLE loans DROP COLUMN credit_limit_override_by;
+
+ALTER TABLE borrowers DROP COLUMN risk_tier;
+ALTER TABLE borrowers DROP COLUMN credit_limit_cents;
@@ -0,0 +1,7 @@
+-- Límite de crédito del prestatario en centavos de CUP; sin límite si es NULL
+-- `risk_tier`: low, medium o high
+ALTER TABLE borrowers ADD COLUMN credit_limit_cents BIGINT;
+ALTER TABLE borrowers ADD COLUMN risk_tier TEXT NOT NULL DEFAULT 'medium';
+
+-- Administrador que autorizó el préstamo por encima del límite de crédito del prestatario
+ALTER TABLE loans ADD COLUMN credit_limit_override_by INTEGER;
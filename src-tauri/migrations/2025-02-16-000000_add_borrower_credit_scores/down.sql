@@ -0,0 +1,7 @@
+ALTER TABLE borrowers DROP COLUMN credit_score_updated_at;
+ALTER TABLE borrowers DROP COLUMN credit_write_offs;
+ALTER TABLE borrowers DROP COLUMN credit_restructures;
+ALTER TABLE borrowers DROP COLUMN credit_days_past_due;
+ALTER TABLE borrowers DROP COLUMN credit_late_installments;
+ALTER TABLE borrowers DROP COLUMN credit_on_time_installments;
+ALTER TABLE borrowers DROP COLUMN credit_score;
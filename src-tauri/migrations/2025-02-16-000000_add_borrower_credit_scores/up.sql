@@ -0,0 +1,9 @@
+-- Puntaje de crédito interno del prestatario (300 a 850) y los factores con que se calculó
+-- `credit_days_past_due` es el mayor atraso actual entre sus préstamos abiertos
+ALTER TABLE borrowers ADD COLUMN credit_score INTEGER NOT NULL DEFAULT 600;
+ALTER TABLE borrowers ADD COLUMN credit_on_time_installments INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE borrowers ADD COLUMN credit_late_installments INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE borrowers ADD COLUMN credit_days_past_due INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE borrowers ADD COLUMN credit_restructures INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE borrowers ADD COLUMN credit_write_offs INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE borrowers ADD COLUMN credit_score_updated_at DATETIME;
@@ -0,0 +1,3 @@
+ALTER TABLE borrowers DROP COLUMN blocked_by;
+ALTER TABLE borrowers DROP COLUMN blocked_at;
+ALTER TABLE borrowers DROP COLUMN blocked_reason;
@@ -0,0 +1,5 @@
+-- Bloqueo del prestatario: mientras `blocked_at` no sea NULL su estado es 'blocked'
+-- y no puede recibir préstamos nuevos; `blocked_by` es el usuario que lo bloqueó
+ALTER TABLE borrowers ADD COLUMN blocked_reason TEXT;
+ALTER TABLE borrowers ADD COLUMN blocked_at DATETIME;
+ALTER TABLE borrowers ADD COLUMN blocked_by INTEGER;
@@ -0,0 +1,8 @@
+ALTER TABLE payments DROP COLUMN deleted_by;
+ALTER TABLE payments DROP COLUMN deleted_at;
+
+ALTER TABLE loans DROP COLUMN deleted_by;
+ALTER TABLE loans DROP COLUMN deleted_at;
+
+ALTER TABLE borrowers DROP COLUMN deleted_by;
+ALTER TABLE borrowers DROP COLUMN deleted_at;
@@ -0,0 +1,10 @@
+-- Borrado lógico: las filas eliminadas conservan su historial y se pueden restaurar
+-- `deleted_by` es el usuario que eliminó el registro
+ALTER TABLE borrowers ADD COLUMN deleted_at DATETIME;
+ALTER TABLE borrowers ADD COLUMN deleted_by INTEGER;
+
+ALTER TABLE loans ADD COLUMN deleted_at DATETIME;
+ALTER TABLE loans ADD COLUMN deleted_by INTEGER;
+
+ALTER TABLE payments ADD COLUMN deleted_at DATETIME;
+ALTER TABLE payments ADD COLUMN deleted_by INTEGER;
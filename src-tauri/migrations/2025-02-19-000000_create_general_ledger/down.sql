@@ -0,0 +1,9 @@
+DROP INDEX idx_journal_lines_borrower_id;
+DROP INDEX idx_journal_lines_account_id;
+DROP INDEX idx_journal_lines_entry_id;
+DROP TABLE journal_lines;
+
+DROP INDEX idx_journal_entries_posted_at;
+DROP TABLE journal_entries;
+
+DROP TABLE accounts;
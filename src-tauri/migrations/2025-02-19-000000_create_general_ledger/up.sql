@@ -0,0 +1,64 @@
+-- Plan de cuentas del libro mayor
+-- `kind`: asset, liability, equity, income o expense
+-- Los saldos de una cuenta se llevan en CUP y además en `currency`, que solo difiere en la caja
+-- de monedas extranjeras (una cuenta 1100-<moneda> por moneda)
+CREATE TABLE accounts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    code TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    currency TEXT NOT NULL DEFAULT 'CUP',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO accounts (code, name, kind) VALUES
+    ('1200', 'Préstamos por cobrar', 'asset'),
+    ('1210', 'Recargos por cobrar', 'asset'),
+    ('1220', 'Intereses por cobrar', 'asset'),
+    ('3100', 'Saldos de apertura', 'equity'),
+    ('4100', 'Ingresos por intereses', 'income'),
+    ('4200', 'Ingresos por recargos', 'income'),
+    ('4300', 'Recuperación de cartera castigada', 'income'),
+    ('4400', 'Ganancias cambiarias', 'income'),
+    ('5100', 'Pérdidas por castigos', 'expense'),
+    ('5200', 'Pérdidas cambiarias', 'expense');
+
+INSERT INTO accounts (code, name, kind, currency)
+SELECT '1100-' || code, 'Caja ' || code, 'asset', code FROM currencies;
+
+-- Asientos del libro mayor; no se modifican ni se borran, los cambios se registran con asientos nuevos
+-- `event`: opening, disbursement, payment, interest, penalty, write_off, recovery o fx_revaluation
+-- `borrower_id`, `loan_id` y `payment_id` identifican lo que originó el asiento; no son claves foráneas
+-- para que el asiento sobreviva a la purga del registro
+-- `posted_at` es la fecha contable del hecho
+CREATE TABLE journal_entries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    event TEXT NOT NULL,
+    description TEXT NOT NULL,
+    borrower_id INTEGER,
+    loan_id INTEGER,
+    payment_id INTEGER,
+    posted_at DATETIME NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_journal_entries_posted_at ON journal_entries(posted_at);
+
+-- Partidas de cada asiento; en cada asiento los débitos y los créditos en CUP suman lo mismo
+-- `amount_cents` es el movimiento en la moneda de la cuenta, positivo si es un débito
+-- `borrower_id` lleva el auxiliar de cuentas por cobrar de cada prestatario
+CREATE TABLE journal_lines (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    entry_id INTEGER NOT NULL,
+    account_id INTEGER NOT NULL,
+    borrower_id INTEGER,
+    debit_cents BIGINT NOT NULL DEFAULT 0,
+    credit_cents BIGINT NOT NULL DEFAULT 0,
+    amount_cents BIGINT NOT NULL DEFAULT 0,
+    FOREIGN KEY (entry_id) REFERENCES journal_entries (id),
+    FOREIGN KEY (account_id) REFERENCES accounts (id)
+);
+
+CREATE INDEX idx_journal_lines_entry_id ON journal_lines(entry_id);
+CREATE INDEX idx_journal_lines_account_id ON journal_lines(account_id);
+CREATE INDEX idx_journal_lines_borrower_id ON journal_lines(borrower_id);
@@ -0,0 +1,12 @@
+DROP INDEX idx_cash_counts_currency;
+DROP TABLE cash_counts;
+
+DROP INDEX idx_cash_movements_opening;
+DROP INDEX idx_cash_movements_currency;
+DROP TABLE cash_movements;
+
+DELETE FROM journal_lines WHERE entry_id IN (
+    SELECT id FROM journal_entries WHERE event IN ('opening', 'cash_deposit', 'cash_withdrawal', 'cash_count')
+);
+DELETE FROM journal_entries WHERE event IN ('opening', 'cash_deposit', 'cash_withdrawal', 'cash_count');
+DELETE FROM accounts WHERE code IN ('3200', '4500', '5300');
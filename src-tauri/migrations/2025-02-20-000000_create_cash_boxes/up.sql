@@ -0,0 +1,49 @@
+-- Cuentas de las cajas: contrapartida de depósitos y retiros manuales, y diferencias de arqueo
+INSERT INTO accounts (code, name, kind) VALUES
+    ('3200', 'Aportes y retiros de caja', 'equity'),
+    ('4500', 'Sobrantes de caja', 'income'),
+    ('5300', 'Faltantes de caja', 'expense');
+
+-- Movimientos manuales de la caja de cada moneda: saldo de apertura, depósitos y retiros
+-- `kind`: opening, deposit o withdrawal; `amount_cents` siempre es positivo
+-- Las entradas por pagos y salidas por desembolsos no se guardan aquí: salen del libro mayor
+-- `entry_id` es el asiento que registró el movimiento en la cuenta de caja
+CREATE TABLE cash_movements (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    currency TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    amount_cents BIGINT NOT NULL,
+    exchange_rate REAL NOT NULL DEFAULT 1.0,
+    amount_cup_cents BIGINT NOT NULL,
+    reason TEXT NOT NULL,
+    entry_id INTEGER NOT NULL,
+    created_by INTEGER NOT NULL,
+    moved_at DATETIME NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (entry_id) REFERENCES journal_entries (id)
+);
+
+CREATE INDEX idx_cash_movements_currency ON cash_movements(currency);
+-- Cada caja se abre una sola vez
+CREATE UNIQUE INDEX idx_cash_movements_opening ON cash_movements(currency) WHERE kind = 'opening';
+
+-- Arqueos de caja al cierre del día
+-- `expected_cents` es el saldo según el libro mayor al momento del arqueo y
+-- `difference_cents` lo contado menos lo esperado; si no es cero se ajusta con el asiento `entry_id`
+CREATE TABLE cash_counts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    currency TEXT NOT NULL,
+    expected_cents BIGINT NOT NULL,
+    counted_cents BIGINT NOT NULL,
+    difference_cents BIGINT NOT NULL,
+    exchange_rate REAL NOT NULL DEFAULT 1.0,
+    difference_cup_cents BIGINT NOT NULL DEFAULT 0,
+    notes TEXT,
+    entry_id INTEGER,
+    counted_by INTEGER NOT NULL,
+    counted_at DATETIME NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (entry_id) REFERENCES journal_entries (id)
+);
+
+CREATE INDEX idx_cash_counts_currency ON cash_counts(currency, counted_at);
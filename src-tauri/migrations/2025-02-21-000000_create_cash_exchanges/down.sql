@@ -0,0 +1,5 @@
+DROP INDEX idx_cash_exchanges_exchanged_at;
+DROP TABLE cash_exchanges;
+
+DELETE FROM journal_lines WHERE entry_id IN (SELECT id FROM journal_entries WHERE event = 'cash_exchange');
+DELETE FROM journal_entries WHERE event = 'cash_exchange';
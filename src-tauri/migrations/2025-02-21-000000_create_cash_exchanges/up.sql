@@ -0,0 +1,29 @@
+-- Cambios de moneda entre las cajas: sale `source_amount_cents` de la caja `source_currency`
+-- y entra `target_amount_cents` a la caja `target_currency`
+-- `effective_rate` es lo recibido por cada unidad entregada y `official_rate` lo mismo según
+-- las tasas de `currencies` en la fecha del cambio; `spread` es la diferencia en porcentaje
+-- Importes en CUP: `source_cost_cup_cents` es el valor en libros de lo entregado,
+-- `target_cup_cents` el valor oficial de lo recibido, `spread_cup_cents` lo que el cambio
+-- se aparta de las tasas oficiales y `gain_cup_cents` la ganancia (o pérdida) realizada
+CREATE TABLE cash_exchanges (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    source_currency TEXT NOT NULL,
+    source_amount_cents BIGINT NOT NULL,
+    target_currency TEXT NOT NULL,
+    target_amount_cents BIGINT NOT NULL,
+    effective_rate DOUBLE NOT NULL,
+    official_rate DOUBLE NOT NULL,
+    spread DOUBLE NOT NULL,
+    source_cost_cup_cents BIGINT NOT NULL,
+    target_cup_cents BIGINT NOT NULL,
+    spread_cup_cents BIGINT NOT NULL,
+    gain_cup_cents BIGINT NOT NULL,
+    notes TEXT,
+    entry_id INTEGER NOT NULL,
+    created_by INTEGER NOT NULL,
+    exchanged_at DATETIME NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (entry_id) REFERENCES journal_entries (id)
+);
+
+CREATE INDEX idx_cash_exchanges_exchanged_at ON cash_exchanges(exchanged_at);
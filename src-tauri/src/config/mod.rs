@@ -15,6 +15,24 @@ pub struct AppConfig {
     pub env: String,
     pub name: String,
     pub master_password: String,
+    /// Estrategia por defecto para repartir pagos entre préstamos
+    pub allocation_strategy: String,
+}
+
+/// Umbrales de atraso y frecuencia de la tarea que recalcula los estados de cobranza
+#[derive(Debug, Clone)]
+pub struct CollectionsConfig {
+    pub overdue_after_days: i64,
+    pub default_after_days: i64,
+    pub check_interval_hours: u64,
+}
+
+/// Tope de lo que un prestatario puede garantizar en préstamos ajenos, en CUP (0 = sin tope)
+/// `limit_action` decide si superarlo solo advierte (`warn`) o rechaza al garante (`refuse`)
+#[derive(Debug, Clone)]
+pub struct GuarantorConfig {
+    pub exposure_limit: f64,
+    pub limit_action: String,
 }
 
 #[derive(Debug, Clone)]
@@ -22,6 +40,8 @@ pub struct Config {
     pub server: ServerConfig,
     pub app: AppConfig,
     pub database: DatabaseConfig,
+    pub collections: CollectionsConfig,
+    pub guarantors: GuarantorConfig,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -71,6 +91,57 @@ impl Config {
             "KeyMaster123".to_string()
         });
 
+        let allocation_strategy = env::var("PAYMENT_ALLOCATION_STRATEGY").unwrap_or_else(|_| {
+            println!("⚠️  PAYMENT_ALLOCATION_STRATEGY no encontrada, usando 'oldest_first'");
+            "oldest_first".to_string()
+        });
+
+        let overdue_after_days = env::var("LOAN_OVERDUE_AFTER_DAYS")
+            .unwrap_or_else(|_| {
+                println!("⚠️  LOAN_OVERDUE_AFTER_DAYS no encontrada, usando '1'");
+                "1".to_string()
+            })
+            .parse()
+            .unwrap_or_else(|_| {
+                println!("⚠️  LOAN_OVERDUE_AFTER_DAYS inválida, usando 1");
+                1
+            });
+        let default_after_days = env::var("LOAN_DEFAULT_AFTER_DAYS")
+            .unwrap_or_else(|_| {
+                println!("⚠️  LOAN_DEFAULT_AFTER_DAYS no encontrada, usando '90'");
+                "90".to_string()
+            })
+            .parse()
+            .unwrap_or_else(|_| {
+                println!("⚠️  LOAN_DEFAULT_AFTER_DAYS inválida, usando 90");
+                90
+            });
+        let check_interval_hours = env::var("STATUS_CHECK_INTERVAL_HOURS")
+            .unwrap_or_else(|_| {
+                println!("⚠️  STATUS_CHECK_INTERVAL_HOURS no encontrada, usando '24'");
+                "24".to_string()
+            })
+            .parse()
+            .unwrap_or_else(|_| {
+                println!("⚠️  STATUS_CHECK_INTERVAL_HOURS inválida, usando 24");
+                24
+            });
+
+        let guarantor_exposure_limit = env::var("GUARANTOR_EXPOSURE_LIMIT")
+            .unwrap_or_else(|_| {
+                println!("⚠️  GUARANTOR_EXPOSURE_LIMIT no encontrada, usando '0' (sin tope)");
+                "0".to_string()
+            })
+            .parse()
+            .unwrap_or_else(|_| {
+                println!("⚠️  GUARANTOR_EXPOSURE_LIMIT inválida, usando 0 (sin tope)");
+                0.0
+            });
+        let guarantor_limit_action = env::var("GUARANTOR_LIMIT_ACTION").unwrap_or_else(|_| {
+            println!("⚠️  GUARANTOR_LIMIT_ACTION no encontrada, usando 'warn'");
+            "warn".to_string()
+        });
+
         let cors_origins = env::var("SERVER_CORS_ORIGINS")
             .unwrap_or_else(|_| {
                 println!("⚠️  SERVER_CORS_ORIGINS no encontrada, usando valores por defecto");
@@ -91,9 +162,19 @@ impl Config {
             app: AppConfig {
                 env: app_env,
                 name: app_name,
-                master_password
+                master_password,
+                allocation_strategy,
             },
             database: DatabaseConfig { url: url_db },
+            collections: CollectionsConfig {
+                overdue_after_days,
+                default_after_days,
+                check_interval_hours,
+            },
+            guarantors: GuarantorConfig {
+                exposure_limit: guarantor_exposure_limit,
+                limit_action: guarantor_limit_action,
+            },
         };
         config
     }
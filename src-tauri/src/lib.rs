@@ -80,6 +80,11 @@ pub async fn run() -> Result<()> {
                 }
             });
 
+            // Junto al servidor, la tarea diaria que marca préstamos vencidos, cobra recargos y actualiza el estado de los prestatarios
+            tauri::async_runtime::spawn(async move {
+                server::tasks::run_loan_status_task().await;
+            });
+
             Ok(())
         })
         // 4. Registrar todos los comandos invocables desde el frontend
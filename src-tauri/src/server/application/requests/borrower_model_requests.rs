@@ -1,7 +1,12 @@
 use serde::Deserialize;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use crate::server::domain::entities::borrower::Borrower;
+use crate::server::domain::entities::borrower::{
+    Borrower, BorrowerBlock, BorrowerPage, BorrowerSortField, CreditScore, RiskTier, SortDirection,
+    TotalsMismatch, TotalsReconciliation,
+};
+use crate::server::domain::services::credit_scorer::{CreditScorer, ScoreFactor};
+use crate::server::domain::value_objects::money::Money;
 
 #[derive(Debug, Serialize)]
 pub struct BorrowerModelResponse {
@@ -11,10 +16,24 @@ pub struct BorrowerModelResponse {
     pub location: String,
     pub total_loans: f64,
     pub total_paid: f64,
+    pub total_penalties: f64,
+    pub written_off_total: f64,
+    pub recovered_total: f64,
+    pub guarantee_exposure: f64,
+    pub credit_limit: Option<f64>,
+    pub risk_tier: String,
+    pub credit_score: CreditScoreModelResponse,
     pub balance: f64,
     pub status: String,
+    pub block: Option<BorrowerBlockModelResponse>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Fecha del borrado lógico y usuario que lo hizo, si está eliminado
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<i32>,
+    /// Advertencias sobre posibles duplicados de prestatarios bloqueados que no impidieron el registro
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 impl From<Borrower> for BorrowerModelResponse {
@@ -33,16 +52,154 @@ impl From<Borrower> for BorrowerModelResponse {
             phone: borrower.phone,
             name: borrower.name,
             location: borrower.location,
-            total_loans: borrower.total_loans,
-            total_paid: borrower.total_paid,
-            balance: borrower.balance,
+            total_loans: borrower.total_loans.to_major(),
+            total_paid: borrower.total_paid.to_major(),
+            total_penalties: borrower.total_penalties.to_major(),
+            written_off_total: borrower.written_off_total.to_major(),
+            recovered_total: borrower.recovered_total.to_major(),
+            guarantee_exposure: borrower.guarantee_exposure.to_major(),
+            credit_limit: borrower.credit_limit.map(|limit| limit.to_major()),
+            risk_tier: borrower.risk_tier.as_str().to_string(),
+            credit_score: CreditScoreModelResponse::from(borrower.credit_score),
+            balance: borrower.balance.to_major(),
             status: borrower.status,
+            block: borrower.block.map(BorrowerBlockModelResponse::from),
             created_at,
             updated_at,
+            deleted_at: borrower.deleted_at.as_deref().map(parse_rfc3339),
+            deleted_by: borrower.deleted_by,
+            warnings: Vec::new(),
         }
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct BorrowerBlockModelResponse {
+    pub reason: String,
+    pub blocked_by: i32,
+    pub blocked_at: DateTime<Utc>,
+}
+
+impl From<BorrowerBlock> for BorrowerBlockModelResponse {
+    fn from(block: BorrowerBlock) -> Self {
+        Self {
+            reason: block.reason,
+            blocked_by: block.blocked_by,
+            blocked_at: parse_rfc3339(&block.blocked_at),
+        }
+    }
+}
+
+/// Puntaje de crédito con el aporte de cada factor
+#[derive(Debug, Serialize)]
+pub struct CreditScoreModelResponse {
+    pub score: i32,
+    pub factors: Vec<CreditScoreFactorModelResponse>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreditScoreFactorModelResponse {
+    pub factor: String,
+    pub value: i32,
+    pub points: i32,
+}
+
+impl From<CreditScore> for CreditScoreModelResponse {
+    fn from(credit_score: CreditScore) -> Self {
+        Self {
+            score: credit_score.score,
+            factors: CreditScorer::breakdown(&credit_score.factors)
+                .into_iter()
+                .map(CreditScoreFactorModelResponse::from)
+                .collect(),
+            updated_at: credit_score.updated_at.as_deref().map(parse_rfc3339),
+        }
+    }
+}
+
+impl From<ScoreFactor> for CreditScoreFactorModelResponse {
+    fn from(factor: ScoreFactor) -> Self {
+        Self {
+            factor: factor.factor.to_string(),
+            value: factor.value,
+            points: factor.points,
+        }
+    }
+}
+
+/// Página del listado de prestatarios; `page_size` falta si se pidieron todas las coincidencias
+#[derive(Debug, Serialize)]
+pub struct BorrowerPageModelResponse {
+    pub items: Vec<BorrowerModelResponse>,
+    pub total: i64,
+    pub page: i64,
+    pub page_size: Option<i64>,
+}
+
+impl BorrowerPageModelResponse {
+    pub fn from_page(page: BorrowerPage, number: i64, page_size: Option<i64>) -> Self {
+        Self {
+            items: page.borrowers.into_iter().map(BorrowerModelResponse::from).collect(),
+            total: page.total,
+            page: number,
+            page_size,
+        }
+    }
+}
+
+/// Diferencia en un total de un prestatario; los montos están en CUP
+/// `difference` es lo que hay que sumar a lo guardado para llegar a lo esperado
+#[derive(Debug, Serialize)]
+pub struct TotalsMismatchModelResponse {
+    pub borrower_id: i32,
+    pub name: String,
+    pub field: String,
+    pub stored: f64,
+    pub expected: f64,
+    pub difference: f64,
+}
+
+impl From<TotalsMismatch> for TotalsMismatchModelResponse {
+    fn from(mismatch: TotalsMismatch) -> Self {
+        let difference = Money::from_minor(
+            mismatch.expected.minor_units().saturating_sub(mismatch.stored.minor_units()),
+            mismatch.stored.currency(),
+        );
+        Self {
+            borrower_id: mismatch.borrower_id,
+            name: mismatch.name,
+            field: mismatch.field.to_string(),
+            stored: mismatch.stored.to_major(),
+            expected: mismatch.expected.to_major(),
+            difference: difference.to_major(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TotalsReconciliationModelResponse {
+    pub checked: i64,
+    pub mismatches: Vec<TotalsMismatchModelResponse>,
+    pub fixed: bool,
+}
+
+impl From<TotalsReconciliation> for TotalsReconciliationModelResponse {
+    fn from(reconciliation: TotalsReconciliation) -> Self {
+        Self {
+            checked: reconciliation.checked,
+            mismatches: reconciliation.mismatches.into_iter().map(TotalsMismatchModelResponse::from).collect(),
+            fixed: reconciliation.fixed,
+        }
+    }
+}
+
+fn parse_rfc3339(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateBorrowerRequest {
     pub phone: String,
@@ -50,9 +207,33 @@ pub struct CreateBorrowerRequest {
     pub location: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// `credit_limit` está en CUP; 0 quita el límite
+#[derive(Debug, Clone)]
 pub struct UpdateBorrowerRequest {
     pub phone: Option<String>,
     pub name: Option<String>,
     pub location: Option<String>,
+    pub credit_limit: Option<f64>,
+    pub risk_tier: Option<RiskTier>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockBorrowerRequest {
+    pub reason: String,
+}
+
+/// Criterios del listado de prestatarios; los saldos están en CUP
+#[derive(Debug, Clone)]
+pub struct SearchBorrowersRequest {
+    pub search: Option<String>,
+    pub status: Option<String>,
+    pub min_balance: Option<f64>,
+    pub max_balance: Option<f64>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub deleted: bool,
+    pub sort: Option<BorrowerSortField>,
+    pub direction: Option<SortDirection>,
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
 }
\ No newline at end of file
@@ -0,0 +1,246 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::domain::entities::cash_box::{CashBoxLine, CashCount, CashExchange, CashMovement};
+
+/// Saldo de apertura, depósito o retiro manual; `amount` está en la moneda de la caja
+#[derive(Debug, Clone)]
+pub struct CashMovementRequest {
+    pub amount: f64,
+    pub reason: String,
+    pub created_by: i32,
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Arqueo de la caja; `counted` es el efectivo contado en la moneda de la caja
+#[derive(Debug, Clone)]
+pub struct CashCountRequest {
+    pub counted: f64,
+    pub notes: Option<String>,
+    pub counted_by: i32,
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Caja de una moneda; `balance` está en la moneda y `balance_cup` es su valor en libros
+#[derive(Debug, Serialize)]
+pub struct CashBoxModelResponse {
+    pub currency: String,
+    pub name: String,
+    pub opening: Option<CashMovementModelResponse>,
+    pub balance: f64,
+    pub balance_cup: f64,
+    pub last_count: Option<CashCountModelResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashMovementModelResponse {
+    pub id: i32,
+    pub currency: String,
+    pub kind: String,
+    pub amount: f64,
+    pub exchange_rate: f32,
+    pub amount_cup: f64,
+    pub reason: String,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub moved_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CashMovement> for CashMovementModelResponse {
+    fn from(movement: CashMovement) -> Self {
+        Self {
+            id: movement.id,
+            currency: movement.currency,
+            kind: movement.kind,
+            amount: movement.amount.to_major(),
+            exchange_rate: movement.exchange_rate,
+            amount_cup: movement.amount_cup.to_major(),
+            reason: movement.reason,
+            entry_id: movement.entry_id,
+            created_by: movement.created_by,
+            moved_at: parse_rfc3339(&movement.moved_at),
+            created_at: parse_rfc3339(&movement.created_at),
+        }
+    }
+}
+
+/// Arqueo de caja; una diferencia positiva es un sobrante y una negativa un faltante
+#[derive(Debug, Serialize)]
+pub struct CashCountModelResponse {
+    pub id: i32,
+    pub currency: String,
+    pub expected: f64,
+    pub counted: f64,
+    pub difference: f64,
+    pub exchange_rate: f32,
+    pub difference_cup: f64,
+    pub notes: Option<String>,
+    pub entry_id: Option<i32>,
+    pub counted_by: i32,
+    pub counted_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CashCount> for CashCountModelResponse {
+    fn from(count: CashCount) -> Self {
+        Self {
+            id: count.id,
+            currency: count.currency,
+            expected: count.expected.to_major(),
+            counted: count.counted.to_major(),
+            difference: count.difference.to_major(),
+            exchange_rate: count.exchange_rate,
+            difference_cup: count.difference_cup.to_major(),
+            notes: count.notes,
+            entry_id: count.entry_id,
+            counted_by: count.counted_by,
+            counted_at: parse_rfc3339(&count.counted_at),
+            created_at: parse_rfc3339(&count.created_at),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashBoxLineModelResponse {
+    pub entry_id: i32,
+    pub event: String,
+    pub description: String,
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub payment_id: Option<i32>,
+    pub posted_at: DateTime<Utc>,
+    pub amount: f64,
+    pub amount_cup: f64,
+    pub balance: f64,
+}
+
+impl From<CashBoxLine> for CashBoxLineModelResponse {
+    fn from(line: CashBoxLine) -> Self {
+        Self {
+            entry_id: line.entry_id,
+            event: line.event,
+            description: line.description,
+            borrower_id: line.borrower_id,
+            loan_id: line.loan_id,
+            payment_id: line.payment_id,
+            posted_at: parse_rfc3339(&line.posted_at),
+            amount: line.amount.to_major(),
+            amount_cup: line.amount_cup.to_major(),
+            balance: line.balance.to_major(),
+        }
+    }
+}
+
+/// Movimientos de la caja en un período con los saldos al inicio y al cierre, en la moneda de la caja
+#[derive(Debug, Serialize)]
+pub struct CashStatementModelResponse {
+    pub currency: String,
+    pub from: Option<DateTime<Utc>>,
+    pub to: DateTime<Utc>,
+    pub opening_balance: f64,
+    pub total_in: f64,
+    pub total_out: f64,
+    pub closing_balance: f64,
+    pub lines: Vec<CashBoxLineModelResponse>,
+}
+
+/// Cambio de moneda entre cajas; se indica lo recibido (`target_amount`) o la tasa aplicada
+/// (`rate`, lo recibido por cada unidad entregada), no ambos
+#[derive(Debug, Clone)]
+pub struct CashExchangeRequest {
+    pub source_currency: String,
+    pub source_amount: f64,
+    pub target_currency: String,
+    pub target_amount: Option<f64>,
+    pub rate: Option<f64>,
+    pub notes: Option<String>,
+    pub created_by: i32,
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Criterios del reporte de cambios; con `currency` solo los que la entregan o la reciben
+#[derive(Debug, Clone, Default)]
+pub struct SearchCashExchangesRequest {
+    pub currency: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashExchangeModelResponse {
+    pub id: i32,
+    pub source_currency: String,
+    pub source_amount: f64,
+    pub target_currency: String,
+    pub target_amount: f64,
+    pub effective_rate: f64,
+    pub official_rate: f64,
+    pub spread: f64,
+    pub source_cost_cup: f64,
+    pub target_cup: f64,
+    pub spread_cup: f64,
+    pub gain_cup: f64,
+    pub notes: Option<String>,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub exchanged_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CashExchange> for CashExchangeModelResponse {
+    fn from(exchange: CashExchange) -> Self {
+        Self {
+            id: exchange.id,
+            source_currency: exchange.source.currency().to_string(),
+            source_amount: exchange.source.to_major(),
+            target_currency: exchange.target.currency().to_string(),
+            target_amount: exchange.target.to_major(),
+            effective_rate: exchange.effective_rate,
+            official_rate: exchange.official_rate,
+            spread: exchange.spread,
+            source_cost_cup: exchange.source_cost_cup.to_major(),
+            target_cup: exchange.target_cup.to_major(),
+            spread_cup: exchange.spread_cup.to_major(),
+            gain_cup: exchange.gain_cup.to_major(),
+            notes: exchange.notes,
+            entry_id: exchange.entry_id,
+            created_by: exchange.created_by,
+            exchanged_at: parse_rfc3339(&exchange.exchanged_at),
+            created_at: parse_rfc3339(&exchange.created_at),
+        }
+    }
+}
+
+/// Cambios de un par de monedas en el período; `average_rate` es lo recibido por unidad entregada
+#[derive(Debug, Serialize)]
+pub struct CashExchangePairModelResponse {
+    pub source_currency: String,
+    pub target_currency: String,
+    pub count: usize,
+    pub source_amount: f64,
+    pub target_amount: f64,
+    pub average_rate: f64,
+    pub spread_cup: f64,
+    pub gain_cup: f64,
+}
+
+/// Ganancias y pérdidas cambiarias realizadas en los cambios del período, en CUP
+#[derive(Debug, Serialize)]
+pub struct CashExchangeReportModelResponse {
+    pub currency: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub count: usize,
+    pub realized_gain: f64,
+    pub realized_loss: f64,
+    pub net_gain: f64,
+    pub spread_cup: f64,
+    pub pairs: Vec<CashExchangePairModelResponse>,
+    pub exchanges: Vec<CashExchangeModelResponse>,
+}
+
+fn parse_rfc3339(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::domain::entities::collateral::Collateral;
+
+/// Garantía de un préstamo; `estimated_value` está en `currency`
+#[derive(Debug, Serialize)]
+pub struct CollateralModelResponse {
+    pub id: i32,
+    pub loan_id: i32,
+    pub description: String,
+    pub estimated_value: f64,
+    pub currency: String,
+    pub storage_location: String,
+    pub status: String,
+    pub payment_id: Option<i32>,
+    pub returned_at: Option<DateTime<Utc>>,
+    pub liquidated_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Collateral> for CollateralModelResponse {
+    fn from(collateral: Collateral) -> Self {
+        Self {
+            id: collateral.id,
+            loan_id: collateral.loan_id,
+            description: collateral.description,
+            estimated_value: collateral.estimated_value.to_major(),
+            currency: collateral.estimated_value.currency().to_string(),
+            storage_location: collateral.storage_location,
+            status: collateral.status,
+            payment_id: collateral.payment_id,
+            returned_at: collateral.returned_at.as_deref().map(parse_rfc3339),
+            liquidated_at: collateral.liquidated_at.as_deref().map(parse_rfc3339),
+            created_at: parse_rfc3339(&collateral.created_at),
+            updated_at: parse_rfc3339(&collateral.updated_at),
+        }
+    }
+}
+
+/// Sin `currency` la garantía se tasa en la moneda del préstamo
+#[derive(Debug, Clone)]
+pub struct RegisterCollateralRequest {
+    pub loan_id: i32,
+    pub description: String,
+    pub estimated_value: f64,
+    pub currency: Option<String>,
+    pub storage_location: String,
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// `amount` es lo obtenido al venderla; sin `currency` se usa la moneda en que se tasó
+#[derive(Debug, Clone)]
+pub struct LiquidateCollateralRequest {
+    pub amount: f64,
+    pub currency: Option<String>,
+    pub date: Option<DateTime<Utc>>,
+}
+
+fn parse_rfc3339(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
@@ -0,0 +1,81 @@
+use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::domain::entities::currency::Currency;
+use crate::server::domain::entities::exchange_rate::ExchangeRate;
+
+#[derive(Debug, Serialize)]
+pub struct CurrencyModelResponse {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub exchange_rate: f32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Currency> for CurrencyModelResponse {
+    fn from(currency: Currency) -> Self {
+        let updated_at = DateTime::parse_from_rfc3339(&currency.updated_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        Self {
+            id: currency.id,
+            code: currency.code,
+            name: currency.name,
+            exchange_rate: currency.exchange_rate,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateCurrencyRequest {
+    pub code: String,
+    pub name: String,
+    pub exchange_rate: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateCurrencyRequest {
+    pub code: Option<String>,
+    pub name: Option<String>,
+    pub exchange_rate: Option<f32>,
+}
+
+
+#[derive(Debug, Serialize)]
+pub struct ExchangeRateModelResponse {
+    pub id: i32,
+    pub currency_code: String,
+    pub exchange_rate: f32,
+    pub effective_from: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ExchangeRate> for ExchangeRateModelResponse {
+    fn from(rate: ExchangeRate) -> Self {
+        let effective_from = DateTime::parse_from_rfc3339(&rate.effective_from)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        let created_at = DateTime::parse_from_rfc3339(&rate.created_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        Self {
+            id: rate.id,
+            currency_code: rate.currency_code,
+            exchange_rate: rate.exchange_rate,
+            effective_from,
+            created_at,
+        }
+    }
+}
+
+/// Tasa a registrar en el historial; sin `effective_from` rige desde ahora
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordExchangeRateRequest {
+    pub exchange_rate: f32,
+    pub effective_from: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::ledger::{AccountBalance, BorrowerReceivable, JournalEntry, JournalLine};
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::Result;
+
+/// Criterios de consulta del libro diario
+#[derive(Debug, Clone, Default)]
+pub struct SearchJournalRequest {
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub event: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AccountBalanceModelResponse {
+    pub code: String,
+    pub name: String,
+    pub kind: String,
+    pub currency: String,
+    pub debit: f64,
+    pub credit: f64,
+    pub balance_cup: f64,
+    pub balance: f64,
+}
+
+impl From<AccountBalance> for AccountBalanceModelResponse {
+    fn from(balance: AccountBalance) -> Self {
+        Self {
+            code: balance.account.code,
+            name: balance.account.name,
+            kind: balance.account.kind,
+            currency: balance.account.currency,
+            debit: balance.debit.to_major(),
+            credit: balance.credit.to_major(),
+            balance_cup: balance.balance_cup.to_major(),
+            balance: balance.balance.to_major(),
+        }
+    }
+}
+
+/// Balance de comprobación: los débitos y créditos acumulados en CUP deben ser iguales
+#[derive(Debug, Serialize)]
+pub struct TrialBalanceModelResponse {
+    pub at: DateTime<Utc>,
+    pub accounts: Vec<AccountBalanceModelResponse>,
+    pub total_debit: f64,
+    pub total_credit: f64,
+    pub balanced: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JournalLineModelResponse {
+    pub account_code: String,
+    pub account_name: String,
+    pub borrower_id: Option<i32>,
+    pub debit: f64,
+    pub credit: f64,
+    pub currency: String,
+    pub amount: f64,
+}
+
+impl From<JournalLine> for JournalLineModelResponse {
+    fn from(line: JournalLine) -> Self {
+        Self {
+            account_code: line.account_code,
+            account_name: line.account_name,
+            borrower_id: line.borrower_id,
+            debit: line.debit.to_major(),
+            credit: line.credit.to_major(),
+            currency: line.amount.currency().to_string(),
+            amount: line.amount.to_major(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct JournalEntryModelResponse {
+    pub id: i32,
+    pub event: String,
+    pub description: String,
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub payment_id: Option<i32>,
+    pub posted_at: DateTime<Utc>,
+    pub lines: Vec<JournalLineModelResponse>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<JournalEntry> for JournalEntryModelResponse {
+    fn from(entry: JournalEntry) -> Self {
+        Self {
+            id: entry.id,
+            event: entry.event,
+            description: entry.description,
+            borrower_id: entry.borrower_id,
+            loan_id: entry.loan_id,
+            payment_id: entry.payment_id,
+            posted_at: parse_rfc3339(&entry.posted_at),
+            lines: entry.lines.into_iter().map(JournalLineModelResponse::from).collect(),
+            created_at: parse_rfc3339(&entry.created_at),
+        }
+    }
+}
+
+/// Saldo de un prestatario según el libro mayor frente al guardado en su ficha
+#[derive(Debug, Serialize)]
+pub struct BorrowerReceivableModelResponse {
+    pub borrower_id: i32,
+    pub name: String,
+    pub ledger_balance: f64,
+    pub stored_balance: f64,
+    pub difference: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReceivablesModelResponse {
+    pub borrowers: Vec<BorrowerReceivableModelResponse>,
+    pub total_ledger_balance: f64,
+    pub total_stored_balance: f64,
+    pub balanced: bool,
+}
+
+impl ReceivablesModelResponse {
+    pub fn from_receivables(receivables: Vec<BorrowerReceivable>) -> Result<Self> {
+        let mut borrowers = Vec::with_capacity(receivables.len());
+        let mut total_ledger = Money::zero(BASE_CURRENCY);
+        let mut total_stored = Money::zero(BASE_CURRENCY);
+        let mut balanced = true;
+
+        for receivable in receivables {
+            let difference = receivable.ledger_balance.checked_sub(&receivable.stored_balance)?;
+            balanced &= difference.minor_units() == 0;
+            total_ledger = total_ledger.checked_add(&receivable.ledger_balance)?;
+            total_stored = total_stored.checked_add(&receivable.stored_balance)?;
+
+            borrowers.push(BorrowerReceivableModelResponse {
+                borrower_id: receivable.borrower_id,
+                name: receivable.name,
+                ledger_balance: receivable.ledger_balance.to_major(),
+                stored_balance: receivable.stored_balance.to_major(),
+                difference: difference.to_major(),
+            });
+        }
+
+        Ok(Self {
+            borrowers,
+            total_ledger_balance: total_ledger.to_major(),
+            total_stored_balance: total_stored.to_major(),
+            balanced,
+        })
+    }
+}
+
+fn parse_rfc3339(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
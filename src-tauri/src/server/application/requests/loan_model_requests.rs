@@ -0,0 +1,348 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::borrower_model_requests::BorrowerModelResponse;
+use crate::server::domain::entities::borrower::Borrower;
+use crate::server::application::services::interest_calculator::InterestBreakdown;
+use crate::server::domain::entities::loan::{
+    InterestMethod, Loan, LoanWriteOff, RatePeriod, ScheduleTerms,
+};
+use crate::server::domain::entities::loan_guarantor::LoanGuarantor;
+use crate::server::domain::entities::loan_installment::LoanInstallment;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::Result;
+
+#[derive(Debug, Serialize)]
+pub struct LoanModelResponse {
+    pub id: i32,
+    pub amount: f64,
+    pub currency: String,
+    pub exchange_rate: f32,
+    pub amount_cup: f64,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub installment_frequency: Option<String>,
+    pub installment_count: Option<i32>,
+    pub first_due_date: Option<DateTime<Utc>>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub remaining: f64,
+    pub status: String,
+    pub borrower: BorrowerModelResponse,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Fecha del borrado lógico y usuario que lo hizo, si está eliminado
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<i32>,
+    pub restructured_from_id: Option<i32>,
+    pub restructured_at: Option<DateTime<Utc>>,
+    pub write_off: Option<LoanWriteOffModelResponse>,
+    pub product_id: Option<i32>,
+    /// Administrador que autorizó el préstamo por encima del límite de crédito
+    pub credit_limit_override_by: Option<i32>,
+    /// Valor en CUP de las garantías en custodia y saldo pendiente como porcentaje de ese valor
+    /// Solo se calculan en el detalle del préstamo y si tiene garantías en custodia
+    pub collateral_value: Option<f64>,
+    pub loan_to_value: Option<f64>,
+    /// Advertencias sobre los garantes que no impidieron registrar el préstamo
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl LoanModelResponse {
+    /// Combina el préstamo con su prestatario, tal como lo espera el frontend
+    pub fn from_parts(loan: Loan, borrower: Borrower) -> Self {
+        let created_at = DateTime::parse_from_rfc3339(&loan.created_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        let updated_at = DateTime::parse_from_rfc3339(&loan.updated_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        Self {
+            id: loan.id,
+            amount: loan.amount.to_major(),
+            currency: loan.amount.currency().to_string(),
+            exchange_rate: loan.exchange_rate,
+            amount_cup: loan.amount_cup.to_major(),
+            interest_rate: loan.interest.rate,
+            rate_period: loan.interest.period.as_str().to_string(),
+            interest_method: loan.interest.method.as_str().to_string(),
+            installment_frequency: loan.schedule.map(|schedule| schedule.frequency.as_str().to_string()),
+            installment_count: loan.schedule.map(|schedule| schedule.periods),
+            first_due_date: loan.schedule.map(|schedule| schedule.first_due_date.and_utc()),
+            due_date: loan.due_date.as_deref().map(parse_rfc3339),
+            remaining: loan.remaining.to_major(),
+            status: loan.status,
+            borrower: BorrowerModelResponse::from(borrower),
+            created_at,
+            updated_at,
+            deleted_at: loan.deleted_at.as_deref().map(parse_rfc3339),
+            deleted_by: loan.deleted_by,
+            restructured_from_id: loan.restructured_from_id,
+            restructured_at: loan.restructured_at.as_deref().map(parse_rfc3339),
+            write_off: loan.write_off.map(LoanWriteOffModelResponse::from),
+            product_id: loan.product_id,
+            credit_limit_override_by: loan.credit_limit_override_by,
+            collateral_value: None,
+            loan_to_value: None,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+/// `amount` está en la moneda del préstamo; `amount_cup` y `recovered` en CUP
+#[derive(Debug, Serialize)]
+pub struct LoanWriteOffModelResponse {
+    pub amount: f64,
+    pub amount_cup: f64,
+    pub recovered: f64,
+    pub reason: String,
+    pub written_off_by: i32,
+    pub written_off_at: DateTime<Utc>,
+}
+
+impl From<LoanWriteOff> for LoanWriteOffModelResponse {
+    fn from(write_off: LoanWriteOff) -> Self {
+        Self {
+            amount: write_off.amount.to_major(),
+            amount_cup: write_off.amount_cup.to_major(),
+            recovered: write_off.recovered_cup.to_major(),
+            reason: write_off.reason,
+            written_off_by: write_off.written_off_by,
+            written_off_at: parse_rfc3339(&write_off.written_off_at),
+        }
+    }
+}
+
+/// `date` permite registrar con fecha anterior; si falta se usa la fecha actual
+/// Con `product_id` las condiciones que falten se toman del producto; sin él,
+/// el préstamo es en CUP y sin interés
+/// `credit_limit_override_by` es el administrador que autoriza superar el límite de crédito
+#[derive(Debug, Clone)]
+pub struct CreateLoanRequest {
+    pub amount: f64,
+    pub currency: Option<String>,
+    pub interest_rate: Option<f64>,
+    pub rate_period: Option<RatePeriod>,
+    pub interest_method: Option<InterestMethod>,
+    pub schedule: Option<ScheduleTerms>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub borrower_id: i32,
+    pub product_id: Option<i32>,
+    pub guarantors: Vec<GuarantorRequest>,
+    pub credit_limit_override_by: Option<i32>,
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Garante registrado (`borrower_id`) o contacto sin ficha (`contact_name`)
+/// `amount` está en la moneda del préstamo
+#[derive(Debug, Clone)]
+pub struct GuarantorRequest {
+    pub borrower_id: Option<i32>,
+    pub contact_name: Option<String>,
+    pub contact_phone: Option<String>,
+    pub amount: f64,
+}
+
+/// `amount` está en la moneda del préstamo
+#[derive(Debug, Serialize)]
+pub struct LoanGuarantorModelResponse {
+    pub id: i32,
+    pub loan_id: i32,
+    pub borrower_id: Option<i32>,
+    pub contact_name: Option<String>,
+    pub contact_phone: Option<String>,
+    pub amount: f64,
+    pub currency: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl From<LoanGuarantor> for LoanGuarantorModelResponse {
+    fn from(guarantor: LoanGuarantor) -> Self {
+        Self {
+            id: guarantor.id,
+            loan_id: guarantor.loan_id,
+            borrower_id: guarantor.borrower_id,
+            contact_name: guarantor.contact_name,
+            contact_phone: guarantor.contact_phone,
+            amount: guarantor.amount.to_major(),
+            currency: guarantor.amount.currency().to_string(),
+            created_at: parse_rfc3339(&guarantor.created_at),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateLoanRequest {
+    pub amount: Option<f64>,
+    pub currency: Option<String>,
+    pub interest_rate: Option<f64>,
+    pub rate_period: Option<RatePeriod>,
+    pub interest_method: Option<InterestMethod>,
+    pub schedule: Option<ScheduleTerms>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub borrower_id: Option<i32>,
+    pub credit_limit_override_by: Option<i32>,
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Condiciones del préstamo que reemplaza al reestructurado; las que falten se toman del original
+/// Sin capitalizar, el interés pendiente se condona y los recargos siguen cobrándose aparte
+#[derive(Debug, Clone)]
+pub struct RestructureLoanRequest {
+    pub interest_rate: Option<f64>,
+    pub rate_period: Option<RatePeriod>,
+    pub interest_method: Option<InterestMethod>,
+    pub schedule: Option<ScheduleTerms>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub capitalize_interest: bool,
+    pub capitalize_penalties: bool,
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// `date` permite castigar con fecha anterior; los pagos desde esa fecha pasan a ser recuperaciones
+#[derive(Debug, Clone)]
+pub struct WriteOffLoanRequest {
+    pub reason: String,
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Resultado de una reestructuración; los importes están en la moneda del préstamo
+#[derive(Debug, Serialize)]
+pub struct LoanRestructureModelResponse {
+    pub previous_loan: LoanModelResponse,
+    pub loan: LoanModelResponse,
+    pub carried_principal: f64,
+    pub capitalized_interest: f64,
+    pub capitalized_penalties: f64,
+}
+
+/// Interés devengado y total adeudado de un préstamo a una fecha, en la moneda del préstamo
+#[derive(Debug, Serialize)]
+pub struct LoanInterestModelResponse {
+    pub loan_id: i32,
+    pub currency: String,
+    pub as_of: DateTime<Utc>,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub principal: f64,
+    pub accrued_interest: f64,
+    pub interest_paid: f64,
+    pub principal_paid: f64,
+    pub outstanding_principal: f64,
+    pub outstanding_interest: f64,
+    pub total_due: f64,
+}
+
+impl LoanInterestModelResponse {
+    pub fn from_breakdown(loan: &Loan, as_of: DateTime<Utc>, breakdown: InterestBreakdown) -> Self {
+        Self {
+            loan_id: loan.id,
+            currency: loan.amount.currency().to_string(),
+            as_of,
+            interest_rate: loan.interest.rate,
+            rate_period: loan.interest.period.as_str().to_string(),
+            interest_method: loan.interest.method.as_str().to_string(),
+            principal: breakdown.principal.to_major(),
+            accrued_interest: breakdown.accrued_interest.to_major(),
+            interest_paid: breakdown.interest_paid.to_major(),
+            principal_paid: breakdown.principal_paid.to_major(),
+            outstanding_principal: breakdown.outstanding_principal.to_major(),
+            outstanding_interest: breakdown.outstanding_interest.to_major(),
+            total_due: breakdown.total_due.to_major(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoanInstallmentModelResponse {
+    pub id: i32,
+    pub number: i32,
+    pub due_date: DateTime<Utc>,
+    pub principal: f64,
+    pub interest: f64,
+    pub total: f64,
+    pub paid: f64,
+    pub status: String,
+    pub paid_at: Option<DateTime<Utc>>,
+}
+
+impl LoanInstallmentModelResponse {
+    pub fn from_installment(installment: LoanInstallment) -> Result<Self> {
+        Ok(Self {
+            id: installment.id,
+            number: installment.number,
+            due_date: parse_rfc3339(&installment.due_date),
+            principal: installment.principal.to_major(),
+            interest: installment.interest.to_major(),
+            total: installment.total()?.to_major(),
+            paid: installment.paid.to_major(),
+            status: installment.status,
+            paid_at: installment.paid_at.as_deref().map(parse_rfc3339),
+        })
+    }
+}
+
+/// Plan de cuotas de un préstamo con sus totales, en la moneda del préstamo
+#[derive(Debug, Serialize)]
+pub struct LoanScheduleModelResponse {
+    pub loan_id: i32,
+    pub currency: String,
+    pub frequency: String,
+    pub installments: Vec<LoanInstallmentModelResponse>,
+    pub total_principal: f64,
+    pub total_interest: f64,
+    pub total_paid: f64,
+    pub total_outstanding: f64,
+}
+
+impl LoanScheduleModelResponse {
+    pub fn from_parts(loan: &Loan, schedule: &ScheduleTerms, installments: Vec<LoanInstallment>) -> Result<Self> {
+        let currency = loan.amount.currency();
+        let mut total_principal = Money::zero(currency);
+        let mut total_interest = Money::zero(currency);
+        let mut total_paid = Money::zero(currency);
+
+        for installment in &installments {
+            total_principal = total_principal.checked_add(&installment.principal)?;
+            total_interest = total_interest.checked_add(&installment.interest)?;
+            total_paid = total_paid.checked_add(&installment.paid)?;
+        }
+
+        let total_outstanding = total_principal.checked_add(&total_interest)?.checked_sub(&total_paid)?;
+
+        Ok(Self {
+            loan_id: loan.id,
+            currency: currency.to_string(),
+            frequency: schedule.frequency.as_str().to_string(),
+            installments: installments
+                .into_iter()
+                .map(LoanInstallmentModelResponse::from_installment)
+                .collect::<Result<Vec<_>>>()?,
+            total_principal: total_principal.to_major(),
+            total_interest: total_interest.to_major(),
+            total_paid: total_paid.to_major(),
+            total_outstanding: total_outstanding.to_major(),
+        })
+    }
+}
+
+fn parse_rfc3339(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
+
+/// Resultado de recalcular los estados de cobranza
+#[derive(Debug, Serialize)]
+pub struct LoanStatusRefreshModelResponse {
+    pub loans_updated: usize,
+    pub borrowers_updated: usize,
+    pub overdue_loans: usize,
+    pub defaulted_loans: usize,
+}
@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::domain::entities::loan::{InstallmentFrequency, InterestMethod, RatePeriod};
+use crate::server::domain::entities::loan_product::LoanProduct;
+
+/// Producto de préstamo; los límites están en la moneda del producto
+#[derive(Debug, Serialize)]
+pub struct LoanProductModelResponse {
+    pub id: i32,
+    pub name: String,
+    pub currency: String,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub installment_frequency: Option<String>,
+    pub installment_count: Option<i32>,
+    pub term_days: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub min_amount: Option<f64>,
+    pub max_amount: Option<f64>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<LoanProduct> for LoanProductModelResponse {
+    fn from(product: LoanProduct) -> Self {
+        Self {
+            id: product.id,
+            name: product.name,
+            currency: product.currency,
+            interest_rate: product.interest.rate,
+            rate_period: product.interest.period.as_str().to_string(),
+            interest_method: product.interest.method.as_str().to_string(),
+            installment_frequency: product.installment_frequency.map(|frequency| frequency.as_str().to_string()),
+            installment_count: product.installment_count,
+            term_days: product.term_days,
+            penalty_rule_id: product.penalty_rule_id,
+            min_amount: product.min_amount.map(|amount| amount.to_major()),
+            max_amount: product.max_amount.map(|amount| amount.to_major()),
+            active: product.active,
+            created_at: parse_rfc3339(&product.created_at),
+            updated_at: parse_rfc3339(&product.updated_at),
+        }
+    }
+}
+
+/// Las condiciones que falten toman el valor por defecto de un préstamo sin producto
+#[derive(Debug, Clone)]
+pub struct CreateLoanProductRequest {
+    pub name: String,
+    pub currency: Option<String>,
+    pub interest_rate: Option<f64>,
+    pub rate_period: Option<RatePeriod>,
+    pub interest_method: Option<InterestMethod>,
+    pub installment_frequency: Option<InstallmentFrequency>,
+    pub installment_count: Option<i32>,
+    pub term_days: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub min_amount: Option<f64>,
+    pub max_amount: Option<f64>,
+}
+
+/// Las condiciones que falten se conservan; un plan de cuotas nuevo reemplaza al
+/// plazo en días y viceversa
+#[derive(Debug, Clone)]
+pub struct UpdateLoanProductRequest {
+    pub name: Option<String>,
+    pub currency: Option<String>,
+    pub interest_rate: Option<f64>,
+    pub rate_period: Option<RatePeriod>,
+    pub interest_method: Option<InterestMethod>,
+    pub installment_frequency: Option<InstallmentFrequency>,
+    pub installment_count: Option<i32>,
+    pub term_days: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub min_amount: Option<f64>,
+    pub max_amount: Option<f64>,
+}
+
+fn parse_rfc3339(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
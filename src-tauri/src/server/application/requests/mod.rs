@@ -1,2 +1,12 @@
 pub  mod auth_model_requests;
-pub mod  borrower_model_requests;
\ No newline at end of file
+pub mod  borrower_model_requests;
+pub mod loan_model_requests;
+pub mod payment_model_requests;
+pub mod currency_model_requests;
+pub mod report_model_requests;
+pub mod penalty_model_requests;
+pub mod loan_product_model_requests;
+pub mod collateral_model_requests;
+pub mod statement_model_requests;
+pub mod ledger_model_requests;
+pub mod cash_box_model_requests;
\ No newline at end of file
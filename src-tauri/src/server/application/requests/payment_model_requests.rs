@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::borrower_model_requests::BorrowerModelResponse;
+use crate::server::domain::entities::borrower::Borrower;
+use crate::server::domain::entities::payment::Payment;
+use crate::server::domain::entities::payment_allocation::{AllocationStrategy, PaymentAllocation};
+
+#[derive(Debug, Serialize)]
+pub struct PaymentModelResponse {
+    pub id: i32,
+    pub amount: f64,
+    pub currency: String,
+    pub exchange_rate: f32,
+    pub amount_cup: f64,
+    pub allocation_strategy: String,
+    pub allocations: Vec<PaymentAllocationModelResponse>,
+    pub loan_id: Option<i32>,
+    pub borrower: BorrowerModelResponse,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Fecha del borrado lógico y usuario que lo hizo, si está eliminado
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<i32>,
+}
+
+impl PaymentModelResponse {
+    /// Combina el pago con su prestatario, tal como lo espera el frontend
+    pub fn from_parts(payment: Payment, borrower: Borrower) -> Self {
+        let created_at = DateTime::parse_from_rfc3339(&payment.created_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        let updated_at = DateTime::parse_from_rfc3339(&payment.updated_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        Self {
+            id: payment.id,
+            amount: payment.amount.to_major(),
+            currency: payment.amount.currency().to_string(),
+            exchange_rate: payment.exchange_rate,
+            amount_cup: payment.amount_cup.to_major(),
+            allocation_strategy: payment.allocation_strategy.as_str().to_string(),
+            allocations: payment
+                .allocations
+                .into_iter()
+                .map(PaymentAllocationModelResponse::from)
+                .collect(),
+            loan_id: payment.loan_id,
+            borrower: BorrowerModelResponse::from(borrower),
+            created_at,
+            updated_at,
+            deleted_at: payment.deleted_at.as_deref().map(parse_rfc3339),
+            deleted_by: payment.deleted_by,
+        }
+    }
+}
+
+/// Parte del pago aplicada a un préstamo; `amount` en la moneda del préstamo
+#[derive(Debug, Serialize)]
+pub struct PaymentAllocationModelResponse {
+    pub loan_id: i32,
+    pub amount: f64,
+    pub currency: String,
+    pub amount_cup: f64,
+}
+
+impl From<PaymentAllocation> for PaymentAllocationModelResponse {
+    fn from(allocation: PaymentAllocation) -> Self {
+        Self {
+            loan_id: allocation.loan_id,
+            amount: allocation.amount.to_major(),
+            currency: allocation.amount.currency().to_string(),
+            amount_cup: allocation.amount_cup.to_major(),
+        }
+    }
+}
+
+/// `date` permite registrar con fecha anterior; si falta se usa la fecha actual
+/// Sin `allocation_strategy` se usa la estrategia configurada por defecto
+#[derive(Debug, Clone)]
+pub struct CreatePaymentRequest {
+    pub amount: f64,
+    pub currency: String,
+    pub allocation_strategy: Option<AllocationStrategy>,
+    pub borrower_id: i32,
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdatePaymentRequest {
+    pub amount: Option<f64>,
+    pub currency: Option<String>,
+    pub allocation_strategy: Option<AllocationStrategy>,
+    pub borrower_id: Option<i32>,
+    pub date: Option<DateTime<Utc>>,
+}
+
+fn parse_rfc3339(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::server::domain::entities::penalty::{Penalty, PenaltyRule};
+
+/// Regla de recargo; los importes están en CUP
+#[derive(Debug, Serialize)]
+pub struct PenaltyRuleModelResponse {
+    pub id: i32,
+    pub name: String,
+    pub kind: String,
+    pub amount: f64,
+    pub rate: f64,
+    pub grace_days: i32,
+    pub cap: Option<f64>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<PenaltyRule> for PenaltyRuleModelResponse {
+    fn from(rule: PenaltyRule) -> Self {
+        Self {
+            id: rule.id,
+            name: rule.name,
+            kind: rule.kind.as_str().to_string(),
+            amount: rule.amount.to_major(),
+            rate: rule.rate,
+            grace_days: rule.grace_days,
+            cap: rule.cap.map(|cap| cap.to_major()),
+            active: rule.active,
+            created_at: parse_rfc3339(&rule.created_at),
+            updated_at: parse_rfc3339(&rule.updated_at),
+        }
+    }
+}
+
+/// Recargo cobrado a un préstamo, en CUP
+#[derive(Debug, Serialize)]
+pub struct PenaltyModelResponse {
+    pub id: i32,
+    pub loan_id: i32,
+    pub borrower_id: i32,
+    pub rule_id: i32,
+    pub amount: f64,
+    pub days_past_due: i32,
+    pub days_charged: i32,
+    pub status: String,
+    pub waived_reason: Option<String>,
+    pub waived_by: Option<i32>,
+    pub waived_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Penalty> for PenaltyModelResponse {
+    fn from(penalty: Penalty) -> Self {
+        Self {
+            id: penalty.id,
+            loan_id: penalty.loan_id,
+            borrower_id: penalty.borrower_id,
+            rule_id: penalty.rule_id,
+            amount: penalty.amount.to_major(),
+            days_past_due: penalty.days_past_due,
+            days_charged: penalty.days_charged,
+            status: penalty.status,
+            waived_reason: penalty.waived_reason,
+            waived_by: penalty.waived_by,
+            waived_at: penalty.waived_at.as_deref().map(parse_rfc3339),
+            created_at: parse_rfc3339(&penalty.created_at),
+        }
+    }
+}
+
+/// Resultado de evaluar los recargos de los préstamos atrasados
+#[derive(Debug, Serialize)]
+pub struct PenaltyAssessmentModelResponse {
+    pub penalties_charged: usize,
+    pub total_charged: f64,
+}
+
+/// `amount` aplica a reglas fijas y `rate` (porcentaje) a porcentuales y diarias
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreatePenaltyRuleRequest {
+    pub name: String,
+    pub kind: String,
+    pub amount: Option<f64>,
+    pub rate: Option<f64>,
+    pub grace_days: i32,
+    pub cap: Option<f64>,
+}
+
+fn parse_rfc3339(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
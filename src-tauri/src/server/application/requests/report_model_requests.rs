@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Saldo de un prestatario contabilizado vs. revaluado a las tasas de una fecha
+#[derive(Debug, Serialize)]
+pub struct BorrowerRevaluationModelResponse {
+    pub borrower_id: i32,
+    pub name: String,
+    pub booked_balance_cup: f64,
+    pub revalued_balance_cup: f64,
+    pub fx_difference_cup: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BalanceRevaluationModelResponse {
+    pub at: DateTime<Utc>,
+    pub borrowers: Vec<BorrowerRevaluationModelResponse>,
+    pub total_booked_cup: f64,
+    pub total_revalued_cup: f64,
+    pub total_fx_difference_cup: f64,
+}
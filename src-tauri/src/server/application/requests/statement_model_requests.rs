@@ -0,0 +1,93 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::Serialize;
+use crate::server::domain::services::statement_builder::{Statement, StatementLine};
+use crate::server::domain::value_objects::money::Money;
+
+/// Estado de cuenta de un prestatario; `from` y `to` faltan si el período no tiene ese límite
+#[derive(Debug, Serialize)]
+pub struct BorrowerStatementModelResponse {
+    pub borrower_id: i32,
+    pub name: String,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub opening_balance_cup: f64,
+    pub opening_balances: Vec<CurrencyBalanceModelResponse>,
+    pub entries: Vec<StatementEntryModelResponse>,
+    pub closing_balance_cup: f64,
+    pub closing_balances: Vec<CurrencyBalanceModelResponse>,
+}
+
+impl BorrowerStatementModelResponse {
+    pub fn from_statement(
+        borrower_id: i32,
+        name: String,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        statement: Statement,
+    ) -> Self {
+        Self {
+            borrower_id,
+            name,
+            from,
+            to,
+            opening_balance_cup: statement.opening_balance_cup.to_major(),
+            opening_balances: statement.opening_balances.iter().map(CurrencyBalanceModelResponse::from).collect(),
+            entries: statement.lines.into_iter().map(StatementEntryModelResponse::from).collect(),
+            closing_balance_cup: statement.closing_balance_cup.to_major(),
+            closing_balances: statement.closing_balances.iter().map(CurrencyBalanceModelResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CurrencyBalanceModelResponse {
+    pub currency: String,
+    pub amount: f64,
+}
+
+impl From<&Money> for CurrencyBalanceModelResponse {
+    fn from(balance: &Money) -> Self {
+        Self {
+            currency: balance.currency().to_string(),
+            amount: balance.to_major(),
+        }
+    }
+}
+
+/// Movimiento del estado de cuenta; los montos positivos aumentan la deuda
+/// `amount` y `balance` están en `currency`; `amount_cup` y `balance_cup` en CUP
+#[derive(Debug, Serialize)]
+pub struct StatementEntryModelResponse {
+    pub date: DateTime<Utc>,
+    pub kind: String,
+    pub reference_id: i32,
+    pub loan_id: Option<i32>,
+    pub description: String,
+    pub currency: String,
+    pub amount: f64,
+    pub amount_cup: f64,
+    pub balance: f64,
+    pub balance_cup: f64,
+}
+
+impl From<StatementLine> for StatementEntryModelResponse {
+    fn from(line: StatementLine) -> Self {
+        let entry = line.entry;
+        Self {
+            date: to_utc(entry.date),
+            kind: entry.kind.to_string(),
+            reference_id: entry.reference_id,
+            loan_id: entry.loan_id,
+            description: entry.description,
+            currency: entry.amount.currency().to_string(),
+            amount: entry.amount.to_major(),
+            amount_cup: entry.amount_cup.to_major(),
+            balance: line.balance.to_major(),
+            balance_cup: line.balance_cup.to_major(),
+        }
+    }
+}
+
+fn to_utc(date: NaiveDateTime) -> DateTime<Utc> {
+    date.and_utc()
+}
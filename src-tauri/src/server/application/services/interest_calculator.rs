@@ -0,0 +1,212 @@
+use chrono::{Duration, NaiveDateTime};
+
+use crate::server::domain::entities::loan::{InterestMethod, InterestTerms};
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::Result;
+
+/// Pago aplicado a un préstamo, en la moneda del préstamo
+#[derive(Debug, Clone)]
+pub struct Repayment {
+    pub date: NaiveDateTime,
+    pub amount: Money,
+}
+
+/// Estado de un préstamo a una fecha; todos los importes están en la moneda del préstamo
+#[derive(Debug, Clone)]
+pub struct InterestBreakdown {
+    pub principal: Money,
+    pub accrued_interest: Money,
+    pub interest_paid: Money,
+    pub principal_paid: Money,
+    pub outstanding_principal: Money,
+    pub outstanding_interest: Money,
+    pub total_due: Money,
+}
+
+/// Calcula el interés devengado y el total adeudado de un préstamo a cualquier fecha
+/// Los pagos cubren primero el interés pendiente y luego el principal
+pub struct InterestCalculator;
+
+impl InterestCalculator {
+    pub fn calculate(
+        principal: &Money,
+        terms: &InterestTerms,
+        start: NaiveDateTime,
+        at: NaiveDateTime,
+        repayments: &[Repayment],
+    ) -> Result<InterestBreakdown> {
+        let rate = terms.rate / 100.0;
+        let period_days = terms.period.days();
+
+        // Flat y saldo decreciente devengan por período completo; el diario devenga por día
+        let (step_days, step_rate) = match terms.method {
+            InterestMethod::SimpleDaily => (1, rate / period_days as f64),
+            InterestMethod::Flat | InterestMethod::DecliningBalance => (period_days, rate),
+        };
+
+        let elapsed_days = (at - start).num_days().max(0);
+        let steps = elapsed_days / step_days;
+
+        let mut sorted: Vec<&Repayment> = repayments.iter().filter(|r| r.date <= at).collect();
+        sorted.sort_by_key(|r| r.date);
+        let mut pending = sorted.into_iter().peekable();
+
+        let mut ledger = LoanLedger::new(principal);
+        let mut step = 0;
+
+        // Se avanza por tramos sin pagos intermedios y se redondea una vez por tramo,
+        // así el devengo diario no pierde centavos día a día
+        while step < steps {
+            let next_boundary = start + Duration::days((step + 1) * step_days);
+            while let Some(repayment) = pending.next_if(|r| r.date < next_boundary) {
+                ledger.apply_repayment(&repayment.amount)?;
+            }
+
+            let run_end = match pending.peek() {
+                Some(repayment) => ((repayment.date - start).num_days() / step_days).min(steps),
+                None => steps,
+            };
+
+            let base = match terms.method {
+                InterestMethod::Flat => principal,
+                _ => &ledger.outstanding_principal,
+            };
+            let interest = base.checked_scale(step_rate * (run_end - step) as f64)?;
+            ledger.accrue(&interest)?;
+
+            step = run_end;
+        }
+
+        for repayment in pending {
+            ledger.apply_repayment(&repayment.amount)?;
+        }
+
+        ledger.into_breakdown(principal)
+    }
+}
+
+struct LoanLedger {
+    outstanding_principal: Money,
+    outstanding_interest: Money,
+    accrued_interest: Money,
+    interest_paid: Money,
+    principal_paid: Money,
+}
+
+impl LoanLedger {
+    fn new(principal: &Money) -> Self {
+        let zero = Money::zero(principal.currency());
+        Self {
+            outstanding_principal: principal.clone(),
+            outstanding_interest: zero.clone(),
+            accrued_interest: zero.clone(),
+            interest_paid: zero.clone(),
+            principal_paid: zero,
+        }
+    }
+
+    fn accrue(&mut self, interest: &Money) -> Result<()> {
+        self.accrued_interest = self.accrued_interest.checked_add(interest)?;
+        self.outstanding_interest = self.outstanding_interest.checked_add(interest)?;
+        Ok(())
+    }
+
+    /// Lo que exceda la deuda total se ignora: el préstamo queda en cero
+    fn apply_repayment(&mut self, amount: &Money) -> Result<()> {
+        let to_interest = min_of(amount, &self.outstanding_interest);
+        self.outstanding_interest = self.outstanding_interest.checked_sub(&to_interest)?;
+        self.interest_paid = self.interest_paid.checked_add(&to_interest)?;
+
+        let remaining = amount.checked_sub(&to_interest)?;
+        let to_principal = min_of(&remaining, &self.outstanding_principal);
+        self.outstanding_principal = self.outstanding_principal.checked_sub(&to_principal)?;
+        self.principal_paid = self.principal_paid.checked_add(&to_principal)?;
+
+        Ok(())
+    }
+
+    fn into_breakdown(self, principal: &Money) -> Result<InterestBreakdown> {
+        let total_due = self.outstanding_principal.checked_add(&self.outstanding_interest)?;
+
+        Ok(InterestBreakdown {
+            principal: principal.clone(),
+            accrued_interest: self.accrued_interest,
+            interest_paid: self.interest_paid,
+            principal_paid: self.principal_paid,
+            outstanding_principal: self.outstanding_principal,
+            outstanding_interest: self.outstanding_interest,
+            total_due,
+        })
+    }
+}
+
+fn min_of(a: &Money, b: &Money) -> Money {
+    if a.minor_units() <= b.minor_units() {
+        a.clone()
+    } else {
+        b.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::domain::entities::loan::RatePeriod;
+    use chrono::NaiveDate;
+
+    fn day(offset: i64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap() + Duration::days(offset)
+    }
+
+    fn cup(minor_units: i64) -> Money {
+        Money::from_minor(minor_units, "CUP")
+    }
+
+    fn terms(rate: f64, period: RatePeriod, method: InterestMethod) -> InterestTerms {
+        InterestTerms { rate, period, method }
+    }
+
+    #[test]
+    fn flat_interest_accrues_only_for_complete_periods() {
+        let terms = terms(10.0, RatePeriod::Monthly, InterestMethod::Flat);
+        let breakdown = InterestCalculator::calculate(&cup(100000), &terms, day(0), day(65), &[]).unwrap();
+
+        assert_eq!(breakdown.accrued_interest, cup(20000));
+        assert_eq!(breakdown.total_due, cup(120000));
+    }
+
+    #[test]
+    fn declining_balance_charges_on_the_principal_left_after_each_repayment() {
+        let terms = terms(10.0, RatePeriod::Monthly, InterestMethod::DecliningBalance);
+        let repayments = [Repayment { date: day(30), amount: cup(60000) }];
+        let breakdown = InterestCalculator::calculate(&cup(100000), &terms, day(0), day(60), &repayments).unwrap();
+
+        // El pago cubre los 100 de interés del primer mes y 500 de principal; el segundo mes cobra sobre 500
+        assert_eq!(breakdown.interest_paid, cup(10000));
+        assert_eq!(breakdown.principal_paid, cup(50000));
+        assert_eq!(breakdown.accrued_interest, cup(15000));
+        assert_eq!(breakdown.total_due, cup(55000));
+    }
+
+    #[test]
+    fn simple_daily_rounds_once_per_run_of_days() {
+        let terms = terms(10.0, RatePeriod::Yearly, InterestMethod::SimpleDaily);
+        let breakdown = InterestCalculator::calculate(&cup(10000), &terms, day(0), day(365), &[]).unwrap();
+
+        // Redondear día a día daría 0.03 × 365 = 10.95
+        assert_eq!(breakdown.accrued_interest, cup(1000));
+    }
+
+    #[test]
+    fn repayments_after_the_date_are_ignored_and_overpayments_leave_the_loan_at_zero() {
+        let terms = InterestTerms::none();
+        let repayments = [
+            Repayment { date: day(10), amount: cup(500000) },
+            Repayment { date: day(40), amount: cup(100) },
+        ];
+        let breakdown = InterestCalculator::calculate(&cup(100000), &terms, day(0), day(30), &repayments).unwrap();
+
+        assert_eq!(breakdown.principal_paid, cup(100000));
+        assert_eq!(breakdown.total_due, cup(0));
+    }
+}
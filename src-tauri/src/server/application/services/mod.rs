@@ -1,2 +1,4 @@
 pub mod auth_service;
-pub  mod jwt_service;
\ No newline at end of file
+pub  mod jwt_service;
+pub mod interest_calculator;
+pub mod schedule_generator;
\ No newline at end of file
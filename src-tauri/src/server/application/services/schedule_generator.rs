@@ -0,0 +1,189 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::loan::{InterestMethod, InterestTerms, ScheduleTerms};
+use crate::server::domain::entities::loan_installment::NewLoanInstallment;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::{AppError, Result};
+
+/// Genera el plan de cuotas (tabla de amortización) de un préstamo
+/// - `Flat`: principal en partes iguales e interés fijo sobre el principal original
+/// - `DecliningBalance`: cuota constante (sistema francés) con interés sobre el saldo pendiente
+/// - `SimpleDaily`: principal en partes iguales e interés por los días reales de cada cuota
+pub struct ScheduleGenerator;
+
+impl ScheduleGenerator {
+    pub fn generate(
+        principal: &Money,
+        interest: &InterestTerms,
+        schedule: &ScheduleTerms,
+        start: NaiveDateTime,
+    ) -> Result<Vec<NewLoanInstallment>> {
+        if schedule.periods < 1 {
+            return Err(AppError::ValidationError(vec![
+                "El plan debe tener al menos una cuota".to_string(),
+            ]));
+        }
+
+        if schedule.first_due_date <= start {
+            return Err(AppError::ValidationError(vec![
+                "La primera cuota debe vencer después de la fecha del préstamo".to_string(),
+            ]));
+        }
+
+        let periods = schedule.periods as i64;
+        let due_dates = (0..periods)
+            .map(|index| due_date(schedule, index))
+            .collect::<Result<Vec<NaiveDateTime>>>()?;
+
+        let rate = interest.rate / 100.0;
+        let installment_rate = rate * schedule.frequency.days() as f64 / interest.period.days() as f64;
+        let daily_rate = rate / interest.period.days() as f64;
+
+        let currency = principal.currency();
+        let even_share = Money::from_minor(principal.minor_units() / periods, currency);
+        let annuity = annuity_payment(principal, installment_rate, periods)?;
+
+        let mut outstanding = principal.clone();
+        let mut previous_date = start;
+        let mut installments = Vec::with_capacity(due_dates.len());
+
+        for (index, due_date) in due_dates.into_iter().enumerate() {
+            let is_last = index as i64 == periods - 1;
+
+            let interest_due = match interest.method {
+                InterestMethod::Flat => principal.checked_scale(installment_rate)?,
+                InterestMethod::DecliningBalance => outstanding.checked_scale(installment_rate)?,
+                InterestMethod::SimpleDaily => {
+                    let days = (due_date - previous_date).num_days().max(0);
+                    outstanding.checked_scale(daily_rate * days as f64)?
+                }
+            };
+
+            // La última cuota absorbe los centavos que deja el redondeo
+            let principal_due = if is_last {
+                outstanding.clone()
+            } else {
+                match interest.method {
+                    InterestMethod::DecliningBalance => {
+                        let share = annuity.checked_sub(&interest_due)?;
+                        Money::from_minor(share.minor_units().clamp(0, outstanding.minor_units()), currency)
+                    }
+                    InterestMethod::Flat | InterestMethod::SimpleDaily => even_share.clone(),
+                }
+            };
+
+            outstanding = outstanding.checked_sub(&principal_due)?;
+            previous_date = due_date;
+
+            installments.push(NewLoanInstallment {
+                number: index as i32 + 1,
+                due_date,
+                principal: principal_due,
+                interest: interest_due,
+            });
+        }
+
+        Ok(installments)
+    }
+}
+
+fn due_date(schedule: &ScheduleTerms, index: i64) -> Result<NaiveDateTime> {
+    schedule
+        .frequency
+        .advance(schedule.first_due_date, index)
+        .ok_or_else(|| AppError::ValidationError(vec![
+            "El plan de cuotas excede el rango de fechas permitido".to_string(),
+        ]))
+}
+
+/// Cuota constante que amortiza `principal` en `periods` cuotas a la tasa `rate` por cuota
+fn annuity_payment(principal: &Money, rate: f64, periods: i64) -> Result<Money> {
+    if rate <= 0.0 {
+        return principal.checked_scale(1.0 / periods as f64);
+    }
+
+    let factor = rate / (1.0 - (1.0 + rate).powf(-(periods as f64)));
+    principal.checked_scale(factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::domain::entities::loan::{InstallmentFrequency, RatePeriod};
+    use chrono::{Duration, NaiveDate};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    fn cup(minor_units: i64) -> Money {
+        Money::from_minor(minor_units, "CUP")
+    }
+
+    fn generate(method: InterestMethod, period: RatePeriod, rate: f64, schedule: ScheduleTerms) -> Result<Vec<NewLoanInstallment>> {
+        let interest = InterestTerms { rate, period, method };
+        ScheduleGenerator::generate(&cup(100000), &interest, &schedule, date(2025, 1, 1))
+    }
+
+    fn monthly(periods: i32) -> ScheduleTerms {
+        ScheduleTerms {
+            frequency: InstallmentFrequency::Monthly,
+            periods,
+            first_due_date: date(2025, 2, 1),
+        }
+    }
+
+    #[test]
+    fn flat_splits_principal_evenly_and_the_last_installment_takes_the_remainder() {
+        let installments = generate(InterestMethod::Flat, RatePeriod::Monthly, 10.0, monthly(3)).unwrap();
+
+        let principals: Vec<i64> = installments.iter().map(|i| i.principal.minor_units()).collect();
+        assert_eq!(principals, vec![33333, 33333, 33334]);
+        assert!(installments.iter().all(|i| i.interest == cup(10000)));
+
+        let due_dates: Vec<NaiveDateTime> = installments.iter().map(|i| i.due_date).collect();
+        assert_eq!(due_dates, vec![date(2025, 2, 1), date(2025, 3, 1), date(2025, 4, 1)]);
+    }
+
+    #[test]
+    fn declining_balance_keeps_a_constant_payment_and_amortizes_the_whole_principal() {
+        let installments = generate(InterestMethod::DecliningBalance, RatePeriod::Monthly, 10.0, monthly(3)).unwrap();
+
+        let payments: Vec<i64> = installments
+            .iter()
+            .map(|i| i.principal.minor_units() + i.interest.minor_units())
+            .collect();
+        // La última cuota liquida el saldo, con los centavos que acumuló el redondeo
+        assert_eq!(payments, vec![40211, 40211, 40213]);
+
+        let total_principal: i64 = installments.iter().map(|i| i.principal.minor_units()).sum();
+        assert_eq!(total_principal, 100000);
+        assert_eq!(installments[0].interest, cup(10000));
+    }
+
+    #[test]
+    fn simple_daily_charges_the_days_of_each_installment_on_the_outstanding_principal() {
+        let schedule = ScheduleTerms {
+            frequency: InstallmentFrequency::Weekly,
+            periods: 2,
+            first_due_date: date(2025, 1, 1) + Duration::days(7),
+        };
+        let installments = generate(InterestMethod::SimpleDaily, RatePeriod::Yearly, 36.5, schedule).unwrap();
+
+        assert_eq!(installments[0].interest, cup(700));
+        assert_eq!(installments[1].interest, cup(350));
+        assert_eq!(installments[1].due_date, date(2025, 1, 15));
+    }
+
+    #[test]
+    fn rejects_empty_plans_and_first_due_dates_not_after_the_loan() {
+        assert!(generate(InterestMethod::Flat, RatePeriod::Monthly, 10.0, monthly(0)).is_err());
+
+        let schedule = ScheduleTerms {
+            frequency: InstallmentFrequency::Monthly,
+            periods: 1,
+            first_due_date: date(2025, 1, 1),
+        };
+        assert!(generate(InterestMethod::Flat, RatePeriod::Monthly, 10.0, schedule).is_err());
+    }
+}
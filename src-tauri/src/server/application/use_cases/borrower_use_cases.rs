@@ -1,6 +1,14 @@
-use crate::server::application::requests::borrower_model_requests::{BorrowerModelResponse, CreateBorrowerRequest, UpdateBorrowerRequest};
-use crate::server::domain::entities::borrower::{NewBorrower, Borrower};
+use crate::server::application::requests::borrower_model_requests::{
+    BlockBorrowerRequest, BorrowerModelResponse, BorrowerPageModelResponse, CreateBorrowerRequest,
+    SearchBorrowersRequest, TotalsReconciliationModelResponse, UpdateBorrowerRequest,
+};
+use crate::server::domain::entities::borrower::{
+    Borrower, BorrowerFilter, BorrowerSortField, NewBorrower, SortDirection,
+};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
 use crate::server::domain::repositories::borrower_repository::BorrowerRepository;
+use crate::server::domain::services::duplicate_detector::DuplicateDetector;
+use crate::server::domain::value_objects::money::Money;
 use crate::utils::error::{Result, AppError};
 
 #[derive(Clone)]
@@ -25,24 +33,52 @@ impl<T: BorrowerRepository> BorrowerUseCases<T> {
             ));
         }
 
+        let warnings = self.blocked_matches(&phone, &name).await?;
+
         let new_borrower = NewBorrower::new(phone, name, location);
         let borrower = self.borrower_repository.create(&new_borrower).await?;
         
         // Convertir la entidad del dominio a BorrowerModelResponse
-        Ok(BorrowerModelResponse::from(borrower))
+        let mut response = BorrowerModelResponse::from(borrower);
+        response.warnings = warnings;
+        Ok(response)
     }
 
-    /// Obtener todos los borrowers - ahora retorna Vec<BorrowerModelResponse>
-    pub async fn get_all_borrowers(&self) -> Result<Vec<BorrowerModelResponse>> {
-        let borrowers = self.borrower_repository.find_all().await?;
-        
-        // Convertir cada entidad a BorrowerModelResponse
-        let responses: Vec<BorrowerModelResponse> = borrowers
-            .into_iter()
-            .map(BorrowerModelResponse::from)
-            .collect();
-            
-        Ok(responses)
+    /// Buscar borrowers con filtros, orden y paginación
+    /// Por defecto se ordenan por fecha de alta, los más recientes primero
+    pub async fn get_all_borrowers(&self, request: SearchBorrowersRequest) -> Result<BorrowerPageModelResponse> {
+        let mut errors = Vec::new();
+        if let (Some(min), Some(max)) = (request.min_balance, request.max_balance) {
+            if min > max {
+                errors.push("El saldo mínimo no puede ser mayor que el máximo".to_string());
+            }
+        }
+        if let (Some(from), Some(to)) = (request.created_from, request.created_to) {
+            if from > to {
+                errors.push("La fecha inicial no puede ser posterior a la final".to_string());
+            }
+        }
+        if !errors.is_empty() {
+            return Err(AppError::ValidationError(errors));
+        }
+
+        let page = request.page.unwrap_or(1);
+        let filter = BorrowerFilter {
+            search: request.search,
+            status: request.status,
+            min_balance: request.min_balance.map(|amount| Money::from_major(amount, BASE_CURRENCY)).transpose()?,
+            max_balance: request.max_balance.map(|amount| Money::from_major(amount, BASE_CURRENCY)).transpose()?,
+            created_from: request.created_from.map(|date| date.naive_utc()),
+            created_to: request.created_to.map(|date| date.naive_utc()),
+            deleted: request.deleted,
+            sort: request.sort.unwrap_or(BorrowerSortField::CreatedAt),
+            direction: request.direction.unwrap_or(SortDirection::Desc),
+            page,
+            page_size: request.page_size,
+        };
+
+        let borrowers = self.borrower_repository.search(&filter).await?;
+        Ok(BorrowerPageModelResponse::from_page(borrowers, page, request.page_size))
     }
 
     /// Obtener borrower por ID - ahora retorna BorrowerModelResponse
@@ -72,6 +108,11 @@ impl<T: BorrowerRepository> BorrowerUseCases<T> {
             }
         }
 
+        let credit_limit = match request.credit_limit {
+            Some(limit) => Some(Money::from_major(limit, BASE_CURRENCY)?).filter(Money::is_positive),
+            None => existing_borrower.credit_limit,
+        };
+
         // Crear borrower actualizado (entidad del dominio)
         let updated_borrower_entity = Borrower {
             id,
@@ -84,6 +125,16 @@ impl<T: BorrowerRepository> BorrowerUseCases<T> {
             status: existing_borrower.status,
             created_at: existing_borrower.created_at,
             updated_at: existing_borrower.updated_at,
+            total_penalties: existing_borrower.total_penalties,
+            written_off_total: existing_borrower.written_off_total,
+            recovered_total: existing_borrower.recovered_total,
+            guarantee_exposure: existing_borrower.guarantee_exposure,
+            credit_limit,
+            risk_tier: request.risk_tier.unwrap_or(existing_borrower.risk_tier),
+            credit_score: existing_borrower.credit_score,
+            block: existing_borrower.block,
+            deleted_at: existing_borrower.deleted_at,
+            deleted_by: existing_borrower.deleted_by,
         };
 
         let updated_borrower = self.borrower_repository.update(id, &updated_borrower_entity).await?;
@@ -93,13 +144,113 @@ impl<T: BorrowerRepository> BorrowerUseCases<T> {
     }
 
     /// Eliminar borrower
-    pub async fn delete_borrower(&self, id: i32) -> Result<()> {
+    pub async fn delete_borrower(&self, id: i32, deleted_by: i32) -> Result<()> {
         // Verificar que el borrower existe
         self.borrower_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Prestatario con ID {} no encontrado", id)))?;
         
-        self.borrower_repository.delete(id).await
+        self.borrower_repository.delete(id, deleted_by).await
+    }
+
+    /// Restaurar un borrower eliminado
+    pub async fn restore_borrower(&self, id: i32) -> Result<BorrowerModelResponse> {
+        self.find_deleted_borrower(id).await?;
+
+        let borrower = self.borrower_repository.restore(id).await?;
+        Ok(BorrowerModelResponse::from(borrower))
+    }
+
+    /// Borrar definitivamente un borrower eliminado
+    pub async fn purge_borrower(&self, id: i32) -> Result<()> {
+        let borrower = self.find_deleted_borrower(id).await?;
+
+        // El bloqueo se perdería con el registro; hay que levantarlo antes
+        if borrower.block.is_some() {
+            return Err(AppError::Conflict(format!(
+                "El prestatario {} está bloqueado; desbloquéelo antes de purgarlo",
+                borrower.name
+            )));
+        }
+
+        self.borrower_repository.purge(id).await
+    }
+
+    /// Bloquear borrower - no podrá recibir préstamos nuevos hasta que se desbloquee
+    pub async fn block_borrower(&self, id: i32, request: BlockBorrowerRequest, blocked_by: i32) -> Result<BorrowerModelResponse> {
+        let borrower = self.find_borrower(id).await?;
+        if borrower.block.is_some() {
+            return Err(AppError::Conflict(format!("El prestatario {} ya está bloqueado", borrower.name)));
+        }
+
+        let reason = request.reason.trim();
+        if reason.is_empty() {
+            return Err(AppError::ValidationError(vec!["El motivo del bloqueo es requerido".to_string()]));
+        }
+
+        let borrower = self.borrower_repository
+            .block(id, reason, blocked_by, chrono::Utc::now().naive_utc())
+            .await?;
+        Ok(BorrowerModelResponse::from(borrower))
+    }
+
+    /// Desbloquear borrower - recupera el estado de cobranza de sus préstamos
+    pub async fn unblock_borrower(&self, id: i32) -> Result<BorrowerModelResponse> {
+        let borrower = self.find_borrower(id).await?;
+        if borrower.block.is_none() {
+            return Err(AppError::Conflict(format!("El prestatario {} no está bloqueado", borrower.name)));
+        }
+
+        let borrower = self.borrower_repository.unblock(id).await?;
+        Ok(BorrowerModelResponse::from(borrower))
+    }
+
+    /// Conciliar los totales de los borrowers con sus préstamos, pagos y recargos
+    /// Sin `fix` solo informa las diferencias; con `fix` además las corrige
+    pub async fn reconcile_totals(&self, fix: bool) -> Result<TotalsReconciliationModelResponse> {
+        let reconciliation = self.borrower_repository.reconcile_totals(fix).await?;
+        Ok(TotalsReconciliationModelResponse::from(reconciliation))
+    }
+
+    async fn find_borrower(&self, id: i32) -> Result<Borrower> {
+        self.borrower_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Prestatario con ID {} no encontrado", id)))
+    }
+
+    /// Busca un borrower eliminado; si existe pero no está eliminado es un conflicto
+    async fn find_deleted_borrower(&self, id: i32) -> Result<Borrower> {
+        if let Some(borrower) = self.borrower_repository.find_deleted_by_id(id).await? {
+            return Ok(borrower);
+        }
+
+        match self.borrower_repository.find_by_id(id).await? {
+            Some(borrower) => Err(AppError::Conflict(format!("El prestatario {} no está eliminado", borrower.name))),
+            None => Err(AppError::NotFound(format!("Prestatario con ID {} no encontrado", id))),
+        }
+    }
+
+    /// Advertencias por prestatarios bloqueados con el mismo teléfono o un nombre casi igual
+    async fn blocked_matches(&self, phone: &str, name: &str) -> Result<Vec<String>> {
+        let mut warnings = Vec::new();
+
+        for blocked in self.borrower_repository.find_blocked().await? {
+            let reason = blocked.block.as_ref().map(|block| block.reason.as_str()).unwrap_or_default();
+            if DuplicateDetector::same_phone(phone, &blocked.phone) {
+                warnings.push(format!(
+                    "El teléfono coincide con el del prestatario bloqueado {} (ID {}): {}",
+                    blocked.name, blocked.id, reason
+                ));
+            } else if DuplicateDetector::similar_names(name, &blocked.name) {
+                warnings.push(format!(
+                    "El nombre se parece al del prestatario bloqueado {} (ID {}): {}",
+                    blocked.name, blocked.id, reason
+                ));
+            }
+        }
+
+        Ok(warnings)
     }
 }
\ No newline at end of file
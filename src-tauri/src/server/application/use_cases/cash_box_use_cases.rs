@@ -0,0 +1,341 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::server::application::requests::cash_box_model_requests::{
+    CashBoxLineModelResponse, CashBoxModelResponse, CashCountModelResponse, CashCountRequest,
+    CashExchangeModelResponse, CashExchangePairModelResponse, CashExchangeReportModelResponse, CashExchangeRequest,
+    CashMovementModelResponse, CashMovementRequest, CashStatementModelResponse, SearchCashExchangesRequest,
+};
+use crate::server::domain::entities::cash_box::{
+    CashExchangeFilter, NewCashCount, NewCashExchange, NewCashMovement, CASH_DEPOSIT, CASH_OPENING, CASH_WITHDRAWAL,
+};
+use crate::server::domain::entities::currency::{Currency, BASE_CURRENCY};
+use crate::server::domain::repositories::cash_box_repository::CashBoxRepository;
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::server::domain::services::exchange_rate_service::ExchangeRateService;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::date::resolve_record_date;
+use crate::utils::error::{Result, AppError};
+
+/// Caja de cada moneda registrada: las entradas por pagos y salidas por desembolsos las registra
+/// el libro mayor; aquí se abren las cajas, se depositan y retiran fondos, se hacen los arqueos
+/// y se cambian monedas de una caja a otra
+#[derive(Clone)]
+pub struct CashBoxUseCases<X: CashBoxRepository, C: CurrencyRepository> {
+    cash_box_repository: X,
+    currency_repository: C,
+    exchange_rate_service: ExchangeRateService<C>,
+}
+
+impl<X: CashBoxRepository, C: CurrencyRepository> CashBoxUseCases<X, C> {
+    pub fn new(cash_box_repository: X, currency_repository: C) -> Self {
+        Self {
+            cash_box_repository,
+            exchange_rate_service: ExchangeRateService::new(currency_repository.clone()),
+            currency_repository,
+        }
+    }
+
+    /// Cajas de todas las monedas con su saldo actual
+    pub async fn get_cash_boxes(&self) -> Result<Vec<CashBoxModelResponse>> {
+        let currencies = self.currency_repository.find_all().await?;
+
+        let mut cash_boxes = Vec::with_capacity(currencies.len());
+        for currency in currencies {
+            cash_boxes.push(self.cash_box(currency).await?);
+        }
+        Ok(cash_boxes)
+    }
+
+    pub async fn get_cash_box(&self, currency: &str) -> Result<CashBoxModelResponse> {
+        let currency = self.find_currency(currency).await?;
+        self.cash_box(currency).await
+    }
+
+    /// Registrar el saldo con el que se abre la caja; cada caja se abre una sola vez
+    pub async fn open_cash_box(&self, currency: &str, request: CashMovementRequest) -> Result<CashMovementModelResponse> {
+        self.record_movement(currency, CASH_OPENING, request).await
+    }
+
+    pub async fn deposit(&self, currency: &str, request: CashMovementRequest) -> Result<CashMovementModelResponse> {
+        self.record_movement(currency, CASH_DEPOSIT, request).await
+    }
+
+    /// Retirar fondos de la caja; no puede quedar en negativo
+    pub async fn withdraw(&self, currency: &str, request: CashMovementRequest) -> Result<CashMovementModelResponse> {
+        self.record_movement(currency, CASH_WITHDRAWAL, request).await
+    }
+
+    /// Entradas y salidas de la caja en el período con el saldo después de cada una
+    pub async fn get_statement(
+        &self,
+        currency: &str,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<CashStatementModelResponse> {
+        let currency = self.find_currency(currency).await?;
+        let to = to.unwrap_or_else(Utc::now);
+        if from.is_some_and(|from| from > to) {
+            return Err(AppError::ValidationError(vec![
+                "La fecha inicial no puede ser posterior a la final".to_string(),
+            ]));
+        }
+
+        let lines = self
+            .cash_box_repository
+            .find_lines(&currency.code, from.map(|from| from.naive_utc()), to.naive_utc())
+            .await?;
+
+        let mut total_in = Money::zero(currency.code.as_str());
+        let mut total_out = Money::zero(currency.code.as_str());
+        for line in &lines {
+            if line.amount.is_positive() {
+                total_in = total_in.checked_add(&line.amount)?;
+            } else {
+                total_out = total_out.checked_sub(&line.amount)?;
+            }
+        }
+
+        // El saldo al cierre es el de la última línea, o el de apertura si no hubo movimientos
+        let closing_balance = match lines.last() {
+            Some(line) => line.balance.clone(),
+            None => self.cash_box_repository.find_balance(&currency.code, to.naive_utc()).await?.balance,
+        };
+        let opening_balance = closing_balance.checked_sub(&total_in)?.checked_add(&total_out)?;
+
+        Ok(CashStatementModelResponse {
+            currency: currency.code,
+            from,
+            to,
+            opening_balance: opening_balance.to_major(),
+            total_in: total_in.to_major(),
+            total_out: total_out.to_major(),
+            closing_balance: closing_balance.to_major(),
+            lines: lines.into_iter().map(CashBoxLineModelResponse::from).collect(),
+        })
+    }
+
+    /// Arqueo de la caja: lo contado se compara con el saldo según el libro mayor y la
+    /// diferencia se registra como sobrante o faltante con la tasa vigente en la fecha del arqueo
+    pub async fn count_cash(&self, currency: &str, request: CashCountRequest) -> Result<CashCountModelResponse> {
+        let currency = self.find_currency(currency).await?;
+        let counted_at = resolve_record_date(request.date)?;
+
+        let counted = Money::from_major(request.counted, currency.code.as_str())?;
+        if counted.minor_units() < 0 {
+            return Err(AppError::ValidationError(vec!["Lo contado no puede ser negativo".to_string()]));
+        }
+
+        let new_count = NewCashCount {
+            counted,
+            exchange_rate: self.exchange_rate_service.rate_at(&currency.code, counted_at).await?,
+            notes: request.notes,
+            counted_by: request.counted_by,
+            counted_at,
+        };
+
+        let count = self.cash_box_repository.record_count(&new_count).await?;
+        Ok(CashCountModelResponse::from(count))
+    }
+
+    /// Arqueos de la caja, los más recientes primero
+    pub async fn get_counts(&self, currency: &str) -> Result<Vec<CashCountModelResponse>> {
+        let currency = self.find_currency(currency).await?;
+
+        let counts = self.cash_box_repository.find_counts(&currency.code).await?;
+        Ok(counts.into_iter().map(CashCountModelResponse::from).collect())
+    }
+
+    /// Cambiar moneda de una caja a otra
+    /// La tasa efectiva se compara con la oficial de `currencies` en la fecha del cambio; lo recibido
+    /// entra por su valor oficial y lo entregado sale por su valor en libros, y la diferencia queda
+    /// como ganancia o pérdida cambiaria realizada
+    pub async fn exchange(&self, request: CashExchangeRequest) -> Result<CashExchangeModelResponse> {
+        let source_currency = self.find_currency(&request.source_currency).await?;
+        let target_currency = self.find_currency(&request.target_currency).await?;
+        if source_currency.code == target_currency.code {
+            return Err(AppError::ValidationError(vec![
+                "Las monedas de origen y destino deben ser distintas".to_string(),
+            ]));
+        }
+
+        let exchanged_at = resolve_record_date(request.date)?;
+        let source = Money::from_major(request.source_amount, source_currency.code.as_str())?;
+        let target_amount = match (request.target_amount, request.rate) {
+            (Some(target_amount), None) => target_amount,
+            (None, Some(rate)) => request.source_amount * rate,
+            _ => {
+                return Err(AppError::ValidationError(vec![
+                    "Indique el monto recibido o la tasa aplicada, no ambos".to_string(),
+                ]))
+            }
+        };
+        let target = Money::from_major(target_amount, target_currency.code.as_str())?;
+        if !source.is_positive() || !target.is_positive() {
+            return Err(AppError::ValidationError(vec!["El monto debe ser de al menos 0.01".to_string()]));
+        }
+
+        let source_rate = self.exchange_rate_service.rate_at(&source_currency.code, exchanged_at).await?;
+        let target_rate = self.exchange_rate_service.rate_at(&target_currency.code, exchanged_at).await?;
+        let effective_rate = target.minor_units() as f64 / source.minor_units() as f64;
+        let official_rate = source_rate as f64 / target_rate as f64;
+
+        // Lo recibido a su valor oficial frente a lo entregado a su valor oficial
+        let target_cup = target.convert(target_rate, BASE_CURRENCY)?;
+        let spread_cup = target_cup.checked_sub(&source.convert(source_rate, BASE_CURRENCY)?)?;
+
+        let new_exchange = NewCashExchange {
+            source,
+            target,
+            effective_rate,
+            official_rate,
+            spread: ((effective_rate / official_rate - 1.0) * 10000.0).round() / 100.0,
+            target_cup,
+            spread_cup,
+            notes: request.notes,
+            created_by: request.created_by,
+            exchanged_at,
+        };
+
+        let exchange = self.cash_box_repository.record_exchange(&new_exchange).await?;
+        Ok(CashExchangeModelResponse::from(exchange))
+    }
+
+    /// Cambios del período con la ganancia y pérdida cambiaria realizada, total y por par de monedas
+    pub async fn get_exchange_report(&self, request: SearchCashExchangesRequest) -> Result<CashExchangeReportModelResponse> {
+        if let (Some(from), Some(to)) = (request.from, request.to) {
+            if from > to {
+                return Err(AppError::ValidationError(vec![
+                    "La fecha inicial no puede ser posterior a la final".to_string(),
+                ]));
+            }
+        }
+        let currency = match &request.currency {
+            Some(currency) => Some(self.find_currency(currency).await?.code),
+            None => None,
+        };
+
+        let filter = CashExchangeFilter {
+            currency: currency.clone(),
+            from: request.from.map(|from| from.naive_utc()),
+            to: request.to.map(|to| to.naive_utc()),
+        };
+        let exchanges = self.cash_box_repository.find_exchanges(&filter).await?;
+
+        let zero = || Money::zero(BASE_CURRENCY);
+        let (mut realized_gain, mut realized_loss, mut spread_cup) = (zero(), zero(), zero());
+        let mut pairs: BTreeMap<(String, String), ExchangePair> = BTreeMap::new();
+        for exchange in &exchanges {
+            if exchange.gain_cup.is_positive() {
+                realized_gain = realized_gain.checked_add(&exchange.gain_cup)?;
+            } else {
+                realized_loss = realized_loss.checked_sub(&exchange.gain_cup)?;
+            }
+            spread_cup = spread_cup.checked_add(&exchange.spread_cup)?;
+
+            let key = (exchange.source.currency().to_string(), exchange.target.currency().to_string());
+            let pair = pairs.entry(key).or_insert_with(|| ExchangePair {
+                count: 0,
+                source: Money::zero(exchange.source.currency()),
+                target: Money::zero(exchange.target.currency()),
+                spread_cup: zero(),
+                gain_cup: zero(),
+            });
+            pair.count += 1;
+            pair.source = pair.source.checked_add(&exchange.source)?;
+            pair.target = pair.target.checked_add(&exchange.target)?;
+            pair.spread_cup = pair.spread_cup.checked_add(&exchange.spread_cup)?;
+            pair.gain_cup = pair.gain_cup.checked_add(&exchange.gain_cup)?;
+        }
+
+        Ok(CashExchangeReportModelResponse {
+            currency,
+            from: request.from,
+            to: request.to,
+            count: exchanges.len(),
+            realized_gain: realized_gain.to_major(),
+            realized_loss: realized_loss.to_major(),
+            net_gain: realized_gain.checked_sub(&realized_loss)?.to_major(),
+            spread_cup: spread_cup.to_major(),
+            pairs: pairs
+                .into_iter()
+                .map(|((source_currency, target_currency), pair)| CashExchangePairModelResponse {
+                    source_currency,
+                    target_currency,
+                    count: pair.count,
+                    source_amount: pair.source.to_major(),
+                    target_amount: pair.target.to_major(),
+                    average_rate: pair.target.minor_units() as f64 / pair.source.minor_units() as f64,
+                    spread_cup: pair.spread_cup.to_major(),
+                    gain_cup: pair.gain_cup.to_major(),
+                })
+                .collect(),
+            exchanges: exchanges.into_iter().map(CashExchangeModelResponse::from).collect(),
+        })
+    }
+
+    async fn record_movement(
+        &self,
+        currency: &str,
+        kind: &'static str,
+        request: CashMovementRequest,
+    ) -> Result<CashMovementModelResponse> {
+        let currency = self.find_currency(currency).await?;
+        let moved_at = resolve_record_date(request.date)?;
+
+        let amount = Money::from_major(request.amount, currency.code.as_str())?;
+        if !amount.is_positive() {
+            return Err(AppError::ValidationError(vec!["El monto debe ser de al menos 0.01".to_string()]));
+        }
+
+        // Se valora en CUP con la tasa vigente en la fecha del movimiento
+        let exchange_rate = self.exchange_rate_service.rate_at(&currency.code, moved_at).await?;
+        let new_movement = NewCashMovement {
+            kind,
+            amount_cup: amount.convert(exchange_rate, BASE_CURRENCY)?,
+            amount,
+            exchange_rate,
+            reason: request.reason,
+            created_by: request.created_by,
+            moved_at,
+        };
+
+        let movement = self.cash_box_repository.record_movement(&new_movement).await?;
+        Ok(CashMovementModelResponse::from(movement))
+    }
+
+    async fn cash_box(&self, currency: Currency) -> Result<CashBoxModelResponse> {
+        let balance = self
+            .cash_box_repository
+            .find_balance(&currency.code, Utc::now().naive_utc())
+            .await?;
+        let opening = self.cash_box_repository.find_opening(&currency.code).await?;
+        let last_count = self.cash_box_repository.find_counts(&currency.code).await?.into_iter().next();
+
+        Ok(CashBoxModelResponse {
+            currency: currency.code,
+            name: currency.name,
+            opening: opening.map(CashMovementModelResponse::from),
+            balance: balance.balance.to_major(),
+            balance_cup: balance.balance_cup.to_major(),
+            last_count: last_count.map(CashCountModelResponse::from),
+        })
+    }
+
+    async fn find_currency(&self, code: &str) -> Result<Currency> {
+        self.currency_repository
+            .find_by_code(&code.to_uppercase())
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Moneda {} no encontrada", code)))
+    }
+}
+
+/// Acumulado de los cambios de un par de monedas
+struct ExchangePair {
+    count: usize,
+    source: Money,
+    target: Money,
+    spread_cup: Money,
+    gain_cup: Money,
+}
@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+
+use crate::server::application::requests::collateral_model_requests::{
+    CollateralModelResponse, LiquidateCollateralRequest, RegisterCollateralRequest,
+};
+use crate::server::domain::entities::collateral::{Collateral, NewCollateral, COLLATERAL_HELD};
+use crate::server::domain::entities::loan::{Loan, LOAN_PAID, LOAN_RESTRUCTURED};
+use crate::server::domain::entities::payment::NewPayment;
+use crate::server::domain::entities::payment_allocation::AllocationStrategy;
+use crate::server::domain::repositories::collateral_repository::CollateralRepository;
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::server::domain::repositories::loan_repository::LoanRepository;
+use crate::server::domain::services::exchange_rate_service::ExchangeRateService;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::date::{parse_stored_date, resolve_record_date};
+use crate::utils::error::{Result, AppError};
+
+/// Garantías de los préstamos: custodia, devolución y liquidación
+#[derive(Clone)]
+pub struct CollateralUseCases<K: CollateralRepository, L: LoanRepository, C: CurrencyRepository> {
+    collateral_repository: K,
+    loan_repository: L,
+    exchange_rate_service: ExchangeRateService<C>,
+}
+
+impl<K: CollateralRepository, L: LoanRepository, C: CurrencyRepository> CollateralUseCases<K, L, C> {
+    pub fn new(collateral_repository: K, loan_repository: L, currency_repository: C) -> Self {
+        Self {
+            collateral_repository,
+            loan_repository,
+            exchange_rate_service: ExchangeRateService::new(currency_repository),
+        }
+    }
+
+    /// Obtener las garantías, opcionalmente solo las de un préstamo
+    pub async fn get_collateral(&self, loan_id: Option<i32>) -> Result<Vec<CollateralModelResponse>> {
+        let collateral = match loan_id {
+            Some(loan_id) => {
+                self.find_loan(loan_id).await?;
+                self.loan_repository.find_collateral(loan_id).await?
+            }
+            None => self.collateral_repository.find_all().await?,
+        };
+
+        Ok(collateral.into_iter().map(CollateralModelResponse::from).collect())
+    }
+
+    /// Obtener una garantía por ID
+    pub async fn get_collateral_by_id(&self, id: i32) -> Result<CollateralModelResponse> {
+        let collateral = self.find_collateral(id).await?;
+        Ok(CollateralModelResponse::from(collateral))
+    }
+
+    /// Registrar una garantía en custodia para un préstamo con saldo
+    pub async fn register_collateral(&self, request: RegisterCollateralRequest) -> Result<CollateralModelResponse> {
+        let loan = self.find_loan(request.loan_id).await?;
+        if loan.status == LOAN_RESTRUCTURED {
+            return Err(AppError::Conflict(
+                "El préstamo fue reestructurado; registre la garantía en el préstamo que lo reemplazó".to_string()
+            ));
+        }
+        if loan.status == LOAN_PAID {
+            return Err(AppError::Conflict("El préstamo ya está saldado".to_string()));
+        }
+
+        let created_at = resolve_record_date(request.date)?;
+        let currency = request.currency.unwrap_or_else(|| loan.amount.currency().to_string());
+
+        // La moneda de la tasación debe tener tasa para poder valorar el respaldo en CUP
+        self.exchange_rate_service.rate_at(&currency, created_at).await?;
+
+        let estimated_value = Money::from_major(request.estimated_value, currency)?;
+        if !estimated_value.is_positive() {
+            return Err(AppError::ValidationError(vec![
+                "El valor estimado debe ser de al menos 0.01".to_string(),
+            ]));
+        }
+
+        let new_collateral = NewCollateral {
+            loan_id: loan.id,
+            description: request.description,
+            estimated_value,
+            storage_location: request.storage_location,
+            created_at,
+        };
+
+        let collateral = self.collateral_repository.create(&new_collateral).await?;
+        Ok(CollateralModelResponse::from(collateral))
+    }
+
+    /// Devolver la garantía al prestatario una vez saldado el préstamo
+    pub async fn return_collateral(&self, id: i32, date: Option<DateTime<Utc>>) -> Result<CollateralModelResponse> {
+        let collateral = self.find_held_collateral(id).await?;
+
+        let loan = self.find_loan(collateral.loan_id).await?;
+        if loan.status != LOAN_PAID {
+            return Err(AppError::Conflict(
+                "Solo se puede devolver la garantía de un préstamo saldado".to_string()
+            ));
+        }
+
+        let returned_at = resolve_record_date(date)?;
+        let collateral = self.collateral_repository.mark_returned(id, returned_at).await?;
+        Ok(CollateralModelResponse::from(collateral))
+    }
+
+    /// Liquidar una garantía: lo obtenido se registra como pago del prestatario que
+    /// cubre primero el préstamo garantizado; lo que sobre va a sus demás préstamos
+    pub async fn liquidate_collateral(&self, id: i32, request: LiquidateCollateralRequest) -> Result<CollateralModelResponse> {
+        let collateral = self.find_held_collateral(id).await?;
+
+        let loan = self.find_loan(collateral.loan_id).await?;
+        if loan.status == LOAN_PAID {
+            return Err(AppError::Conflict(
+                "El préstamo ya está saldado; la garantía se debe devolver".to_string()
+            ));
+        }
+
+        // Un pago anterior al préstamo no lo cubriría
+        let liquidated_at = resolve_record_date(request.date)?;
+        if liquidated_at < parse_stored_date(&loan.created_at) {
+            return Err(AppError::ValidationError(vec![
+                "La fecha de liquidación no puede ser anterior al préstamo".to_string(),
+            ]));
+        }
+
+        let currency = request
+            .currency
+            .unwrap_or_else(|| collateral.estimated_value.currency().to_string());
+        let exchange_rate = self.exchange_rate_service.rate_at(&currency, liquidated_at).await?;
+
+        let amount = Money::from_major(request.amount, currency)?;
+        if !amount.is_positive() {
+            return Err(AppError::ValidationError(vec!["El monto debe ser de al menos 0.01".to_string()]));
+        }
+
+        let proceeds = NewPayment::new(
+            amount,
+            exchange_rate,
+            AllocationStrategy::OldestFirst,
+            loan.borrower_id,
+            liquidated_at,
+        )?
+        .with_loan(loan.id);
+
+        let collateral = self.collateral_repository.liquidate(id, &proceeds).await?;
+        Ok(CollateralModelResponse::from(collateral))
+    }
+
+    async fn find_collateral(&self, id: i32) -> Result<Collateral> {
+        self.collateral_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Garantía con ID {} no encontrada", id)))
+    }
+
+    async fn find_held_collateral(&self, id: i32) -> Result<Collateral> {
+        let collateral = self.find_collateral(id).await?;
+        if collateral.status != COLLATERAL_HELD {
+            return Err(AppError::Conflict(format!(
+                "La garantía {} ya no está en custodia",
+                collateral.description
+            )));
+        }
+
+        Ok(collateral)
+    }
+
+    async fn find_loan(&self, id: i32) -> Result<Loan> {
+        self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))
+    }
+}
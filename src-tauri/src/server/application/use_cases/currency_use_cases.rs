@@ -0,0 +1,139 @@
+use crate::server::application::requests::currency_model_requests::{
+    CreateCurrencyRequest, CurrencyModelResponse, ExchangeRateModelResponse, RecordExchangeRateRequest, UpdateCurrencyRequest,
+};
+use crate::server::domain::entities::currency::{Currency, NewCurrency, BASE_CURRENCY};
+use crate::server::domain::entities::exchange_rate::NewExchangeRate;
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::utils::date::resolve_record_date;
+use crate::utils::error::{Result, AppError};
+
+#[derive(Clone)]
+pub struct CurrencyUseCases<T: CurrencyRepository> {
+    currency_repository: T,
+}
+
+impl<T: CurrencyRepository> CurrencyUseCases<T> {
+    pub fn new(currency_repository: T) -> Self {
+        Self { currency_repository }
+    }
+
+    /// Obtener todas las monedas con su tasa actual
+    pub async fn get_all_currencies(&self) -> Result<Vec<CurrencyModelResponse>> {
+        let currencies = self.currency_repository.find_all().await?;
+
+        Ok(currencies.into_iter().map(CurrencyModelResponse::from).collect())
+    }
+
+    /// Registrar una nueva moneda
+    pub async fn create_currency(&self, request: CreateCurrencyRequest) -> Result<CurrencyModelResponse> {
+        let CreateCurrencyRequest { code, name, exchange_rate } = request;
+
+        if self.currency_repository.find_by_code(&code).await?.is_some() {
+            return Err(AppError::Conflict(format!(
+                "Ya existe una moneda con el código {}",
+                code
+            )));
+        }
+
+        let new_currency = NewCurrency::new(code, name, exchange_rate);
+        let currency = self.currency_repository.create(&new_currency).await?;
+
+        Ok(CurrencyModelResponse::from(currency))
+    }
+
+    /// Actualizar una moneda - la moneda base siempre conserva tasa 1
+    pub async fn update_currency(&self, id: i32, request: UpdateCurrencyRequest) -> Result<CurrencyModelResponse> {
+        let existing_currency = self.find_currency(id).await?;
+
+        if existing_currency.code == BASE_CURRENCY {
+            let changes_code = request.code.as_deref().is_some_and(|code| code != BASE_CURRENCY);
+            let changes_rate = request.exchange_rate.is_some_and(|rate| rate != 1.0);
+            if changes_code || changes_rate {
+                return Err(AppError::Conflict(format!(
+                    "La moneda base {} no puede cambiar su código ni su tasa",
+                    BASE_CURRENCY
+                )));
+            }
+        }
+
+        if let Some(code) = &request.code {
+            if self.currency_repository.exists_by_code_excluding_id(code, id).await? {
+                return Err(AppError::Conflict(format!(
+                    "Ya existe una moneda con el código {}",
+                    code
+                )));
+            }
+            if *code != existing_currency.code && self.currency_repository.is_in_use(&existing_currency.code).await? {
+                return Err(AppError::Conflict(
+                    "No se puede cambiar el código de una moneda con préstamos, pagos o productos registrados".to_string()
+                ));
+            }
+        }
+
+        let updated_currency_entity = Currency {
+            id,
+            code: request.code.unwrap_or(existing_currency.code),
+            name: request.name.unwrap_or(existing_currency.name),
+            exchange_rate: request.exchange_rate.unwrap_or(existing_currency.exchange_rate),
+            updated_at: existing_currency.updated_at,
+        };
+
+        let updated_currency = self.currency_repository.update(id, &updated_currency_entity).await?;
+
+        Ok(CurrencyModelResponse::from(updated_currency))
+    }
+
+    /// Eliminar una moneda que no esté en uso
+    pub async fn delete_currency(&self, id: i32) -> Result<()> {
+        let currency = self.find_currency(id).await?;
+
+        if currency.code == BASE_CURRENCY {
+            return Err(AppError::Conflict(format!(
+                "La moneda base {} no se puede eliminar",
+                BASE_CURRENCY
+            )));
+        }
+
+        if self.currency_repository.is_in_use(&currency.code).await? {
+            return Err(AppError::Conflict(format!(
+                "La moneda {} tiene préstamos, pagos o productos registrados y no se puede eliminar",
+                currency.code
+            )));
+        }
+
+        self.currency_repository.delete(id).await
+    }
+
+    /// Obtener el historial de tasas de una moneda
+    pub async fn get_rate_history(&self, id: i32) -> Result<Vec<ExchangeRateModelResponse>> {
+        let currency = self.find_currency(id).await?;
+        let history = self.currency_repository.find_rate_history(&currency.code).await?;
+
+        Ok(history.into_iter().map(ExchangeRateModelResponse::from).collect())
+    }
+
+    /// Registrar una tasa en el historial, opcionalmente con vigencia retroactiva
+    pub async fn record_rate(&self, id: i32, request: RecordExchangeRateRequest) -> Result<ExchangeRateModelResponse> {
+        let currency = self.find_currency(id).await?;
+
+        if currency.code == BASE_CURRENCY {
+            return Err(AppError::Conflict(format!(
+                "La moneda base {} no puede cambiar su tasa",
+                BASE_CURRENCY
+            )));
+        }
+
+        let effective_from = resolve_record_date(request.effective_from)?;
+        let new_rate = NewExchangeRate::new(currency.code, request.exchange_rate, effective_from);
+        let rate = self.currency_repository.record_rate(&new_rate).await?;
+
+        Ok(ExchangeRateModelResponse::from(rate))
+    }
+
+    async fn find_currency(&self, id: i32) -> Result<Currency> {
+        self.currency_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Moneda con ID {} no encontrada", id)))
+    }
+}
@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+
+use crate::server::application::requests::ledger_model_requests::{
+    AccountBalanceModelResponse, JournalEntryModelResponse, ReceivablesModelResponse, SearchJournalRequest,
+    TrialBalanceModelResponse,
+};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::ledger::{JournalFilter, ACCOUNT_CASH};
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::server::domain::repositories::ledger_repository::LedgerRepository;
+use crate::server::domain::services::exchange_rate_service::ExchangeRateService;
+use crate::server::domain::services::journal_poster::JournalPoster;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::{Result, AppError};
+
+/// Consultas del libro mayor y revaluación de las cajas en moneda extranjera
+/// Los asientos de préstamos, pagos y recargos los registran sus repositorios al guardarlos
+#[derive(Clone)]
+pub struct LedgerUseCases<G: LedgerRepository, C: CurrencyRepository> {
+    ledger_repository: G,
+    exchange_rate_service: ExchangeRateService<C>,
+}
+
+impl<G: LedgerRepository, C: CurrencyRepository> LedgerUseCases<G, C> {
+    pub fn new(ledger_repository: G, currency_repository: C) -> Self {
+        Self {
+            ledger_repository,
+            exchange_rate_service: ExchangeRateService::new(currency_repository),
+        }
+    }
+
+    /// Balance de comprobación con los asientos registrados hasta `at`
+    pub async fn get_trial_balance(&self, at: Option<DateTime<Utc>>) -> Result<TrialBalanceModelResponse> {
+        let at = at.unwrap_or_else(Utc::now);
+        let balances = self.ledger_repository.find_balances(at.naive_utc()).await?;
+
+        let mut total_debit = Money::zero(BASE_CURRENCY);
+        let mut total_credit = Money::zero(BASE_CURRENCY);
+        for balance in &balances {
+            total_debit = total_debit.checked_add(&balance.debit)?;
+            total_credit = total_credit.checked_add(&balance.credit)?;
+        }
+
+        Ok(TrialBalanceModelResponse {
+            at,
+            accounts: balances.into_iter().map(AccountBalanceModelResponse::from).collect(),
+            total_debit: total_debit.to_major(),
+            total_credit: total_credit.to_major(),
+            balanced: total_debit.minor_units() == total_credit.minor_units(),
+        })
+    }
+
+    /// Asientos del libro diario que cumplen los criterios, en orden cronológico
+    pub async fn get_journal(&self, request: SearchJournalRequest) -> Result<Vec<JournalEntryModelResponse>> {
+        if let (Some(from), Some(to)) = (request.from, request.to) {
+            if from > to {
+                return Err(AppError::ValidationError(vec![
+                    "La fecha inicial no puede ser posterior a la final".to_string(),
+                ]));
+            }
+        }
+
+        let filter = JournalFilter {
+            borrower_id: request.borrower_id,
+            loan_id: request.loan_id,
+            event: request.event,
+            from: request.from.map(|from| from.naive_utc()),
+            to: request.to.map(|to| to.naive_utc()),
+        };
+        let entries = self.ledger_repository.find_entries(&filter).await?;
+
+        Ok(entries.into_iter().map(JournalEntryModelResponse::from).collect())
+    }
+
+    /// Cuentas por cobrar de cada prestatario según el libro mayor frente a su saldo guardado
+    pub async fn get_receivables(&self) -> Result<ReceivablesModelResponse> {
+        let receivables = self.ledger_repository.find_receivables().await?;
+        ReceivablesModelResponse::from_receivables(receivables)
+    }
+
+    /// Revalúa las cajas en moneda extranjera con las tasas vigentes en `at`
+    /// La diferencia con su valor en CUP se registra como ganancia o pérdida cambiaria;
+    /// las cajas que ya están al día no generan asiento
+    pub async fn revalue_cash(&self, at: Option<DateTime<Utc>>) -> Result<Vec<JournalEntryModelResponse>> {
+        let posted_at = at.unwrap_or_else(Utc::now).naive_utc();
+        let balances = self.ledger_repository.find_balances(posted_at).await?;
+
+        let mut entries = Vec::new();
+        for balance in balances {
+            let account = &balance.account;
+            if !account.code.starts_with(ACCOUNT_CASH) || account.currency == BASE_CURRENCY {
+                continue;
+            }
+
+            let rate = self.exchange_rate_service.rate_at(&account.currency, posted_at).await?;
+            let revalued = balance.balance.convert(rate, BASE_CURRENCY)?;
+            let difference = revalued.checked_sub(&balance.balance_cup)?;
+            if difference.minor_units() == 0 {
+                continue;
+            }
+
+            let entry = JournalPoster::fx_revaluation(&account.currency, rate, &difference, posted_at)?;
+            entries.push(self.ledger_repository.post(&entry).await?);
+        }
+
+        Ok(entries.into_iter().map(JournalEntryModelResponse::from).collect())
+    }
+}
@@ -0,0 +1,202 @@
+use crate::server::application::requests::loan_product_model_requests::{
+    CreateLoanProductRequest, LoanProductModelResponse, UpdateLoanProductRequest,
+};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::loan::InterestTerms;
+use crate::server::domain::entities::loan_product::{LoanProduct, NewLoanProduct};
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::server::domain::repositories::loan_product_repository::LoanProductRepository;
+use crate::server::domain::repositories::penalty_repository::PenaltyRepository;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::{Result, AppError};
+
+/// Productos de préstamo: condiciones por defecto y límites para los préstamos nuevos
+#[derive(Clone)]
+pub struct LoanProductUseCases<R: LoanProductRepository, N: PenaltyRepository, C: CurrencyRepository> {
+    product_repository: R,
+    penalty_repository: N,
+    currency_repository: C,
+}
+
+impl<R: LoanProductRepository, N: PenaltyRepository, C: CurrencyRepository> LoanProductUseCases<R, N, C> {
+    pub fn new(product_repository: R, penalty_repository: N, currency_repository: C) -> Self {
+        Self {
+            product_repository,
+            penalty_repository,
+            currency_repository,
+        }
+    }
+
+    /// Obtener todos los productos
+    pub async fn get_products(&self) -> Result<Vec<LoanProductModelResponse>> {
+        let products = self.product_repository.find_all().await?;
+
+        Ok(products.into_iter().map(LoanProductModelResponse::from).collect())
+    }
+
+    /// Obtener un producto por ID
+    pub async fn get_product(&self, id: i32) -> Result<LoanProductModelResponse> {
+        let product = self.find_product(id).await?;
+        Ok(LoanProductModelResponse::from(product))
+    }
+
+    /// Registrar un producto
+    pub async fn create_product(&self, request: CreateLoanProductRequest) -> Result<LoanProductModelResponse> {
+        if self.product_repository.find_by_name(&request.name).await?.is_some() {
+            return Err(AppError::Conflict(format!(
+                "Ya existe un producto con el nombre {}",
+                request.name
+            )));
+        }
+
+        let currency = request.currency.unwrap_or_else(|| BASE_CURRENCY.to_string());
+        let defaults = InterestTerms::none();
+
+        let new_product = NewLoanProduct {
+            name: request.name,
+            interest: InterestTerms {
+                rate: request.interest_rate.unwrap_or(defaults.rate),
+                period: request.rate_period.unwrap_or(defaults.period),
+                method: request.interest_method.unwrap_or(defaults.method),
+            },
+            installment_frequency: request.installment_frequency,
+            installment_count: request.installment_count,
+            term_days: request.term_days,
+            penalty_rule_id: request.penalty_rule_id,
+            min_amount: request.min_amount.map(|amount| Money::from_major(amount, &currency)).transpose()?,
+            max_amount: request.max_amount.map(|amount| Money::from_major(amount, &currency)).transpose()?,
+            currency,
+        };
+        self.check_terms(&new_product).await?;
+
+        let product = self.product_repository.create(&new_product).await?;
+        Ok(LoanProductModelResponse::from(product))
+    }
+
+    /// Actualizar un producto - los préstamos ya creados conservan sus condiciones
+    pub async fn update_product(&self, id: i32, request: UpdateLoanProductRequest) -> Result<LoanProductModelResponse> {
+        let existing = self.find_product(id).await?;
+
+        if let Some(name) = &request.name {
+            let same_name = self.product_repository.find_by_name(name).await?;
+            if same_name.is_some_and(|product| product.id != id) {
+                return Err(AppError::Conflict(format!(
+                    "Ya existe un producto con el nombre {}",
+                    name
+                )));
+            }
+        }
+
+        // Un plan nuevo reemplaza al plazo en días y un plazo nuevo reemplaza al plan
+        let schedule_changed = request.installment_frequency.is_some() || request.installment_count.is_some();
+        let (installment_frequency, installment_count, term_days) = if schedule_changed {
+            (
+                request.installment_frequency.or(existing.installment_frequency),
+                request.installment_count.or(existing.installment_count),
+                None,
+            )
+        } else if request.term_days.is_some() {
+            (None, None, request.term_days)
+        } else {
+            (existing.installment_frequency, existing.installment_count, existing.term_days)
+        };
+
+        // Los límites se expresan en la moneda del producto, también si esta cambia
+        let currency = request.currency.unwrap_or(existing.currency);
+        let min_amount = match request.min_amount {
+            Some(amount) => Some(Money::from_major(amount, &currency)?),
+            None => existing.min_amount.map(|amount| Money::from_minor(amount.minor_units(), &currency)),
+        };
+        let max_amount = match request.max_amount {
+            Some(amount) => Some(Money::from_major(amount, &currency)?),
+            None => existing.max_amount.map(|amount| Money::from_minor(amount.minor_units(), &currency)),
+        };
+
+        let product = NewLoanProduct {
+            name: request.name.unwrap_or(existing.name),
+            interest: InterestTerms {
+                rate: request.interest_rate.unwrap_or(existing.interest.rate),
+                period: request.rate_period.unwrap_or(existing.interest.period),
+                method: request.interest_method.unwrap_or(existing.interest.method),
+            },
+            installment_frequency,
+            installment_count,
+            term_days,
+            penalty_rule_id: request.penalty_rule_id.or(existing.penalty_rule_id),
+            min_amount,
+            max_amount,
+            currency,
+        };
+        self.check_terms(&product).await?;
+
+        let product = self.product_repository.update(id, &product).await?;
+        Ok(LoanProductModelResponse::from(product))
+    }
+
+    /// Desactivar un producto - deja de ofrecerse, pero sus préstamos se conservan
+    pub async fn deactivate_product(&self, id: i32) -> Result<LoanProductModelResponse> {
+        self.find_product(id).await?;
+
+        let product = self.product_repository.set_active(id, false).await?;
+        Ok(LoanProductModelResponse::from(product))
+    }
+
+    async fn find_product(&self, id: i32) -> Result<LoanProduct> {
+        self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Producto con ID {} no encontrado", id)))
+    }
+
+    /// Verifica que las condiciones del producto se puedan aplicar a un préstamo
+    async fn check_terms(&self, product: &NewLoanProduct) -> Result<()> {
+        if product.currency != BASE_CURRENCY
+            && self.currency_repository.find_by_code(&product.currency).await?.is_none()
+        {
+            return Err(AppError::NotFound(format!("Moneda {} no encontrada", product.currency)));
+        }
+
+        match (product.installment_frequency, product.installment_count, product.term_days) {
+            (Some(_), None, _) | (None, Some(_), _) => {
+                return Err(AppError::ValidationError(vec![
+                    "El plan de cuotas necesita la frecuencia y el número de cuotas".to_string(),
+                ]))
+            }
+            (Some(_), Some(_), Some(_)) => {
+                return Err(AppError::ValidationError(vec![
+                    "Un producto con plan de cuotas no lleva plazo en días".to_string(),
+                ]))
+            }
+            _ => {}
+        }
+
+        if let Some(rule_id) = product.penalty_rule_id {
+            let rule = self.penalty_repository
+                .find_rule_by_id(rule_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("Regla de recargo con ID {} no encontrada", rule_id)))?;
+            if !rule.active {
+                return Err(AppError::Conflict(format!(
+                    "La regla de recargo {} está desactivada",
+                    rule.name
+                )));
+            }
+        }
+
+        let limits = [&product.min_amount, &product.max_amount];
+        if limits.iter().any(|limit| limit.as_ref().is_some_and(|amount| !amount.is_positive())) {
+            return Err(AppError::ValidationError(vec![
+                "Los límites del monto deben ser de al menos 0.01".to_string(),
+            ]));
+        }
+        if let (Some(min), Some(max)) = (&product.min_amount, &product.max_amount) {
+            if min.minor_units() > max.minor_units() {
+                return Err(AppError::ValidationError(vec![
+                    "El monto mínimo no puede ser mayor que el máximo".to_string(),
+                ]));
+            }
+        }
+
+        Ok(())
+    }
+}
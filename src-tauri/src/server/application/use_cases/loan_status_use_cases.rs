@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+
+use crate::server::application::requests::loan_model_requests::LoanStatusRefreshModelResponse;
+use crate::server::domain::entities::loan::{
+    LOAN_DEFAULTED, LOAN_OVERDUE, LOAN_PAID, LOAN_RESTRUCTURED, LOAN_WRITTEN_OFF,
+};
+use crate::server::domain::repositories::borrower_repository::BorrowerRepository;
+use crate::server::domain::repositories::loan_repository::LoanRepository;
+use crate::server::domain::services::delinquency_policy::{DelinquencyPolicy, DelinquencyThresholds};
+use crate::utils::error::Result;
+
+/// Recalcula el estado de cobranza de préstamos y prestatarios según los días de atraso
+#[derive(Clone)]
+pub struct LoanStatusUseCases<L: LoanRepository, B: BorrowerRepository> {
+    loan_repository: L,
+    borrower_repository: B,
+    policy: DelinquencyPolicy,
+}
+
+impl<L: LoanRepository, B: BorrowerRepository> LoanStatusUseCases<L, B> {
+    pub fn new(loan_repository: L, borrower_repository: B, thresholds: DelinquencyThresholds) -> Self {
+        Self {
+            loan_repository,
+            borrower_repository,
+            policy: DelinquencyPolicy::new(thresholds),
+        }
+    }
+
+    /// Marca préstamos vencidos o en mora y ajusta el estado de cada prestatario
+    /// Solo escribe los registros cuyo estado cambia; el interés de las cuotas vencidas se
+    /// registra y el puntaje de crédito se recalcula para todos porque los días de atraso
+    /// avanzan aunque el estado no cambie
+    pub async fn refresh_statuses(&self, now: NaiveDateTime) -> Result<LoanStatusRefreshModelResponse> {
+        let loans = self.loan_repository.find_all().await?;
+
+        let mut summary = LoanStatusRefreshModelResponse {
+            loans_updated: 0,
+            borrowers_updated: 0,
+            overdue_loans: 0,
+            defaulted_loans: 0,
+        };
+        let mut statuses_by_borrower: HashMap<i32, Vec<&'static str>> = HashMap::new();
+
+        for loan in &loans {
+            // Un préstamo reestructurado está cerrado; su atraso lo refleja el préstamo nuevo
+            if loan.status == LOAN_RESTRUCTURED {
+                continue;
+            }
+
+            // Un préstamo castigado conserva su estado; para el prestatario pesa mientras quede algo sin recuperar
+            if loan.status == LOAN_WRITTEN_OFF {
+                let status = if loan.remaining.is_positive() { LOAN_WRITTEN_OFF } else { LOAN_PAID };
+                statuses_by_borrower.entry(loan.borrower_id).or_default().push(status);
+                continue;
+            }
+
+            let installments = match loan.schedule {
+                Some(_) => self.loan_repository.find_installments(loan.id).await?,
+                None => Vec::new(),
+            };
+
+            let days_past_due = DelinquencyPolicy::days_past_due(loan, &installments, now)?;
+            let status = self.policy.loan_status(loan, days_past_due);
+
+            if status != loan.status {
+                self.loan_repository.update_status(loan.id, status).await?;
+                summary.loans_updated += 1;
+            }
+            if status == LOAN_OVERDUE {
+                summary.overdue_loans += 1;
+            } else if status == LOAN_DEFAULTED {
+                summary.defaulted_loans += 1;
+            }
+
+            statuses_by_borrower.entry(loan.borrower_id).or_default().push(status);
+        }
+
+        for borrower in self.borrower_repository.find_all().await? {
+            let loan_statuses = statuses_by_borrower.remove(&borrower.id).unwrap_or_default();
+            let status = DelinquencyPolicy::borrower_status(&loan_statuses);
+
+            // Un prestatario bloqueado sigue así hasta que alguien lo desbloquee
+            if borrower.block.is_none() && status != borrower.status {
+                self.borrower_repository.update_status(borrower.id, status).await?;
+                summary.borrowers_updated += 1;
+            }
+            self.borrower_repository.recognize_interest(borrower.id, now).await?;
+            self.borrower_repository.refresh_credit_score(borrower.id, now).await?;
+        }
+
+        Ok(summary)
+    }
+}
@@ -0,0 +1,894 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::server::application::requests::loan_model_requests::{
+    CreateLoanRequest, GuarantorRequest, LoanGuarantorModelResponse, LoanInterestModelResponse,
+    LoanModelResponse, LoanRestructureModelResponse, LoanScheduleModelResponse, RestructureLoanRequest,
+    UpdateLoanRequest, WriteOffLoanRequest,
+};
+use crate::server::application::services::interest_calculator::{InterestCalculator, Repayment};
+use crate::server::application::services::schedule_generator::ScheduleGenerator;
+use crate::server::domain::entities::borrower::Borrower;
+use crate::server::domain::entities::collateral::COLLATERAL_HELD;
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::loan::{
+    InterestTerms, Loan, LoanRestructuring, NewLoan, NewLoanWriteOff, ScheduleTerms, LOAN_PAID,
+    LOAN_RESTRUCTURED, LOAN_WRITTEN_OFF,
+};
+use crate::server::domain::entities::loan_guarantor::{GuarantorExposureLimit, LoanGuarantor, NewLoanGuarantor};
+use crate::server::domain::entities::loan_product::LoanProduct;
+use crate::server::domain::repositories::borrower_repository::BorrowerRepository;
+use crate::server::domain::repositories::cash_box_repository::CashBoxRepository;
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::server::domain::repositories::loan_product_repository::LoanProductRepository;
+use crate::server::domain::repositories::loan_repository::LoanRepository;
+use crate::server::domain::services::exchange_rate_service::ExchangeRateService;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::date::{parse_stored_date, resolve_record_date};
+use crate::utils::error::{Result, AppError};
+
+#[derive(Clone)]
+pub struct LoanUseCases<
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+> {
+    loan_repository: T,
+    borrower_repository: U,
+    product_repository: R,
+    cash_box_repository: X,
+    exchange_rate_service: ExchangeRateService<C>,
+    guarantor_limit: GuarantorExposureLimit,
+}
+
+impl<T, U, C, R, X> LoanUseCases<T, U, C, R, X>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    pub fn new(
+        loan_repository: T,
+        borrower_repository: U,
+        currency_repository: C,
+        product_repository: R,
+        cash_box_repository: X,
+        guarantor_limit: GuarantorExposureLimit,
+    ) -> Self {
+        Self {
+            loan_repository,
+            borrower_repository,
+            product_repository,
+            cash_box_repository,
+            exchange_rate_service: ExchangeRateService::new(currency_repository),
+            guarantor_limit,
+        }
+    }
+
+    /// Crear nuevo préstamo - actualiza los totales del prestatario
+    /// Con producto, lo que no venga en la solicitud se toma de él y el monto debe respetar sus límites
+    /// Los garantes que superen el límite de exposición se rechazan o quedan como advertencia,
+    /// igual que un desembolso mayor que lo que hay en la caja de su moneda
+    pub async fn create_loan(&self, request: CreateLoanRequest) -> Result<LoanModelResponse> {
+        let CreateLoanRequest {
+            amount, currency, interest_rate, rate_period, interest_method,
+            schedule, due_date, borrower_id, product_id, guarantors, credit_limit_override_by, date,
+        } = request;
+
+        let borrower = self.find_borrower(borrower_id).await?;
+        ensure_not_blocked(&borrower)?;
+
+        let product = match product_id {
+            Some(product_id) => {
+                let product = self.find_product(product_id).await?;
+                if !product.active {
+                    return Err(AppError::Conflict(format!(
+                        "El producto {} está desactivado",
+                        product.name
+                    )));
+                }
+                Some(product)
+            }
+            None => None,
+        };
+
+        // Se aplica la tasa vigente en la fecha del registro, no la de hoy
+        let created_at = resolve_record_date(date)?;
+        let currency = currency
+            .or_else(|| product.as_ref().map(|product| product.currency.clone()))
+            .unwrap_or_else(|| BASE_CURRENCY.to_string());
+        let exchange_rate = self.exchange_rate_service.rate_at(&currency, created_at).await?;
+
+        let amount = Money::from_major(amount, currency)?;
+        if !amount.is_positive() {
+            return Err(AppError::ValidationError(vec!["El monto debe ser de al menos 0.01".to_string()]));
+        }
+
+        let defaults = product.as_ref().map(|product| product.interest).unwrap_or_else(InterestTerms::none);
+        let interest = InterestTerms {
+            rate: interest_rate.unwrap_or(defaults.rate),
+            period: rate_period.unwrap_or(defaults.period),
+            method: interest_method.unwrap_or(defaults.method),
+        };
+
+        // El plan o el plazo del producto solo se usan si la solicitud no trae ni plan ni vencimiento
+        let mut due_date = due_date.map(|due_date| due_date.naive_utc());
+        let mut schedule = schedule;
+        if let Some(product) = &product {
+            if schedule.is_none() && due_date.is_none() {
+                schedule = product.schedule_from(created_at);
+                due_date = product.due_date_from(created_at);
+            }
+            self.check_product_limits(product, &amount, created_at).await?;
+        }
+        check_due_date(due_date, created_at)?;
+
+        let guarantors = guarantors
+            .into_iter()
+            .map(|guarantor| new_guarantor(guarantor, amount.currency()))
+            .collect::<Result<Vec<_>>>()?;
+        let mut warnings = self
+            .check_guarantors(borrower_id, &guarantors, &[], &amount, &amount, exchange_rate)
+            .await?;
+
+        let excess = self.check_credit_limit(&borrower, &amount, None).await?;
+        let credit_limit_override_by = authorize_credit_limit(excess, credit_limit_override_by, &mut warnings)?;
+        if let Some(warning) = self.check_cash_box(&amount, created_at).await? {
+            warnings.push(warning);
+        }
+
+        let mut new_loan = NewLoan::new(amount, exchange_rate, interest, borrower_id, created_at)?
+            .with_due_date(due_date)
+            .with_guarantors(guarantors)
+            .with_credit_limit_override(credit_limit_override_by);
+        if let Some(product) = &product {
+            new_loan = new_loan.with_product(product.id, product.penalty_rule_id);
+        }
+        let new_loan = with_generated_schedule(new_loan, schedule)?;
+        let loan = self.loan_repository.create(&new_loan).await?;
+
+        // Releer el prestatario para devolver los totales ya actualizados
+        let borrower = self.find_borrower(borrower_id).await?;
+        let mut response = LoanModelResponse::from_parts(loan, borrower);
+        response.warnings = warnings;
+        Ok(response)
+    }
+
+    /// Obtener todos los préstamos junto a su prestatario
+    /// Con `deleted` se obtienen los eliminados, también los de prestatarios eliminados
+    pub async fn get_all_loans(&self, deleted: bool) -> Result<Vec<LoanModelResponse>> {
+        let (loans, mut borrowers) = if deleted {
+            (
+                self.loan_repository.find_deleted().await?,
+                self.borrower_repository.find_deleted().await?,
+            )
+        } else {
+            (self.loan_repository.find_all().await?, Vec::new())
+        };
+        borrowers.extend(self.borrower_repository.find_all().await?);
+
+        let borrowers: HashMap<i32, Borrower> = borrowers
+            .into_iter()
+            .map(|borrower| (borrower.id, borrower))
+            .collect();
+
+        let responses: Vec<LoanModelResponse> = loans
+            .into_iter()
+            .filter_map(|loan| {
+                let borrower = borrowers.get(&loan.borrower_id)?.clone();
+                Some(LoanModelResponse::from_parts(loan, borrower))
+            })
+            .collect();
+
+        Ok(responses)
+    }
+
+    /// Obtener préstamo por ID, con el respaldo de sus garantías
+    pub async fn get_loan_by_id(&self, id: i32) -> Result<LoanModelResponse> {
+        let loan = self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        // El respaldo se valora con las tasas de hoy, igual que el saldo que cubre
+        let now = Utc::now().naive_utc();
+        let mut collateral_value = Money::zero(BASE_CURRENCY);
+        for collateral in self.loan_repository.find_collateral(id).await? {
+            if collateral.status == COLLATERAL_HELD {
+                let value = self.exchange_rate_service.convert_to_cup(&collateral.estimated_value, now).await?;
+                collateral_value = collateral_value.checked_add(&value)?;
+            }
+        }
+        let remaining_cup = self.exchange_rate_service.convert_to_cup(&loan.remaining, now).await?;
+
+        let borrower = self.find_borrower(loan.borrower_id).await?;
+        let mut response = LoanModelResponse::from_parts(loan, borrower);
+        if collateral_value.is_positive() {
+            let ratio = remaining_cup.minor_units() as f64 / collateral_value.minor_units() as f64 * 100.0;
+            response.collateral_value = Some(collateral_value.to_major());
+            response.loan_to_value = Some((ratio * 100.0).round() / 100.0);
+        }
+        Ok(response)
+    }
+
+    /// Actualizar préstamo - reajusta los totales de los prestatarios afectados
+    pub async fn update_loan(&self, id: i32, request: UpdateLoanRequest) -> Result<LoanModelResponse> {
+        let existing_loan = self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        if existing_loan.status == LOAN_RESTRUCTURED {
+            return Err(AppError::Conflict(
+                "Un préstamo reestructurado no se puede modificar; su historial se conserva".to_string()
+            ));
+        }
+        if existing_loan.status == LOAN_WRITTEN_OFF {
+            return Err(AppError::Conflict("Un préstamo castigado no se puede modificar".to_string()));
+        }
+
+        let borrower_id = request.borrower_id.unwrap_or(existing_loan.borrower_id);
+        let borrower = self.find_borrower(borrower_id).await?;
+        if borrower_id != existing_loan.borrower_id {
+            ensure_not_blocked(&borrower)?;
+        }
+
+        let rate_inputs_changed = request.currency.is_some() || request.date.is_some();
+        let currency = request.currency.unwrap_or_else(|| existing_loan.amount.currency().to_string());
+
+        // Lo garantizado está en la moneda del préstamo, así que no puede cambiar bajo los garantes
+        let guarantors = self.loan_repository.find_guarantors(id).await?;
+        if !guarantors.is_empty() && currency != existing_loan.amount.currency() {
+            return Err(AppError::Conflict(
+                "Quite los garantes antes de cambiar la moneda del préstamo".to_string()
+            ));
+        }
+        if guarantors.iter().any(|guarantor| guarantor.borrower_id == Some(borrower_id)) {
+            return Err(AppError::ValidationError(vec![
+                "El prestatario no puede ser garante de su propio préstamo".to_string(),
+            ]));
+        }
+        let created_at = match request.date {
+            Some(date) => resolve_record_date(Some(date))?,
+            None => parse_stored_date(&existing_loan.created_at),
+        };
+
+        // Si cambian la moneda o la fecha se vuelve a resolver la tasa; si no, se conserva la original
+        let exchange_rate = if !rate_inputs_changed {
+            existing_loan.exchange_rate
+        } else {
+            self.exchange_rate_service.rate_at(&currency, created_at).await?
+        };
+
+        // Sin un monto nuevo se conserva el importe anterior, aunque cambie la moneda
+        let amount = match request.amount {
+            Some(amount) => Money::from_major(amount, currency)?,
+            None => Money::from_minor(existing_loan.amount.minor_units(), currency),
+        };
+        if !amount.is_positive() {
+            return Err(AppError::ValidationError(vec!["El monto debe ser de al menos 0.01".to_string()]));
+        }
+
+        // Un préstamo creado con un producto sigue sujeto a sus límites
+        if let Some(product_id) = existing_loan.product_id {
+            let product = self.find_product(product_id).await?;
+            self.check_product_limits(&product, &amount, created_at).await?;
+        }
+
+        // Un cambio de monto, moneda o prestatario vuelve a pasar por el límite de crédito;
+        // el préstamo cuenta por su importe nuevo en lugar de su saldo actual
+        let mut warnings = Vec::new();
+        let credit_limit_override_by = if amount != existing_loan.amount || borrower_id != existing_loan.borrower_id {
+            let excess = self.check_credit_limit(&borrower, &amount, Some(id)).await?;
+            authorize_credit_limit(excess, request.credit_limit_override_by, &mut warnings)?
+        } else {
+            None
+        };
+
+        let interest = InterestTerms {
+            rate: request.interest_rate.unwrap_or(existing_loan.interest.rate),
+            period: request.rate_period.unwrap_or(existing_loan.interest.period),
+            method: request.interest_method.unwrap_or(existing_loan.interest.method),
+        };
+
+        let due_date = match request.due_date {
+            Some(due_date) => Some(due_date.naive_utc()),
+            None => existing_loan.due_date.as_deref().map(parse_stored_date),
+        };
+        let schedule = request.schedule.or(existing_loan.schedule);
+        if schedule.is_none() {
+            check_due_date(due_date, created_at)?;
+        }
+
+        // Un préstamo con plan lo conserva y se regenera con el importe y condiciones nuevos
+        let updated_loan_entity = with_generated_schedule(
+            NewLoan::new(amount, exchange_rate, interest, borrower_id, created_at)?
+                .with_due_date(due_date)
+                .with_credit_limit_override(credit_limit_override_by.or(existing_loan.credit_limit_override_by)),
+            schedule,
+        )?;
+
+        let loan = self.loan_repository.update(id, &updated_loan_entity).await?;
+        let borrower = self.find_borrower(loan.borrower_id).await?;
+
+        let mut response = LoanModelResponse::from_parts(loan, borrower);
+        response.warnings = warnings;
+        Ok(response)
+    }
+
+    /// Interés devengado y total adeudado del préstamo a la fecha `at` (por defecto, ahora)
+    pub async fn get_loan_interest(&self, id: i32, at: Option<DateTime<Utc>>) -> Result<LoanInterestModelResponse> {
+        let loan = self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        let as_of = at.unwrap_or_else(Utc::now);
+
+        // Cada parte de pago asignada al préstamo cuenta como un abono en su fecha
+        let repayments: Vec<Repayment> = self.loan_repository
+            .find_allocations(id)
+            .await?
+            .into_iter()
+            .map(|allocation| Repayment {
+                date: parse_stored_date(&allocation.created_at),
+                amount: allocation.amount,
+            })
+            .collect();
+
+        let breakdown = InterestCalculator::calculate(
+            &loan.amount,
+            &loan.interest,
+            parse_stored_date(&loan.created_at),
+            as_of.naive_utc(),
+            &repayments,
+        )?;
+
+        Ok(LoanInterestModelResponse::from_breakdown(&loan, as_of, breakdown))
+    }
+
+    /// Plan de cuotas del préstamo con lo pagado de cada cuota
+    pub async fn get_loan_schedule(&self, id: i32) -> Result<LoanScheduleModelResponse> {
+        let loan = self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        let schedule = loan.schedule.ok_or_else(|| {
+            AppError::NotFound(format!("El préstamo con ID {} no tiene plan de cuotas", id))
+        })?;
+
+        let installments = self.loan_repository.find_installments(id).await?;
+        LoanScheduleModelResponse::from_parts(&loan, &schedule, installments)
+    }
+
+    /// Reestructurar un préstamo - lo cierra y crea uno nuevo con el principal pendiente y las condiciones nuevas
+    /// El préstamo nuevo conserva la moneda y la tasa de cambio del original, así la
+    /// reestructuración no altera el saldo en CUP salvo por lo que se capitaliza
+    pub async fn restructure_loan(&self, id: i32, request: RestructureLoanRequest) -> Result<LoanRestructureModelResponse> {
+        let loan = self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        if loan.status == LOAN_RESTRUCTURED {
+            return Err(AppError::Conflict("El préstamo ya fue reestructurado".to_string()));
+        }
+        if loan.status == LOAN_WRITTEN_OFF {
+            return Err(AppError::Conflict("No se puede reestructurar un préstamo castigado".to_string()));
+        }
+        if !loan.remaining.is_positive() {
+            return Err(AppError::Conflict("No se puede reestructurar un préstamo saldado".to_string()));
+        }
+
+        let restructured_at = resolve_record_date(request.date)?;
+        if restructured_at <= parse_stored_date(&loan.created_at) {
+            return Err(AppError::ValidationError(vec![
+                "La fecha de reestructuración debe ser posterior a la fecha del préstamo".to_string(),
+            ]));
+        }
+
+        // Lo adeudado se mide a la fecha de la reestructuración con los pagos asignados hasta entonces
+        let repayments: Vec<Repayment> = self.loan_repository
+            .find_allocations(id)
+            .await?
+            .into_iter()
+            .map(|allocation| Repayment {
+                date: parse_stored_date(&allocation.created_at),
+                amount: allocation.amount,
+            })
+            .collect();
+        let breakdown = InterestCalculator::calculate(
+            &loan.amount,
+            &loan.interest,
+            parse_stored_date(&loan.created_at),
+            restructured_at,
+            &repayments,
+        )?;
+
+        let currency = loan.amount.currency().to_string();
+        let capitalized_interest = if request.capitalize_interest {
+            breakdown.outstanding_interest.clone()
+        } else {
+            Money::zero(&currency)
+        };
+        let capitalized_penalties = if request.capitalize_penalties {
+            self.loan_repository.find_charged_penalties(id).await?
+        } else {
+            Money::zero(BASE_CURRENCY)
+        };
+
+        let penalties_in_currency = capitalized_penalties.convert(1.0 / loan.exchange_rate, &currency)?;
+
+        let amount = breakdown
+            .outstanding_principal
+            .checked_add(&capitalized_interest)?
+            .checked_add(&penalties_in_currency)?;
+        if !amount.is_positive() {
+            return Err(AppError::Conflict(
+                "El préstamo no tiene principal pendiente que reestructurar".to_string()
+            ));
+        }
+
+        let interest = InterestTerms {
+            rate: request.interest_rate.unwrap_or(loan.interest.rate),
+            period: request.rate_period.unwrap_or(loan.interest.period),
+            method: request.interest_method.unwrap_or(loan.interest.method),
+        };
+
+        let due_date = request.due_date.map(|due_date| due_date.naive_utc());
+        if request.schedule.is_none() {
+            check_due_date(due_date, restructured_at)?;
+        }
+
+        // El préstamo nuevo sigue ligado al producto del original y a su regla de recargo
+        let mut new_loan = NewLoan::new(amount, loan.exchange_rate, interest, loan.borrower_id, restructured_at)?
+            .with_due_date(due_date)
+            .with_restructured_from(id);
+        if let Some(product_id) = loan.product_id {
+            new_loan = new_loan.with_product(product_id, loan.penalty_rule_id);
+        }
+        let new_loan = with_generated_schedule(new_loan, request.schedule)?;
+
+        let restructuring = LoanRestructuring {
+            new_loan,
+            carried_principal_cup: breakdown.outstanding_principal.convert(loan.exchange_rate, BASE_CURRENCY)?,
+            capitalized_penalties,
+            restructured_at,
+        };
+        let new_loan = self.loan_repository.restructure(id, &restructuring).await?;
+
+        // Releer el original para devolverlo ya cerrado, junto con los totales del prestatario
+        let previous_loan = self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+        let borrower = self.find_borrower(new_loan.borrower_id).await?;
+
+        Ok(LoanRestructureModelResponse {
+            previous_loan: LoanModelResponse::from_parts(previous_loan, borrower.clone()),
+            loan: LoanModelResponse::from_parts(new_loan, borrower),
+            carried_principal: breakdown.outstanding_principal.to_major(),
+            capitalized_interest: capitalized_interest.to_major(),
+            capitalized_penalties: penalties_in_currency.to_major(),
+        })
+    }
+
+    /// Castigar un préstamo incobrable - lo pendiente sale del saldo del prestatario y
+    /// queda en su total castigado; los pagos que reciba después cuentan como recuperaciones
+    pub async fn write_off_loan(&self, id: i32, request: WriteOffLoanRequest, written_off_by: i32) -> Result<LoanModelResponse> {
+        let loan = self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        if loan.status == LOAN_RESTRUCTURED {
+            return Err(AppError::Conflict(
+                "Un préstamo reestructurado no se puede castigar; su saldo está en el préstamo nuevo".to_string()
+            ));
+        }
+        if loan.status == LOAN_WRITTEN_OFF {
+            return Err(AppError::Conflict("El préstamo ya fue castigado".to_string()));
+        }
+
+        let reason = request.reason.trim();
+        if reason.is_empty() {
+            return Err(AppError::ValidationError(vec!["El motivo del castigo es requerido".to_string()]));
+        }
+
+        let written_off_at = resolve_record_date(request.date)?;
+        if written_off_at <= parse_stored_date(&loan.created_at) {
+            return Err(AppError::ValidationError(vec![
+                "La fecha del castigo debe ser posterior a la fecha del préstamo".to_string(),
+            ]));
+        }
+
+        let write_off = NewLoanWriteOff {
+            reason: reason.to_string(),
+            written_off_by,
+            written_off_at,
+        };
+        let loan = self.loan_repository.write_off(id, &write_off).await?;
+        let borrower = self.find_borrower(loan.borrower_id).await?;
+
+        Ok(LoanModelResponse::from_parts(loan, borrower))
+    }
+
+    /// Eliminar préstamo - descuenta su importe de los totales del prestatario hasta que se restaure
+    /// Los préstamos que forman parte de una reestructuración o que fueron castigados se conservan como historial
+    pub async fn delete_loan(&self, id: i32, deleted_by: i32) -> Result<()> {
+        let loan = self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        if loan.status == LOAN_RESTRUCTURED || loan.restructured_from_id.is_some() {
+            return Err(AppError::Conflict(
+                "No se puede eliminar un préstamo que forma parte de una reestructuración".to_string()
+            ));
+        }
+        if loan.status == LOAN_WRITTEN_OFF {
+            return Err(AppError::Conflict("No se puede eliminar un préstamo castigado".to_string()));
+        }
+
+        self.loan_repository.delete(id, deleted_by).await
+    }
+
+    /// Restaurar un préstamo eliminado - vuelve a sumar su importe a los totales del prestatario
+    pub async fn restore_loan(&self, id: i32) -> Result<LoanModelResponse> {
+        let loan = self.find_deleted_loan(id).await?;
+
+        let borrower = match self.borrower_repository.find_by_id(loan.borrower_id).await? {
+            Some(borrower) => borrower,
+            None => {
+                return Err(AppError::Conflict(format!(
+                    "El prestatario con ID {} está eliminado; restáurelo antes que el préstamo",
+                    loan.borrower_id
+                )))
+            }
+        };
+
+        let loan = self.loan_repository.restore(id).await?;
+        Ok(LoanModelResponse::from_parts(loan, borrower))
+    }
+
+    /// Borrar definitivamente un préstamo eliminado
+    pub async fn purge_loan(&self, id: i32) -> Result<()> {
+        self.find_deleted_loan(id).await?;
+
+        self.loan_repository.purge(id).await
+    }
+
+    /// Garantes de un préstamo
+    pub async fn get_loan_guarantors(&self, id: i32) -> Result<Vec<LoanGuarantorModelResponse>> {
+        self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        let guarantors = self.loan_repository.find_guarantors(id).await?;
+        Ok(guarantors.into_iter().map(LoanGuarantorModelResponse::from).collect())
+    }
+
+    /// Agregar un garante a un préstamo con saldo pendiente
+    pub async fn add_loan_guarantor(&self, id: i32, request: GuarantorRequest) -> Result<LoanGuarantorModelResponse> {
+        let loan = self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        if loan.status == LOAN_RESTRUCTURED || !loan.remaining.is_positive() {
+            return Err(AppError::Conflict(
+                "Solo se pueden agregar garantes a un préstamo con saldo pendiente".to_string()
+            ));
+        }
+
+        let guarantor = new_guarantor(request, loan.amount.currency())?;
+        let existing = self.loan_repository.find_guarantors(id).await?;
+        let warnings = self
+            .check_guarantors(
+                loan.borrower_id,
+                std::slice::from_ref(&guarantor),
+                &existing,
+                &loan.amount,
+                &loan.remaining,
+                loan.exchange_rate,
+            )
+            .await?;
+
+        let guarantor = self.loan_repository.add_guarantor(id, &guarantor).await?;
+        let mut response = LoanGuarantorModelResponse::from(guarantor);
+        response.warnings = warnings;
+        Ok(response)
+    }
+
+    /// Quitar un garante de un préstamo
+    pub async fn remove_loan_guarantor(&self, id: i32, guarantor_id: i32) -> Result<()> {
+        self.loan_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Préstamo con ID {} no encontrado", id)))?;
+
+        self.loan_repository.remove_guarantor(id, guarantor_id).await
+    }
+
+    /// Devuelve el motivo si el préstamo nuevo lleva al prestatario por encima de su límite
+    /// La exposición es el saldo pendiente de sus préstamos abiertos más el préstamo nuevo,
+    /// todo en CUP con las tasas de hoy; `excluded_loan` es el préstamo que se corrige, que
+    /// cuenta por `amount` en lugar de por su saldo
+    async fn check_credit_limit(&self, borrower: &Borrower, amount: &Money, excluded_loan: Option<i32>) -> Result<Option<String>> {
+        let limit = match &borrower.credit_limit {
+            Some(limit) => limit,
+            None => return Ok(None),
+        };
+
+        let now = Utc::now().naive_utc();
+        let mut exposure = self.exchange_rate_service.convert_to_cup(amount, now).await?;
+        for loan in self.loan_repository.find_by_borrower(borrower.id).await? {
+            let closed = [LOAN_PAID, LOAN_RESTRUCTURED, LOAN_WRITTEN_OFF].contains(&loan.status.as_str());
+            if closed || !loan.remaining.is_positive() || Some(loan.id) == excluded_loan {
+                continue;
+            }
+            let remaining = self.exchange_rate_service.convert_to_cup(&loan.remaining, now).await?;
+            exposure = exposure.checked_add(&remaining)?;
+        }
+
+        if exposure.minor_units() <= limit.minor_units() {
+            return Ok(None);
+        }
+
+        Ok(Some(format!(
+            "La exposición de {} llegaría a {:.2} CUP y su límite de crédito es {:.2} CUP",
+            borrower.name,
+            exposure.to_major(),
+            limit.to_major()
+        )))
+    }
+
+    /// Busca un préstamo eliminado; si existe pero no está eliminado es un conflicto
+    async fn find_deleted_loan(&self, id: i32) -> Result<Loan> {
+        if let Some(loan) = self.loan_repository.find_deleted_by_id(id).await? {
+            return Ok(loan);
+        }
+
+        match self.loan_repository.find_by_id(id).await? {
+            Some(_) => Err(AppError::Conflict(format!("El préstamo con ID {} no está eliminado", id))),
+            None => Err(AppError::NotFound(format!("Préstamo con ID {} no encontrado", id))),
+        }
+    }
+
+    async fn find_borrower(&self, borrower_id: i32) -> Result<Borrower> {
+        self.borrower_repository
+            .find_by_id(borrower_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Prestatario con ID {} no encontrado", borrower_id)))
+    }
+
+    async fn find_product(&self, product_id: i32) -> Result<LoanProduct> {
+        self.product_repository
+            .find_by_id(product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Producto con ID {} no encontrado", product_id)))
+    }
+
+    /// Advierte si el desembolso supera el saldo de la caja de su moneda en la fecha del préstamo
+    /// Las cajas que todavía no tienen saldo de apertura no se controlan
+    async fn check_cash_box(&self, amount: &Money, at: NaiveDateTime) -> Result<Option<String>> {
+        if self.cash_box_repository.find_opening(amount.currency()).await?.is_none() {
+            return Ok(None);
+        }
+
+        let available = self.cash_box_repository.find_balance(amount.currency(), at).await?.balance;
+        if amount.minor_units() <= available.minor_units() {
+            return Ok(None);
+        }
+
+        Ok(Some(format!(
+            "La caja {} tiene {:.2} y el desembolso es de {:.2}",
+            amount.currency(),
+            available.to_major(),
+            amount.to_major()
+        )))
+    }
+
+    /// Valida los garantes nuevos de un préstamo y devuelve las advertencias de exposición
+    /// Cada garante registrado responde por lo garantizado hasta el saldo `remaining`,
+    /// que se suma a lo que ya garantiza en otros préstamos
+    async fn check_guarantors(
+        &self,
+        borrower_id: i32,
+        guarantors: &[NewLoanGuarantor],
+        existing: &[LoanGuarantor],
+        amount: &Money,
+        remaining: &Money,
+        exchange_rate: f32,
+    ) -> Result<Vec<String>> {
+        let mut warnings = Vec::new();
+        let mut guarantor_ids: Vec<i32> = existing.iter().filter_map(|guarantor| guarantor.borrower_id).collect();
+
+        for guarantor in guarantors {
+            if !guarantor.amount.is_positive() {
+                return Err(AppError::ValidationError(vec![
+                    "Lo garantizado debe ser de al menos 0.01".to_string(),
+                ]));
+            }
+            if guarantor.amount.minor_units() > amount.minor_units() {
+                return Err(AppError::ValidationError(vec![
+                    "Lo garantizado no puede superar el monto del préstamo".to_string(),
+                ]));
+            }
+
+            let guarantor_id = match guarantor.borrower_id {
+                Some(guarantor_id) if guarantor.contact_name.is_none() => guarantor_id,
+                Some(_) => {
+                    return Err(AppError::ValidationError(vec![
+                        "Indique un prestatario o un contacto como garante, no ambos".to_string(),
+                    ]))
+                }
+                None if guarantor.contact_name.is_some() => continue,
+                None => {
+                    return Err(AppError::ValidationError(vec![
+                        "Un garante sin ficha necesita un nombre de contacto".to_string(),
+                    ]))
+                }
+            };
+
+            if guarantor_id == borrower_id {
+                return Err(AppError::ValidationError(vec![
+                    "El prestatario no puede ser garante de su propio préstamo".to_string(),
+                ]));
+            }
+            if guarantor_ids.contains(&guarantor_id) {
+                return Err(AppError::Conflict(format!(
+                    "El prestatario con ID {} ya es garante del préstamo",
+                    guarantor_id
+                )));
+            }
+            guarantor_ids.push(guarantor_id);
+
+            let guarantor_borrower = self.find_borrower(guarantor_id).await?;
+            let limit = match &self.guarantor_limit.limit {
+                Some(limit) => limit,
+                None => continue,
+            };
+
+            let exposure = guarantor_borrower
+                .guarantee_exposure
+                .checked_add(&guarantor.exposure(remaining, exchange_rate)?)?;
+            if exposure.minor_units() > limit.minor_units() {
+                let message = format!(
+                    "{} garantizaría {:.2} CUP en préstamos ajenos y el límite es {:.2} CUP",
+                    guarantor_borrower.name,
+                    exposure.to_major(),
+                    limit.to_major()
+                );
+                if self.guarantor_limit.refuse {
+                    return Err(AppError::Conflict(message));
+                }
+                warnings.push(message);
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    /// Los límites están en la moneda del producto; si el préstamo es en otra moneda
+    /// se comparan en CUP con las tasas vigentes en la fecha del préstamo
+    async fn check_product_limits(&self, product: &LoanProduct, amount: &Money, at: NaiveDateTime) -> Result<()> {
+        if product.min_amount.is_none() && product.max_amount.is_none() {
+            return Ok(());
+        }
+
+        let in_same_currency = amount.currency() == product.currency;
+        let amount_cup = if in_same_currency {
+            amount.clone()
+        } else {
+            self.exchange_rate_service.convert_to_cup(amount, at).await?
+        };
+
+        if let Some(min) = &product.min_amount {
+            let min_cup = if in_same_currency {
+                min.clone()
+            } else {
+                self.exchange_rate_service.convert_to_cup(min, at).await?
+            };
+            if amount_cup.minor_units() < min_cup.minor_units() {
+                return Err(AppError::ValidationError(vec![format!(
+                    "El monto mínimo del producto {} es {:.2} {}",
+                    product.name,
+                    min.to_major(),
+                    min.currency()
+                )]));
+            }
+        }
+        if let Some(max) = &product.max_amount {
+            let max_cup = if in_same_currency {
+                max.clone()
+            } else {
+                self.exchange_rate_service.convert_to_cup(max, at).await?
+            };
+            if amount_cup.minor_units() > max_cup.minor_units() {
+                return Err(AppError::ValidationError(vec![format!(
+                    "El monto máximo del producto {} es {:.2} {}",
+                    product.name,
+                    max.to_major(),
+                    max.currency()
+                )]));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn with_generated_schedule(new_loan: NewLoan, schedule: Option<ScheduleTerms>) -> Result<NewLoan> {
+    match schedule {
+        Some(schedule) => {
+            let installments = ScheduleGenerator::generate(
+                &new_loan.amount,
+                &new_loan.interest,
+                &schedule,
+                new_loan.created_at,
+            )?;
+            Ok(new_loan.with_schedule(schedule, installments))
+        }
+        None => Ok(new_loan),
+    }
+}
+
+/// Un nombre de contacto en blanco cuenta como ausente
+fn new_guarantor(request: GuarantorRequest, currency: &str) -> Result<NewLoanGuarantor> {
+    let blank_to_none = |value: Option<String>| {
+        value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    Ok(NewLoanGuarantor {
+        borrower_id: request.borrower_id,
+        contact_name: blank_to_none(request.contact_name),
+        contact_phone: blank_to_none(request.contact_phone),
+        amount: Money::from_major(request.amount, currency)?,
+    })
+}
+
+/// Superar el límite solo se admite con la autorización de un administrador, que queda registrada
+fn authorize_credit_limit(
+    excess: Option<String>,
+    credit_limit_override_by: Option<i32>,
+    warnings: &mut Vec<String>,
+) -> Result<Option<i32>> {
+    match (excess, credit_limit_override_by) {
+        (Some(excess), Some(user_id)) => {
+            warnings.push(format!("{}; autorizado por un administrador", excess));
+            Ok(Some(user_id))
+        }
+        (Some(excess), None) => Err(AppError::Conflict(excess)),
+        (None, _) => Ok(None),
+    }
+}
+
+fn check_due_date(due_date: Option<NaiveDateTime>, created_at: NaiveDateTime) -> Result<()> {
+    match due_date {
+        Some(due_date) if due_date <= created_at => Err(AppError::ValidationError(vec![
+            "La fecha de vencimiento debe ser posterior a la fecha del préstamo".to_string(),
+        ])),
+        _ => Ok(()),
+    }
+}
+
+/// Un prestatario bloqueado no puede recibir préstamos nuevos
+fn ensure_not_blocked(borrower: &Borrower) -> Result<()> {
+    match &borrower.block {
+        Some(block) => Err(AppError::Conflict(format!(
+            "El prestatario {} está bloqueado: {}",
+            borrower.name, block.reason
+        ))),
+        None => Ok(()),
+    }
+}
@@ -1,2 +1,13 @@
 pub mod user_use_cases;
-pub mod  borrower_use_cases;
\ No newline at end of file
+pub mod  borrower_use_cases;
+pub mod loan_use_cases;
+pub mod payment_use_cases;
+pub mod currency_use_cases;
+pub mod report_use_cases;
+pub mod loan_status_use_cases;
+pub mod penalty_use_cases;
+pub mod loan_product_use_cases;
+pub mod collateral_use_cases;
+pub mod statement_use_cases;
+pub mod ledger_use_cases;
+pub mod cash_box_use_cases;
\ No newline at end of file
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+
+use crate::server::application::requests::payment_model_requests::{CreatePaymentRequest, PaymentModelResponse, UpdatePaymentRequest};
+use crate::server::domain::entities::borrower::Borrower;
+use crate::server::domain::entities::payment::{NewPayment, Payment};
+use crate::server::domain::entities::payment_allocation::AllocationStrategy;
+use crate::server::domain::repositories::borrower_repository::BorrowerRepository;
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::server::domain::repositories::payment_repository::PaymentRepository;
+use crate::server::domain::services::exchange_rate_service::ExchangeRateService;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::date::{parse_stored_date, resolve_record_date};
+use crate::utils::error::{Result, AppError};
+
+#[derive(Clone)]
+pub struct PaymentUseCases<T: PaymentRepository, U: BorrowerRepository, C: CurrencyRepository> {
+    payment_repository: T,
+    borrower_repository: U,
+    exchange_rate_service: ExchangeRateService<C>,
+    default_allocation_strategy: AllocationStrategy,
+}
+
+impl<T: PaymentRepository, U: BorrowerRepository, C: CurrencyRepository> PaymentUseCases<T, U, C> {
+    pub fn new(
+        payment_repository: T,
+        borrower_repository: U,
+        currency_repository: C,
+        default_allocation_strategy: AllocationStrategy,
+    ) -> Self {
+        Self {
+            payment_repository,
+            borrower_repository,
+            exchange_rate_service: ExchangeRateService::new(currency_repository),
+            default_allocation_strategy,
+        }
+    }
+
+    /// Registrar nuevo pago - se convierte a CUP, se reparte entre los préstamos abiertos
+    /// y actualiza los totales del prestatario
+    pub async fn create_payment(&self, request: CreatePaymentRequest) -> Result<PaymentModelResponse> {
+        let CreatePaymentRequest { amount, currency, allocation_strategy, borrower_id, date } = request;
+
+        self.find_borrower(borrower_id).await?;
+
+        // Se aplica la tasa vigente en la fecha del registro, no la de hoy
+        let created_at = resolve_record_date(date)?;
+        let exchange_rate = self.exchange_rate_service.rate_at(&currency, created_at).await?;
+
+        let amount = Money::from_major(amount, currency)?;
+        if !amount.is_positive() {
+            return Err(AppError::ValidationError(vec!["El monto debe ser de al menos 0.01".to_string()]));
+        }
+
+        let allocation_strategy = allocation_strategy.unwrap_or(self.default_allocation_strategy);
+        let new_payment = NewPayment::new(amount, exchange_rate, allocation_strategy, borrower_id, created_at)?;
+        let payment = self.payment_repository.create(&new_payment).await?;
+
+        // Releer el prestatario para devolver los totales ya actualizados
+        let borrower = self.find_borrower(borrower_id).await?;
+        Ok(PaymentModelResponse::from_parts(payment, borrower))
+    }
+
+    /// Obtener todos los pagos junto a su prestatario
+    /// Con `deleted` se obtienen los eliminados, también los de prestatarios eliminados
+    pub async fn get_all_payments(&self, deleted: bool) -> Result<Vec<PaymentModelResponse>> {
+        let (payments, mut borrowers) = if deleted {
+            (
+                self.payment_repository.find_deleted().await?,
+                self.borrower_repository.find_deleted().await?,
+            )
+        } else {
+            (self.payment_repository.find_all().await?, Vec::new())
+        };
+        borrowers.extend(self.borrower_repository.find_all().await?);
+
+        let borrowers: HashMap<i32, Borrower> = borrowers
+            .into_iter()
+            .map(|borrower| (borrower.id, borrower))
+            .collect();
+
+        let responses: Vec<PaymentModelResponse> = payments
+            .into_iter()
+            .filter_map(|payment| {
+                let borrower = borrowers.get(&payment.borrower_id)?.clone();
+                Some(PaymentModelResponse::from_parts(payment, borrower))
+            })
+            .collect();
+
+        Ok(responses)
+    }
+
+    /// Obtener pago por ID
+    pub async fn get_payment_by_id(&self, id: i32) -> Result<PaymentModelResponse> {
+        let payment = self.payment_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Pago con ID {} no encontrado", id)))?;
+
+        let borrower = self.find_borrower(payment.borrower_id).await?;
+        Ok(PaymentModelResponse::from_parts(payment, borrower))
+    }
+
+    /// Actualizar pago - reajusta los totales de los prestatarios afectados
+    pub async fn update_payment(&self, id: i32, request: UpdatePaymentRequest) -> Result<PaymentModelResponse> {
+        let existing_payment = self.payment_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Pago con ID {} no encontrado", id)))?;
+
+        let borrower_id = request.borrower_id.unwrap_or(existing_payment.borrower_id);
+        if borrower_id != existing_payment.borrower_id {
+            self.find_borrower(borrower_id).await?;
+        }
+
+        let rate_inputs_changed = request.currency.is_some() || request.date.is_some();
+        let currency = request.currency.unwrap_or_else(|| existing_payment.amount.currency().to_string());
+        let created_at = match request.date {
+            Some(date) => resolve_record_date(Some(date))?,
+            None => parse_stored_date(&existing_payment.created_at),
+        };
+
+        // Si cambian la moneda o la fecha se vuelve a resolver la tasa; si no, se conserva la original
+        let exchange_rate = if !rate_inputs_changed {
+            existing_payment.exchange_rate
+        } else {
+            self.exchange_rate_service.rate_at(&currency, created_at).await?
+        };
+
+        // Sin un monto nuevo se conserva el importe anterior, aunque cambie la moneda
+        let amount = match request.amount {
+            Some(amount) => Money::from_major(amount, currency)?,
+            None => Money::from_minor(existing_payment.amount.minor_units(), currency),
+        };
+        if !amount.is_positive() {
+            return Err(AppError::ValidationError(vec!["El monto debe ser de al menos 0.01".to_string()]));
+        }
+
+        let allocation_strategy = request.allocation_strategy.unwrap_or(existing_payment.allocation_strategy);
+        let updated_payment_entity = NewPayment::new(amount, exchange_rate, allocation_strategy, borrower_id, created_at)?;
+
+        let payment = self.payment_repository.update(id, &updated_payment_entity).await?;
+        let borrower = self.find_borrower(payment.borrower_id).await?;
+
+        Ok(PaymentModelResponse::from_parts(payment, borrower))
+    }
+
+    /// Eliminar pago - descuenta su importe de lo pagado por el prestatario hasta que se restaure
+    pub async fn delete_payment(&self, id: i32, deleted_by: i32) -> Result<()> {
+        self.payment_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Pago con ID {} no encontrado", id)))?;
+
+        self.payment_repository.delete(id, deleted_by).await
+    }
+
+    /// Restaurar un pago eliminado - vuelve a repartirse entre los préstamos del prestatario
+    pub async fn restore_payment(&self, id: i32) -> Result<PaymentModelResponse> {
+        let payment = self.find_deleted_payment(id).await?;
+
+        let borrower = match self.borrower_repository.find_by_id(payment.borrower_id).await? {
+            Some(borrower) => borrower,
+            None => {
+                return Err(AppError::Conflict(format!(
+                    "El prestatario con ID {} está eliminado; restáurelo antes que el pago",
+                    payment.borrower_id
+                )))
+            }
+        };
+
+        let payment = self.payment_repository.restore(id).await?;
+        Ok(PaymentModelResponse::from_parts(payment, borrower))
+    }
+
+    /// Borrar definitivamente un pago eliminado
+    pub async fn purge_payment(&self, id: i32) -> Result<()> {
+        self.find_deleted_payment(id).await?;
+
+        self.payment_repository.purge(id).await
+    }
+
+    /// Busca un pago eliminado; si existe pero no está eliminado es un conflicto
+    async fn find_deleted_payment(&self, id: i32) -> Result<Payment> {
+        if let Some(payment) = self.payment_repository.find_deleted_by_id(id).await? {
+            return Ok(payment);
+        }
+
+        match self.payment_repository.find_by_id(id).await? {
+            Some(_) => Err(AppError::Conflict(format!("El pago con ID {} no está eliminado", id))),
+            None => Err(AppError::NotFound(format!("Pago con ID {} no encontrado", id))),
+        }
+    }
+
+    async fn find_borrower(&self, borrower_id: i32) -> Result<Borrower> {
+        self.borrower_repository
+            .find_by_id(borrower_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Prestatario con ID {} no encontrado", borrower_id)))
+    }
+}
@@ -0,0 +1,187 @@
+use chrono::NaiveDateTime;
+
+use crate::server::application::requests::penalty_model_requests::{
+    CreatePenaltyRuleRequest, PenaltyAssessmentModelResponse, PenaltyModelResponse, PenaltyRuleModelResponse,
+};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::loan::LOAN_WRITTEN_OFF;
+use crate::server::domain::entities::penalty::{NewPenalty, NewPenaltyRule, Penalty, PenaltyKind, PENALTY_CHARGED};
+use crate::server::domain::repositories::loan_repository::LoanRepository;
+use crate::server::domain::repositories::penalty_repository::PenaltyRepository;
+use crate::server::domain::services::delinquency_policy::DelinquencyPolicy;
+use crate::server::domain::services::penalty_calculator::PenaltyCalculator;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::{Result, AppError};
+
+/// Reglas de recargo, cobro de recargos a préstamos atrasados y condonaciones
+#[derive(Clone)]
+pub struct PenaltyUseCases<P: PenaltyRepository, L: LoanRepository> {
+    penalty_repository: P,
+    loan_repository: L,
+}
+
+impl<P: PenaltyRepository, L: LoanRepository> PenaltyUseCases<P, L> {
+    pub fn new(penalty_repository: P, loan_repository: L) -> Self {
+        Self {
+            penalty_repository,
+            loan_repository,
+        }
+    }
+
+    /// Obtener todas las reglas de recargo
+    pub async fn get_rules(&self) -> Result<Vec<PenaltyRuleModelResponse>> {
+        let rules = self.penalty_repository.find_rules().await?;
+
+        Ok(rules.into_iter().map(PenaltyRuleModelResponse::from).collect())
+    }
+
+    /// Registrar una regla de recargo
+    pub async fn create_rule(&self, request: CreatePenaltyRuleRequest) -> Result<PenaltyRuleModelResponse> {
+        let CreatePenaltyRuleRequest { name, kind, amount, rate, grace_days, cap } = request;
+
+        let kind = PenaltyKind::parse(&kind).ok_or_else(|| {
+            AppError::ValidationError(vec!["El tipo debe ser fixed, percentage o daily".to_string()])
+        })?;
+
+        // Cada tipo necesita su propio parámetro; el otro se guarda en cero
+        let (amount, rate) = match kind {
+            PenaltyKind::Fixed => {
+                let amount = Money::from_major(amount.unwrap_or(0.0), BASE_CURRENCY)?;
+                if !amount.is_positive() {
+                    return Err(AppError::ValidationError(vec![
+                        "Un recargo fijo necesita un monto de al menos 0.01".to_string(),
+                    ]));
+                }
+                (amount, 0.0)
+            }
+            PenaltyKind::Percentage | PenaltyKind::Daily => match rate {
+                Some(rate) if rate > 0.0 => (Money::zero(BASE_CURRENCY), rate),
+                _ => {
+                    return Err(AppError::ValidationError(vec![
+                        "Un recargo porcentual o diario necesita una tasa mayor que 0".to_string(),
+                    ]))
+                }
+            },
+        };
+
+        let cap = cap.map(|cap| Money::from_major(cap, BASE_CURRENCY)).transpose()?;
+        if cap.as_ref().is_some_and(|cap| !cap.is_positive()) {
+            return Err(AppError::ValidationError(vec!["El tope debe ser de al menos 0.01".to_string()]));
+        }
+
+        let new_rule = NewPenaltyRule { name, kind, amount, rate, grace_days, cap };
+        let rule = self.penalty_repository.create_rule(&new_rule).await?;
+
+        Ok(PenaltyRuleModelResponse::from(rule))
+    }
+
+    /// Desactivar una regla - deja de cobrar, pero sus recargos se conservan
+    pub async fn deactivate_rule(&self, id: i32) -> Result<PenaltyRuleModelResponse> {
+        self.penalty_repository
+            .find_rule_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Regla de recargo con ID {} no encontrada", id)))?;
+
+        let rule = self.penalty_repository.set_rule_active(id, false).await?;
+        Ok(PenaltyRuleModelResponse::from(rule))
+    }
+
+    /// Obtener los recargos, opcionalmente de un solo prestatario
+    pub async fn get_penalties(&self, borrower_id: Option<i32>) -> Result<Vec<PenaltyModelResponse>> {
+        let penalties = self.penalty_repository.find_all(borrower_id).await?;
+
+        Ok(penalties.into_iter().map(PenaltyModelResponse::from).collect())
+    }
+
+    /// Condonar un recargo - se resta del saldo del prestatario y queda registrado quién y por qué
+    pub async fn waive_penalty(&self, id: i32, reason: String, waived_by: i32) -> Result<PenaltyModelResponse> {
+        let penalty = self.penalty_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Recargo con ID {} no encontrado", id)))?;
+
+        if penalty.status != PENALTY_CHARGED {
+            return Err(AppError::Conflict("El recargo ya fue condonado".to_string()));
+        }
+
+        let penalty = self.penalty_repository.waive(id, reason.trim(), waived_by).await?;
+        Ok(PenaltyModelResponse::from(penalty))
+    }
+
+    /// Cobra a cada préstamo atrasado lo que le corresponde según la regla de su producto,
+    /// o la regla activa de menor ID si no tiene producto
+    /// Lo vencido se valora en CUP con la tasa con que se registró el préstamo
+    pub async fn assess_penalties(&self, now: NaiveDateTime) -> Result<PenaltyAssessmentModelResponse> {
+        let mut summary = PenaltyAssessmentModelResponse {
+            penalties_charged: 0,
+            total_charged: 0.0,
+        };
+
+        let rules: Vec<_> = self.penalty_repository
+            .find_rules()
+            .await?
+            .into_iter()
+            .filter(|rule| rule.active)
+            .collect();
+        // Los préstamos sin producto usan la regla activa más antigua; apilar todas cobraría varias veces el mismo atraso
+        let default_rule_id = match rules.iter().map(|rule| rule.id).min() {
+            Some(id) => id,
+            None => return Ok(summary),
+        };
+
+        let mut total_charged = Money::zero(BASE_CURRENCY);
+
+        for loan in self.loan_repository.find_all().await? {
+            // Un préstamo castigado ya no genera recargos
+            if !loan.remaining.is_positive() || loan.status == LOAN_WRITTEN_OFF {
+                continue;
+            }
+
+            let installments = match loan.schedule {
+                Some(_) => self.loan_repository.find_installments(loan.id).await?,
+                None => Vec::new(),
+            };
+
+            let days_past_due = DelinquencyPolicy::days_past_due(&loan, &installments, now)?;
+            if days_past_due == 0 {
+                continue;
+            }
+
+            let overdue_cup = DelinquencyPolicy::overdue_amount(&loan, &installments, now)?
+                .convert(loan.exchange_rate, BASE_CURRENCY)?;
+            let previous = self.penalty_repository.find_by_loan(loan.id).await?;
+
+            // Un préstamo creado con un producto solo aplica la regla de recargo del producto
+            let rule_id = loan.penalty_rule_id.unwrap_or(default_rule_id);
+            for rule in rules.iter().filter(|rule| rule.id == rule_id) {
+                let previous_for_rule: Vec<Penalty> = previous
+                    .iter()
+                    .filter(|penalty| penalty.rule_id == rule.id)
+                    .cloned()
+                    .collect();
+
+                let charge = match PenaltyCalculator::assess(rule, &overdue_cup, days_past_due, &previous_for_rule, now)? {
+                    Some(charge) => charge,
+                    None => continue,
+                };
+
+                let new_penalty = NewPenalty {
+                    loan_id: loan.id,
+                    borrower_id: loan.borrower_id,
+                    rule_id: rule.id,
+                    amount: charge.amount,
+                    days_past_due: days_past_due as i32,
+                    days_charged: charge.days_charged as i32,
+                    created_at: now,
+                };
+                let penalty = self.penalty_repository.create(&new_penalty).await?;
+
+                total_charged = total_charged.checked_add(&penalty.amount)?;
+                summary.penalties_charged += 1;
+            }
+        }
+
+        summary.total_charged = total_charged.to_major();
+        Ok(summary)
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::server::application::requests::report_model_requests::{BalanceRevaluationModelResponse, BorrowerRevaluationModelResponse};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::repositories::borrower_repository::BorrowerRepository;
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::server::domain::repositories::loan_repository::LoanRepository;
+use crate::server::domain::repositories::payment_repository::PaymentRepository;
+use crate::server::domain::services::exchange_rate_service::ExchangeRateService;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::date::parse_stored_date;
+use crate::utils::error::Result;
+
+#[derive(Clone)]
+pub struct ReportUseCases<L: LoanRepository, P: PaymentRepository, B: BorrowerRepository, C: CurrencyRepository> {
+    loan_repository: L,
+    payment_repository: P,
+    borrower_repository: B,
+    exchange_rate_service: ExchangeRateService<C>,
+}
+
+/// Acumulado por prestatario en CUP: (saldo contabilizado, saldo revaluado)
+type BalancePair = (Money, Money);
+
+impl<L: LoanRepository, P: PaymentRepository, B: BorrowerRepository, C: CurrencyRepository> ReportUseCases<L, P, B, C> {
+    pub fn new(loan_repository: L, payment_repository: P, borrower_repository: B, currency_repository: C) -> Self {
+        Self {
+            loan_repository,
+            payment_repository,
+            borrower_repository,
+            exchange_rate_service: ExchangeRateService::new(currency_repository),
+        }
+    }
+
+    /// Revalúa los saldos de todos los prestatarios con las tasas vigentes en `at`
+    /// Solo se consideran préstamos y pagos registrados hasta esa fecha
+    pub async fn revalue_balances(&self, at: Option<DateTime<Utc>>) -> Result<BalanceRevaluationModelResponse> {
+        let at = at.unwrap_or_else(Utc::now);
+        let cutoff = at.naive_utc();
+
+        let mut rates: HashMap<String, f32> = HashMap::new();
+        let mut balances: BTreeMap<i32, BalancePair> = BTreeMap::new();
+
+        for loan in self.loan_repository.find_all().await? {
+            if parse_stored_date(&loan.created_at) > cutoff {
+                continue;
+            }
+            let rate = self.cached_rate(&mut rates, loan.amount.currency(), cutoff).await?;
+            let revalued = loan.amount.convert(rate, BASE_CURRENCY)?;
+            let (booked, revalued_total) = balances.entry(loan.borrower_id).or_insert_with(zero_pair);
+            *booked = booked.checked_add(&loan.amount_cup)?;
+            *revalued_total = revalued_total.checked_add(&revalued)?;
+        }
+
+        for payment in self.payment_repository.find_all().await? {
+            if parse_stored_date(&payment.created_at) > cutoff {
+                continue;
+            }
+            let rate = self.cached_rate(&mut rates, payment.amount.currency(), cutoff).await?;
+            let revalued = payment.amount.convert(rate, BASE_CURRENCY)?;
+            let (booked, revalued_total) = balances.entry(payment.borrower_id).or_insert_with(zero_pair);
+            *booked = booked.checked_sub(&payment.amount_cup)?;
+            *revalued_total = revalued_total.checked_sub(&revalued)?;
+        }
+
+        let names: HashMap<i32, String> = self.borrower_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|borrower| (borrower.id, borrower.name))
+            .collect();
+
+        let (mut total_booked, mut total_revalued) = zero_pair();
+        let mut borrowers = Vec::with_capacity(balances.len());
+
+        for (borrower_id, (booked, revalued)) in balances {
+            total_booked = total_booked.checked_add(&booked)?;
+            total_revalued = total_revalued.checked_add(&revalued)?;
+
+            borrowers.push(BorrowerRevaluationModelResponse {
+                borrower_id,
+                name: names.get(&borrower_id).cloned().unwrap_or_default(),
+                booked_balance_cup: booked.to_major(),
+                revalued_balance_cup: revalued.to_major(),
+                fx_difference_cup: revalued.checked_sub(&booked)?.to_major(),
+            });
+        }
+
+        Ok(BalanceRevaluationModelResponse {
+            at,
+            borrowers,
+            total_booked_cup: total_booked.to_major(),
+            total_revalued_cup: total_revalued.to_major(),
+            total_fx_difference_cup: total_revalued.checked_sub(&total_booked)?.to_major(),
+        })
+    }
+
+    async fn cached_rate(&self, rates: &mut HashMap<String, f32>, code: &str, at: NaiveDateTime) -> Result<f32> {
+        if let Some(rate) = rates.get(code) {
+            return Ok(*rate);
+        }
+
+        let rate = self.exchange_rate_service.rate_at(code, at).await?;
+        rates.insert(code.to_string(), rate);
+        Ok(rate)
+    }
+}
+
+fn zero_pair() -> BalancePair {
+    (Money::zero(BASE_CURRENCY), Money::zero(BASE_CURRENCY))
+}
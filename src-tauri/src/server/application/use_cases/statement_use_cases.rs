@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+
+use crate::server::application::requests::statement_model_requests::BorrowerStatementModelResponse;
+use crate::server::domain::entities::ledger::{JournalFilter, EVENT_INTEREST};
+use crate::server::domain::repositories::borrower_repository::BorrowerRepository;
+use crate::server::domain::repositories::ledger_repository::LedgerRepository;
+use crate::server::domain::repositories::loan_repository::LoanRepository;
+use crate::server::domain::repositories::payment_repository::PaymentRepository;
+use crate::server::domain::repositories::penalty_repository::PenaltyRepository;
+use crate::server::domain::services::statement_builder::StatementBuilder;
+use crate::utils::error::{Result, AppError};
+
+/// Estados de cuenta de los prestatarios
+#[derive(Clone)]
+pub struct StatementUseCases<
+    B: BorrowerRepository,
+    L: LoanRepository,
+    P: PaymentRepository,
+    N: PenaltyRepository,
+    G: LedgerRepository,
+> {
+    borrower_repository: B,
+    loan_repository: L,
+    payment_repository: P,
+    penalty_repository: N,
+    ledger_repository: G,
+}
+
+impl<B: BorrowerRepository, L: LoanRepository, P: PaymentRepository, N: PenaltyRepository, G: LedgerRepository>
+    StatementUseCases<B, L, P, N, G>
+{
+    pub fn new(
+        borrower_repository: B,
+        loan_repository: L,
+        payment_repository: P,
+        penalty_repository: N,
+        ledger_repository: G,
+    ) -> Self {
+        Self {
+            borrower_repository,
+            loan_repository,
+            payment_repository,
+            penalty_repository,
+            ledger_repository,
+        }
+    }
+
+    /// Estado de cuenta de un prestatario entre dos fechas, ambas incluidas
+    /// Lo anterior a `from` forma el saldo inicial; sin fechas abarca todo el historial
+    pub async fn get_statement(
+        &self,
+        borrower_id: i32,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<BorrowerStatementModelResponse> {
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err(AppError::ValidationError(vec![
+                    "La fecha inicial no puede ser posterior a la final".to_string(),
+                ]));
+            }
+        }
+
+        let borrower = self.borrower_repository
+            .find_by_id(borrower_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Prestatario con ID {} no encontrado", borrower_id)))?;
+
+        let loans = self.loan_repository.find_by_borrower(borrower_id).await?;
+        let payments = self.payment_repository.find_by_borrower(borrower_id).await?;
+        let penalties = self.penalty_repository.find_all(Some(borrower_id)).await?;
+        let interest = self.ledger_repository
+            .find_entries(&JournalFilter {
+                borrower_id: Some(borrower_id),
+                loan_id: None,
+                event: Some(EVENT_INTEREST.to_string()),
+                from: None,
+                to: None,
+            })
+            .await?;
+
+        let statement = StatementBuilder::build(
+            &loans,
+            &payments,
+            &penalties,
+            &interest,
+            from.map(|from| from.naive_utc()),
+            to.map(|to| to.naive_utc()),
+        )?;
+
+        Ok(BorrowerStatementModelResponse::from_statement(borrower.id, borrower.name, from, to, statement))
+    }
+}
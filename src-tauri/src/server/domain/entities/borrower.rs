@@ -1,15 +1,183 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::value_objects::money::Money;
+
+pub const BORROWER_CURRENT: &str = "current";
+pub const BORROWER_LATE: &str = "late";
+pub const BORROWER_DELINQUENT: &str = "delinquent";
+pub const BORROWER_PAID_OFF: &str = "paid_off";
+/// Bloqueado por el personal; la tarea diaria no cambia este estado
+pub const BORROWER_BLOCKED: &str = "blocked";
+
+/// Categoría de riesgo que el personal asigna al prestatario
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiskTier {
+    Low,
+    Medium,
+    High,
+}
+
+impl RiskTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskTier::Low => "low",
+            RiskTier::Medium => "medium",
+            RiskTier::High => "high",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "low" => Some(RiskTier::Low),
+            "medium" => Some(RiskTier::Medium),
+            "high" => Some(RiskTier::High),
+            _ => None,
+        }
+    }
+}
+
+/// Factores del historial de pagos con que se calcula el puntaje de crédito
+/// `days_past_due` es el mayor atraso actual entre los préstamos abiertos
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CreditFactors {
+    pub on_time_installments: i32,
+    pub late_installments: i32,
+    pub days_past_due: i32,
+    pub restructures: i32,
+    pub write_offs: i32,
+}
+
+/// Puntaje de crédito interno, entre 300 y 850
+/// `updated_at` falta mientras no se haya calculado nunca
+#[derive(Debug, Clone)]
+pub struct CreditScore {
+    pub score: i32,
+    pub factors: CreditFactors,
+    pub updated_at: Option<String>,
+}
+
+/// Bloqueo de un prestatario que no debe recibir préstamos nuevos
+#[derive(Debug, Clone)]
+pub struct BorrowerBlock {
+    pub reason: String,
+    pub blocked_by: i32,
+    pub blocked_at: String,
+}
+
+/// Los totales están siempre en CUP; el saldo incluye los recargos sin condonar
+/// y descuenta lo castigado que todavía no se ha recuperado
+/// `status` lo recalcula la tarea diaria a partir de los atrasos de sus préstamos
+/// `guarantee_exposure` es lo que garantiza en préstamos ajenos todavía pendientes, en CUP
+/// `credit_limit` es el máximo en CUP que puede deber en préstamos abiertos; sin límite si falta
+/// `credit_score` se recalcula con cada pago o cambio de préstamo y en la tarea diaria
+/// `block` está presente mientras el prestatario está bloqueado
+/// Un prestatario eliminado (`deleted_at`) conserva sus préstamos y pagos y se puede restaurar
 #[derive(Debug, Clone)]
 pub struct Borrower {
     pub id: i32,
     pub phone: String,
     pub name: String,
     pub location: String,
-    pub total_loans: f64,
-    pub total_paid: f64,
-    pub balance: f64,
+    pub total_loans: Money,
+    pub total_paid: Money,
+    pub balance: Money,
     pub status: String,
     pub created_at: String,  // Cambiar a String
     pub updated_at: String,  // Cambiar a String
+    pub total_penalties: Money,
+    pub written_off_total: Money,
+    pub recovered_total: Money,
+    pub guarantee_exposure: Money,
+    pub credit_limit: Option<Money>,
+    pub risk_tier: RiskTier,
+    pub credit_score: CreditScore,
+    pub block: Option<BorrowerBlock>,
+    pub deleted_at: Option<String>,
+    pub deleted_by: Option<i32>,
+}
+
+/// Campo por el que se ordena el listado de prestatarios
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorrowerSortField {
+    Name,
+    CreatedAt,
+    Balance,
+    TotalLoans,
+    CreditScore,
+}
+
+impl BorrowerSortField {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "name" => Some(BorrowerSortField::Name),
+            "created_at" => Some(BorrowerSortField::CreatedAt),
+            "balance" => Some(BorrowerSortField::Balance),
+            "total_loans" => Some(BorrowerSortField::TotalLoans),
+            "credit_score" => Some(BorrowerSortField::CreditScore),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "asc" => Some(SortDirection::Asc),
+            "desc" => Some(SortDirection::Desc),
+            _ => None,
+        }
+    }
+}
+
+/// Criterios del listado de prestatarios
+/// `search` busca en el nombre, el teléfono y la ubicación; los saldos están en CUP
+/// Las fechas de alta incluyen ambos extremos; sin `page_size` se devuelven todas las coincidencias
+#[derive(Debug, Clone)]
+pub struct BorrowerFilter {
+    pub search: Option<String>,
+    pub status: Option<String>,
+    pub min_balance: Option<Money>,
+    pub max_balance: Option<Money>,
+    pub created_from: Option<NaiveDateTime>,
+    pub created_to: Option<NaiveDateTime>,
+    pub deleted: bool,
+    pub sort: BorrowerSortField,
+    pub direction: SortDirection,
+    pub page: i64,
+    pub page_size: Option<i64>,
+}
+
+/// Página del listado de prestatarios; `total` cuenta todas las coincidencias
+#[derive(Debug, Clone)]
+pub struct BorrowerPage {
+    pub borrowers: Vec<Borrower>,
+    pub total: i64,
+}
+
+/// Total guardado de un prestatario que no coincide con el que resulta de sus préstamos,
+/// pagos y recargos; ambos montos en CUP
+#[derive(Debug, Clone)]
+pub struct TotalsMismatch {
+    pub borrower_id: i32,
+    pub name: String,
+    pub field: &'static str,
+    pub stored: Money,
+    pub expected: Money,
+}
+
+/// Resultado de conciliar los totales de todos los prestatarios
+/// `fixed` indica si las diferencias encontradas se corrigieron
+#[derive(Debug, Clone)]
+pub struct TotalsReconciliation {
+    pub checked: i64,
+    pub mismatches: Vec<TotalsMismatch>,
+    pub fixed: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,138 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::value_objects::money::Money;
+
+/// Movimientos manuales de la caja de una moneda
+/// Las entradas por pagos y salidas por desembolsos no son movimientos manuales: salen del libro mayor
+pub const CASH_OPENING: &str = "opening";
+pub const CASH_DEPOSIT: &str = "deposit";
+pub const CASH_WITHDRAWAL: &str = "withdrawal";
+
+/// Saldo de apertura, depósito o retiro de la caja de una moneda
+/// `amount` siempre es positivo y `amount_cup` es su valor en CUP con la tasa `exchange_rate`
+#[derive(Debug, Clone)]
+pub struct CashMovement {
+    pub id: i32,
+    pub currency: String,
+    pub kind: String,
+    pub amount: Money,
+    pub exchange_rate: f32,
+    pub amount_cup: Money,
+    pub reason: String,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub moved_at: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewCashMovement {
+    pub kind: &'static str,
+    pub amount: Money,
+    pub exchange_rate: f32,
+    pub amount_cup: Money,
+    pub reason: String,
+    pub created_by: i32,
+    pub moved_at: NaiveDateTime,
+}
+
+/// Arqueo de la caja de una moneda
+/// `expected` es el saldo según el libro mayor al momento del arqueo y `difference` lo contado
+/// menos lo esperado; si no es cero se ajusta con el asiento `entry_id` por `difference_cup`
+#[derive(Debug, Clone)]
+pub struct CashCount {
+    pub id: i32,
+    pub currency: String,
+    pub expected: Money,
+    pub counted: Money,
+    pub difference: Money,
+    pub exchange_rate: f32,
+    pub difference_cup: Money,
+    pub notes: Option<String>,
+    pub entry_id: Option<i32>,
+    pub counted_by: i32,
+    pub counted_at: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewCashCount {
+    pub counted: Money,
+    pub exchange_rate: f32,
+    pub notes: Option<String>,
+    pub counted_by: i32,
+    pub counted_at: NaiveDateTime,
+}
+
+/// Saldo de la caja de una moneda a una fecha, en esa moneda; `balance_cup` es su valor en libros
+#[derive(Debug, Clone)]
+pub struct CashBalance {
+    pub balance: Money,
+    pub balance_cup: Money,
+}
+
+/// Entrada o salida de la caja según el libro mayor: pagos, desembolsos, movimientos manuales,
+/// ajustes de arqueo y revaluaciones; `amount` es positivo si entra a la caja
+/// `balance` es el saldo de la caja después del movimiento
+#[derive(Debug, Clone)]
+pub struct CashBoxLine {
+    pub entry_id: i32,
+    pub event: String,
+    pub description: String,
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub payment_id: Option<i32>,
+    pub posted_at: String,
+    pub amount: Money,
+    pub amount_cup: Money,
+    pub balance: Money,
+}
+
+/// Cambio de moneda entre dos cajas: sale `source` de su caja y entra `target` a la suya
+/// `effective_rate` es lo recibido por cada unidad entregada y `official_rate` lo mismo con las
+/// tasas registradas; `spread` es cuánto se aparta la primera de la segunda, en porcentaje
+/// `source_cost_cup` es el valor en libros de lo entregado y `target_cup` el valor oficial de lo
+/// recibido; `spread_cup` es lo que el cambio se aparta de las tasas oficiales y `gain_cup` la
+/// ganancia realizada frente al valor en libros, negativa si es pérdida
+#[derive(Debug, Clone)]
+pub struct CashExchange {
+    pub id: i32,
+    pub source: Money,
+    pub target: Money,
+    pub effective_rate: f64,
+    pub official_rate: f64,
+    pub spread: f64,
+    pub source_cost_cup: Money,
+    pub target_cup: Money,
+    pub spread_cup: Money,
+    pub gain_cup: Money,
+    pub notes: Option<String>,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub exchanged_at: String,
+    pub created_at: String,
+}
+
+/// El valor en libros de lo entregado se calcula al registrar el cambio, con el saldo de su caja
+#[derive(Debug, Clone)]
+pub struct NewCashExchange {
+    pub source: Money,
+    pub target: Money,
+    pub effective_rate: f64,
+    pub official_rate: f64,
+    pub spread: f64,
+    pub target_cup: Money,
+    pub spread_cup: Money,
+    pub notes: Option<String>,
+    pub created_by: i32,
+    pub exchanged_at: NaiveDateTime,
+}
+
+/// Criterios de consulta de los cambios; las fechas incluyen ambos extremos y `currency`
+/// toma los cambios en que la moneda se entrega o se recibe
+#[derive(Debug, Clone, Default)]
+pub struct CashExchangeFilter {
+    pub currency: Option<String>,
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+}
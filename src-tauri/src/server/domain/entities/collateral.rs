@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::value_objects::money::Money;
+
+/// Garantía en custodia mientras el préstamo tenga saldo
+pub const COLLATERAL_HELD: &str = "held";
+/// Garantía devuelta al prestatario una vez saldado el préstamo
+pub const COLLATERAL_RETURNED: &str = "returned";
+/// Garantía vendida; lo obtenido se registró como pago del préstamo
+pub const COLLATERAL_LIQUIDATED: &str = "liquidated";
+
+/// Bien que respalda un préstamo; `estimated_value` está en la moneda en que se tasó
+/// `payment_id` es el pago con que se registró lo obtenido al liquidarla
+#[derive(Debug, Clone)]
+pub struct Collateral {
+    pub id: i32,
+    pub loan_id: i32,
+    pub description: String,
+    pub estimated_value: Money,
+    pub storage_location: String,
+    pub status: String,
+    pub payment_id: Option<i32>,
+    pub returned_at: Option<String>,
+    pub liquidated_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewCollateral {
+    pub loan_id: i32,
+    pub description: String,
+    pub estimated_value: Money,
+    pub storage_location: String,
+    pub created_at: NaiveDateTime,
+}
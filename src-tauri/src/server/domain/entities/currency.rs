@@ -0,0 +1,28 @@
+/// Moneda base del sistema, todos los agregados se expresan en ella
+pub const BASE_CURRENCY: &str = "CUP";
+
+#[derive(Debug, Clone)]
+pub struct Currency {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub exchange_rate: f32,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewCurrency {
+    pub code: String,
+    pub name: String,
+    pub exchange_rate: f32,
+}
+
+impl NewCurrency {
+    pub fn new(code: String, name: String, exchange_rate: f32) -> Self {
+        Self {
+            code,
+            name,
+            exchange_rate,
+        }
+    }
+}
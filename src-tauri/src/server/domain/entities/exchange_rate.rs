@@ -0,0 +1,28 @@
+use chrono::NaiveDateTime;
+
+/// Tasa de una moneda vigente desde `effective_from` hasta el siguiente registro del historial
+#[derive(Debug, Clone)]
+pub struct ExchangeRate {
+    pub id: i32,
+    pub currency_code: String,
+    pub exchange_rate: f32,
+    pub effective_from: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewExchangeRate {
+    pub currency_code: String,
+    pub exchange_rate: f32,
+    pub effective_from: NaiveDateTime,
+}
+
+impl NewExchangeRate {
+    pub fn new(currency_code: String, exchange_rate: f32, effective_from: NaiveDateTime) -> Self {
+        Self {
+            currency_code,
+            exchange_rate,
+            effective_from,
+        }
+    }
+}
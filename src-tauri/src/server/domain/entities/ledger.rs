@@ -0,0 +1,163 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::value_objects::money::Money;
+
+/// Tipos de cuenta con saldo deudor; los demás (pasivo, patrimonio e ingresos) lo tienen acreedor
+pub const ACCOUNT_ASSET: &str = "asset";
+pub const ACCOUNT_EXPENSE: &str = "expense";
+
+/// Prefijo de las cuentas de caja; hay una por moneda, p. ej. 1100-USD
+pub const ACCOUNT_CASH: &str = "1100";
+pub const ACCOUNT_LOANS_RECEIVABLE: &str = "1200";
+pub const ACCOUNT_PENALTIES_RECEIVABLE: &str = "1210";
+pub const ACCOUNT_INTEREST_RECEIVABLE: &str = "1220";
+pub const ACCOUNT_OPENING_BALANCES: &str = "3100";
+pub const ACCOUNT_CASH_CONTRIBUTIONS: &str = "3200";
+pub const ACCOUNT_INTEREST_INCOME: &str = "4100";
+pub const ACCOUNT_PENALTY_INCOME: &str = "4200";
+pub const ACCOUNT_RECOVERY_INCOME: &str = "4300";
+pub const ACCOUNT_FX_GAIN: &str = "4400";
+pub const ACCOUNT_CASH_OVERAGE: &str = "4500";
+pub const ACCOUNT_WRITE_OFF_EXPENSE: &str = "5100";
+pub const ACCOUNT_FX_LOSS: &str = "5200";
+pub const ACCOUNT_CASH_SHORTAGE: &str = "5300";
+
+/// Cuentas por cobrar que forman el saldo de un prestatario
+pub const RECEIVABLE_ACCOUNTS: [&str; 3] = [
+    ACCOUNT_LOANS_RECEIVABLE,
+    ACCOUNT_PENALTIES_RECEIVABLE,
+    ACCOUNT_INTEREST_RECEIVABLE,
+];
+
+pub const EVENT_OPENING: &str = "opening";
+pub const EVENT_DISBURSEMENT: &str = "disbursement";
+pub const EVENT_PAYMENT: &str = "payment";
+pub const EVENT_INTEREST: &str = "interest";
+pub const EVENT_PENALTY: &str = "penalty";
+pub const EVENT_WRITE_OFF: &str = "write_off";
+pub const EVENT_RECOVERY: &str = "recovery";
+pub const EVENT_FX_REVALUATION: &str = "fx_revaluation";
+pub const EVENT_CASH_DEPOSIT: &str = "cash_deposit";
+pub const EVENT_CASH_WITHDRAWAL: &str = "cash_withdrawal";
+pub const EVENT_CASH_COUNT: &str = "cash_count";
+pub const EVENT_CASH_EXCHANGE: &str = "cash_exchange";
+
+/// Código de la cuenta de caja de una moneda
+pub fn cash_account(currency: &str) -> String {
+    format!("{}-{}", ACCOUNT_CASH, currency)
+}
+
+/// Cuenta del plan de cuentas; `currency` solo difiere de CUP en la caja de monedas extranjeras
+#[derive(Debug, Clone)]
+pub struct Account {
+    pub code: String,
+    pub name: String,
+    pub kind: String,
+    pub currency: String,
+}
+
+/// Saldo de una cuenta a una fecha
+/// `debit` y `credit` son los movimientos acumulados en CUP; `balance` es el saldo en la moneda
+/// de la cuenta con el signo natural de su tipo (deudor en activos y gastos, acreedor en los demás)
+#[derive(Debug, Clone)]
+pub struct AccountBalance {
+    pub account: Account,
+    pub debit: Money,
+    pub credit: Money,
+    pub balance_cup: Money,
+    pub balance: Money,
+}
+
+/// Partida de un asiento; `debit` y `credit` están en CUP y solo uno de los dos es distinto de cero
+/// `amount` es el movimiento en la moneda de la cuenta, positivo si es un débito
+#[derive(Debug, Clone)]
+pub struct JournalLine {
+    pub id: i32,
+    pub account_code: String,
+    pub account_name: String,
+    pub borrower_id: Option<i32>,
+    pub debit: Money,
+    pub credit: Money,
+    pub amount: Money,
+}
+
+/// Asiento del libro mayor; nunca se modifica, las correcciones se registran con asientos nuevos
+#[derive(Debug, Clone)]
+pub struct JournalEntry {
+    pub id: i32,
+    pub event: String,
+    pub description: String,
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub payment_id: Option<i32>,
+    pub posted_at: String,
+    pub lines: Vec<JournalLine>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewJournalLine {
+    pub account_code: String,
+    pub borrower_id: Option<i32>,
+    pub debit: Money,
+    pub credit: Money,
+    pub amount: Money,
+}
+
+impl NewJournalLine {
+    /// Partida a partir de su movimiento en CUP, positivo al débito y negativo al crédito
+    pub fn signed(account_code: String, borrower_id: Option<i32>, amount_cup: Money, amount: Money) -> Self {
+        let zero = Money::zero(amount_cup.currency());
+        let (debit, credit) = if amount_cup.minor_units() >= 0 {
+            (amount_cup, zero)
+        } else {
+            let credit = Money::from_minor(amount_cup.minor_units().saturating_neg(), amount_cup.currency());
+            (zero, credit)
+        };
+
+        Self {
+            account_code,
+            borrower_id,
+            debit,
+            credit,
+            amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewJournalEntry {
+    pub event: &'static str,
+    pub description: String,
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub payment_id: Option<i32>,
+    pub posted_at: NaiveDateTime,
+    pub lines: Vec<NewJournalLine>,
+}
+
+/// Parte de un pago que cubre interés y recargos, en CUP; el resto cubre principal
+#[derive(Debug, Clone)]
+pub struct PaymentSplit {
+    pub interest: Money,
+    pub penalties: Money,
+}
+
+/// Criterios de consulta del libro diario; las fechas incluyen ambos extremos
+#[derive(Debug, Clone, Default)]
+pub struct JournalFilter {
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub event: Option<String>,
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+}
+
+/// Saldo por cobrar de un prestatario según el libro mayor, junto al que tiene guardado
+#[derive(Debug, Clone)]
+pub struct BorrowerReceivable {
+    pub borrower_id: i32,
+    pub name: String,
+    pub ledger_balance: Money,
+    pub stored_balance: Money,
+}
@@ -0,0 +1,311 @@
+use chrono::{Duration, Months, NaiveDateTime};
+
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::loan_guarantor::NewLoanGuarantor;
+use crate::server::domain::entities::loan_installment::NewLoanInstallment;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::Result;
+
+pub const LOAN_ACTIVE: &str = "active";
+pub const LOAN_PAID: &str = "paid";
+pub const LOAN_OVERDUE: &str = "overdue";
+pub const LOAN_DEFAULTED: &str = "defaulted";
+pub const LOAN_RESTRUCTURED: &str = "restructured";
+pub const LOAN_WRITTEN_OFF: &str = "written_off";
+
+/// Período al que se refiere la tasa de interés
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RatePeriod {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl RatePeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RatePeriod::Daily => "daily",
+            RatePeriod::Weekly => "weekly",
+            RatePeriod::Monthly => "monthly",
+            RatePeriod::Yearly => "yearly",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "daily" => Some(RatePeriod::Daily),
+            "weekly" => Some(RatePeriod::Weekly),
+            "monthly" => Some(RatePeriod::Monthly),
+            "yearly" => Some(RatePeriod::Yearly),
+            _ => None,
+        }
+    }
+
+    /// Duración del período en días (convención comercial de 30/365)
+    pub fn days(&self) -> i64 {
+        match self {
+            RatePeriod::Daily => 1,
+            RatePeriod::Weekly => 7,
+            RatePeriod::Monthly => 30,
+            RatePeriod::Yearly => 365,
+        }
+    }
+}
+
+/// Forma en que se calcula el interés
+/// - `Flat`: cada período completo cobra la tasa sobre el principal original
+/// - `DecliningBalance`: cada período completo cobra la tasa sobre el principal pendiente
+/// - `SimpleDaily`: el interés se devenga por día sobre el principal pendiente, sin capitalizar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterestMethod {
+    Flat,
+    DecliningBalance,
+    SimpleDaily,
+}
+
+impl InterestMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InterestMethod::Flat => "flat",
+            InterestMethod::DecliningBalance => "declining_balance",
+            InterestMethod::SimpleDaily => "simple_daily",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "flat" => Some(InterestMethod::Flat),
+            "declining_balance" => Some(InterestMethod::DecliningBalance),
+            "simple_daily" => Some(InterestMethod::SimpleDaily),
+            _ => None,
+        }
+    }
+}
+
+/// Condiciones de interés de un préstamo; `rate` es el porcentaje por `period`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InterestTerms {
+    pub rate: f64,
+    pub period: RatePeriod,
+    pub method: InterestMethod,
+}
+
+impl InterestTerms {
+    /// Préstamo sin interés, valor por defecto de los préstamos anteriores
+    pub fn none() -> Self {
+        Self {
+            rate: 0.0,
+            period: RatePeriod::Monthly,
+            method: InterestMethod::Flat,
+        }
+    }
+}
+
+/// Frecuencia de las cuotas del plan de pagos
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallmentFrequency {
+    Weekly,
+    Biweekly,
+    Monthly,
+}
+
+impl InstallmentFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstallmentFrequency::Weekly => "weekly",
+            InstallmentFrequency::Biweekly => "biweekly",
+            InstallmentFrequency::Monthly => "monthly",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "weekly" => Some(InstallmentFrequency::Weekly),
+            "biweekly" => Some(InstallmentFrequency::Biweekly),
+            "monthly" => Some(InstallmentFrequency::Monthly),
+            _ => None,
+        }
+    }
+
+    /// Duración nominal de una cuota en días, usada para prorratear la tasa
+    pub fn days(&self) -> i64 {
+        match self {
+            InstallmentFrequency::Weekly => 7,
+            InstallmentFrequency::Biweekly => 14,
+            InstallmentFrequency::Monthly => 30,
+        }
+    }
+
+    /// Fecha `periods` cuotas después de `from`; las mensuales caen el mismo día del mes
+    pub fn advance(&self, from: NaiveDateTime, periods: i64) -> Option<NaiveDateTime> {
+        match self {
+            InstallmentFrequency::Weekly | InstallmentFrequency::Biweekly => {
+                from.checked_add_signed(Duration::days(periods * self.days()))
+            }
+            InstallmentFrequency::Monthly => from.checked_add_months(Months::new(periods as u32)),
+        }
+    }
+}
+
+/// Parámetros del plan de cuotas de un préstamo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduleTerms {
+    pub frequency: InstallmentFrequency,
+    pub periods: i32,
+    pub first_due_date: NaiveDateTime,
+}
+
+/// `remaining` es el saldo pendiente en la moneda del préstamo después de aplicar los pagos asignados
+/// `due_date` es el vencimiento final; con plan de cuotas coincide con la última cuota
+/// Un préstamo reestructurado queda cerrado en `restructured_at` y su saldo pasa al
+/// préstamo nuevo, que lo referencia con `restructured_from_id`; `carried_over_cup` es
+/// el principal en CUP que pasó al nuevo
+/// Un préstamo castigado sigue recibiendo pagos, que cuentan como recuperaciones
+/// `penalty_rule_id` viene del producto; sin ella le aplican todas las reglas activas
+/// Un préstamo eliminado (`deleted_at`) no cuenta en los saldos hasta que se restaure
+#[derive(Debug, Clone)]
+pub struct Loan {
+    pub id: i32,
+    pub amount: Money,
+    pub exchange_rate: f32,
+    pub amount_cup: Money,
+    pub interest: InterestTerms,
+    pub schedule: Option<ScheduleTerms>,
+    pub remaining: Money,
+    pub due_date: Option<String>,
+    pub status: String,
+    pub borrower_id: i32,
+    pub created_at: String,
+    pub updated_at: String,
+    pub restructured_from_id: Option<i32>,
+    pub restructured_at: Option<String>,
+    pub carried_over_cup: Money,
+    pub write_off: Option<LoanWriteOff>,
+    pub product_id: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub credit_limit_override_by: Option<i32>,
+    pub deleted_at: Option<String>,
+    pub deleted_by: Option<i32>,
+}
+
+/// Castigo de un préstamo que no se espera cobrar
+/// `amount` es el saldo pendiente al castigar, en la moneda del préstamo;
+/// `amount_cup` es lo que salió del saldo del prestatario (el principal sin cubrir,
+/// porque el interés del plan nunca formó parte de ese saldo) y `recovered_cup`
+/// lo cobrado después del castigo
+#[derive(Debug, Clone)]
+pub struct LoanWriteOff {
+    pub amount: Money,
+    pub amount_cup: Money,
+    pub recovered_cup: Money,
+    pub reason: String,
+    pub written_off_by: i32,
+    pub written_off_at: String,
+}
+
+/// Datos para castigar un préstamo; los importes castigados se calculan al persistir
+/// con los pagos asignados antes de `written_off_at`
+#[derive(Debug, Clone)]
+pub struct NewLoanWriteOff {
+    pub reason: String,
+    pub written_off_by: i32,
+    pub written_off_at: NaiveDateTime,
+}
+
+/// `exchange_rate` es la tasa vigente en `created_at`, resuelta antes de persistir
+/// `installments` reemplaza al plan de cuotas guardado cuando hay `schedule`
+/// `guarantors` solo se guardan al crear el préstamo; después se agregan o quitan uno a uno
+#[derive(Debug, Clone)]
+pub struct NewLoan {
+    pub amount: Money,
+    pub exchange_rate: f32,
+    pub amount_cup: Money,
+    pub interest: InterestTerms,
+    pub schedule: Option<ScheduleTerms>,
+    pub installments: Vec<NewLoanInstallment>,
+    pub due_date: Option<NaiveDateTime>,
+    pub borrower_id: i32,
+    pub created_at: NaiveDateTime,
+    pub restructured_from_id: Option<i32>,
+    pub product_id: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub guarantors: Vec<NewLoanGuarantor>,
+    pub credit_limit_override_by: Option<i32>,
+}
+
+impl NewLoan {
+    /// Calcula el equivalente en CUP con la tasa ya resuelta para la fecha del registro
+    pub fn new(
+        amount: Money,
+        exchange_rate: f32,
+        interest: InterestTerms,
+        borrower_id: i32,
+        created_at: NaiveDateTime,
+    ) -> Result<Self> {
+        let amount_cup = amount.convert(exchange_rate, BASE_CURRENCY)?;
+
+        Ok(Self {
+            amount,
+            exchange_rate,
+            amount_cup,
+            interest,
+            schedule: None,
+            installments: Vec::new(),
+            due_date: None,
+            borrower_id,
+            created_at,
+            restructured_from_id: None,
+            product_id: None,
+            penalty_rule_id: None,
+            guarantors: Vec::new(),
+            credit_limit_override_by: None,
+        })
+    }
+
+    /// El vencimiento del préstamo pasa a ser el de la última cuota del plan
+    pub fn with_schedule(mut self, schedule: ScheduleTerms, installments: Vec<NewLoanInstallment>) -> Self {
+        self.schedule = Some(schedule);
+        self.due_date = installments.iter().map(|installment| installment.due_date).max();
+        self.installments = installments;
+        self
+    }
+
+    pub fn with_due_date(mut self, due_date: Option<NaiveDateTime>) -> Self {
+        self.due_date = due_date;
+        self
+    }
+
+    pub fn with_restructured_from(mut self, loan_id: i32) -> Self {
+        self.restructured_from_id = Some(loan_id);
+        self
+    }
+
+    pub fn with_product(mut self, product_id: i32, penalty_rule_id: Option<i32>) -> Self {
+        self.product_id = Some(product_id);
+        self.penalty_rule_id = penalty_rule_id;
+        self
+    }
+
+    pub fn with_guarantors(mut self, guarantors: Vec<NewLoanGuarantor>) -> Self {
+        self.guarantors = guarantors;
+        self
+    }
+
+    /// Registra al administrador que autorizó superar el límite de crédito del prestatario
+    pub fn with_credit_limit_override(mut self, user_id: Option<i32>) -> Self {
+        self.credit_limit_override_by = user_id;
+        self
+    }
+}
+
+/// Datos para cerrar un préstamo y abrir el que lo reemplaza
+/// `carried_principal_cup` es el principal pendiente del original que pasa al nuevo;
+/// `capitalized_penalties` (en CUP) son los recargos sin condonar que se suman a su principal
+#[derive(Debug, Clone)]
+pub struct LoanRestructuring {
+    pub new_loan: NewLoan,
+    pub carried_principal_cup: Money,
+    pub capitalized_penalties: Money,
+    pub restructured_at: NaiveDateTime,
+}
@@ -0,0 +1,61 @@
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::Result;
+
+/// Garante de un préstamo: un prestatario registrado (`borrower_id`) o un contacto
+/// sin ficha, identificado por `contact_name`
+/// `amount` es lo garantizado, en la moneda del préstamo
+#[derive(Debug, Clone)]
+pub struct LoanGuarantor {
+    pub id: i32,
+    pub loan_id: i32,
+    pub borrower_id: Option<i32>,
+    pub contact_name: Option<String>,
+    pub contact_phone: Option<String>,
+    pub amount: Money,
+    pub created_at: String,
+}
+
+impl LoanGuarantor {
+    /// Lo que el garante responde hoy por el préstamo, en CUP
+    pub fn exposure(&self, remaining: &Money, exchange_rate: f32) -> Result<Money> {
+        guaranteed_exposure(&self.amount, remaining, exchange_rate)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewLoanGuarantor {
+    pub borrower_id: Option<i32>,
+    pub contact_name: Option<String>,
+    pub contact_phone: Option<String>,
+    pub amount: Money,
+}
+
+impl NewLoanGuarantor {
+    pub fn exposure(&self, remaining: &Money, exchange_rate: f32) -> Result<Money> {
+        guaranteed_exposure(&self.amount, remaining, exchange_rate)
+    }
+}
+
+/// Tope de lo que un prestatario puede garantizar en préstamos ajenos, en CUP
+/// Sin `limit` no hay tope; con `refuse` se rechaza el garante que lo supere,
+/// si no solo se advierte
+#[derive(Debug, Clone)]
+pub struct GuarantorExposureLimit {
+    pub limit: Option<Money>,
+    pub refuse: bool,
+}
+
+/// El garante nunca responde por más de lo que queda pendiente del préstamo
+fn guaranteed_exposure(amount: &Money, remaining: &Money, exchange_rate: f32) -> Result<Money> {
+    let covered = if remaining.minor_units() < amount.minor_units() {
+        remaining
+    } else {
+        amount
+    };
+    if !covered.is_positive() {
+        return Ok(Money::zero(BASE_CURRENCY));
+    }
+
+    covered.convert(exchange_rate, BASE_CURRENCY)
+}
@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::Result;
+
+pub const INSTALLMENT_PENDING: &str = "pending";
+pub const INSTALLMENT_PARTIAL: &str = "partial";
+pub const INSTALLMENT_PAID: &str = "paid";
+/// Cuota que quedó sin cubrir cuando el préstamo se reestructuró
+pub const INSTALLMENT_CLOSED: &str = "closed";
+
+/// Cuota del plan de pagos; los importes están en la moneda del préstamo
+#[derive(Debug, Clone)]
+pub struct LoanInstallment {
+    pub id: i32,
+    pub loan_id: i32,
+    pub number: i32,
+    pub due_date: String,
+    pub principal: Money,
+    pub interest: Money,
+    pub paid: Money,
+    pub status: String,
+    pub paid_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl LoanInstallment {
+    pub fn total(&self) -> Result<Money> {
+        self.principal.checked_add(&self.interest)
+    }
+
+    pub fn outstanding(&self) -> Result<Money> {
+        self.total()?.checked_sub(&self.paid)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewLoanInstallment {
+    pub number: i32,
+    pub due_date: NaiveDateTime,
+    pub principal: Money,
+    pub interest: Money,
+}
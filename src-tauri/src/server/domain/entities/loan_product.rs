@@ -0,0 +1,58 @@
+use chrono::{Duration, NaiveDateTime};
+
+use crate::server::domain::entities::loan::{InstallmentFrequency, InterestTerms, ScheduleTerms};
+use crate::server::domain::value_objects::money::Money;
+
+/// Condiciones por defecto que se aplican a los préstamos creados con el producto
+/// `installment_frequency` e `installment_count` definen el plan de cuotas; sin plan,
+/// `term_days` es el plazo hasta el vencimiento. Los límites están en la moneda del producto
+/// y `penalty_rule_id` reemplaza a las reglas generales de recargo en sus préstamos
+#[derive(Debug, Clone)]
+pub struct LoanProduct {
+    pub id: i32,
+    pub name: String,
+    pub currency: String,
+    pub interest: InterestTerms,
+    pub installment_frequency: Option<InstallmentFrequency>,
+    pub installment_count: Option<i32>,
+    pub term_days: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub min_amount: Option<Money>,
+    pub max_amount: Option<Money>,
+    pub active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl LoanProduct {
+    /// Plan de cuotas para un préstamo que empieza en `start`; la primera cuota vence una cuota después
+    pub fn schedule_from(&self, start: NaiveDateTime) -> Option<ScheduleTerms> {
+        match (self.installment_frequency, self.installment_count) {
+            (Some(frequency), Some(periods)) => Some(ScheduleTerms {
+                frequency,
+                periods,
+                first_due_date: frequency.advance(start, 1)?,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Vencimiento de un préstamo sin plan que empieza en `start`
+    pub fn due_date_from(&self, start: NaiveDateTime) -> Option<NaiveDateTime> {
+        self.term_days
+            .and_then(|days| start.checked_add_signed(Duration::days(days as i64)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewLoanProduct {
+    pub name: String,
+    pub currency: String,
+    pub interest: InterestTerms,
+    pub installment_frequency: Option<InstallmentFrequency>,
+    pub installment_count: Option<i32>,
+    pub term_days: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub min_amount: Option<Money>,
+    pub max_amount: Option<Money>,
+}
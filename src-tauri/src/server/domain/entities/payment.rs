@@ -0,0 +1,66 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::payment_allocation::{AllocationStrategy, PaymentAllocation};
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::Result;
+
+/// `allocations` es el reparto del pago entre los préstamos del prestatario
+/// Con `loan_id` el pago cubre primero ese préstamo y reparte el resto con su estrategia
+/// Un pago eliminado (`deleted_at`) no cuenta en los saldos hasta que se restaure
+#[derive(Debug, Clone)]
+pub struct Payment {
+    pub id: i32,
+    pub amount: Money,
+    pub exchange_rate: f32,
+    pub amount_cup: Money,
+    pub allocation_strategy: AllocationStrategy,
+    pub allocations: Vec<PaymentAllocation>,
+    pub borrower_id: i32,
+    pub loan_id: Option<i32>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub deleted_at: Option<String>,
+    pub deleted_by: Option<i32>,
+}
+
+/// `exchange_rate` es la tasa vigente en `created_at`, resuelta antes de persistir
+#[derive(Debug, Clone)]
+pub struct NewPayment {
+    pub amount: Money,
+    pub exchange_rate: f32,
+    pub amount_cup: Money,
+    pub allocation_strategy: AllocationStrategy,
+    pub borrower_id: i32,
+    pub loan_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
+
+impl NewPayment {
+    /// Calcula el equivalente en CUP con la tasa ya resuelta para la fecha del registro
+    pub fn new(
+        amount: Money,
+        exchange_rate: f32,
+        allocation_strategy: AllocationStrategy,
+        borrower_id: i32,
+        created_at: NaiveDateTime,
+    ) -> Result<Self> {
+        let amount_cup = amount.convert(exchange_rate, BASE_CURRENCY)?;
+
+        Ok(Self {
+            amount,
+            exchange_rate,
+            amount_cup,
+            allocation_strategy,
+            borrower_id,
+            loan_id: None,
+            created_at,
+        })
+    }
+
+    /// Aplica el pago primero al préstamo indicado
+    pub fn with_loan(mut self, loan_id: i32) -> Self {
+        self.loan_id = Some(loan_id);
+        self
+    }
+}
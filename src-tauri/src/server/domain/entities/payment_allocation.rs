@@ -0,0 +1,44 @@
+use crate::server::domain::value_objects::money::Money;
+
+/// Forma en que un pago se reparte entre los préstamos abiertos del prestatario
+/// - `OldestFirst`: cubre primero el préstamo más antiguo
+/// - `HighestInterest`: cubre primero el préstamo con la tasa diaria equivalente más alta
+/// - `ProRata`: reparte en proporción al saldo pendiente de cada préstamo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocationStrategy {
+    OldestFirst,
+    HighestInterest,
+    ProRata,
+}
+
+impl AllocationStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AllocationStrategy::OldestFirst => "oldest_first",
+            AllocationStrategy::HighestInterest => "highest_interest",
+            AllocationStrategy::ProRata => "pro_rata",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "oldest_first" => Some(AllocationStrategy::OldestFirst),
+            "highest_interest" => Some(AllocationStrategy::HighestInterest),
+            "pro_rata" => Some(AllocationStrategy::ProRata),
+            _ => None,
+        }
+    }
+}
+
+/// Parte de un pago aplicada a un préstamo
+/// `amount` está en la moneda del préstamo y `amount_cup` es la porción del pago que lo cubre
+/// `created_at` es la fecha del pago al que pertenece
+#[derive(Debug, Clone)]
+pub struct PaymentAllocation {
+    pub id: i32,
+    pub payment_id: i32,
+    pub loan_id: i32,
+    pub amount: Money,
+    pub amount_cup: Money,
+    pub created_at: String,
+}
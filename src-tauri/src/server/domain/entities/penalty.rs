@@ -0,0 +1,95 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::value_objects::money::Money;
+
+pub const PENALTY_CHARGED: &str = "charged";
+pub const PENALTY_WAIVED: &str = "waived";
+/// Recargo sumado al principal de un préstamo reestructurado
+pub const PENALTY_CAPITALIZED: &str = "capitalized";
+
+/// Forma de calcular un recargo por atraso
+/// - `Fixed`: monto fijo, una sola vez por préstamo
+/// - `Percentage`: porcentaje de lo vencido, una sola vez por préstamo
+/// - `Daily`: porcentaje de lo vencido por cada día de atraso
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PenaltyKind {
+    Fixed,
+    Percentage,
+    Daily,
+}
+
+impl PenaltyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PenaltyKind::Fixed => "fixed",
+            PenaltyKind::Percentage => "percentage",
+            PenaltyKind::Daily => "daily",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "fixed" => Some(PenaltyKind::Fixed),
+            "percentage" => Some(PenaltyKind::Percentage),
+            "daily" => Some(PenaltyKind::Daily),
+            _ => None,
+        }
+    }
+}
+
+/// Regla de recargo; `amount` y `cap` están en CUP y `rate` es un porcentaje
+/// Los días de gracia se descuentan del atraso antes de cobrar, y el tope
+/// limita lo cobrado por la regla a cada préstamo sin contar lo condonado
+#[derive(Debug, Clone)]
+pub struct PenaltyRule {
+    pub id: i32,
+    pub name: String,
+    pub kind: PenaltyKind,
+    pub amount: Money,
+    pub rate: f64,
+    pub grace_days: i32,
+    pub cap: Option<Money>,
+    pub active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewPenaltyRule {
+    pub name: String,
+    pub kind: PenaltyKind,
+    pub amount: Money,
+    pub rate: f64,
+    pub grace_days: i32,
+    pub cap: Option<Money>,
+}
+
+/// Recargo cobrado a un préstamo, en CUP
+/// `days_charged` son los días que cubre un recargo diario; en los demás tipos es 0
+#[derive(Debug, Clone)]
+pub struct Penalty {
+    pub id: i32,
+    pub loan_id: i32,
+    pub borrower_id: i32,
+    pub rule_id: i32,
+    pub amount: Money,
+    pub days_past_due: i32,
+    pub days_charged: i32,
+    pub status: String,
+    pub waived_reason: Option<String>,
+    pub waived_by: Option<i32>,
+    pub waived_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewPenalty {
+    pub loan_id: i32,
+    pub borrower_id: i32,
+    pub rule_id: i32,
+    pub amount: Money,
+    pub days_past_due: i32,
+    pub days_charged: i32,
+    pub created_at: NaiveDateTime,
+}
@@ -1,2 +1,4 @@
 pub mod entities;
-pub mod  repositories;
\ No newline at end of file
+pub mod  repositories;
+pub mod services;
+pub mod value_objects;
\ No newline at end of file
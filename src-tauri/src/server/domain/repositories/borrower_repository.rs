@@ -1,14 +1,35 @@
-use crate::server::domain::entities::borrower::{NewBorrower, Borrower};
+use crate::server::domain::entities::borrower::{
+    Borrower, BorrowerFilter, BorrowerPage, NewBorrower, TotalsReconciliation,
+};
 use crate::utils::error::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
+/// `delete` es un borrado lógico que `restore` revierte; las consultas omiten los borrowers
+/// eliminados salvo `find_deleted*`, `find_blocked` y `search` con `deleted`, y `purge` borra
+/// definitivamente uno ya eliminado
+/// `recognize_interest` registra el interés de las cuotas vencidas a la fecha dada
+/// `reconcile_totals` recalcula los totales de todos, eliminados incluidos, y con `fix` corrige
+/// el libro mayor con asientos de ajuste
 #[async_trait]
 pub trait BorrowerRepository: Clone + Send + Sync {
     async fn create(&self, new_borrower: &NewBorrower) -> Result<Borrower>;
     async fn find_all(&self) -> Result<Vec<Borrower>>;
+    async fn search(&self, filter: &BorrowerFilter) -> Result<BorrowerPage>;
     async fn find_by_id(&self, id: i32) -> Result<Option<Borrower>>;
     async fn update(&self, id: i32, borrower: &Borrower) -> Result<Borrower>;
-    async fn delete(&self, id: i32) -> Result<()>;
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<()>;
+    async fn restore(&self, id: i32) -> Result<Borrower>;
+    async fn purge(&self, id: i32) -> Result<()>;
+    async fn find_deleted(&self) -> Result<Vec<Borrower>>;
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<Borrower>>;
     async fn exists_by_phone(&self, phone: &str) -> Result<bool>;
     async fn exists_by_phone_excluding_id(&self, phone: &str, excluded_id: i32) -> Result<bool>;
+    async fn update_status(&self, id: i32, status: &str) -> Result<()>;
+    async fn refresh_credit_score(&self, id: i32, now: NaiveDateTime) -> Result<()>;
+    async fn recognize_interest(&self, id: i32, now: NaiveDateTime) -> Result<()>;
+    async fn find_blocked(&self) -> Result<Vec<Borrower>>;
+    async fn block(&self, id: i32, reason: &str, blocked_by: i32, blocked_at: NaiveDateTime) -> Result<Borrower>;
+    async fn unblock(&self, id: i32) -> Result<Borrower>;
+    async fn reconcile_totals(&self, fix: bool) -> Result<TotalsReconciliation>;
 }
\ No newline at end of file
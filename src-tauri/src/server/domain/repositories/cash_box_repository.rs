@@ -0,0 +1,28 @@
+use crate::server::domain::entities::cash_box::{
+    CashBalance, CashBoxLine, CashCount, CashExchange, CashExchangeFilter, CashMovement, NewCashCount,
+    NewCashExchange, NewCashMovement,
+};
+use crate::utils::error::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+/// La caja de cada moneda es su cuenta de caja en el libro mayor; aquí solo se guardan los
+/// movimientos manuales, los arqueos y los cambios de moneda, cada uno con el asiento que lo registró
+#[async_trait]
+pub trait CashBoxRepository: Clone + Send + Sync {
+    /// Saldo de la caja de `currency` con los asientos hasta `at`
+    async fn find_balance(&self, currency: &str, at: NaiveDateTime) -> Result<CashBalance>;
+    /// Entradas y salidas de la caja entre `from` y `to`, ambos incluidos
+    async fn find_lines(&self, currency: &str, from: Option<NaiveDateTime>, to: NaiveDateTime) -> Result<Vec<CashBoxLine>>;
+    async fn find_opening(&self, currency: &str) -> Result<Option<CashMovement>>;
+    /// Arqueos de la caja, los más recientes primero
+    async fn find_counts(&self, currency: &str) -> Result<Vec<CashCount>>;
+    /// Registra el movimiento y su asiento; un retiro no puede dejar la caja en negativo
+    async fn record_movement(&self, movement: &NewCashMovement) -> Result<CashMovement>;
+    /// Registra el arqueo frente al saldo de la caja en ese momento y ajusta la diferencia
+    async fn record_count(&self, count: &NewCashCount) -> Result<CashCount>;
+    /// Cambios de moneda entre cajas en orden cronológico
+    async fn find_exchanges(&self, filter: &CashExchangeFilter) -> Result<Vec<CashExchange>>;
+    /// Registra el cambio y su asiento; mueve ambas cajas a la vez y la de origen no puede quedar en negativo
+    async fn record_exchange(&self, exchange: &NewCashExchange) -> Result<CashExchange>;
+}
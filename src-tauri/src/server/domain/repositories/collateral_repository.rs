@@ -0,0 +1,17 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::collateral::{Collateral, NewCollateral};
+use crate::server::domain::entities::payment::NewPayment;
+use crate::utils::error::Result;
+use async_trait::async_trait;
+
+/// `liquidate` registra lo obtenido como pago del prestatario y marca la garantía
+/// en una sola transacción; el pago se aplica primero al préstamo garantizado
+#[async_trait]
+pub trait CollateralRepository: Clone + Send + Sync {
+    async fn create(&self, new_collateral: &NewCollateral) -> Result<Collateral>;
+    async fn find_all(&self) -> Result<Vec<Collateral>>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Collateral>>;
+    async fn mark_returned(&self, id: i32, returned_at: NaiveDateTime) -> Result<Collateral>;
+    async fn liquidate(&self, id: i32, proceeds: &NewPayment) -> Result<Collateral>;
+}
@@ -0,0 +1,23 @@
+use crate::server::domain::entities::currency::{Currency, NewCurrency};
+use crate::server::domain::entities::exchange_rate::{ExchangeRate, NewExchangeRate};
+use crate::utils::error::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+/// Cada cambio de tasa (alta, actualización o tasa histórica) queda registrado
+/// en `exchange_rate_history`; `currencies.exchange_rate` refleja solo la tasa vigente hoy
+#[async_trait]
+pub trait CurrencyRepository: Clone + Send + Sync {
+    async fn create(&self, new_currency: &NewCurrency) -> Result<Currency>;
+    async fn find_all(&self) -> Result<Vec<Currency>>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Currency>>;
+    async fn find_by_code(&self, code: &str) -> Result<Option<Currency>>;
+    async fn update(&self, id: i32, currency: &Currency) -> Result<Currency>;
+    async fn delete(&self, id: i32) -> Result<()>;
+    async fn exists_by_code_excluding_id(&self, code: &str, excluded_id: i32) -> Result<bool>;
+    /// Indica si algún préstamo, pago o producto de préstamo usa la moneda
+    async fn is_in_use(&self, code: &str) -> Result<bool>;
+    async fn record_rate(&self, new_rate: &NewExchangeRate) -> Result<ExchangeRate>;
+    async fn find_rate_at(&self, code: &str, at: NaiveDateTime) -> Result<Option<ExchangeRate>>;
+    async fn find_rate_history(&self, code: &str) -> Result<Vec<ExchangeRate>>;
+}
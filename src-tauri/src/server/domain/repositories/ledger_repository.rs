@@ -0,0 +1,17 @@
+use crate::server::domain::entities::ledger::{
+    AccountBalance, BorrowerReceivable, JournalEntry, JournalFilter, NewJournalEntry,
+};
+use crate::utils::error::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+/// Los asientos de préstamos, pagos y recargos se registran en la misma transacción que el
+/// hecho que los origina; `post` registra los que no dependen de otro registro, como la
+/// revaluación de la caja
+#[async_trait]
+pub trait LedgerRepository: Clone + Send + Sync {
+    async fn find_balances(&self, at: NaiveDateTime) -> Result<Vec<AccountBalance>>;
+    async fn find_entries(&self, filter: &JournalFilter) -> Result<Vec<JournalEntry>>;
+    async fn find_receivables(&self) -> Result<Vec<BorrowerReceivable>>;
+    async fn post(&self, entry: &NewJournalEntry) -> Result<JournalEntry>;
+}
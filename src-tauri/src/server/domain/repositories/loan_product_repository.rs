@@ -0,0 +1,15 @@
+use crate::server::domain::entities::loan_product::{LoanProduct, NewLoanProduct};
+use crate::utils::error::Result;
+use async_trait::async_trait;
+
+/// Los productos no se eliminan: al desactivarlos dejan de ofrecerse, pero los
+/// préstamos creados con ellos conservan la referencia
+#[async_trait]
+pub trait LoanProductRepository: Clone + Send + Sync {
+    async fn create(&self, new_product: &NewLoanProduct) -> Result<LoanProduct>;
+    async fn find_all(&self) -> Result<Vec<LoanProduct>>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<LoanProduct>>;
+    async fn find_by_name(&self, name: &str) -> Result<Option<LoanProduct>>;
+    async fn update(&self, id: i32, product: &NewLoanProduct) -> Result<LoanProduct>;
+    async fn set_active(&self, id: i32, active: bool) -> Result<LoanProduct>;
+}
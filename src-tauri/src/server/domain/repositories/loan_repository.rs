@@ -0,0 +1,44 @@
+use crate::server::domain::entities::collateral::Collateral;
+use crate::server::domain::entities::loan::{Loan, LoanRestructuring, NewLoan, NewLoanWriteOff};
+use crate::server::domain::entities::loan_guarantor::{LoanGuarantor, NewLoanGuarantor};
+use crate::server::domain::entities::loan_installment::LoanInstallment;
+use crate::server::domain::entities::payment_allocation::PaymentAllocation;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::Result;
+use async_trait::async_trait;
+
+/// Las operaciones que crean, modifican o eliminan préstamos también
+/// actualizan `total_loans` y `balance` del prestatario en la misma transacción
+/// El plan de cuotas se guarda y reemplaza junto con el préstamo, y los pagos
+/// del prestatario se vuelven a repartir para recalcular los saldos pendientes
+/// `restructure` cierra el préstamo y crea su reemplazo en una sola transacción
+/// `write_off` castiga el préstamo y saca lo castigado del saldo del prestatario
+/// Al cambiar los garantes o el saldo de un préstamo se recalcula lo que garantiza cada prestatario
+/// Las garantías en custodia pasan al préstamo nuevo al reestructurar, y un préstamo
+/// con garantías registradas no se puede eliminar
+/// `delete` es un borrado lógico que `restore` revierte; las consultas omiten los préstamos
+/// eliminados salvo `find_deleted*`, y `purge` borra definitivamente uno ya eliminado
+#[async_trait]
+pub trait LoanRepository: Clone + Send + Sync {
+    async fn create(&self, new_loan: &NewLoan) -> Result<Loan>;
+    async fn find_all(&self) -> Result<Vec<Loan>>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Loan>>;
+    async fn find_by_borrower(&self, borrower_id: i32) -> Result<Vec<Loan>>;
+    async fn update(&self, id: i32, loan: &NewLoan) -> Result<Loan>;
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<()>;
+    async fn restore(&self, id: i32) -> Result<Loan>;
+    async fn purge(&self, id: i32) -> Result<()>;
+    async fn find_deleted(&self) -> Result<Vec<Loan>>;
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<Loan>>;
+    async fn find_installments(&self, loan_id: i32) -> Result<Vec<LoanInstallment>>;
+    async fn find_allocations(&self, loan_id: i32) -> Result<Vec<PaymentAllocation>>;
+    async fn update_status(&self, id: i32, status: &str) -> Result<()>;
+    /// Recargos cobrados y sin condonar del préstamo, en CUP
+    async fn find_charged_penalties(&self, loan_id: i32) -> Result<Money>;
+    async fn restructure(&self, id: i32, restructuring: &LoanRestructuring) -> Result<Loan>;
+    async fn write_off(&self, id: i32, write_off: &NewLoanWriteOff) -> Result<Loan>;
+    async fn find_guarantors(&self, loan_id: i32) -> Result<Vec<LoanGuarantor>>;
+    async fn add_guarantor(&self, loan_id: i32, guarantor: &NewLoanGuarantor) -> Result<LoanGuarantor>;
+    async fn remove_guarantor(&self, loan_id: i32, guarantor_id: i32) -> Result<()>;
+    async fn find_collateral(&self, loan_id: i32) -> Result<Vec<Collateral>>;
+}
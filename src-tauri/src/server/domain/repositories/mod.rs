@@ -1,2 +1,10 @@
 pub mod borrower_repository;
 pub mod user_repository;
+pub mod loan_repository;
+pub mod payment_repository;
+pub mod currency_repository;
+pub mod penalty_repository;
+pub mod loan_product_repository;
+pub mod collateral_repository;
+pub mod ledger_repository;
+pub mod cash_box_repository;
\ No newline at end of file
@@ -0,0 +1,22 @@
+use crate::server::domain::entities::payment::{NewPayment, Payment};
+use crate::utils::error::Result;
+use async_trait::async_trait;
+
+/// Las operaciones que crean, modifican o eliminan pagos también
+/// actualizan `total_paid` y `balance` del prestatario en la misma transacción
+/// y vuelven a repartir sus pagos entre sus préstamos abiertos
+/// `delete` es un borrado lógico que `restore` revierte; las consultas omiten los pagos
+/// eliminados salvo `find_deleted*`, y `purge` borra definitivamente uno ya eliminado
+#[async_trait]
+pub trait PaymentRepository: Clone + Send + Sync {
+    async fn create(&self, new_payment: &NewPayment) -> Result<Payment>;
+    async fn find_all(&self) -> Result<Vec<Payment>>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Payment>>;
+    async fn find_by_borrower(&self, borrower_id: i32) -> Result<Vec<Payment>>;
+    async fn update(&self, id: i32, payment: &NewPayment) -> Result<Payment>;
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<()>;
+    async fn restore(&self, id: i32) -> Result<Payment>;
+    async fn purge(&self, id: i32) -> Result<()>;
+    async fn find_deleted(&self) -> Result<Vec<Payment>>;
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<Payment>>;
+}
@@ -0,0 +1,18 @@
+use crate::server::domain::entities::penalty::{NewPenalty, NewPenaltyRule, Penalty, PenaltyRule};
+use crate::utils::error::Result;
+use async_trait::async_trait;
+
+/// Registrar o condonar un recargo actualiza `total_penalties` y `balance`
+/// del prestatario en la misma transacción
+#[async_trait]
+pub trait PenaltyRepository: Clone + Send + Sync {
+    async fn create_rule(&self, new_rule: &NewPenaltyRule) -> Result<PenaltyRule>;
+    async fn find_rules(&self) -> Result<Vec<PenaltyRule>>;
+    async fn find_rule_by_id(&self, id: i32) -> Result<Option<PenaltyRule>>;
+    async fn set_rule_active(&self, id: i32, active: bool) -> Result<PenaltyRule>;
+    async fn create(&self, new_penalty: &NewPenalty) -> Result<Penalty>;
+    async fn find_all(&self, borrower_id: Option<i32>) -> Result<Vec<Penalty>>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Penalty>>;
+    async fn find_by_loan(&self, loan_id: i32) -> Result<Vec<Penalty>>;
+    async fn waive(&self, id: i32, reason: &str, waived_by: i32) -> Result<Penalty>;
+}
@@ -0,0 +1,288 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::borrower::CreditFactors;
+use crate::server::domain::entities::loan::{Loan, LOAN_RESTRUCTURED, LOAN_WRITTEN_OFF};
+use crate::server::domain::entities::loan_installment::{
+    LoanInstallment, INSTALLMENT_CLOSED, INSTALLMENT_PAID,
+};
+use crate::server::domain::services::delinquency_policy::DelinquencyPolicy;
+use crate::utils::date::parse_stored_date;
+use crate::utils::error::Result;
+
+pub const BASE_SCORE: i32 = 600;
+pub const MIN_SCORE: i32 = 300;
+pub const MAX_SCORE: i32 = 850;
+
+/// Préstamo del prestatario con su plan de cuotas
+/// `settled_at` es la fecha del último pago que recibió, si ya quedó saldado
+#[derive(Debug, Clone)]
+pub struct LoanHistory {
+    pub loan: Loan,
+    pub installments: Vec<LoanInstallment>,
+    pub settled_at: Option<NaiveDateTime>,
+}
+
+/// Aporte de un factor al puntaje
+#[derive(Debug, Clone)]
+pub struct ScoreFactor {
+    pub factor: &'static str,
+    pub value: i32,
+    pub points: i32,
+}
+
+/// Puntos por unidad de cada factor y el tope de lo que puede sumar o restar
+const ON_TIME_POINTS: (i32, i32) = (10, 150);
+const LATE_POINTS: (i32, i32) = (-25, -250);
+const DAY_PAST_DUE_POINTS: (i32, i32) = (-2, -200);
+const RESTRUCTURE_POINTS: (i32, i32) = (-50, -150);
+const WRITE_OFF_POINTS: (i32, i32) = (-200, -400);
+
+/// Calcula el puntaje de crédito interno a partir del historial de pagos
+/// Parte de 600, suma por cada cuota pagada a tiempo y resta por atrasos,
+/// reestructuraciones y castigos; el resultado queda entre 300 y 850
+pub struct CreditScorer;
+
+impl CreditScorer {
+    /// Cuenta los factores del historial a la fecha
+    /// Una cuota pagada después de su vencimiento, cerrada sin pagar o vencida con saldo es tardía;
+    /// un préstamo sin plan cuenta como una sola cuota con su vencimiento
+    pub fn assess(history: &[LoanHistory], now: NaiveDateTime) -> Result<CreditFactors> {
+        let mut factors = CreditFactors::default();
+
+        for entry in history {
+            let loan = &entry.loan;
+            if loan.restructured_at.is_some() {
+                factors.restructures += 1;
+            }
+            if loan.write_off.is_some() {
+                factors.write_offs += 1;
+            }
+
+            if loan.schedule.is_some() {
+                for installment in &entry.installments {
+                    let due = parse_stored_date(&installment.due_date);
+                    let on_time = match installment.status.as_str() {
+                        INSTALLMENT_PAID => installment
+                            .paid_at
+                            .as_deref()
+                            .is_some_and(|paid_at| parse_stored_date(paid_at) <= due),
+                        INSTALLMENT_CLOSED => false,
+                        _ if due < now => false,
+                        _ => continue,
+                    };
+                    record_installment(&mut factors, on_time);
+                }
+            } else if let Some(due) = loan.due_date.as_deref().map(parse_stored_date) {
+                if !loan.remaining.is_positive() {
+                    // El saldo de un préstamo reestructurado pasó al nuevo; no se pagó ni se atrasó
+                    if loan.status != LOAN_RESTRUCTURED {
+                        record_installment(&mut factors, entry.settled_at.is_some_and(|settled| settled <= due));
+                    }
+                } else if due < now {
+                    record_installment(&mut factors, false);
+                }
+            }
+
+            if loan.status != LOAN_RESTRUCTURED && loan.status != LOAN_WRITTEN_OFF {
+                let days = DelinquencyPolicy::days_past_due(loan, &entry.installments, now)?;
+                factors.days_past_due = factors.days_past_due.max(days.min(i32::MAX as i64) as i32);
+            }
+        }
+
+        Ok(factors)
+    }
+
+    /// Puntos que aporta cada factor, en el orden en que se muestran
+    pub fn breakdown(factors: &CreditFactors) -> Vec<ScoreFactor> {
+        [
+            ("on_time_installments", factors.on_time_installments, ON_TIME_POINTS),
+            ("late_installments", factors.late_installments, LATE_POINTS),
+            ("days_past_due", factors.days_past_due, DAY_PAST_DUE_POINTS),
+            ("restructures", factors.restructures, RESTRUCTURE_POINTS),
+            ("write_offs", factors.write_offs, WRITE_OFF_POINTS),
+        ]
+        .into_iter()
+        .map(|(factor, value, (per_unit, cap))| {
+            let points = value.saturating_mul(per_unit);
+            ScoreFactor {
+                factor,
+                value,
+                points: if cap < 0 { points.max(cap) } else { points.min(cap) },
+            }
+        })
+        .collect()
+    }
+
+    pub fn score(factors: &CreditFactors) -> i32 {
+        let points: i32 = Self::breakdown(factors).iter().map(|factor| factor.points).sum();
+        (BASE_SCORE + points).clamp(MIN_SCORE, MAX_SCORE)
+    }
+}
+
+fn record_installment(factors: &mut CreditFactors, on_time: bool) {
+    if on_time {
+        factors.on_time_installments += 1;
+    } else {
+        factors.late_installments += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::domain::entities::loan::{
+        InstallmentFrequency, InterestTerms, ScheduleTerms, LOAN_ACTIVE, LOAN_PAID,
+    };
+    use crate::server::domain::entities::loan_installment::INSTALLMENT_PENDING;
+    use crate::server::domain::value_objects::money::Money;
+    use chrono::{Duration, NaiveDate};
+
+    fn day(offset: i64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap() + Duration::days(offset)
+    }
+
+    fn stored(offset: i64) -> String {
+        day(offset).and_utc().to_rfc3339()
+    }
+
+    fn cup(minor_units: i64) -> Money {
+        Money::from_minor(minor_units, "CUP")
+    }
+
+    /// Préstamo de 1000 CUP; con `due` no tiene plan y vence ese día, sin él tiene plan mensual
+    fn loan(remaining: i64, status: &str, due: Option<i64>) -> Loan {
+        Loan {
+            id: 1,
+            amount: cup(100000),
+            exchange_rate: 1.0,
+            amount_cup: cup(100000),
+            interest: InterestTerms::none(),
+            schedule: match due {
+                Some(_) => None,
+                None => Some(ScheduleTerms {
+                    frequency: InstallmentFrequency::Monthly,
+                    periods: 4,
+                    first_due_date: day(30),
+                }),
+            },
+            remaining: cup(remaining),
+            due_date: due.map(stored),
+            status: status.to_string(),
+            borrower_id: 1,
+            created_at: stored(0),
+            updated_at: stored(0),
+            restructured_from_id: None,
+            restructured_at: None,
+            carried_over_cup: cup(0),
+            write_off: None,
+            product_id: None,
+            penalty_rule_id: None,
+            credit_limit_override_by: None,
+            deleted_at: None,
+            deleted_by: None,
+        }
+    }
+
+    fn installment(number: i32, due: i64, status: &str, paid_at: Option<i64>) -> LoanInstallment {
+        let paid = if status == INSTALLMENT_PAID { cup(25000) } else { cup(0) };
+        LoanInstallment {
+            id: number,
+            loan_id: 1,
+            number,
+            due_date: stored(due),
+            principal: cup(25000),
+            interest: cup(0),
+            paid,
+            status: status.to_string(),
+            paid_at: paid_at.map(stored),
+            created_at: stored(0),
+            updated_at: stored(0),
+        }
+    }
+
+    #[test]
+    fn installments_paid_after_their_due_date_or_overdue_count_as_late() {
+        let history = [LoanHistory {
+            loan: loan(50000, LOAN_ACTIVE, None),
+            installments: vec![
+                installment(1, 30, INSTALLMENT_PAID, Some(28)),
+                installment(2, 60, INSTALLMENT_PAID, Some(65)),
+                installment(3, 90, INSTALLMENT_PENDING, None),
+                installment(4, 120, INSTALLMENT_PENDING, None),
+            ],
+            settled_at: None,
+        }];
+
+        let factors = CreditScorer::assess(&history, day(100)).unwrap();
+
+        // La cuota 4 todavía no vence y no cuenta
+        assert_eq!(factors.on_time_installments, 1);
+        assert_eq!(factors.late_installments, 2);
+        assert_eq!(factors.days_past_due, 10);
+    }
+
+    #[test]
+    fn loans_without_a_schedule_count_as_one_installment() {
+        let history = [
+            LoanHistory {
+                loan: loan(0, LOAN_PAID, Some(30)),
+                installments: Vec::new(),
+                settled_at: Some(day(20)),
+            },
+            LoanHistory {
+                loan: loan(0, LOAN_PAID, Some(30)),
+                installments: Vec::new(),
+                settled_at: Some(day(45)),
+            },
+        ];
+
+        let factors = CreditScorer::assess(&history, day(100)).unwrap();
+
+        assert_eq!(factors.on_time_installments, 1);
+        assert_eq!(factors.late_installments, 1);
+        assert_eq!(factors.days_past_due, 0);
+    }
+
+    #[test]
+    fn restructured_loans_count_closed_installments_as_late_without_days_past_due() {
+        let mut restructured = loan(50000, LOAN_RESTRUCTURED, None);
+        restructured.restructured_at = Some(stored(70));
+        let history = [LoanHistory {
+            loan: restructured,
+            installments: vec![
+                installment(1, 30, INSTALLMENT_PAID, Some(30)),
+                installment(2, 60, INSTALLMENT_CLOSED, None),
+                installment(3, 90, INSTALLMENT_CLOSED, None),
+            ],
+            settled_at: None,
+        }];
+
+        let factors = CreditScorer::assess(&history, day(100)).unwrap();
+
+        // La cuota 2 sigue con saldo, pero ese saldo pasó a la reestructuración
+        assert_eq!(factors.on_time_installments, 1);
+        assert_eq!(factors.late_installments, 2);
+        assert_eq!(factors.restructures, 1);
+        assert_eq!(factors.days_past_due, 0);
+    }
+
+    #[test]
+    fn score_caps_each_factor_and_stays_between_the_limits() {
+        let spotless = CreditFactors {
+            on_time_installments: 100,
+            ..CreditFactors::default()
+        };
+        let ruined = CreditFactors {
+            on_time_installments: 5,
+            late_installments: 20,
+            days_past_due: 200,
+            restructures: 4,
+            write_offs: 3,
+        };
+
+        assert_eq!(CreditScorer::score(&CreditFactors::default()), BASE_SCORE);
+        assert_eq!(CreditScorer::score(&spotless), BASE_SCORE + ON_TIME_POINTS.1);
+        assert_eq!(CreditScorer::score(&ruined), MIN_SCORE);
+        assert!(CreditScorer::score(&spotless) <= MAX_SCORE);
+    }
+}
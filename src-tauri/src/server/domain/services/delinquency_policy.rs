@@ -0,0 +1,237 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::borrower::{
+    BORROWER_CURRENT, BORROWER_DELINQUENT, BORROWER_LATE, BORROWER_PAID_OFF,
+};
+use crate::server::domain::entities::loan::{
+    Loan, LOAN_ACTIVE, LOAN_DEFAULTED, LOAN_OVERDUE, LOAN_PAID, LOAN_WRITTEN_OFF,
+};
+use crate::server::domain::entities::loan_installment::LoanInstallment;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::date::parse_stored_date;
+use crate::utils::error::Result;
+
+/// Días de atraso a partir de los cuales un préstamo cambia de estado
+#[derive(Debug, Clone, Copy)]
+pub struct DelinquencyThresholds {
+    pub overdue_after_days: i64,
+    pub default_after_days: i64,
+}
+
+/// Clasifica préstamos y prestatarios según los días de atraso
+#[derive(Debug, Clone, Copy)]
+pub struct DelinquencyPolicy {
+    thresholds: DelinquencyThresholds,
+}
+
+impl DelinquencyPolicy {
+    pub fn new(thresholds: DelinquencyThresholds) -> Self {
+        Self { thresholds }
+    }
+
+    /// Días desde el vencimiento más antiguo sin cubrir
+    /// Con plan se mira la primera cuota con saldo; sin plan, el vencimiento del préstamo
+    pub fn days_past_due(loan: &Loan, installments: &[LoanInstallment], now: NaiveDateTime) -> Result<i64> {
+        if !loan.remaining.is_positive() {
+            return Ok(0);
+        }
+
+        let mut oldest_due = None;
+        if loan.schedule.is_some() {
+            for installment in installments {
+                if installment.outstanding()?.is_positive() {
+                    oldest_due = Some(parse_stored_date(&installment.due_date));
+                    break;
+                }
+            }
+        } else {
+            oldest_due = loan.due_date.as_deref().map(parse_stored_date);
+        }
+
+        Ok(oldest_due
+            .map(|due| (now - due).num_days().max(0))
+            .unwrap_or(0))
+    }
+
+    /// Parte del saldo ya vencida a la fecha, en la moneda del préstamo
+    /// Con plan son las cuotas vencidas sin cubrir; sin plan, todo el saldo una vez pasado el vencimiento
+    pub fn overdue_amount(loan: &Loan, installments: &[LoanInstallment], now: NaiveDateTime) -> Result<Money> {
+        let mut overdue = Money::zero(loan.remaining.currency());
+        if !loan.remaining.is_positive() {
+            return Ok(overdue);
+        }
+
+        if loan.schedule.is_some() {
+            for installment in installments {
+                if parse_stored_date(&installment.due_date) <= now {
+                    overdue = overdue.checked_add(&installment.outstanding()?)?;
+                }
+            }
+        } else if loan.due_date.as_deref().is_some_and(|due| parse_stored_date(due) <= now) {
+            overdue = loan.remaining.clone();
+        }
+
+        Ok(overdue)
+    }
+
+    pub fn loan_status(&self, loan: &Loan, days_past_due: i64) -> &'static str {
+        if !loan.remaining.is_positive() {
+            LOAN_PAID
+        } else if days_past_due >= self.thresholds.default_after_days {
+            LOAN_DEFAULTED
+        } else if days_past_due >= self.thresholds.overdue_after_days {
+            LOAN_OVERDUE
+        } else {
+            LOAN_ACTIVE
+        }
+    }
+
+    /// Estado del prestatario según el peor de sus préstamos
+    /// Un préstamo castigado con saldo sin recuperar cuenta como en mora
+    pub fn borrower_status(loan_statuses: &[&str]) -> &'static str {
+        if loan_statuses.is_empty() {
+            BORROWER_CURRENT
+        } else if loan_statuses.iter().all(|status| *status == LOAN_PAID) {
+            BORROWER_PAID_OFF
+        } else if loan_statuses.contains(&LOAN_DEFAULTED) || loan_statuses.contains(&LOAN_WRITTEN_OFF) {
+            BORROWER_DELINQUENT
+        } else if loan_statuses.contains(&LOAN_OVERDUE) {
+            BORROWER_LATE
+        } else {
+            BORROWER_CURRENT
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CollectionsConfig;
+    use crate::server::domain::entities::loan::{InstallmentFrequency, InterestTerms, ScheduleTerms};
+    use crate::server::domain::entities::loan_installment::{INSTALLMENT_PAID, INSTALLMENT_PARTIAL, INSTALLMENT_PENDING};
+    use chrono::{Duration, NaiveDate};
+
+    fn day(offset: i64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap() + Duration::days(offset)
+    }
+
+    fn stored(offset: i64) -> String {
+        day(offset).and_utc().to_rfc3339()
+    }
+
+    fn usd(minor_units: i64) -> Money {
+        Money::from_minor(minor_units, "USD")
+    }
+
+    /// Préstamo de 300 USD; con `due` no tiene plan y vence ese día, sin él tiene plan mensual
+    fn loan(remaining: i64, due: Option<i64>) -> Loan {
+        Loan {
+            id: 1,
+            amount: usd(30000),
+            exchange_rate: 120.0,
+            amount_cup: Money::from_minor(3600000, "CUP"),
+            interest: InterestTerms::none(),
+            schedule: match due {
+                Some(_) => None,
+                None => Some(ScheduleTerms {
+                    frequency: InstallmentFrequency::Monthly,
+                    periods: 3,
+                    first_due_date: day(30),
+                }),
+            },
+            remaining: usd(remaining),
+            due_date: due.map(stored),
+            status: LOAN_ACTIVE.to_string(),
+            borrower_id: 1,
+            created_at: stored(0),
+            updated_at: stored(0),
+            restructured_from_id: None,
+            restructured_at: None,
+            carried_over_cup: Money::zero("CUP"),
+            write_off: None,
+            product_id: None,
+            penalty_rule_id: None,
+            credit_limit_override_by: None,
+            deleted_at: None,
+            deleted_by: None,
+        }
+    }
+
+    fn installment(number: i32, due: i64, paid: i64, status: &str) -> LoanInstallment {
+        LoanInstallment {
+            id: number,
+            loan_id: 1,
+            number,
+            due_date: stored(due),
+            principal: usd(10000),
+            interest: usd(0),
+            paid: usd(paid),
+            status: status.to_string(),
+            paid_at: None,
+            created_at: stored(0),
+            updated_at: stored(0),
+        }
+    }
+
+    fn schedule() -> Vec<LoanInstallment> {
+        vec![
+            installment(1, 30, 10000, INSTALLMENT_PAID),
+            installment(2, 60, 4000, INSTALLMENT_PARTIAL),
+            installment(3, 90, 0, INSTALLMENT_PENDING),
+        ]
+    }
+
+    /// Política armada como en la fábrica de servicios, con los umbrales por defecto de la configuración
+    fn policy() -> DelinquencyPolicy {
+        let collections = CollectionsConfig {
+            overdue_after_days: 1,
+            default_after_days: 90,
+            check_interval_hours: 24,
+        };
+        DelinquencyPolicy::new(DelinquencyThresholds {
+            overdue_after_days: collections.overdue_after_days,
+            default_after_days: collections.default_after_days,
+        })
+    }
+
+    #[test]
+    fn scheduled_loans_are_late_from_the_first_installment_with_a_balance() {
+        let loan = loan(16000, None);
+
+        assert_eq!(DelinquencyPolicy::days_past_due(&loan, &schedule(), day(50)).unwrap(), 0);
+        assert_eq!(DelinquencyPolicy::days_past_due(&loan, &schedule(), day(75)).unwrap(), 15);
+        assert_eq!(DelinquencyPolicy::overdue_amount(&loan, &schedule(), day(50)).unwrap(), usd(0));
+        assert_eq!(DelinquencyPolicy::overdue_amount(&loan, &schedule(), day(75)).unwrap(), usd(6000));
+        assert_eq!(DelinquencyPolicy::overdue_amount(&loan, &schedule(), day(90)).unwrap(), usd(16000));
+    }
+
+    #[test]
+    fn loans_without_a_schedule_are_fully_overdue_after_their_due_date() {
+        let loan = loan(30000, Some(30));
+
+        assert_eq!(DelinquencyPolicy::days_past_due(&loan, &[], day(20)).unwrap(), 0);
+        assert_eq!(DelinquencyPolicy::days_past_due(&loan, &[], day(45)).unwrap(), 15);
+        assert_eq!(DelinquencyPolicy::overdue_amount(&loan, &[], day(20)).unwrap(), usd(0));
+        assert_eq!(DelinquencyPolicy::overdue_amount(&loan, &[], day(45)).unwrap(), usd(30000));
+    }
+
+    #[test]
+    fn paid_loans_are_never_past_due() {
+        let loan = loan(0, Some(30));
+
+        assert_eq!(DelinquencyPolicy::days_past_due(&loan, &[], day(200)).unwrap(), 0);
+        assert_eq!(DelinquencyPolicy::overdue_amount(&loan, &[], day(200)).unwrap(), usd(0));
+        assert_eq!(policy().loan_status(&loan, 0), LOAN_PAID);
+    }
+
+    #[test]
+    fn loan_status_follows_the_configured_thresholds() {
+        let policy = policy();
+        let loan = loan(30000, Some(30));
+
+        assert_eq!(policy.loan_status(&loan, 0), LOAN_ACTIVE);
+        assert_eq!(policy.loan_status(&loan, 1), LOAN_OVERDUE);
+        assert_eq!(policy.loan_status(&loan, 89), LOAN_OVERDUE);
+        assert_eq!(policy.loan_status(&loan, 90), LOAN_DEFAULTED);
+    }
+}
@@ -0,0 +1,118 @@
+/// Dígitos con que se comparan los teléfonos; lo que sobra a la izquierda es el prefijo de país
+const PHONE_DIGITS: usize = 8;
+
+/// Detecta prestatarios que probablemente son la misma persona registrada de otra forma
+pub struct DuplicateDetector;
+
+impl DuplicateDetector {
+    /// Teléfonos iguales sin contar espacios, guiones ni el prefijo de país
+    pub fn same_phone(a: &str, b: &str) -> bool {
+        let (a, b) = (phone_key(a), phone_key(b));
+        !a.is_empty() && a == b
+    }
+
+    /// Nombres iguales sin contar mayúsculas, tildes ni el orden de las palabras,
+    /// o que se diferencian en una letra (dos en nombres largos)
+    pub fn similar_names(a: &str, b: &str) -> bool {
+        let (a, b) = (name_key(a), name_key(b));
+        if a.is_empty() || b.is_empty() {
+            return false;
+        }
+
+        let tolerance = if a.chars().count().min(b.chars().count()) >= 10 { 2 } else { 1 };
+        edit_distance(&a, &b) <= tolerance
+    }
+}
+
+fn phone_key(phone: &str) -> String {
+    let digits: Vec<char> = phone.chars().filter(char::is_ascii_digit).collect();
+    digits[digits.len().saturating_sub(PHONE_DIGITS)..].iter().collect()
+}
+
+fn name_key(name: &str) -> String {
+    let normalized: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'ä' => 'a',
+            'é' | 'è' | 'ë' => 'e',
+            'í' | 'ì' | 'ï' => 'i',
+            'ó' | 'ò' | 'ö' => 'o',
+            'ú' | 'ù' | 'ü' => 'u',
+            'ñ' => 'n',
+            c if c.is_alphanumeric() => c,
+            _ => ' ',
+        })
+        .collect();
+
+    let mut words: Vec<&str> = normalized.split_whitespace().collect();
+    words.sort_unstable();
+    words.join(" ")
+}
+
+/// Distancia de Levenshtein por caracteres
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phone_key_keeps_the_last_digits_without_country_prefix() {
+        assert_eq!(phone_key("+53 5 123-4567"), "51234567");
+        assert_eq!(phone_key("0053 51234567"), "51234567");
+        assert_eq!(phone_key("1234"), "1234");
+        assert_eq!(phone_key("sin teléfono"), "");
+        assert!(DuplicateDetector::same_phone("+53 51234567", "5123 4567"));
+        assert!(!DuplicateDetector::same_phone("", "---"));
+    }
+
+    #[test]
+    fn name_key_ignores_case_accents_punctuation_and_word_order() {
+        assert_eq!(name_key("Pérez, José Ángel"), "angel jose perez");
+        assert_eq!(name_key("José Ángel PÉREZ"), "angel jose perez");
+        assert_eq!(name_key("Muñoz"), "munoz");
+        assert_eq!(name_key("  ,. "), "");
+    }
+
+    #[test]
+    fn edit_distance_counts_insertions_deletions_and_substitutions() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("", "ana"), 3);
+        assert_eq!(edit_distance("ana", "ana"), 0);
+        assert_eq!(edit_distance("ana", "ama"), 1);
+        assert_eq!(edit_distance("ana", "anna"), 1);
+        assert_eq!(edit_distance("gomez", "gomes"), 1);
+        assert_eq!(edit_distance("perez", "prez"), 1);
+    }
+
+    #[test]
+    fn long_names_tolerate_two_edits_and_short_names_one() {
+        // "ana garcia" tiene 10 caracteres y "ana gomez" 9
+        assert!(DuplicateDetector::similar_names("Ana García", "Ama Garsia"));
+        assert!(DuplicateDetector::similar_names("Ana Gómez", "Ana Gomes"));
+        assert!(!DuplicateDetector::similar_names("Ana Gómez", "Ama Gomes"));
+        assert!(!DuplicateDetector::similar_names("Ana García", "Eva Garsía"));
+    }
+
+    #[test]
+    fn empty_names_are_never_similar() {
+        assert!(!DuplicateDetector::similar_names("", ""));
+        assert!(!DuplicateDetector::similar_names("", "A"));
+        assert!(!DuplicateDetector::similar_names("...", "-"));
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::{AppError, Result};
+
+/// Resuelve la tasa de cambio a CUP vigente en una fecha a partir del historial de tasas
+#[derive(Clone)]
+pub struct ExchangeRateService<T: CurrencyRepository> {
+    currency_repository: T,
+}
+
+impl<T: CurrencyRepository> ExchangeRateService<T> {
+    pub fn new(currency_repository: T) -> Self {
+        Self { currency_repository }
+    }
+
+    /// Tasa de `code` a CUP vigente en `at`; la moneda base siempre vale 1
+    pub async fn rate_at(&self, code: &str, at: NaiveDateTime) -> Result<f32> {
+        if code == BASE_CURRENCY {
+            return Ok(1.0);
+        }
+
+        self.currency_repository
+            .find_rate_at(code, at)
+            .await?
+            .map(|rate| rate.exchange_rate)
+            .ok_or_else(|| AppError::NotFound(format!(
+                "No hay una tasa registrada para {} en la fecha {}",
+                code,
+                at.format("%Y-%m-%d %H:%M")
+            )))
+    }
+
+    /// Convierte un importe a CUP con la tasa de su moneda vigente en `at`
+    pub async fn convert_to_cup(&self, amount: &Money, at: NaiveDateTime) -> Result<Money> {
+        let rate = self.rate_at(amount.currency(), at).await?;
+        amount.convert(rate, BASE_CURRENCY)
+    }
+}
@@ -0,0 +1,397 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::cash_box::{NewCashMovement, CASH_OPENING, CASH_WITHDRAWAL};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::ledger::{
+    cash_account, NewJournalEntry, NewJournalLine, PaymentSplit, ACCOUNT_CASH_CONTRIBUTIONS, ACCOUNT_CASH_OVERAGE,
+    ACCOUNT_CASH_SHORTAGE, ACCOUNT_FX_GAIN, ACCOUNT_FX_LOSS, ACCOUNT_INTEREST_INCOME, ACCOUNT_INTEREST_RECEIVABLE,
+    ACCOUNT_LOANS_RECEIVABLE, ACCOUNT_OPENING_BALANCES, ACCOUNT_PENALTIES_RECEIVABLE, ACCOUNT_PENALTY_INCOME,
+    ACCOUNT_RECOVERY_INCOME, ACCOUNT_WRITE_OFF_EXPENSE, EVENT_CASH_COUNT, EVENT_CASH_DEPOSIT, EVENT_CASH_EXCHANGE,
+    EVENT_CASH_WITHDRAWAL, EVENT_DISBURSEMENT, EVENT_FX_REVALUATION, EVENT_INTEREST, EVENT_OPENING, EVENT_PAYMENT,
+    EVENT_PENALTY, EVENT_RECOVERY, EVENT_WRITE_OFF,
+};
+use crate::server::domain::entities::loan::Loan;
+use crate::server::domain::entities::payment::Payment;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::date::parse_stored_date;
+use crate::utils::error::{AppError, Result};
+
+/// Arma los asientos de partida doble de cada hecho financiero
+/// Lo que un asiento deja en las cuentas por cobrar de un prestatario es exactamente lo que
+/// el mismo hecho suma o resta a su saldo; los totales guardados del prestatario se
+/// reconstruyen desde esas partidas
+/// El interés de un plan de cuotas se reconoce como ingreso a medida que las cuotas vencen o se
+/// pagan, y el que se capitaliza en una reestructuración al reestructurar
+pub struct JournalPoster;
+
+impl JournalPoster {
+    /// Desembolso: el préstamo pasa a cobrar y sale de la caja de su moneda
+    pub fn disbursement(loan: &Loan) -> Result<NewJournalEntry> {
+        Ok(NewJournalEntry {
+            event: EVENT_DISBURSEMENT,
+            description: format!("Desembolso del préstamo #{}", loan.id),
+            borrower_id: Some(loan.borrower_id),
+            loan_id: Some(loan.id),
+            payment_id: None,
+            posted_at: parse_stored_date(&loan.created_at),
+            lines: vec![
+                cup_line(ACCOUNT_LOANS_RECEIVABLE, Some(loan.borrower_id), loan.amount_cup.clone()),
+                cash_line(loan.amount_cup.checked_neg()?, loan.amount.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Pago: entra a la caja de su moneda y sale de las cuentas por cobrar del prestatario según
+    /// lo que cubre: el interés y los recargos de `split` y el resto el principal
+    pub fn payment(payment: &Payment, split: &PaymentSplit) -> Result<NewJournalEntry> {
+        let principal = payment
+            .amount_cup
+            .checked_sub(&split.interest)?
+            .checked_sub(&split.penalties)?;
+
+        Ok(NewJournalEntry {
+            event: EVENT_PAYMENT,
+            description: format!("Pago #{}", payment.id),
+            borrower_id: Some(payment.borrower_id),
+            loan_id: payment.loan_id,
+            payment_id: Some(payment.id),
+            posted_at: parse_stored_date(&payment.created_at),
+            lines: vec![
+                cash_line(payment.amount_cup.clone(), payment.amount.clone()),
+                cup_line(ACCOUNT_INTEREST_RECEIVABLE, Some(payment.borrower_id), split.interest.checked_neg()?),
+                cup_line(ACCOUNT_PENALTIES_RECEIVABLE, Some(payment.borrower_id), split.penalties.checked_neg()?),
+                cup_line(ACCOUNT_LOANS_RECEIVABLE, Some(payment.borrower_id), principal.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Corrección de lo registrado para un pago: su anulación o un cambio en su reparto
+    /// `lines` son los movimientos netos que faltan por cuenta
+    pub fn payment_correction(
+        payment: &Payment,
+        lines: Vec<NewJournalLine>,
+        description: String,
+        posted_at: NaiveDateTime,
+    ) -> Result<NewJournalEntry> {
+        Ok(NewJournalEntry {
+            event: EVENT_PAYMENT,
+            description,
+            borrower_id: Some(payment.borrower_id),
+            loan_id: payment.loan_id,
+            payment_id: Some(payment.id),
+            posted_at,
+            lines,
+        })
+    }
+
+    /// Interés de las cuotas de un préstamo que vencieron o se pagaron: pasa a cobrar y a ingresos
+    /// Es negativo cuando deja de corresponder, p. ej. al reestructurar, castigar o eliminar el préstamo
+    pub fn interest(borrower_id: i32, loan_id: i32, amount: &Money, posted_at: NaiveDateTime) -> Result<NewJournalEntry> {
+        let description = if amount.is_positive() {
+            format!("Interés reconocido del préstamo #{}", loan_id)
+        } else {
+            format!("Interés revertido del préstamo #{}", loan_id)
+        };
+
+        Ok(NewJournalEntry {
+            event: EVENT_INTEREST,
+            description,
+            borrower_id: Some(borrower_id),
+            loan_id: Some(loan_id),
+            payment_id: None,
+            posted_at,
+            lines: vec![
+                cup_line(ACCOUNT_INTEREST_RECEIVABLE, Some(borrower_id), amount.clone()),
+                cup_line(ACCOUNT_INTEREST_INCOME, None, amount.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Recargos cobrados a un préstamo; negativos al condonarlos o al quitarlos del saldo
+    pub fn penalty(
+        borrower_id: i32,
+        loan_id: i32,
+        amount: &Money,
+        posted_at: NaiveDateTime,
+        description: String,
+    ) -> Result<NewJournalEntry> {
+        Ok(NewJournalEntry {
+            event: EVENT_PENALTY,
+            description,
+            borrower_id: Some(borrower_id),
+            loan_id: Some(loan_id),
+            payment_id: None,
+            posted_at,
+            lines: vec![
+                cup_line(ACCOUNT_PENALTIES_RECEIVABLE, Some(borrower_id), amount.clone()),
+                cup_line(ACCOUNT_PENALTY_INCOME, None, amount.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Los recargos de un préstamo pasan a otro prestatario junto con el préstamo
+    pub fn penalty_transfer(
+        loan_id: i32,
+        from_borrower_id: i32,
+        to_borrower_id: i32,
+        amount: &Money,
+        posted_at: NaiveDateTime,
+    ) -> Result<NewJournalEntry> {
+        Ok(NewJournalEntry {
+            event: EVENT_PENALTY,
+            description: format!("Recargos del préstamo #{} pasados a otro prestatario", loan_id),
+            borrower_id: Some(to_borrower_id),
+            loan_id: Some(loan_id),
+            payment_id: None,
+            posted_at,
+            lines: vec![
+                cup_line(ACCOUNT_PENALTIES_RECEIVABLE, Some(to_borrower_id), amount.clone()),
+                cup_line(ACCOUNT_PENALTIES_RECEIVABLE, Some(from_borrower_id), amount.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Reestructuración: el préstamo nuevo reemplaza al principal pendiente del anterior y a los
+    /// recargos capitalizados; lo que agrega por encima de ambos es interés capitalizado
+    /// Lo que sale del anterior queda a cargo de su prestatario aunque luego el nuevo cambie de manos
+    pub fn restructure(
+        previous: &Loan,
+        loan: &Loan,
+        carried_principal_cup: &Money,
+        capitalized_penalties: &Money,
+        posted_at: NaiveDateTime,
+    ) -> Result<NewJournalEntry> {
+        let capitalized_interest = loan
+            .amount_cup
+            .checked_sub(carried_principal_cup)?
+            .checked_sub(capitalized_penalties)?;
+
+        Ok(NewJournalEntry {
+            event: EVENT_INTEREST,
+            description: format!("Reestructuración del préstamo #{} en el #{}", previous.id, loan.id),
+            borrower_id: Some(loan.borrower_id),
+            loan_id: Some(loan.id),
+            payment_id: None,
+            posted_at,
+            lines: vec![
+                cup_line(ACCOUNT_LOANS_RECEIVABLE, Some(loan.borrower_id), loan.amount_cup.clone()),
+                cup_line(ACCOUNT_LOANS_RECEIVABLE, Some(previous.borrower_id), carried_principal_cup.checked_neg()?),
+                cup_line(ACCOUNT_PENALTIES_RECEIVABLE, Some(previous.borrower_id), capitalized_penalties.checked_neg()?),
+                cup_line(ACCOUNT_INTEREST_INCOME, None, capitalized_interest.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Castigo: lo que sale del saldo del prestatario pasa a pérdida
+    pub fn write_off(loan: &Loan, amount_cup: &Money, posted_at: NaiveDateTime) -> Result<NewJournalEntry> {
+        Ok(NewJournalEntry {
+            event: EVENT_WRITE_OFF,
+            description: format!("Castigo del préstamo #{}", loan.id),
+            borrower_id: Some(loan.borrower_id),
+            loan_id: Some(loan.id),
+            payment_id: None,
+            posted_at,
+            lines: vec![
+                cup_line(ACCOUNT_WRITE_OFF_EXPENSE, None, amount_cup.clone()),
+                cup_line(ACCOUNT_LOANS_RECEIVABLE, Some(loan.borrower_id), amount_cup.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Cambio en lo recuperado de préstamos castigados de un prestatario
+    /// El pago ya descontó lo recuperado de lo que debe, así que vuelve al saldo como ingreso
+    pub fn recovery(borrower_id: i32, delta: &Money, posted_at: NaiveDateTime) -> Result<NewJournalEntry> {
+        Ok(NewJournalEntry {
+            event: EVENT_RECOVERY,
+            description: "Recuperación de préstamos castigados".to_string(),
+            borrower_id: Some(borrower_id),
+            loan_id: None,
+            payment_id: None,
+            posted_at,
+            lines: vec![
+                cup_line(ACCOUNT_LOANS_RECEIVABLE, Some(borrower_id), delta.clone()),
+                cup_line(ACCOUNT_RECOVERY_INCOME, None, delta.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Ajuste de conciliación: lleva un total del prestatario en el libro mayor a lo que dicen sus
+    /// registros; `delta` es lo que le falta al total y se registra con el evento que forma ese
+    /// total, contra la cuenta que lo acompaña en ese evento
+    pub fn totals_adjustment(
+        borrower_id: i32,
+        field: &str,
+        delta: &Money,
+        posted_at: NaiveDateTime,
+    ) -> Result<NewJournalEntry> {
+        let (event, receivable, counterpart, amount) = match field {
+            "total_loans" => (EVENT_DISBURSEMENT, ACCOUNT_LOANS_RECEIVABLE, ACCOUNT_OPENING_BALANCES, delta.clone()),
+            "total_paid" => (EVENT_PAYMENT, ACCOUNT_LOANS_RECEIVABLE, ACCOUNT_OPENING_BALANCES, delta.checked_neg()?),
+            "total_penalties" => (EVENT_PENALTY, ACCOUNT_PENALTIES_RECEIVABLE, ACCOUNT_PENALTY_INCOME, delta.clone()),
+            "written_off_total" => (EVENT_WRITE_OFF, ACCOUNT_LOANS_RECEIVABLE, ACCOUNT_WRITE_OFF_EXPENSE, delta.checked_neg()?),
+            "recovered_total" => (EVENT_RECOVERY, ACCOUNT_LOANS_RECEIVABLE, ACCOUNT_RECOVERY_INCOME, delta.clone()),
+            _ => return Err(AppError::DatabaseError(format!("No se puede ajustar el total {}", field))),
+        };
+
+        Ok(NewJournalEntry {
+            event,
+            description: format!("Ajuste de conciliación de {}", field),
+            borrower_id: Some(borrower_id),
+            loan_id: None,
+            payment_id: None,
+            posted_at,
+            lines: vec![
+                cup_line(receivable, Some(borrower_id), amount.clone()),
+                cup_line(counterpart, None, amount.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Revaluación de la caja de una moneda extranjera; `difference` es lo que cambia su valor en CUP
+    pub fn fx_revaluation(currency: &str, rate: f32, difference: &Money, posted_at: NaiveDateTime) -> Result<NewJournalEntry> {
+        let counterpart = if difference.is_positive() { ACCOUNT_FX_GAIN } else { ACCOUNT_FX_LOSS };
+
+        Ok(NewJournalEntry {
+            event: EVENT_FX_REVALUATION,
+            description: format!("Revaluación de la caja {} a {}", currency, rate),
+            borrower_id: None,
+            loan_id: None,
+            payment_id: None,
+            posted_at,
+            lines: vec![
+                cash_line(difference.clone(), Money::zero(currency)),
+                cup_line(counterpart, None, difference.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Movimiento manual de caja: el saldo de apertura sale de los saldos de apertura y los
+    /// depósitos y retiros de los aportes y retiros de caja
+    pub fn cash_movement(movement: &NewCashMovement) -> Result<NewJournalEntry> {
+        let (event, counterpart, description) = match movement.kind {
+            CASH_OPENING => (EVENT_OPENING, ACCOUNT_OPENING_BALANCES, "Saldo de apertura"),
+            CASH_WITHDRAWAL => (EVENT_CASH_WITHDRAWAL, ACCOUNT_CASH_CONTRIBUTIONS, "Retiro"),
+            _ => (EVENT_CASH_DEPOSIT, ACCOUNT_CASH_CONTRIBUTIONS, "Depósito"),
+        };
+        let (amount_cup, amount) = if movement.kind == CASH_WITHDRAWAL {
+            (movement.amount_cup.checked_neg()?, movement.amount.checked_neg()?)
+        } else {
+            (movement.amount_cup.clone(), movement.amount.clone())
+        };
+
+        Ok(NewJournalEntry {
+            event,
+            description: format!("{} de la caja {}: {}", description, movement.amount.currency(), movement.reason),
+            borrower_id: None,
+            loan_id: None,
+            payment_id: None,
+            posted_at: movement.moved_at,
+            lines: vec![
+                cup_line(counterpart, None, amount_cup.checked_neg()?),
+                cash_line(amount_cup, amount),
+            ],
+        })
+    }
+
+    /// Ajuste de arqueo: la caja queda en lo contado y la diferencia pasa a sobrantes o faltantes
+    pub fn cash_count(difference: &Money, difference_cup: &Money, counted_at: NaiveDateTime) -> Result<NewJournalEntry> {
+        let counterpart = if difference.is_positive() { ACCOUNT_CASH_OVERAGE } else { ACCOUNT_CASH_SHORTAGE };
+
+        Ok(NewJournalEntry {
+            event: EVENT_CASH_COUNT,
+            description: format!("Diferencia de arqueo de la caja {}", difference.currency()),
+            borrower_id: None,
+            loan_id: None,
+            payment_id: None,
+            posted_at: counted_at,
+            lines: vec![
+                cash_line(difference_cup.clone(), difference.clone()),
+                cup_line(counterpart, None, difference_cup.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Cambio de moneda: lo entregado sale de su caja por su valor en libros y lo recibido entra
+    /// a la suya por su valor oficial; la diferencia es ganancia o pérdida cambiaria realizada
+    pub fn cash_exchange(
+        source: &Money,
+        source_cost_cup: &Money,
+        target: &Money,
+        target_cup: &Money,
+        posted_at: NaiveDateTime,
+    ) -> Result<NewJournalEntry> {
+        let gain = target_cup.checked_sub(source_cost_cup)?;
+        let counterpart = if gain.is_positive() { ACCOUNT_FX_GAIN } else { ACCOUNT_FX_LOSS };
+
+        Ok(NewJournalEntry {
+            event: EVENT_CASH_EXCHANGE,
+            description: format!(
+                "Cambio de {:.2} {} por {:.2} {}",
+                source.to_major(),
+                source.currency(),
+                target.to_major(),
+                target.currency()
+            ),
+            borrower_id: None,
+            loan_id: None,
+            payment_id: None,
+            posted_at,
+            lines: vec![
+                cash_line(target_cup.clone(), target.clone()),
+                cash_line(source_cost_cup.checked_neg()?, source.checked_neg()?),
+                cup_line(counterpart, None, gain.checked_neg()?),
+            ],
+        })
+    }
+
+    /// Asiento que anula a otro: mismas cuentas con los débitos y créditos invertidos
+    pub fn reversal(entry: NewJournalEntry, description: String, posted_at: NaiveDateTime) -> Result<NewJournalEntry> {
+        let mut lines = Vec::with_capacity(entry.lines.len());
+        for line in entry.lines {
+            lines.push(NewJournalLine {
+                account_code: line.account_code,
+                borrower_id: line.borrower_id,
+                debit: line.credit,
+                credit: line.debit,
+                amount: line.amount.checked_neg()?,
+            });
+        }
+
+        Ok(NewJournalEntry {
+            description,
+            posted_at,
+            lines,
+            ..entry
+        })
+    }
+
+    /// Un asiento solo se registra si sus débitos y créditos en CUP suman lo mismo
+    pub fn ensure_balanced(entry: &NewJournalEntry) -> Result<()> {
+        let mut debit = Money::zero(BASE_CURRENCY);
+        let mut credit = Money::zero(BASE_CURRENCY);
+        for line in &entry.lines {
+            debit = debit.checked_add(&line.debit)?;
+            credit = credit.checked_add(&line.credit)?;
+        }
+
+        if debit.minor_units() != credit.minor_units() {
+            return Err(AppError::DatabaseError(format!(
+                "El asiento \"{}\" no cuadra: débitos {} y créditos {}",
+                entry.description,
+                debit.to_major(),
+                credit.to_major()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Partida de una cuenta en CUP; positiva al débito y negativa al crédito
+fn cup_line(account_code: &str, borrower_id: Option<i32>, amount: Money) -> NewJournalLine {
+    NewJournalLine::signed(account_code.to_string(), borrower_id, amount.clone(), amount)
+}
+
+/// Partida de la caja de la moneda de `amount`; `amount_cup` es su valor en CUP
+fn cash_line(amount_cup: Money, amount: Money) -> NewJournalLine {
+    NewJournalLine::signed(cash_account(amount.currency()), None, amount_cup, amount)
+}
@@ -0,0 +1,8 @@
+pub mod exchange_rate_service;
+pub mod payment_allocator;
+pub mod delinquency_policy;
+pub mod penalty_calculator;
+pub mod credit_scorer;
+pub mod duplicate_detector;
+pub mod statement_builder;
+pub mod journal_poster;
\ No newline at end of file
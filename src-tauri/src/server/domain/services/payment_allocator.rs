@@ -0,0 +1,333 @@
+use std::cmp::Ordering;
+
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::loan::InterestTerms;
+use crate::server::domain::entities::loan_installment::LoanInstallment;
+use crate::server::domain::entities::payment_allocation::AllocationStrategy;
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::error::Result;
+
+/// Préstamo con saldo pendiente que puede recibir parte de un pago
+#[derive(Debug, Clone)]
+pub struct OpenLoan {
+    pub loan_id: i32,
+    pub remaining: Money,
+    pub exchange_rate: f32,
+    pub interest: InterestTerms,
+    pub created_at: NaiveDateTime,
+}
+
+/// Porción de un pago asignada a un préstamo, todavía sin persistir
+#[derive(Debug, Clone)]
+pub struct Allocation {
+    pub loan_id: i32,
+    pub amount: Money,
+    pub amount_cup: Money,
+}
+
+/// Interés que cubre lo recibido por un préstamo con plan de cuotas, en la moneda del préstamo
+/// `by_receipt` sigue el orden de lo recibido y `by_installment` el de las cuotas
+#[derive(Debug, Clone)]
+pub struct InterestSplit {
+    pub by_receipt: Vec<Money>,
+    pub by_installment: Vec<Money>,
+}
+
+/// Reparte un pago en CUP entre préstamos abiertos según una estrategia
+/// Cada préstamo se valora en CUP con la tasa con que se registró; lo que
+/// sobre después de cubrir todos los saldos queda sin asignar
+pub struct PaymentAllocator;
+
+impl PaymentAllocator {
+    pub fn allocate(amount_cup: &Money, strategy: AllocationStrategy, loans: &[OpenLoan]) -> Result<Vec<Allocation>> {
+        let mut candidates: Vec<(&OpenLoan, Money)> = Vec::new();
+        for loan in loans.iter().filter(|loan| loan.remaining.is_positive()) {
+            candidates.push((loan, loan.remaining.convert(loan.exchange_rate, BASE_CURRENCY)?));
+        }
+
+        candidates.sort_by(|(a, _), (b, _)| match strategy {
+            AllocationStrategy::HighestInterest => daily_rate(&b.interest)
+                .partial_cmp(&daily_rate(&a.interest))
+                .unwrap_or(Ordering::Equal)
+                .then(oldest_first(a, b)),
+            AllocationStrategy::OldestFirst | AllocationStrategy::ProRata => oldest_first(a, b),
+        });
+
+        let capacities: Vec<i64> = candidates.iter().map(|(_, cup)| cup.minor_units()).collect();
+        let shares = match strategy {
+            AllocationStrategy::ProRata => pro_rata_shares(amount_cup.minor_units(), &capacities),
+            _ => sequential_shares(amount_cup.minor_units(), &capacities),
+        };
+
+        let mut allocations = Vec::new();
+        for ((loan, remaining_cup), share) in candidates.into_iter().zip(shares) {
+            if share <= 0 {
+                continue;
+            }
+
+            // Si la porción cubre el saldo completo se salda exacto, sin arrastrar redondeos de la conversión
+            let amount = if share >= remaining_cup.minor_units() {
+                loan.remaining.clone()
+            } else {
+                let converted = Money::from_minor(share, BASE_CURRENCY)
+                    .convert(1.0 / loan.exchange_rate, loan.remaining.currency())?;
+                if converted.minor_units() > loan.remaining.minor_units() {
+                    loan.remaining.clone()
+                } else {
+                    converted
+                }
+            };
+
+            allocations.push(Allocation {
+                loan_id: loan.loan_id,
+                amount,
+                amount_cup: Money::from_minor(share, BASE_CURRENCY),
+            });
+        }
+
+        Ok(allocations)
+    }
+
+    /// Cubre primero el saldo de `loan_id` y reparte lo que sobre entre los demás con la estrategia
+    pub fn allocate_to_loan_first(
+        amount_cup: &Money,
+        strategy: AllocationStrategy,
+        loans: &[OpenLoan],
+        loan_id: i32,
+    ) -> Result<Vec<Allocation>> {
+        let (target, others): (Vec<OpenLoan>, Vec<OpenLoan>) =
+            loans.iter().cloned().partition(|loan| loan.loan_id == loan_id);
+
+        let mut allocations = Self::allocate(amount_cup, AllocationStrategy::OldestFirst, &target)?;
+        let mut left = amount_cup.clone();
+        for allocation in &allocations {
+            left = left.checked_sub(&allocation.amount_cup)?;
+        }
+
+        allocations.extend(Self::allocate(&left, strategy, &others)?);
+        Ok(allocations)
+    }
+
+    /// Separa el interés de lo recibido por un préstamo con plan, en el orden en que llegó
+    /// Lo recibido cubre las cuotas igual que al saldarlas, la más antigua primero, y dentro de
+    /// cada cuota primero el interés; lo pagado que guarda cada cuota no se tiene en cuenta
+    pub fn split_interest(installments: &[LoanInstallment], received: &[Money]) -> Result<InterestSplit> {
+        let mut paid: Vec<Money> = installments
+            .iter()
+            .map(|installment| Money::zero(installment.interest.currency()))
+            .collect();
+        let mut by_installment = paid.clone();
+        let mut by_receipt = Vec::with_capacity(received.len());
+
+        for amount in received {
+            let mut left = amount.clone();
+            let mut interest = Money::zero(amount.currency());
+
+            for (index, installment) in installments.iter().enumerate() {
+                if !left.is_positive() {
+                    break;
+                }
+
+                let outstanding = installment.total()?.checked_sub(&paid[index])?;
+                if !outstanding.is_positive() {
+                    continue;
+                }
+
+                let covered = if left.minor_units() < outstanding.minor_units() { left.clone() } else { outstanding };
+                let interest_left = installment.interest.checked_sub(&by_installment[index])?;
+                let to_interest = Money::from_minor(
+                    covered.minor_units().min(interest_left.minor_units()).max(0),
+                    covered.currency(),
+                );
+
+                left = left.checked_sub(&covered)?;
+                paid[index] = paid[index].checked_add(&covered)?;
+                by_installment[index] = by_installment[index].checked_add(&to_interest)?;
+                interest = interest.checked_add(&to_interest)?;
+            }
+
+            by_receipt.push(interest);
+        }
+
+        Ok(InterestSplit {
+            by_receipt,
+            by_installment,
+        })
+    }
+}
+
+fn oldest_first(a: &OpenLoan, b: &OpenLoan) -> Ordering {
+    a.created_at.cmp(&b.created_at).then(a.loan_id.cmp(&b.loan_id))
+}
+
+/// Tasa diaria equivalente, para comparar préstamos con períodos distintos
+fn daily_rate(terms: &InterestTerms) -> f64 {
+    terms.rate / terms.period.days() as f64
+}
+
+fn sequential_shares(amount: i64, capacities: &[i64]) -> Vec<i64> {
+    let mut left = amount;
+    capacities
+        .iter()
+        .map(|capacity| {
+            let share = left.min(*capacity).max(0);
+            left -= share;
+            share
+        })
+        .collect()
+}
+
+/// Reparto proporcional por centavos; los centavos que deja el truncado
+/// se entregan de uno en uno en el orden de los préstamos
+fn pro_rata_shares(amount: i64, capacities: &[i64]) -> Vec<i64> {
+    let total: i64 = capacities.iter().sum();
+    if total <= 0 || amount <= 0 {
+        return vec![0; capacities.len()];
+    }
+    if amount >= total {
+        return capacities.to_vec();
+    }
+
+    let mut shares: Vec<i64> = capacities
+        .iter()
+        .map(|capacity| (amount as i128 * *capacity as i128 / total as i128) as i64)
+        .collect();
+
+    let mut left = amount - shares.iter().sum::<i64>();
+    while left > 0 {
+        for (share, capacity) in shares.iter_mut().zip(capacities) {
+            if left == 0 {
+                break;
+            }
+            if *share < *capacity {
+                *share += 1;
+                left -= 1;
+            }
+        }
+    }
+
+    shares
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::domain::entities::loan::{InterestMethod, RatePeriod};
+    use chrono::{Duration, NaiveDate};
+
+    fn open_loan(loan_id: i32, remaining: Money, exchange_rate: f32, monthly_rate: f64, age_days: i64) -> OpenLoan {
+        OpenLoan {
+            loan_id,
+            remaining,
+            exchange_rate,
+            interest: InterestTerms {
+                rate: monthly_rate,
+                period: RatePeriod::Monthly,
+                method: InterestMethod::Flat,
+            },
+            created_at: NaiveDate::from_ymd_opt(2025, 6, 1).unwrap().and_hms_opt(0, 0, 0).unwrap()
+                - Duration::days(age_days),
+        }
+    }
+
+    fn cup(minor_units: i64) -> Money {
+        Money::from_minor(minor_units, BASE_CURRENCY)
+    }
+
+    fn shares(allocations: &[Allocation]) -> Vec<(i32, i64)> {
+        allocations.iter().map(|a| (a.loan_id, a.amount_cup.minor_units())).collect()
+    }
+
+    #[test]
+    fn oldest_first_fills_loans_in_order_and_leaves_the_excess_unallocated() {
+        let loans = [open_loan(1, cup(50000), 1.0, 0.0, 10), open_loan(2, cup(30000), 1.0, 0.0, 20)];
+
+        let allocations = PaymentAllocator::allocate(&cup(40000), AllocationStrategy::OldestFirst, &loans).unwrap();
+        assert_eq!(shares(&allocations), vec![(2, 30000), (1, 10000)]);
+
+        let allocations = PaymentAllocator::allocate(&cup(99999), AllocationStrategy::OldestFirst, &loans).unwrap();
+        assert_eq!(shares(&allocations), vec![(2, 30000), (1, 50000)]);
+    }
+
+    #[test]
+    fn highest_interest_compares_daily_rates_and_breaks_ties_by_age() {
+        let loans = [
+            open_loan(1, cup(10000), 1.0, 5.0, 30),
+            open_loan(2, cup(10000), 1.0, 10.0, 10),
+            open_loan(3, cup(10000), 1.0, 10.0, 20),
+        ];
+
+        let allocations = PaymentAllocator::allocate(&cup(25000), AllocationStrategy::HighestInterest, &loans).unwrap();
+        assert_eq!(shares(&allocations), vec![(3, 10000), (2, 10000), (1, 5000)]);
+    }
+
+    #[test]
+    fn pro_rata_hands_out_truncated_cents_one_at_a_time_in_loan_order() {
+        assert_eq!(pro_rata_shares(100, &[100, 100, 100]), vec![34, 33, 33]);
+        assert_eq!(pro_rata_shares(2, &[1, 1, 1]), vec![1, 1, 0]);
+        assert_eq!(pro_rata_shares(500, &[100, 300]), vec![100, 300]);
+        assert_eq!(pro_rata_shares(0, &[100, 300]), vec![0, 0]);
+
+        let shares = pro_rata_shares(1001, &[333, 667, 1]);
+        assert_eq!(shares.iter().sum::<i64>(), 1001);
+        assert!(shares.iter().zip([333, 667, 1]).all(|(share, capacity)| *share <= capacity));
+    }
+
+    #[test]
+    fn foreign_currency_loans_are_valued_at_their_own_rate() {
+        let loans = [open_loan(1, Money::from_minor(1000, "USD"), 370.0, 0.0, 10)];
+
+        let allocations = PaymentAllocator::allocate(&cup(185000), AllocationStrategy::OldestFirst, &loans).unwrap();
+        assert_eq!(allocations[0].amount, Money::from_minor(500, "USD"));
+
+        // Al cubrir el saldo completo se salda exacto y el excedente queda sin asignar
+        let allocations = PaymentAllocator::allocate(&cup(400000), AllocationStrategy::OldestFirst, &loans).unwrap();
+        assert_eq!(allocations[0].amount, Money::from_minor(1000, "USD"));
+        assert_eq!(allocations[0].amount_cup, cup(370000));
+    }
+
+    #[test]
+    fn allocate_to_loan_first_covers_the_chosen_loan_before_the_rest() {
+        let loans = [open_loan(1, cup(10000), 1.0, 0.0, 30), open_loan(2, cup(10000), 1.0, 0.0, 10)];
+
+        let allocations =
+            PaymentAllocator::allocate_to_loan_first(&cup(15000), AllocationStrategy::OldestFirst, &loans, 2).unwrap();
+        assert_eq!(shares(&allocations), vec![(2, 10000), (1, 5000)]);
+    }
+
+    fn installment(number: i32, principal: i64, interest: i64) -> LoanInstallment {
+        LoanInstallment {
+            id: number,
+            loan_id: 1,
+            number,
+            due_date: String::new(),
+            principal: cup(principal),
+            interest: cup(interest),
+            paid: cup(0),
+            status: String::new(),
+            paid_at: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn split_interest_covers_the_interest_of_each_installment_first() {
+        let installments = [installment(1, 50000, 10000), installment(2, 50000, 10000)];
+
+        let split = PaymentAllocator::split_interest(&installments, &[cup(5000), cup(60000), cup(10000)]).unwrap();
+        assert_eq!(split.by_receipt, vec![cup(5000), cup(10000), cup(5000)]);
+        assert_eq!(split.by_installment, vec![cup(10000), cup(10000)]);
+    }
+
+    #[test]
+    fn split_interest_leaves_what_exceeds_the_schedule_without_interest() {
+        let installments = [installment(1, 50000, 10000)];
+
+        let split = PaymentAllocator::split_interest(&installments, &[cup(70000), cup(1000)]).unwrap();
+        assert_eq!(split.by_receipt, vec![cup(10000), cup(0)]);
+        assert_eq!(split.by_installment, vec![cup(10000)]);
+    }
+}
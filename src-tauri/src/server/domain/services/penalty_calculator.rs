@@ -0,0 +1,191 @@
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::penalty::{Penalty, PenaltyKind, PenaltyRule, PENALTY_CHARGED};
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::date::parse_stored_date;
+use crate::utils::error::Result;
+
+/// Recargo que corresponde cobrar a un préstamo, todavía sin persistir
+#[derive(Debug, Clone)]
+pub struct PenaltyCharge {
+    pub amount: Money,
+    pub days_charged: i64,
+}
+
+/// Decide cuánto cobrar a un préstamo atrasado según una regla
+/// `previous` son los recargos ya registrados para el mismo préstamo y regla;
+/// los fijos y porcentuales se cobran una vez y los diarios solo por los días no cobrados
+pub struct PenaltyCalculator;
+
+impl PenaltyCalculator {
+    pub fn assess(
+        rule: &PenaltyRule,
+        overdue_cup: &Money,
+        days_past_due: i64,
+        previous: &[Penalty],
+        now: NaiveDateTime,
+    ) -> Result<Option<PenaltyCharge>> {
+        let chargeable_days = days_past_due - rule.grace_days as i64;
+        if !rule.active || chargeable_days <= 0 {
+            return Ok(None);
+        }
+
+        let charge = match rule.kind {
+            PenaltyKind::Fixed | PenaltyKind::Percentage if !previous.is_empty() => return Ok(None),
+            PenaltyKind::Fixed => PenaltyCharge {
+                amount: rule.amount.clone(),
+                days_charged: 0,
+            },
+            PenaltyKind::Percentage => PenaltyCharge {
+                amount: overdue_cup.checked_scale(rule.rate / 100.0)?,
+                days_charged: 0,
+            },
+            PenaltyKind::Daily => {
+                // Los días ya cubiertos por un cargo anterior, aunque se haya condonado, no se vuelven a cobrar
+                let last_charge = previous.iter().map(|penalty| parse_stored_date(&penalty.created_at)).max();
+                let days = match last_charge {
+                    Some(last) => chargeable_days.min((now.date() - last.date()).num_days()),
+                    None => chargeable_days,
+                };
+                if days <= 0 {
+                    return Ok(None);
+                }
+
+                PenaltyCharge {
+                    amount: overdue_cup.checked_scale(rule.rate / 100.0 * days as f64)?,
+                    days_charged: days,
+                }
+            }
+        };
+
+        let amount = match &rule.cap {
+            Some(cap) => {
+                let mut charged = Money::zero(BASE_CURRENCY);
+                for penalty in previous.iter().filter(|penalty| penalty.status == PENALTY_CHARGED) {
+                    charged = charged.checked_add(&penalty.amount)?;
+                }
+                let room = cap.checked_sub(&charged)?;
+                if room.minor_units() < charge.amount.minor_units() {
+                    room
+                } else {
+                    charge.amount
+                }
+            }
+            None => charge.amount,
+        };
+
+        if !amount.is_positive() {
+            return Ok(None);
+        }
+
+        Ok(Some(PenaltyCharge {
+            amount,
+            days_charged: charge.days_charged,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::domain::entities::penalty::PENALTY_WAIVED;
+    use chrono::{Duration, NaiveDate};
+
+    fn day(offset: i64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap() + Duration::days(offset)
+    }
+
+    fn cup(minor_units: i64) -> Money {
+        Money::from_minor(minor_units, "CUP")
+    }
+
+    fn rule(kind: PenaltyKind, amount: i64, rate: f64, grace_days: i32, cap: Option<i64>) -> PenaltyRule {
+        PenaltyRule {
+            id: 1,
+            name: "Atraso".to_string(),
+            kind,
+            amount: cup(amount),
+            rate,
+            grace_days,
+            cap: cap.map(cup),
+            active: true,
+            created_at: day(0).and_utc().to_rfc3339(),
+            updated_at: day(0).and_utc().to_rfc3339(),
+        }
+    }
+
+    fn penalty(amount: i64, days_charged: i32, status: &str, created_at: NaiveDateTime) -> Penalty {
+        Penalty {
+            id: 1,
+            loan_id: 1,
+            borrower_id: 1,
+            rule_id: 1,
+            amount: cup(amount),
+            days_past_due: days_charged,
+            days_charged,
+            status: status.to_string(),
+            waived_reason: None,
+            waived_by: None,
+            waived_at: None,
+            created_at: created_at.and_utc().to_rfc3339(),
+            updated_at: created_at.and_utc().to_rfc3339(),
+        }
+    }
+
+    #[test]
+    fn nothing_is_charged_within_the_grace_period() {
+        let rule = rule(PenaltyKind::Daily, 0, 1.0, 5, None);
+
+        let within = PenaltyCalculator::assess(&rule, &cup(100000), 5, &[], day(5)).unwrap();
+        let after = PenaltyCalculator::assess(&rule, &cup(100000), 7, &[], day(7)).unwrap().unwrap();
+
+        assert!(within.is_none());
+        // Solo cuentan los dos días posteriores a la gracia
+        assert_eq!(after.days_charged, 2);
+        assert_eq!(after.amount, cup(2000));
+    }
+
+    #[test]
+    fn daily_penalty_charges_only_the_days_since_the_last_charge() {
+        let rule = rule(PenaltyKind::Daily, 0, 1.0, 0, None);
+        let previous = [penalty(3000, 3, PENALTY_CHARGED, day(3))];
+
+        let same_day = PenaltyCalculator::assess(&rule, &cup(100000), 3, &previous, day(3)).unwrap();
+        let later = PenaltyCalculator::assess(&rule, &cup(100000), 5, &previous, day(5)).unwrap().unwrap();
+
+        assert!(same_day.is_none());
+        assert_eq!(later.days_charged, 2);
+        assert_eq!(later.amount, cup(2000));
+    }
+
+    #[test]
+    fn waived_penalties_free_room_under_the_cap_but_keep_their_days() {
+        let rule = rule(PenaltyKind::Daily, 0, 1.0, 0, Some(5000));
+        let previous = [
+            penalty(3000, 3, PENALTY_WAIVED, day(3)),
+            penalty(1500, 2, PENALTY_CHARGED, day(5)),
+        ];
+
+        let charge = PenaltyCalculator::assess(&rule, &cup(100000), 10, &previous, day(10)).unwrap().unwrap();
+
+        // Cinco días nuevos valdrían 50, pero al tope solo le quedan 35 porque lo condonado no cuenta
+        assert_eq!(charge.days_charged, 5);
+        assert_eq!(charge.amount, cup(3500));
+    }
+
+    #[test]
+    fn fixed_and_percentage_penalties_are_charged_once() {
+        let fixed = rule(PenaltyKind::Fixed, 2500, 0.0, 0, None);
+        let percentage = rule(PenaltyKind::Percentage, 0, 5.0, 0, None);
+        let previous = [penalty(2500, 0, PENALTY_WAIVED, day(1))];
+
+        let first_fixed = PenaltyCalculator::assess(&fixed, &cup(100000), 1, &[], day(1)).unwrap().unwrap();
+        let first_percentage = PenaltyCalculator::assess(&percentage, &cup(100000), 1, &[], day(1)).unwrap().unwrap();
+
+        assert_eq!(first_fixed.amount, cup(2500));
+        assert_eq!(first_percentage.amount, cup(5000));
+        assert!(PenaltyCalculator::assess(&fixed, &cup(100000), 30, &previous, day(30)).unwrap().is_none());
+        assert!(PenaltyCalculator::assess(&percentage, &cup(100000), 30, &previous, day(30)).unwrap().is_none());
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::NaiveDateTime;
+
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::ledger::{JournalEntry, ACCOUNT_INTEREST_RECEIVABLE};
+use crate::server::domain::entities::loan::Loan;
+use crate::server::domain::entities::payment::Payment;
+use crate::server::domain::entities::penalty::{Penalty, PENALTY_CAPITALIZED, PENALTY_WAIVED};
+use crate::server::domain::value_objects::money::Money;
+use crate::utils::date::parse_stored_date;
+use crate::utils::error::Result;
+
+pub const ENTRY_LOAN: &str = "loan";
+pub const ENTRY_PAYMENT: &str = "payment";
+pub const ENTRY_PENALTY: &str = "penalty";
+/// Interés reconocido o revertido en el libro mayor al vencer o pagarse las cuotas
+pub const ENTRY_INTEREST: &str = "interest";
+/// Castigos, recuperaciones, condonaciones y reestructuraciones
+pub const ENTRY_ADJUSTMENT: &str = "adjustment";
+
+/// Movimiento del estado de cuenta; los montos son positivos si aumentan la deuda
+/// `amount` está en la moneda original del movimiento y `amount_cup` es lo que movió el saldo
+/// `reference_id` es el préstamo, pago o recargo que lo originó, o el asiento del interés
+#[derive(Debug, Clone)]
+pub struct StatementEntry {
+    pub date: NaiveDateTime,
+    pub kind: &'static str,
+    pub reference_id: i32,
+    pub loan_id: Option<i32>,
+    pub description: String,
+    pub amount: Money,
+    pub amount_cup: Money,
+}
+
+/// Movimiento con los saldos acumulados después de aplicarlo
+/// `balance` acumula solo los movimientos en la moneda de este
+#[derive(Debug, Clone)]
+pub struct StatementLine {
+    pub entry: StatementEntry,
+    pub balance: Money,
+    pub balance_cup: Money,
+}
+
+/// Estado de cuenta de un período; los saldos por moneda van ordenados por código
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub opening_balance_cup: Money,
+    pub opening_balances: Vec<Money>,
+    pub lines: Vec<StatementLine>,
+    pub closing_balance_cup: Money,
+    pub closing_balances: Vec<Money>,
+}
+
+/// Arma el estado de cuenta de un prestatario a partir de sus préstamos, pagos y recargos y
+/// del interés que el libro mayor le reconoció
+/// Cada movimiento reproduce el efecto que tuvo en el saldo del prestatario, así que el
+/// saldo final sin fecha de corte coincide con el saldo registrado
+pub struct StatementBuilder;
+
+impl StatementBuilder {
+    /// Los movimientos anteriores a `from` forman el saldo inicial; los posteriores a `to` se omiten
+    pub fn build(
+        loans: &[Loan],
+        payments: &[Payment],
+        penalties: &[Penalty],
+        interest: &[JournalEntry],
+        from: Option<NaiveDateTime>,
+        to: Option<NaiveDateTime>,
+    ) -> Result<Statement> {
+        let mut balance_cup = Money::zero(BASE_CURRENCY);
+        let mut balances: BTreeMap<String, Money> = BTreeMap::new();
+        let mut opening: Option<(Money, Vec<Money>)> = None;
+        let mut lines = Vec::new();
+
+        for entry in Self::entries(loans, payments, penalties, interest)? {
+            if to.is_some_and(|to| entry.date > to) {
+                break;
+            }
+            let in_period = from.is_none_or(|from| entry.date >= from);
+            if in_period && opening.is_none() {
+                opening = Some((balance_cup.clone(), balances.values().cloned().collect()));
+            }
+
+            balance_cup = balance_cup.checked_add(&entry.amount_cup)?;
+            let currency = entry.amount.currency().to_string();
+            let balance = balances
+                .entry(currency.clone())
+                .or_insert_with(|| Money::zero(&currency));
+            *balance = balance.checked_add(&entry.amount)?;
+
+            if in_period {
+                lines.push(StatementLine {
+                    balance: balance.clone(),
+                    balance_cup: balance_cup.clone(),
+                    entry,
+                });
+            }
+        }
+
+        // Sin movimientos en el período el saldo inicial es el mismo que el final
+        let (opening_balance_cup, opening_balances) =
+            opening.unwrap_or_else(|| (balance_cup.clone(), balances.values().cloned().collect()));
+
+        Ok(Statement {
+            opening_balance_cup,
+            opening_balances,
+            lines,
+            closing_balance_cup: balance_cup,
+            closing_balances: balances.into_values().collect(),
+        })
+    }
+
+    /// Movimientos en orden cronológico; en la misma fecha van préstamos, recargos e interés, pagos y ajustes
+    fn entries(
+        loans: &[Loan],
+        payments: &[Payment],
+        penalties: &[Penalty],
+        interest: &[JournalEntry],
+    ) -> Result<Vec<StatementEntry>> {
+        let mut entries = Vec::new();
+        let loans_by_id: HashMap<i32, &Loan> = loans.iter().map(|loan| (loan.id, loan)).collect();
+
+        for loan in loans {
+            let description = match loan.restructured_from_id {
+                Some(previous_id) => format!("Préstamo #{} por reestructuración del #{}", loan.id, previous_id),
+                None => format!("Préstamo #{}", loan.id),
+            };
+            entries.push(StatementEntry {
+                date: parse_stored_date(&loan.created_at),
+                kind: ENTRY_LOAN,
+                reference_id: loan.id,
+                loan_id: Some(loan.id),
+                description,
+                amount: loan.amount.clone(),
+                amount_cup: loan.amount_cup.clone(),
+            });
+
+            if let Some(restructured_at) = loan.restructured_at.as_deref() {
+                if loan.carried_over_cup.is_positive() {
+                    let carried = loan.carried_over_cup.convert(1.0 / loan.exchange_rate, loan.amount.currency())?;
+                    entries.push(StatementEntry {
+                        date: parse_stored_date(restructured_at),
+                        kind: ENTRY_ADJUSTMENT,
+                        reference_id: loan.id,
+                        loan_id: Some(loan.id),
+                        description: format!("Principal del préstamo #{} pasado a su reestructuración", loan.id),
+                        amount: carried.checked_neg()?,
+                        amount_cup: loan.carried_over_cup.checked_neg()?,
+                    });
+                }
+            }
+
+            if let Some(write_off) = &loan.write_off {
+                entries.push(StatementEntry {
+                    date: parse_stored_date(&write_off.written_off_at),
+                    kind: ENTRY_ADJUSTMENT,
+                    reference_id: loan.id,
+                    loan_id: Some(loan.id),
+                    description: format!("Castigo del préstamo #{}: {}", loan.id, write_off.reason),
+                    amount: write_off.amount.checked_neg()?,
+                    amount_cup: write_off.amount_cup.checked_neg()?,
+                });
+            }
+        }
+
+        for penalty in penalties {
+            entries.push(StatementEntry {
+                date: parse_stored_date(&penalty.created_at),
+                kind: ENTRY_PENALTY,
+                reference_id: penalty.id,
+                loan_id: Some(penalty.loan_id),
+                description: format!("Recargo por atraso del préstamo #{}", penalty.loan_id),
+                amount: penalty.amount.clone(),
+                amount_cup: penalty.amount.clone(),
+            });
+
+            let (date, description) = match penalty.status.as_str() {
+                PENALTY_WAIVED => (
+                    penalty.waived_at.as_deref().unwrap_or(&penalty.updated_at),
+                    format!(
+                        "Condonación del recargo #{}: {}",
+                        penalty.id,
+                        penalty.waived_reason.as_deref().unwrap_or_default()
+                    ),
+                ),
+                PENALTY_CAPITALIZED => (
+                    loans_by_id
+                        .get(&penalty.loan_id)
+                        .and_then(|loan| loan.restructured_at.as_deref())
+                        .unwrap_or(&penalty.updated_at),
+                    format!("Recargo #{} sumado al principal de la reestructuración", penalty.id),
+                ),
+                _ => continue,
+            };
+            entries.push(StatementEntry {
+                date: parse_stored_date(date),
+                kind: ENTRY_ADJUSTMENT,
+                reference_id: penalty.id,
+                loan_id: Some(penalty.loan_id),
+                description,
+                amount: penalty.amount.checked_neg()?,
+                amount_cup: penalty.amount.checked_neg()?,
+            });
+        }
+
+        for payment in payments {
+            let paid_at = parse_stored_date(&payment.created_at);
+            entries.push(StatementEntry {
+                date: paid_at,
+                kind: ENTRY_PAYMENT,
+                reference_id: payment.id,
+                loan_id: payment.loan_id,
+                description: format!("Pago #{}", payment.id),
+                amount: payment.amount.checked_neg()?,
+                amount_cup: payment.amount_cup.checked_neg()?,
+            });
+
+            // Lo que llega a un préstamo castigado después del castigo vuelve al saldo como recuperación
+            for allocation in &payment.allocations {
+                let written_off_at = loans_by_id
+                    .get(&allocation.loan_id)
+                    .and_then(|loan| loan.write_off.as_ref())
+                    .map(|write_off| parse_stored_date(&write_off.written_off_at));
+                if written_off_at.is_some_and(|written_off_at| paid_at >= written_off_at) {
+                    entries.push(StatementEntry {
+                        date: paid_at,
+                        kind: ENTRY_ADJUSTMENT,
+                        reference_id: payment.id,
+                        loan_id: Some(allocation.loan_id),
+                        description: format!(
+                            "Recuperación del préstamo castigado #{} con el pago #{}",
+                            allocation.loan_id, payment.id
+                        ),
+                        amount: allocation.amount.clone(),
+                        amount_cup: allocation.amount_cup.clone(),
+                    });
+                }
+            }
+        }
+
+        // Del asiento de interés solo cuenta lo que movió los intereses por cobrar
+        for journal_entry in interest {
+            let mut amount = Money::zero(BASE_CURRENCY);
+            for line in &journal_entry.lines {
+                if line.account_code == ACCOUNT_INTEREST_RECEIVABLE {
+                    amount = amount.checked_add(&line.debit)?.checked_sub(&line.credit)?;
+                }
+            }
+            if amount.minor_units() == 0 {
+                continue;
+            }
+
+            entries.push(StatementEntry {
+                date: parse_stored_date(&journal_entry.posted_at),
+                kind: ENTRY_INTEREST,
+                reference_id: journal_entry.id,
+                loan_id: journal_entry.loan_id,
+                description: journal_entry.description.clone(),
+                amount: amount.clone(),
+                amount_cup: amount,
+            });
+        }
+
+        entries.sort_by_key(|entry| (entry.date, kind_order(entry.kind)));
+        Ok(entries)
+    }
+}
+
+fn kind_order(kind: &str) -> u8 {
+    match kind {
+        ENTRY_LOAN => 0,
+        ENTRY_PENALTY | ENTRY_INTEREST => 1,
+        ENTRY_PAYMENT => 2,
+        _ => 3,
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use crate::utils::error::{AppError, Result};
+
+/// Unidades menores (centavos) por unidad de cada moneda con la que opera el negocio
+pub const MINOR_UNITS_PER_MAJOR: i64 = 100;
+
+/// Importe monetario exacto: unidades menores enteras más el código de la moneda
+/// Toda la aritmética es verificada; nunca se mezclan monedas distintas
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Money {
+    minor_units: i64,
+    currency: String,
+}
+
+impl Money {
+    pub fn from_minor(minor_units: i64, currency: impl Into<String>) -> Self {
+        Self {
+            minor_units,
+            currency: currency.into(),
+        }
+    }
+
+    pub fn zero(currency: impl Into<String>) -> Self {
+        Self::from_minor(0, currency)
+    }
+
+    /// Crea un importe a partir de unidades mayores (p. ej. 12.34), redondeando al centavo
+    pub fn from_major(amount: f64, currency: impl Into<String>) -> Result<Self> {
+        let minor_units = (amount * MINOR_UNITS_PER_MAJOR as f64).round();
+        if !minor_units.is_finite() || minor_units.abs() >= i64::MAX as f64 {
+            return Err(overflow_error());
+        }
+
+        Ok(Self::from_minor(minor_units as i64, currency))
+    }
+
+    pub fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Valor en unidades mayores, solo para presentación
+    pub fn to_major(&self) -> f64 {
+        self.minor_units as f64 / MINOR_UNITS_PER_MAJOR as f64
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.minor_units > 0
+    }
+
+    pub fn checked_add(&self, other: &Money) -> Result<Money> {
+        self.ensure_same_currency(other)?;
+        self.minor_units
+            .checked_add(other.minor_units)
+            .map(|minor_units| Self::from_minor(minor_units, self.currency.clone()))
+            .ok_or_else(overflow_error)
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money> {
+        self.ensure_same_currency(other)?;
+        self.minor_units
+            .checked_sub(other.minor_units)
+            .map(|minor_units| Self::from_minor(minor_units, self.currency.clone()))
+            .ok_or_else(overflow_error)
+    }
+
+    pub fn checked_neg(&self) -> Result<Money> {
+        self.minor_units
+            .checked_neg()
+            .map(|minor_units| Self::from_minor(minor_units, self.currency.clone()))
+            .ok_or_else(overflow_error)
+    }
+
+    /// Multiplica por `factor` en la misma moneda, redondeando al centavo
+    pub fn checked_scale(&self, factor: f64) -> Result<Money> {
+        scale_minor_units(self.minor_units, factor)
+            .map(|minor_units| Self::from_minor(minor_units, self.currency.clone()))
+    }
+
+    /// Convierte a otra moneda multiplicando por `rate` y redondeando al centavo
+    pub fn convert(&self, rate: f32, target_currency: impl Into<String>) -> Result<Money> {
+        scale_minor_units(self.minor_units, rate as f64)
+            .map(|minor_units| Self::from_minor(minor_units, target_currency))
+    }
+
+    fn ensure_same_currency(&self, other: &Money) -> Result<()> {
+        if self.currency != other.currency {
+            return Err(AppError::ValidationError(vec![format!(
+                "No se pueden operar importes en monedas distintas ({} y {})",
+                self.currency, other.currency
+            )]));
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let abs = self.minor_units.unsigned_abs();
+        let per_major = MINOR_UNITS_PER_MAJOR as u64;
+        write!(f, "{}{}.{:02} {}", sign, abs / per_major, abs % per_major, self.currency)
+    }
+}
+
+fn scale_minor_units(minor_units: i64, factor: f64) -> Result<i64> {
+    let scaled = (minor_units as f64 * factor).round();
+    if !scaled.is_finite() || scaled.abs() >= i64::MAX as f64 {
+        return Err(overflow_error());
+    }
+
+    Ok(scaled as i64)
+}
+
+fn overflow_error() -> AppError {
+    AppError::ValidationError(vec!["El importe excede el máximo permitido".to_string()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_major_rounds_to_the_nearest_minor_unit() {
+        assert_eq!(Money::from_major(12.345, "CUP").unwrap().minor_units(), 1235);
+        assert_eq!(Money::from_major(0.1 + 0.2, "CUP").unwrap().minor_units(), 30);
+        assert_eq!(Money::from_major(-1.005, "CUP").unwrap().minor_units(), -100);
+        assert_eq!(Money::from_major(-1.006, "CUP").unwrap().minor_units(), -101);
+    }
+
+    #[test]
+    fn from_major_rejects_non_finite_and_out_of_range_amounts() {
+        assert!(Money::from_major(f64::NAN, "CUP").is_err());
+        assert!(Money::from_major(f64::INFINITY, "CUP").is_err());
+        assert!(Money::from_major(1e17, "CUP").is_err());
+    }
+
+    #[test]
+    fn checked_arithmetic_detects_overflow() {
+        let max = Money::from_minor(i64::MAX, "CUP");
+        let min = Money::from_minor(i64::MIN, "CUP");
+        let one = Money::from_minor(1, "CUP");
+
+        assert!(max.checked_add(&one).is_err());
+        assert!(min.checked_sub(&one).is_err());
+        assert!(min.checked_neg().is_err());
+        assert_eq!(max.checked_sub(&one).unwrap().minor_units(), i64::MAX - 1);
+    }
+
+    #[test]
+    fn arithmetic_rejects_mixed_currencies() {
+        let cup = Money::from_minor(100, "CUP");
+        let usd = Money::from_minor(100, "USD");
+
+        assert!(cup.checked_add(&usd).is_err());
+        assert!(cup.checked_sub(&usd).is_err());
+    }
+
+    #[test]
+    fn convert_rounds_to_the_nearest_minor_unit_in_the_target_currency() {
+        let usd = Money::from_minor(1001, "USD");
+        let cup = usd.convert(370.0, "CUP").unwrap();
+        assert_eq!(cup, Money::from_minor(370370, "CUP"));
+
+        let third = Money::from_minor(100, "CUP").checked_scale(1.0 / 3.0).unwrap();
+        assert_eq!(third.minor_units(), 33);
+        assert!(Money::from_minor(i64::MAX, "CUP").convert(2.0, "USD").is_err());
+    }
+
+    #[test]
+    fn display_keeps_the_sign_and_two_decimals() {
+        assert_eq!(Money::from_minor(-5, "CUP").to_string(), "-0.05 CUP");
+        assert_eq!(Money::from_minor(123456, "USD").to_string(), "1234.56 USD");
+        assert_eq!(Money::from_minor(i64::MIN, "CUP").to_string(), "-92233720368547758.08 CUP");
+    }
+}
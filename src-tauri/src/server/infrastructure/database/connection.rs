@@ -5,8 +5,18 @@ use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 use colored::Colorize;
 use std::path::Path;
 
+use crate::server::infrastructure::database::repositories::diesel_borrower_repository::reconcile_borrower_totals;
+use crate::server::infrastructure::database::repositories::diesel_ledger_repository::post_ledger_history;
+use crate::server::infrastructure::database::repositories::diesel_payment_repository::reallocate_all_payments;
+
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 
+/// Migración que introduce el reparto de pagos; al aplicarla hay que repartir los pagos existentes
+const PAYMENT_ALLOCATIONS_MIGRATION: &str = "20250207000000";
+
+/// Migración que introduce el libro mayor; al aplicarla hay que registrar la historia existente
+const GENERAL_LEDGER_MIGRATION: &str = "20250219000000";
+
 pub type DbPool = Pool<ConnectionManager<SqliteConnection>>;
 pub type DbConnection = PooledConnection<ConnectionManager<SqliteConnection>>;  
 
@@ -38,6 +48,7 @@ pub fn init_database(database_url: &str) -> Result<DbPool, Box<dyn std::error::E
     // Ejecutar migraciones
     let mut conn = pool.get()?;
     run_migrations(&mut conn)?;
+    reconcile_totals(&mut conn)?;
     
     println!("{} {}", "Base de datos inicializada:".green(), db_path);
     
@@ -56,6 +67,13 @@ fn run_migrations(conn: &mut DbConnection) -> Result<(), Box<dyn std::error::Err
     let migration_output = conn.run_pending_migrations(MIGRATIONS)
         .expect("Failed to run migrations");
     
+    let allocations_introduced = migration_output
+        .iter()
+        .any(|version| version.to_string() == PAYMENT_ALLOCATIONS_MIGRATION);
+    let ledger_introduced = migration_output
+        .iter()
+        .any(|version| version.to_string() == GENERAL_LEDGER_MIGRATION);
+
     if migration_output.is_empty() {
         println!("{}", "No hay migraciones pendientes".green());
     } else {
@@ -64,6 +82,43 @@ fn run_migrations(conn: &mut DbConnection) -> Result<(), Box<dyn std::error::Err
             println!("   - {}", migration);
         }
     }
+
+    if allocations_introduced {
+        reallocate_all_payments(conn)?;
+        println!("{}", "Pagos existentes repartidos entre sus préstamos".green());
+    }
+
+    if ledger_introduced {
+        post_ledger_history(conn)?;
+        println!("{}", "Historia existente registrada en el libro mayor".green());
+    }
     
+    Ok(())
+}
+
+/// Informa los totales de los prestatarios que no cuadren con sus préstamos, pagos y recargos
+/// No los corrige: la corrección registra asientos de ajuste y se hace desde la conciliación del administrador
+fn reconcile_totals(conn: &mut DbConnection) -> Result<(), Box<dyn std::error::Error>> {
+    let reconciliation = reconcile_borrower_totals(conn, false)?;
+
+    if reconciliation.mismatches.is_empty() {
+        println!("{}", "Totales de prestatarios conciliados".green());
+    } else {
+        println!(
+            "{} diferencia(s) en los totales de prestatarios; corríjalas desde la conciliación del administrador",
+            reconciliation.mismatches.len()
+        );
+        for mismatch in reconciliation.mismatches {
+            println!(
+                "   - {} #{} {}: {} -> {}",
+                mismatch.name,
+                mismatch.borrower_id,
+                mismatch.field,
+                mismatch.stored.to_major(),
+                mismatch.expected.to_major()
+            );
+        }
+    }
+
     Ok(())
 }
\ No newline at end of file
@@ -1,4 +1,8 @@
-use crate::server::domain::entities::borrower::{Borrower, NewBorrower};
+use crate::server::domain::entities::borrower::{
+    Borrower, BorrowerBlock, CreditFactors, CreditScore, NewBorrower, RiskTier, BORROWER_CURRENT,
+};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::value_objects::money::Money;
 use crate::server::infrastructure::database::schema::borrowers;
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
@@ -13,12 +17,30 @@ pub struct BorrowerModel {
     pub phone: String,
     pub name: String,
     pub location: String,
-    pub total_loans: f64,      // Double en SQLite se mapea a f64
-    pub total_paid: f64,       // Double en SQLite se mapea a f64  
-    pub balance: f64,          // Double en SQLite se mapea a f64
+    pub total_loans_cents: i64,    // Centavos de CUP
+    pub total_paid_cents: i64,     // Centavos de CUP
+    pub balance_cents: i64,        // Centavos de CUP
     pub status: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub total_penalties_cents: i64, // Centavos de CUP
+    pub written_off_total_cents: i64, // Centavos de CUP
+    pub recovered_total_cents: i64, // Centavos de CUP
+    pub guarantee_exposure_cents: i64, // Centavos de CUP
+    pub credit_limit_cents: Option<i64>, // Centavos de CUP
+    pub risk_tier: String,
+    pub credit_score: i32,
+    pub credit_on_time_installments: i32,
+    pub credit_late_installments: i32,
+    pub credit_days_past_due: i32,
+    pub credit_restructures: i32,
+    pub credit_write_offs: i32,
+    pub credit_score_updated_at: Option<NaiveDateTime>,
+    pub blocked_reason: Option<String>,
+    pub blocked_at: Option<NaiveDateTime>,
+    pub blocked_by: Option<i32>,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub deleted_by: Option<i32>,
 }
 
 /// Modelo para insertar nuevos borrowers en la base de datos
@@ -29,6 +51,7 @@ pub struct NewBorrowerModel {
     pub phone: String,
     pub name: String,
     pub location: String,
+    pub status: String,
     // Los campos con valores por defecto se omiten en la inserción
     // Diesel los manejará automáticamente con los valores por defecto de la base de datos
 }
@@ -41,12 +64,39 @@ impl From<BorrowerModel> for Borrower {
             phone: model.phone,
             name: model.name,
             location: model.location,
-            total_loans: model.total_loans,
-            total_paid: model.total_paid,
-            balance: model.balance,
+            total_loans: Money::from_minor(model.total_loans_cents, BASE_CURRENCY),
+            total_paid: Money::from_minor(model.total_paid_cents, BASE_CURRENCY),
+            balance: Money::from_minor(model.balance_cents, BASE_CURRENCY),
             status: model.status,
             created_at: model.created_at.and_utc().to_rfc3339(),
             updated_at: model.updated_at.and_utc().to_rfc3339(),
+            total_penalties: Money::from_minor(model.total_penalties_cents, BASE_CURRENCY),
+            written_off_total: Money::from_minor(model.written_off_total_cents, BASE_CURRENCY),
+            recovered_total: Money::from_minor(model.recovered_total_cents, BASE_CURRENCY),
+            guarantee_exposure: Money::from_minor(model.guarantee_exposure_cents, BASE_CURRENCY),
+            credit_limit: model.credit_limit_cents.map(|cents| Money::from_minor(cents, BASE_CURRENCY)),
+            risk_tier: RiskTier::parse(&model.risk_tier).unwrap_or(RiskTier::Medium),
+            credit_score: CreditScore {
+                score: model.credit_score,
+                factors: CreditFactors {
+                    on_time_installments: model.credit_on_time_installments,
+                    late_installments: model.credit_late_installments,
+                    days_past_due: model.credit_days_past_due,
+                    restructures: model.credit_restructures,
+                    write_offs: model.credit_write_offs,
+                },
+                updated_at: model.credit_score_updated_at.map(|date| date.and_utc().to_rfc3339()),
+            },
+            block: match (model.blocked_at, model.blocked_by) {
+                (Some(blocked_at), Some(blocked_by)) => Some(BorrowerBlock {
+                    reason: model.blocked_reason.unwrap_or_default(),
+                    blocked_by,
+                    blocked_at: blocked_at.and_utc().to_rfc3339(),
+                }),
+                _ => None,
+            },
+            deleted_at: model.deleted_at.map(|date| date.and_utc().to_rfc3339()),
+            deleted_by: model.deleted_by,
         }
     }
 }
@@ -58,6 +108,8 @@ impl From<NewBorrower> for NewBorrowerModel {
             phone: entity.phone,
             name: entity.name,
             location: entity.location,
+            // El valor por defecto de la columna es anterior a los estados de cobranza
+            status: BORROWER_CURRENT.to_string(),
         }
     }
 }
\ No newline at end of file
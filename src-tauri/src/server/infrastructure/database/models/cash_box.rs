@@ -0,0 +1,184 @@
+use crate::server::domain::entities::cash_box::{CashCount, CashExchange, CashMovement};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::schema::{cash_counts, cash_exchanges, cash_movements};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `cash_movements`
+/// `amount_cents` está en centavos de `currency` y `amount_cup_cents` en centavos de CUP
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = cash_movements)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CashMovementModel {
+    pub id: i32,
+    pub currency: String,
+    pub kind: String,
+    pub amount_cents: i64,
+    pub exchange_rate: f32,
+    pub amount_cup_cents: i64,
+    pub reason: String,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub moved_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+/// Modelo para insertar un movimiento manual de caja junto al asiento que lo registró
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = cash_movements)]
+pub struct NewCashMovementModel {
+    pub currency: String,
+    pub kind: String,
+    pub amount_cents: i64,
+    pub exchange_rate: f32,
+    pub amount_cup_cents: i64,
+    pub reason: String,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub moved_at: NaiveDateTime,
+}
+
+/// Modelo de base de datos para la tabla `cash_counts`
+/// Los importes están en centavos de `currency`, salvo `difference_cup_cents` que está en CUP
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = cash_counts)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CashCountModel {
+    pub id: i32,
+    pub currency: String,
+    pub expected_cents: i64,
+    pub counted_cents: i64,
+    pub difference_cents: i64,
+    pub exchange_rate: f32,
+    pub difference_cup_cents: i64,
+    pub notes: Option<String>,
+    pub entry_id: Option<i32>,
+    pub counted_by: i32,
+    pub counted_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+/// Modelo para insertar un arqueo de caja
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = cash_counts)]
+pub struct NewCashCountModel {
+    pub currency: String,
+    pub expected_cents: i64,
+    pub counted_cents: i64,
+    pub difference_cents: i64,
+    pub exchange_rate: f32,
+    pub difference_cup_cents: i64,
+    pub notes: Option<String>,
+    pub entry_id: Option<i32>,
+    pub counted_by: i32,
+    pub counted_at: NaiveDateTime,
+}
+
+/// Modelo de base de datos para la tabla `cash_exchanges`
+/// Los importes de origen y destino están en centavos de su moneda; los demás, en centavos de CUP
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = cash_exchanges)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CashExchangeModel {
+    pub id: i32,
+    pub source_currency: String,
+    pub source_amount_cents: i64,
+    pub target_currency: String,
+    pub target_amount_cents: i64,
+    pub effective_rate: f64,
+    pub official_rate: f64,
+    pub spread: f64,
+    pub source_cost_cup_cents: i64,
+    pub target_cup_cents: i64,
+    pub spread_cup_cents: i64,
+    pub gain_cup_cents: i64,
+    pub notes: Option<String>,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub exchanged_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+/// Modelo para insertar un cambio de moneda junto al asiento que lo registró
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = cash_exchanges)]
+pub struct NewCashExchangeModel {
+    pub source_currency: String,
+    pub source_amount_cents: i64,
+    pub target_currency: String,
+    pub target_amount_cents: i64,
+    pub effective_rate: f64,
+    pub official_rate: f64,
+    pub spread: f64,
+    pub source_cost_cup_cents: i64,
+    pub target_cup_cents: i64,
+    pub spread_cup_cents: i64,
+    pub gain_cup_cents: i64,
+    pub notes: Option<String>,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub exchanged_at: NaiveDateTime,
+}
+
+/// Conversión de CashMovementModel (base de datos) a CashMovement (dominio)
+impl From<CashMovementModel> for CashMovement {
+    fn from(model: CashMovementModel) -> Self {
+        Self {
+            id: model.id,
+            amount: Money::from_minor(model.amount_cents, model.currency.as_str()),
+            currency: model.currency,
+            kind: model.kind,
+            exchange_rate: model.exchange_rate,
+            amount_cup: Money::from_minor(model.amount_cup_cents, BASE_CURRENCY),
+            reason: model.reason,
+            entry_id: model.entry_id,
+            created_by: model.created_by,
+            moved_at: model.moved_at.and_utc().to_rfc3339(),
+            created_at: model.created_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+/// Conversión de CashCountModel (base de datos) a CashCount (dominio)
+impl From<CashCountModel> for CashCount {
+    fn from(model: CashCountModel) -> Self {
+        Self {
+            id: model.id,
+            expected: Money::from_minor(model.expected_cents, model.currency.as_str()),
+            counted: Money::from_minor(model.counted_cents, model.currency.as_str()),
+            difference: Money::from_minor(model.difference_cents, model.currency.as_str()),
+            currency: model.currency,
+            exchange_rate: model.exchange_rate,
+            difference_cup: Money::from_minor(model.difference_cup_cents, BASE_CURRENCY),
+            notes: model.notes,
+            entry_id: model.entry_id,
+            counted_by: model.counted_by,
+            counted_at: model.counted_at.and_utc().to_rfc3339(),
+            created_at: model.created_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+/// Conversión de CashExchangeModel (base de datos) a CashExchange (dominio)
+impl From<CashExchangeModel> for CashExchange {
+    fn from(model: CashExchangeModel) -> Self {
+        Self {
+            id: model.id,
+            source: Money::from_minor(model.source_amount_cents, model.source_currency),
+            target: Money::from_minor(model.target_amount_cents, model.target_currency),
+            effective_rate: model.effective_rate,
+            official_rate: model.official_rate,
+            spread: model.spread,
+            source_cost_cup: Money::from_minor(model.source_cost_cup_cents, BASE_CURRENCY),
+            target_cup: Money::from_minor(model.target_cup_cents, BASE_CURRENCY),
+            spread_cup: Money::from_minor(model.spread_cup_cents, BASE_CURRENCY),
+            gain_cup: Money::from_minor(model.gain_cup_cents, BASE_CURRENCY),
+            notes: model.notes,
+            entry_id: model.entry_id,
+            created_by: model.created_by,
+            exchanged_at: model.exchanged_at.and_utc().to_rfc3339(),
+            created_at: model.created_at.and_utc().to_rfc3339(),
+        }
+    }
+}
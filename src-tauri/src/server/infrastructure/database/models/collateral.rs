@@ -0,0 +1,72 @@
+use crate::server::domain::entities::collateral::{Collateral, NewCollateral};
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::schema::loan_collaterals;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `loan_collaterals`
+/// `estimated_value_cents` está en centavos de `currency`
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = loan_collaterals)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CollateralModel {
+    pub id: i32,
+    pub loan_id: i32,
+    pub description: String,
+    pub estimated_value_cents: i64,
+    pub currency: String,
+    pub storage_location: String,
+    pub status: String,
+    pub payment_id: Option<i32>,
+    pub returned_at: Option<NaiveDateTime>,
+    pub liquidated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Modelo para insertar una garantía; entra siempre en custodia
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = loan_collaterals)]
+pub struct NewCollateralModel {
+    pub loan_id: i32,
+    pub description: String,
+    pub estimated_value_cents: i64,
+    pub currency: String,
+    pub storage_location: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Conversión de CollateralModel (base de datos) a Collateral (dominio)
+impl From<CollateralModel> for Collateral {
+    fn from(model: CollateralModel) -> Self {
+        Self {
+            id: model.id,
+            loan_id: model.loan_id,
+            description: model.description,
+            estimated_value: Money::from_minor(model.estimated_value_cents, model.currency),
+            storage_location: model.storage_location,
+            status: model.status,
+            payment_id: model.payment_id,
+            returned_at: model.returned_at.map(|date| date.and_utc().to_rfc3339()),
+            liquidated_at: model.liquidated_at.map(|date| date.and_utc().to_rfc3339()),
+            created_at: model.created_at.and_utc().to_rfc3339(),
+            updated_at: model.updated_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+/// Conversión de NewCollateral (dominio) a NewCollateralModel (base de datos)
+impl From<NewCollateral> for NewCollateralModel {
+    fn from(entity: NewCollateral) -> Self {
+        Self {
+            loan_id: entity.loan_id,
+            description: entity.description,
+            estimated_value_cents: entity.estimated_value.minor_units(),
+            currency: entity.estimated_value.currency().to_string(),
+            storage_location: entity.storage_location,
+            created_at: entity.created_at,
+            updated_at: entity.created_at,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::server::domain::entities::currency::{Currency, NewCurrency};
+use crate::server::infrastructure::database::schema::currencies;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `currencies`
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = currencies)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CurrencyModel {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub exchange_rate: f32,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Modelo para insertar nuevas monedas
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = currencies)]
+pub struct NewCurrencyModel {
+    pub code: String,
+    pub name: String,
+    pub exchange_rate: f32,
+}
+
+/// Conversión de CurrencyModel (base de datos) a Currency (dominio)
+impl From<CurrencyModel> for Currency {
+    fn from(model: CurrencyModel) -> Self {
+        Self {
+            id: model.id,
+            code: model.code,
+            name: model.name,
+            exchange_rate: model.exchange_rate,
+            updated_at: model.updated_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+/// Conversión de NewCurrency (dominio) a NewCurrencyModel (base de datos)
+impl From<NewCurrency> for NewCurrencyModel {
+    fn from(entity: NewCurrency) -> Self {
+        Self {
+            code: entity.code,
+            name: entity.name,
+            exchange_rate: entity.exchange_rate,
+        }
+    }
+}
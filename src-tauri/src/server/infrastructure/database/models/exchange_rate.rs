@@ -0,0 +1,49 @@
+use crate::server::domain::entities::exchange_rate::{ExchangeRate, NewExchangeRate};
+use crate::server::infrastructure::database::schema::exchange_rate_history;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `exchange_rate_history`
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = exchange_rate_history)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ExchangeRateModel {
+    pub id: i32,
+    pub currency_code: String,
+    pub exchange_rate: f32,
+    pub effective_from: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+/// Modelo para insertar una tasa en el historial
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = exchange_rate_history)]
+pub struct NewExchangeRateModel {
+    pub currency_code: String,
+    pub exchange_rate: f32,
+    pub effective_from: NaiveDateTime,
+}
+
+/// Conversión de ExchangeRateModel (base de datos) a ExchangeRate (dominio)
+impl From<ExchangeRateModel> for ExchangeRate {
+    fn from(model: ExchangeRateModel) -> Self {
+        Self {
+            id: model.id,
+            currency_code: model.currency_code,
+            exchange_rate: model.exchange_rate,
+            effective_from: model.effective_from.and_utc().to_rfc3339(),
+            created_at: model.created_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+/// Conversión de NewExchangeRate (dominio) a NewExchangeRateModel (base de datos)
+impl From<NewExchangeRate> for NewExchangeRateModel {
+    fn from(entity: NewExchangeRate) -> Self {
+        Self {
+            currency_code: entity.currency_code,
+            exchange_rate: entity.exchange_rate,
+            effective_from: entity.effective_from,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::server::domain::entities::ledger::{Account, JournalEntry, JournalLine, NewJournalEntry};
+use crate::server::infrastructure::database::schema::{accounts, journal_entries, journal_lines};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `accounts`
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = accounts)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct AccountModel {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub kind: String,
+    pub currency: String,
+    pub created_at: NaiveDateTime,
+}
+
+/// Modelo para insertar una cuenta; se usa al abrir la caja de una moneda nueva
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = accounts)]
+pub struct NewAccountModel {
+    pub code: String,
+    pub name: String,
+    pub kind: String,
+    pub currency: String,
+}
+
+/// Modelo de base de datos para la tabla `journal_entries`
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = journal_entries)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct JournalEntryModel {
+    pub id: i32,
+    pub event: String,
+    pub description: String,
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub payment_id: Option<i32>,
+    pub posted_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+/// Modelo para insertar la cabecera de un asiento
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = journal_entries)]
+pub struct NewJournalEntryModel {
+    pub event: String,
+    pub description: String,
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub payment_id: Option<i32>,
+    pub posted_at: NaiveDateTime,
+}
+
+/// Modelo de base de datos para la tabla `journal_lines`
+/// `debit_cents` y `credit_cents` están en centavos de CUP; `amount_cents` en los de la cuenta
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = journal_lines)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct JournalLineModel {
+    pub id: i32,
+    pub entry_id: i32,
+    pub account_id: i32,
+    pub borrower_id: Option<i32>,
+    pub debit_cents: i64,
+    pub credit_cents: i64,
+    pub amount_cents: i64,
+}
+
+/// Modelo para insertar una partida
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = journal_lines)]
+pub struct NewJournalLineModel {
+    pub entry_id: i32,
+    pub account_id: i32,
+    pub borrower_id: Option<i32>,
+    pub debit_cents: i64,
+    pub credit_cents: i64,
+    pub amount_cents: i64,
+}
+
+/// Conversión de AccountModel (base de datos) a Account (dominio)
+impl From<AccountModel> for Account {
+    fn from(model: AccountModel) -> Self {
+        Self {
+            code: model.code,
+            name: model.name,
+            kind: model.kind,
+            currency: model.currency,
+        }
+    }
+}
+
+/// Conversión de NewJournalEntry (dominio) a NewJournalEntryModel (base de datos)
+impl From<&NewJournalEntry> for NewJournalEntryModel {
+    fn from(entity: &NewJournalEntry) -> Self {
+        Self {
+            event: entity.event.to_string(),
+            description: entity.description.clone(),
+            borrower_id: entity.borrower_id,
+            loan_id: entity.loan_id,
+            payment_id: entity.payment_id,
+            posted_at: entity.posted_at,
+        }
+    }
+}
+
+impl JournalEntryModel {
+    /// Convierte el asiento a dominio con sus partidas ya convertidas
+    pub fn into_entity(self, lines: Vec<JournalLine>) -> JournalEntry {
+        JournalEntry {
+            id: self.id,
+            event: self.event,
+            description: self.description,
+            borrower_id: self.borrower_id,
+            loan_id: self.loan_id,
+            payment_id: self.payment_id,
+            posted_at: self.posted_at.and_utc().to_rfc3339(),
+            lines,
+            created_at: self.created_at.and_utc().to_rfc3339(),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::server::domain::entities::loan::{
+    InstallmentFrequency, InterestMethod, InterestTerms, Loan, LoanWriteOff, NewLoan, RatePeriod,
+    ScheduleTerms,
+};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::schema::loans;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `loans`
+/// El orden de los campos sigue el de las columnas en `schema.rs`
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = loans)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct LoanModel {
+    pub id: i32,
+    pub amount_cents: i64,
+    pub currency: String,
+    pub status: String,
+    pub borrower_id: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub exchange_rate: f32,
+    pub amount_cup_cents: i64,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub installment_frequency: Option<String>,
+    pub installment_count: Option<i32>,
+    pub first_due_date: Option<NaiveDateTime>,
+    pub remaining_cents: i64,
+    pub due_date: Option<NaiveDateTime>,
+    pub restructured_from_id: Option<i32>,
+    pub restructured_at: Option<NaiveDateTime>,
+    pub carried_over_cup_cents: i64,   // Centavos de CUP
+    pub written_off_at: Option<NaiveDateTime>,
+    pub written_off_cents: i64,
+    pub written_off_cup_cents: i64,    // Centavos de CUP
+    pub recovered_cup_cents: i64,      // Centavos de CUP
+    pub write_off_reason: Option<String>,
+    pub written_off_by: Option<i32>,
+    pub product_id: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub credit_limit_override_by: Option<i32>,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub deleted_by: Option<i32>,
+}
+
+/// Modelo para insertar nuevos préstamos
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = loans)]
+pub struct NewLoanModel {
+    pub amount_cents: i64,
+    pub currency: String,
+    pub exchange_rate: f32,
+    pub amount_cup_cents: i64,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub installment_frequency: Option<String>,
+    pub installment_count: Option<i32>,
+    pub first_due_date: Option<NaiveDateTime>,
+    pub remaining_cents: i64,
+    pub due_date: Option<NaiveDateTime>,
+    pub borrower_id: i32,
+    pub created_at: NaiveDateTime,
+    pub restructured_from_id: Option<i32>,
+    pub product_id: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub credit_limit_override_by: Option<i32>,
+}
+
+/// Conversión de LoanModel (base de datos) a Loan (dominio)
+impl From<LoanModel> for Loan {
+    fn from(model: LoanModel) -> Self {
+        Self {
+            id: model.id,
+            amount: Money::from_minor(model.amount_cents, model.currency.clone()),
+            exchange_rate: model.exchange_rate,
+            amount_cup: Money::from_minor(model.amount_cup_cents, BASE_CURRENCY),
+            // Valores desconocidos en la base de datos se tratan como los valores por defecto de la columna
+            interest: InterestTerms {
+                rate: model.interest_rate,
+                period: RatePeriod::parse(&model.rate_period).unwrap_or(RatePeriod::Monthly),
+                method: InterestMethod::parse(&model.interest_method).unwrap_or(InterestMethod::Flat),
+            },
+            schedule: match (
+                model.installment_frequency.as_deref().and_then(InstallmentFrequency::parse),
+                model.installment_count,
+                model.first_due_date,
+            ) {
+                (Some(frequency), Some(periods), Some(first_due_date)) => Some(ScheduleTerms {
+                    frequency,
+                    periods,
+                    first_due_date,
+                }),
+                _ => None,
+            },
+            write_off: match (model.written_off_at, model.written_off_by) {
+                (Some(written_off_at), Some(written_off_by)) => Some(LoanWriteOff {
+                    amount: Money::from_minor(model.written_off_cents, model.currency.clone()),
+                    amount_cup: Money::from_minor(model.written_off_cup_cents, BASE_CURRENCY),
+                    recovered_cup: Money::from_minor(model.recovered_cup_cents, BASE_CURRENCY),
+                    reason: model.write_off_reason.unwrap_or_default(),
+                    written_off_by,
+                    written_off_at: written_off_at.and_utc().to_rfc3339(),
+                }),
+                _ => None,
+            },
+            remaining: Money::from_minor(model.remaining_cents, model.currency),
+            due_date: model.due_date.map(|due_date| due_date.and_utc().to_rfc3339()),
+            status: model.status,
+            borrower_id: model.borrower_id,
+            created_at: model.created_at.and_utc().to_rfc3339(),
+            updated_at: model.updated_at.and_utc().to_rfc3339(),
+            restructured_from_id: model.restructured_from_id,
+            restructured_at: model.restructured_at.map(|restructured_at| restructured_at.and_utc().to_rfc3339()),
+            carried_over_cup: Money::from_minor(model.carried_over_cup_cents, BASE_CURRENCY),
+            product_id: model.product_id,
+            penalty_rule_id: model.penalty_rule_id,
+            credit_limit_override_by: model.credit_limit_override_by,
+            deleted_at: model.deleted_at.map(|deleted_at| deleted_at.and_utc().to_rfc3339()),
+            deleted_by: model.deleted_by,
+        }
+    }
+}
+
+/// Conversión de NewLoan (dominio) a NewLoanModel (base de datos)
+impl From<NewLoan> for NewLoanModel {
+    fn from(entity: NewLoan) -> Self {
+        Self {
+            amount_cents: entity.amount.minor_units(),
+            currency: entity.amount.currency().to_string(),
+            exchange_rate: entity.exchange_rate,
+            amount_cup_cents: entity.amount_cup.minor_units(),
+            interest_rate: entity.interest.rate,
+            rate_period: entity.interest.period.as_str().to_string(),
+            interest_method: entity.interest.method.as_str().to_string(),
+            installment_frequency: entity.schedule.map(|schedule| schedule.frequency.as_str().to_string()),
+            installment_count: entity.schedule.map(|schedule| schedule.periods),
+            first_due_date: entity.schedule.map(|schedule| schedule.first_due_date),
+            // Sin pagos asignados todavía el saldo pendiente es el monto completo
+            remaining_cents: entity.amount.minor_units(),
+            due_date: entity.due_date,
+            borrower_id: entity.borrower_id,
+            created_at: entity.created_at,
+            restructured_from_id: entity.restructured_from_id,
+            product_id: entity.product_id,
+            penalty_rule_id: entity.penalty_rule_id,
+            credit_limit_override_by: entity.credit_limit_override_by,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::server::domain::entities::loan_guarantor::{LoanGuarantor, NewLoanGuarantor};
+use crate::server::infrastructure::database::schema::loan_guarantors;
+use crate::server::domain::value_objects::money::Money;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `loan_guarantors`
+/// Lo garantizado está en centavos de la moneda del préstamo, que no se guarda en el garante
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = loan_guarantors)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct LoanGuarantorModel {
+    pub id: i32,
+    pub loan_id: i32,
+    pub borrower_id: Option<i32>,
+    pub contact_name: Option<String>,
+    pub contact_phone: Option<String>,
+    pub guaranteed_amount_cents: i64,
+    pub created_at: NaiveDateTime,
+}
+
+/// Modelo para insertar los garantes de un préstamo ya creado
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = loan_guarantors)]
+pub struct NewLoanGuarantorModel {
+    pub loan_id: i32,
+    pub borrower_id: Option<i32>,
+    pub contact_name: Option<String>,
+    pub contact_phone: Option<String>,
+    pub guaranteed_amount_cents: i64,
+}
+
+impl LoanGuarantorModel {
+    /// Conversión a dominio; la moneda viene del préstamo garantizado
+    pub fn into_entity(self, currency: &str) -> LoanGuarantor {
+        LoanGuarantor {
+            id: self.id,
+            loan_id: self.loan_id,
+            borrower_id: self.borrower_id,
+            contact_name: self.contact_name,
+            contact_phone: self.contact_phone,
+            amount: Money::from_minor(self.guaranteed_amount_cents, currency),
+            created_at: self.created_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+impl NewLoanGuarantorModel {
+    pub fn from_entity(loan_id: i32, entity: &NewLoanGuarantor) -> Self {
+        Self {
+            loan_id,
+            borrower_id: entity.borrower_id,
+            contact_name: entity.contact_name.clone(),
+            contact_phone: entity.contact_phone.clone(),
+            guaranteed_amount_cents: entity.amount.minor_units(),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::server::domain::entities::loan_installment::{LoanInstallment, NewLoanInstallment};
+use crate::server::infrastructure::database::schema::loan_installments;
+use crate::server::domain::value_objects::money::Money;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `loan_installments`
+/// Los importes están en centavos de la moneda del préstamo, que no se guarda en la cuota
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = loan_installments)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct LoanInstallmentModel {
+    pub id: i32,
+    pub loan_id: i32,
+    pub number: i32,
+    pub due_date: NaiveDateTime,
+    pub principal_cents: i64,
+    pub interest_cents: i64,
+    pub paid_cents: i64,
+    pub status: String,
+    pub paid_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Modelo para insertar las cuotas de un préstamo ya creado
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = loan_installments)]
+pub struct NewLoanInstallmentModel {
+    pub loan_id: i32,
+    pub number: i32,
+    pub due_date: NaiveDateTime,
+    pub principal_cents: i64,
+    pub interest_cents: i64,
+}
+
+impl LoanInstallmentModel {
+    /// Conversión a dominio; la moneda viene del préstamo al que pertenece la cuota
+    pub fn into_entity(self, currency: &str) -> LoanInstallment {
+        LoanInstallment {
+            id: self.id,
+            loan_id: self.loan_id,
+            number: self.number,
+            due_date: self.due_date.and_utc().to_rfc3339(),
+            principal: Money::from_minor(self.principal_cents, currency),
+            interest: Money::from_minor(self.interest_cents, currency),
+            paid: Money::from_minor(self.paid_cents, currency),
+            status: self.status,
+            paid_at: self.paid_at.map(|paid_at| paid_at.and_utc().to_rfc3339()),
+            created_at: self.created_at.and_utc().to_rfc3339(),
+            updated_at: self.updated_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+impl NewLoanInstallmentModel {
+    pub fn from_entity(loan_id: i32, entity: &NewLoanInstallment) -> Self {
+        Self {
+            loan_id,
+            number: entity.number,
+            due_date: entity.due_date,
+            principal_cents: entity.principal.minor_units(),
+            interest_cents: entity.interest.minor_units(),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::server::domain::entities::loan::{InstallmentFrequency, InterestMethod, InterestTerms, RatePeriod};
+use crate::server::domain::entities::loan_product::{LoanProduct, NewLoanProduct};
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::schema::loan_products;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `loan_products`
+/// `min_amount_cents` y `max_amount_cents` están en centavos de la moneda del producto
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = loan_products)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct LoanProductModel {
+    pub id: i32,
+    pub name: String,
+    pub currency: String,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub installment_frequency: Option<String>,
+    pub installment_count: Option<i32>,
+    pub term_days: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub min_amount_cents: Option<i64>,
+    pub max_amount_cents: Option<i64>,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Modelo para insertar nuevos productos
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = loan_products)]
+pub struct NewLoanProductModel {
+    pub name: String,
+    pub currency: String,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub installment_frequency: Option<String>,
+    pub installment_count: Option<i32>,
+    pub term_days: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub min_amount_cents: Option<i64>,
+    pub max_amount_cents: Option<i64>,
+}
+
+/// Conversión de LoanProductModel (base de datos) a LoanProduct (dominio)
+impl From<LoanProductModel> for LoanProduct {
+    fn from(model: LoanProductModel) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            // Valores desconocidos en la base de datos se tratan como los valores por defecto de la columna
+            interest: InterestTerms {
+                rate: model.interest_rate,
+                period: RatePeriod::parse(&model.rate_period).unwrap_or(RatePeriod::Monthly),
+                method: InterestMethod::parse(&model.interest_method).unwrap_or(InterestMethod::Flat),
+            },
+            installment_frequency: model.installment_frequency.as_deref().and_then(InstallmentFrequency::parse),
+            installment_count: model.installment_count,
+            term_days: model.term_days,
+            penalty_rule_id: model.penalty_rule_id,
+            min_amount: model.min_amount_cents.map(|cents| Money::from_minor(cents, model.currency.clone())),
+            max_amount: model.max_amount_cents.map(|cents| Money::from_minor(cents, model.currency.clone())),
+            currency: model.currency,
+            active: model.active,
+            created_at: model.created_at.and_utc().to_rfc3339(),
+            updated_at: model.updated_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+/// Conversión de NewLoanProduct (dominio) a NewLoanProductModel (base de datos)
+impl From<NewLoanProduct> for NewLoanProductModel {
+    fn from(entity: NewLoanProduct) -> Self {
+        Self {
+            name: entity.name,
+            currency: entity.currency,
+            interest_rate: entity.interest.rate,
+            rate_period: entity.interest.period.as_str().to_string(),
+            interest_method: entity.interest.method.as_str().to_string(),
+            installment_frequency: entity.installment_frequency.map(|frequency| frequency.as_str().to_string()),
+            installment_count: entity.installment_count,
+            term_days: entity.term_days,
+            penalty_rule_id: entity.penalty_rule_id,
+            min_amount_cents: entity.min_amount.map(|amount| amount.minor_units()),
+            max_amount_cents: entity.max_amount.map(|amount| amount.minor_units()),
+        }
+    }
+}
@@ -1,2 +1,14 @@
 pub mod user;
-pub mod borrower;
\ No newline at end of file
+pub mod borrower;
+pub mod loan;
+pub mod loan_installment;
+pub mod payment;
+pub mod payment_allocation;
+pub mod penalty;
+pub mod currency;
+pub mod exchange_rate;
+pub mod loan_product;
+pub mod loan_guarantor;
+pub mod collateral;
+pub mod ledger;
+pub mod cash_box;
\ No newline at end of file
@@ -0,0 +1,78 @@
+use crate::server::domain::entities::payment::{NewPayment, Payment};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::payment_allocation::AllocationStrategy;
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::schema::payments;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `payments`
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = payments)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PaymentModel {
+    pub id: i32,
+    pub amount_cents: i64,
+    pub currency: String,
+    pub exchange_rate: f32,
+    pub amount_cup_cents: i64,
+    pub borrower_id: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub allocation_strategy: String,
+    pub loan_id: Option<i32>,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub deleted_by: Option<i32>,
+}
+
+/// Modelo para insertar nuevos pagos
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = payments)]
+pub struct NewPaymentModel {
+    pub amount_cents: i64,
+    pub currency: String,
+    pub exchange_rate: f32,
+    pub amount_cup_cents: i64,
+    pub allocation_strategy: String,
+    pub borrower_id: i32,
+    pub loan_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
+
+/// Conversión de PaymentModel (base de datos) a Payment (dominio)
+/// Las asignaciones viven en otra tabla y las carga el repositorio
+impl From<PaymentModel> for Payment {
+    fn from(model: PaymentModel) -> Self {
+        Self {
+            id: model.id,
+            amount: Money::from_minor(model.amount_cents, model.currency),
+            exchange_rate: model.exchange_rate,
+            amount_cup: Money::from_minor(model.amount_cup_cents, BASE_CURRENCY),
+            allocation_strategy: AllocationStrategy::parse(&model.allocation_strategy)
+                .unwrap_or(AllocationStrategy::OldestFirst),
+            allocations: Vec::new(),
+            borrower_id: model.borrower_id,
+            loan_id: model.loan_id,
+            created_at: model.created_at.and_utc().to_rfc3339(),
+            updated_at: model.updated_at.and_utc().to_rfc3339(),
+            deleted_at: model.deleted_at.map(|date| date.and_utc().to_rfc3339()),
+            deleted_by: model.deleted_by,
+        }
+    }
+}
+
+/// Conversión de NewPayment (dominio) a NewPaymentModel (base de datos)
+impl From<NewPayment> for NewPaymentModel {
+    fn from(entity: NewPayment) -> Self {
+        Self {
+            amount_cents: entity.amount.minor_units(),
+            currency: entity.amount.currency().to_string(),
+            exchange_rate: entity.exchange_rate,
+            amount_cup_cents: entity.amount_cup.minor_units(),
+            allocation_strategy: entity.allocation_strategy.as_str().to_string(),
+            borrower_id: entity.borrower_id,
+            loan_id: entity.loan_id,
+            created_at: entity.created_at,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::payment_allocation::PaymentAllocation;
+use crate::server::domain::services::payment_allocator::Allocation;
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::schema::payment_allocations;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `payment_allocations`
+/// `amount_cents` está en la moneda del préstamo, que no se guarda en la asignación
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = payment_allocations)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PaymentAllocationModel {
+    pub id: i32,
+    pub payment_id: i32,
+    pub loan_id: i32,
+    pub amount_cents: i64,
+    pub amount_cup_cents: i64,
+    pub created_at: NaiveDateTime,
+}
+
+/// Modelo para insertar la parte de un pago asignada a un préstamo
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = payment_allocations)]
+pub struct NewPaymentAllocationModel {
+    pub payment_id: i32,
+    pub loan_id: i32,
+    pub amount_cents: i64,
+    pub amount_cup_cents: i64,
+    pub created_at: NaiveDateTime,
+}
+
+impl PaymentAllocationModel {
+    /// Conversión a dominio; la moneda viene del préstamo que recibe la asignación
+    pub fn into_entity(self, currency: &str) -> PaymentAllocation {
+        PaymentAllocation {
+            id: self.id,
+            payment_id: self.payment_id,
+            loan_id: self.loan_id,
+            amount: Money::from_minor(self.amount_cents, currency),
+            amount_cup: Money::from_minor(self.amount_cup_cents, BASE_CURRENCY),
+            created_at: self.created_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+impl NewPaymentAllocationModel {
+    /// La asignación toma la fecha del pago para que el historial del préstamo sea cronológico
+    pub fn from_allocation(payment_id: i32, paid_at: NaiveDateTime, allocation: &Allocation) -> Self {
+        Self {
+            payment_id,
+            loan_id: allocation.loan_id,
+            amount_cents: allocation.amount.minor_units(),
+            amount_cup_cents: allocation.amount_cup.minor_units(),
+            created_at: paid_at,
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::penalty::{NewPenalty, NewPenaltyRule, Penalty, PenaltyKind, PenaltyRule};
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::schema::{penalties, penalty_rules};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Modelo de base de datos para la tabla `penalty_rules`
+/// `amount_cents` y `cap_cents` están en centavos de CUP
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = penalty_rules)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PenaltyRuleModel {
+    pub id: i32,
+    pub name: String,
+    pub kind: String,
+    pub amount_cents: i64,
+    pub rate: f64,
+    pub grace_days: i32,
+    pub cap_cents: Option<i64>,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Modelo para insertar nuevas reglas de recargo
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = penalty_rules)]
+pub struct NewPenaltyRuleModel {
+    pub name: String,
+    pub kind: String,
+    pub amount_cents: i64,
+    pub rate: f64,
+    pub grace_days: i32,
+    pub cap_cents: Option<i64>,
+}
+
+/// Modelo de base de datos para la tabla `penalties`
+/// `amount_cents` está en centavos de CUP
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = penalties)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PenaltyModel {
+    pub id: i32,
+    pub loan_id: i32,
+    pub borrower_id: i32,
+    pub rule_id: i32,
+    pub amount_cents: i64,
+    pub days_past_due: i32,
+    pub days_charged: i32,
+    pub status: String,
+    pub waived_reason: Option<String>,
+    pub waived_by: Option<i32>,
+    pub waived_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Modelo para insertar un recargo cobrado
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = penalties)]
+pub struct NewPenaltyModel {
+    pub loan_id: i32,
+    pub borrower_id: i32,
+    pub rule_id: i32,
+    pub amount_cents: i64,
+    pub days_past_due: i32,
+    pub days_charged: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Conversión de PenaltyRuleModel (base de datos) a PenaltyRule (dominio)
+impl From<PenaltyRuleModel> for PenaltyRule {
+    fn from(model: PenaltyRuleModel) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            kind: PenaltyKind::parse(&model.kind).unwrap_or(PenaltyKind::Fixed),
+            amount: Money::from_minor(model.amount_cents, BASE_CURRENCY),
+            rate: model.rate,
+            grace_days: model.grace_days,
+            cap: model.cap_cents.map(|cents| Money::from_minor(cents, BASE_CURRENCY)),
+            active: model.active,
+            created_at: model.created_at.and_utc().to_rfc3339(),
+            updated_at: model.updated_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+/// Conversión de NewPenaltyRule (dominio) a NewPenaltyRuleModel (base de datos)
+impl From<NewPenaltyRule> for NewPenaltyRuleModel {
+    fn from(entity: NewPenaltyRule) -> Self {
+        Self {
+            name: entity.name,
+            kind: entity.kind.as_str().to_string(),
+            amount_cents: entity.amount.minor_units(),
+            rate: entity.rate,
+            grace_days: entity.grace_days,
+            cap_cents: entity.cap.map(|cap| cap.minor_units()),
+        }
+    }
+}
+
+/// Conversión de PenaltyModel (base de datos) a Penalty (dominio)
+impl From<PenaltyModel> for Penalty {
+    fn from(model: PenaltyModel) -> Self {
+        Self {
+            id: model.id,
+            loan_id: model.loan_id,
+            borrower_id: model.borrower_id,
+            rule_id: model.rule_id,
+            amount: Money::from_minor(model.amount_cents, BASE_CURRENCY),
+            days_past_due: model.days_past_due,
+            days_charged: model.days_charged,
+            status: model.status,
+            waived_reason: model.waived_reason,
+            waived_by: model.waived_by,
+            waived_at: model.waived_at.map(|waived_at| waived_at.and_utc().to_rfc3339()),
+            created_at: model.created_at.and_utc().to_rfc3339(),
+            updated_at: model.updated_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+/// Conversión de NewPenalty (dominio) a NewPenaltyModel (base de datos)
+impl From<NewPenalty> for NewPenaltyModel {
+    fn from(entity: NewPenalty) -> Self {
+        Self {
+            loan_id: entity.loan_id,
+            borrower_id: entity.borrower_id,
+            rule_id: entity.rule_id,
+            amount_cents: entity.amount.minor_units(),
+            days_past_due: entity.days_past_due,
+            days_charged: entity.days_charged,
+            created_at: entity.created_at,
+            updated_at: entity.created_at,
+        }
+    }
+}
@@ -1,11 +1,37 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use diesel::prelude::*;
-use crate::server::domain::entities::borrower::{Borrower, NewBorrower};
+use diesel::sqlite::Sqlite;
+use crate::server::domain::entities::borrower::{
+    Borrower, BorrowerFilter, BorrowerPage, BorrowerSortField, NewBorrower, SortDirection, TotalsMismatch,
+    TotalsReconciliation, BORROWER_BLOCKED,
+};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::loan::{Loan, LOAN_PAID, LOAN_RESTRUCTURED, LOAN_WRITTEN_OFF};
+use crate::server::domain::entities::loan_installment::LoanInstallment;
+use crate::server::domain::entities::penalty::PENALTY_CHARGED;
 use crate::server::domain::repositories::borrower_repository::BorrowerRepository;
+use crate::server::domain::services::credit_scorer::{CreditScorer, LoanHistory};
+use crate::server::domain::services::delinquency_policy::DelinquencyPolicy;
+use crate::server::domain::services::journal_poster::JournalPoster;
+use crate::server::domain::value_objects::money::Money;
 use crate::server::infrastructure::database::models::borrower::{BorrowerModel, NewBorrowerModel};
-use crate::server::infrastructure::database::schema::borrowers;
+use crate::server::infrastructure::database::models::loan::LoanModel;
+use crate::server::infrastructure::database::models::loan_guarantor::LoanGuarantorModel;
+use crate::server::infrastructure::database::models::loan_installment::LoanInstallmentModel;
+use crate::server::infrastructure::database::schema::{
+    borrowers, loan_guarantors, loan_installments, loans, payment_allocations, payments, penalties,
+};
 use crate::server::infrastructure::database::connection::DbPool;
-use crate::utils::error::Result;
+use crate::server::infrastructure::database::repositories::diesel_ledger_repository::{
+    borrower_interest, post_entry, receivable_totals, sync_borrower_ledger,
+};
+use crate::utils::error::{AppError, Result};
+
+// Para ordenar por nombre sin distinguir mayúsculas
+define_sql_function!(fn lower(value: diesel::sql_types::Text) -> diesel::sql_types::Text);
 
 /// Implementación concreta del repositorio de borrowers usando Diesel con SQLite
 /// Solo se encarga de operaciones CRUD básicas con la base de datos
@@ -43,24 +69,61 @@ impl BorrowerRepository for DieselBorrowerRepository {
         Ok(borrower_model.into())
     }
 
-    /// Obtener todos los borrowers de la base de datos
+    /// Obtener todos los borrowers no eliminados de la base de datos
     async fn find_all(&self) -> Result<Vec<Borrower>> {
         let mut conn = self.pool.get()?;
         
         // Consultar todos los borrowers
         let borrower_models = borrowers::table
+            .filter(borrowers::deleted_at.is_null())
             .load::<BorrowerModel>(&mut conn)?;
             
         // Convertir cada modelo a entidad del dominio
         Ok(borrower_models.into_iter().map(|model| model.into()).collect())
     }
 
-    /// Buscar un borrower por su ID
+    /// Buscar borrowers con filtros, orden y paginación resueltos en la consulta
+    async fn search(&self, filter: &BorrowerFilter) -> Result<BorrowerPage> {
+        let mut conn = self.pool.get()?;
+
+        let total = filtered_borrowers(filter)
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        let mut query = match (filter.sort, filter.direction) {
+            (BorrowerSortField::Name, SortDirection::Asc) => filtered_borrowers(filter).order(lower(borrowers::name).asc()),
+            (BorrowerSortField::Name, SortDirection::Desc) => filtered_borrowers(filter).order(lower(borrowers::name).desc()),
+            (BorrowerSortField::CreatedAt, SortDirection::Asc) => filtered_borrowers(filter).order(borrowers::created_at.asc()),
+            (BorrowerSortField::CreatedAt, SortDirection::Desc) => filtered_borrowers(filter).order(borrowers::created_at.desc()),
+            (BorrowerSortField::Balance, SortDirection::Asc) => filtered_borrowers(filter).order(borrowers::balance_cents.asc()),
+            (BorrowerSortField::Balance, SortDirection::Desc) => filtered_borrowers(filter).order(borrowers::balance_cents.desc()),
+            (BorrowerSortField::TotalLoans, SortDirection::Asc) => filtered_borrowers(filter).order(borrowers::total_loans_cents.asc()),
+            (BorrowerSortField::TotalLoans, SortDirection::Desc) => filtered_borrowers(filter).order(borrowers::total_loans_cents.desc()),
+            (BorrowerSortField::CreditScore, SortDirection::Asc) => filtered_borrowers(filter).order(borrowers::credit_score.asc()),
+            (BorrowerSortField::CreditScore, SortDirection::Desc) => filtered_borrowers(filter).order(borrowers::credit_score.desc()),
+        }
+        // El ID desempata para que las páginas no repitan ni salten filas
+        .then_order_by(borrowers::id.asc());
+
+        if let Some(page_size) = filter.page_size {
+            query = query.limit(page_size).offset((filter.page - 1) * page_size);
+        }
+
+        let borrower_models = query.load::<BorrowerModel>(&mut conn)?;
+
+        Ok(BorrowerPage {
+            borrowers: borrower_models.into_iter().map(|model| model.into()).collect(),
+            total,
+        })
+    }
+
+    /// Buscar un borrower no eliminado por su ID
     async fn find_by_id(&self, id: i32) -> Result<Option<Borrower>> {
         let mut conn = self.pool.get()?;
         
         let borrower_model = borrowers::table
             .find(id)
+            .filter(borrowers::deleted_at.is_null())
             .first::<BorrowerModel>(&mut conn)
             .optional()?;
             
@@ -71,16 +134,15 @@ impl BorrowerRepository for DieselBorrowerRepository {
     async fn update(&self, id: i32, borrower: &Borrower) -> Result<Borrower> {
         let mut conn = self.pool.get()?;
 
-        // Actualizar todos los campos del borrower
+        // Actualizar los campos editables; los totales salen del libro mayor
         diesel::update(borrowers::table.find(id))
             .set((
                 borrowers::phone.eq(&borrower.phone),
                 borrowers::name.eq(&borrower.name),
                 borrowers::location.eq(&borrower.location),
-                borrowers::total_loans.eq(borrower.total_loans),
-                borrowers::total_paid.eq(borrower.total_paid),
-                borrowers::balance.eq(borrower.balance),
                 borrowers::status.eq(&borrower.status),
+                borrowers::credit_limit_cents.eq(borrower.credit_limit.as_ref().map(|limit| limit.minor_units())),
+                borrowers::risk_tier.eq(borrower.risk_tier.as_str()),
                 borrowers::updated_at.eq(chrono::Utc::now().naive_utc()),
             ))
             .execute(&mut conn)?;
@@ -93,14 +155,107 @@ impl BorrowerRepository for DieselBorrowerRepository {
         Ok(updated_borrower.into())
     }
 
-    /// Eliminar un borrower por su ID
-    async fn delete(&self, id: i32) -> Result<()> {
+    /// Eliminar un borrower de forma lógica; sus préstamos y pagos se conservan
+    /// Se rechaza mientras tenga préstamos abiertos o saldo pendiente, que quedarían ocultos
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<()> {
         let mut conn = self.pool.get()?;
-        
-        diesel::delete(borrowers::table.find(id))
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let borrower = find_borrower(conn, id)?;
+            let open_loan_count = loans::table
+                .filter(loans::borrower_id.eq(id))
+                .filter(loans::deleted_at.is_null())
+                .filter(loans::status.ne_all([LOAN_PAID, LOAN_RESTRUCTURED]))
+                .count()
+                .get_result::<i64>(conn)?;
+            if open_loan_count > 0 || borrower.balance.is_positive() {
+                return Err(AppError::Conflict(format!(
+                    "El prestatario {} tiene préstamos abiertos o saldo pendiente y no se puede eliminar",
+                    borrower.name
+                )));
+            }
+
+            diesel::update(borrowers::table.find(id))
+                .set((
+                    borrowers::deleted_at.eq(chrono::Utc::now().naive_utc()),
+                    borrowers::deleted_by.eq(deleted_by),
+                ))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    /// Restaurar un borrower eliminado
+    async fn restore(&self, id: i32) -> Result<Borrower> {
+        let mut conn = self.pool.get()?;
+
+        diesel::update(borrowers::table.find(id))
+            .set((
+                borrowers::deleted_at.eq(None::<NaiveDateTime>),
+                borrowers::deleted_by.eq(None::<i32>),
+            ))
             .execute(&mut conn)?;
-            
-        Ok(())
+
+        find_borrower(&mut conn, id)
+    }
+
+    /// Borrar definitivamente un borrower eliminado
+    /// Se rechaza mientras tenga préstamos, pagos, recargos o garantías, aunque estén eliminados
+    async fn purge(&self, id: i32) -> Result<()> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let loan_count = loans::table
+                .filter(loans::borrower_id.eq(id))
+                .count()
+                .get_result::<i64>(conn)?;
+            let payment_count = payments::table
+                .filter(payments::borrower_id.eq(id))
+                .count()
+                .get_result::<i64>(conn)?;
+            let penalty_count = penalties::table
+                .filter(penalties::borrower_id.eq(id))
+                .count()
+                .get_result::<i64>(conn)?;
+            let guarantee_count = loan_guarantors::table
+                .filter(loan_guarantors::borrower_id.eq(id))
+                .count()
+                .get_result::<i64>(conn)?;
+            if loan_count + payment_count + penalty_count + guarantee_count > 0 {
+                return Err(AppError::Conflict(
+                    "El prestatario tiene préstamos, pagos, recargos o garantías registrados y no se puede purgar".to_string()
+                ));
+            }
+
+            diesel::delete(borrowers::table.find(id)).execute(conn)?;
+            Ok(())
+        })
+    }
+
+    /// Obtener los borrowers eliminados
+    async fn find_deleted(&self) -> Result<Vec<Borrower>> {
+        let mut conn = self.pool.get()?;
+
+        let borrower_models = borrowers::table
+            .filter(borrowers::deleted_at.is_not_null())
+            .order(borrowers::deleted_at.desc())
+            .load::<BorrowerModel>(&mut conn)?;
+
+        Ok(borrower_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Buscar un borrower eliminado por su ID
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<Borrower>> {
+        let mut conn = self.pool.get()?;
+
+        let borrower_model = borrowers::table
+            .find(id)
+            .filter(borrowers::deleted_at.is_not_null())
+            .first::<BorrowerModel>(&mut conn)
+            .optional()?;
+
+        Ok(borrower_model.map(|model| model.into()))
     }
 
     /// Verificar si existe un borrower con el teléfono dado
@@ -128,4 +283,515 @@ impl BorrowerRepository for DieselBorrowerRepository {
         
         Ok(exists)
     }
-}
\ No newline at end of file
+
+    /// Cambiar el estado de cobranza de un borrower
+    async fn update_status(&self, id: i32, status: &str) -> Result<()> {
+        let mut conn = self.pool.get()?;
+
+        diesel::update(borrowers::table.find(id))
+            .set((
+                borrowers::status.eq(status),
+                borrowers::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    /// Recalcular el puntaje de crédito de un borrower a la fecha dada
+    async fn refresh_credit_score(&self, id: i32, now: NaiveDateTime) -> Result<()> {
+        let mut conn = self.pool.get()?;
+        refresh_credit_score(&mut conn, id, now)
+    }
+
+    /// Registrar el interés de las cuotas vencidas de un borrower a la fecha dada
+    async fn recognize_interest(&self, id: i32, now: NaiveDateTime) -> Result<()> {
+        let mut conn = self.pool.get()?;
+        conn.transaction::<_, AppError, _>(|conn| sync_borrower_ledger(conn, id, now))
+    }
+
+    /// Obtener los borrowers bloqueados, también los eliminados
+    async fn find_blocked(&self) -> Result<Vec<Borrower>> {
+        let mut conn = self.pool.get()?;
+
+        let borrower_models = borrowers::table
+            .filter(borrowers::blocked_at.is_not_null())
+            .load::<BorrowerModel>(&mut conn)?;
+
+        Ok(borrower_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Bloquear un borrower guardando el motivo, la fecha y quién lo bloqueó
+    async fn block(&self, id: i32, reason: &str, blocked_by: i32, blocked_at: NaiveDateTime) -> Result<Borrower> {
+        let mut conn = self.pool.get()?;
+
+        diesel::update(borrowers::table.find(id))
+            .set((
+                borrowers::status.eq(BORROWER_BLOCKED),
+                borrowers::blocked_reason.eq(reason),
+                borrowers::blocked_at.eq(blocked_at),
+                borrowers::blocked_by.eq(blocked_by),
+                borrowers::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        find_borrower(&mut conn, id)
+    }
+
+    /// Desbloquear un borrower; recupera el estado que le dan sus préstamos
+    async fn unblock(&self, id: i32) -> Result<Borrower> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let status = loan_based_status(conn, id)?;
+
+            diesel::update(borrowers::table.find(id))
+                .set((
+                    borrowers::status.eq(status),
+                    borrowers::blocked_reason.eq(None::<String>),
+                    borrowers::blocked_at.eq(None::<NaiveDateTime>),
+                    borrowers::blocked_by.eq(None::<i32>),
+                    borrowers::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            find_borrower(conn, id)
+        })
+    }
+
+    /// Conciliar los totales guardados de todos los borrowers con sus registros
+    async fn reconcile_totals(&self, fix: bool) -> Result<TotalsReconciliation> {
+        let mut conn = self.pool.get()?;
+        reconcile_borrower_totals(&mut conn, fix)
+    }
+}
+
+/// Recalcula los totales de cada borrower desde sus préstamos, pagos y recargos no eliminados y el
+/// interés de sus cuotas, y los compara con los guardados
+/// Con `fix`, en una sola transacción, pone al día los asientos que dependen del reparto de los
+/// pagos, registra un asiento de ajuste por cada diferencia que quede entre el libro mayor y los
+/// registros y reconstruye los totales guardados desde el libro mayor
+/// Lo pasado a una reestructuración no cuenta dos veces en lo prestado, y lo castigado y lo
+/// recuperado salen de lo registrado en cada préstamo
+pub(crate) fn reconcile_borrower_totals(conn: &mut SqliteConnection, fix: bool) -> Result<TotalsReconciliation> {
+    conn.transaction::<_, AppError, _>(|conn| {
+        let now = chrono::Utc::now().naive_utc();
+        let stored_borrowers: Vec<Borrower> = borrowers::table
+            .order(borrowers::id.asc())
+            .load::<BorrowerModel>(conn)?
+            .into_iter()
+            .map(|model| model.into())
+            .collect();
+
+        let loan_rows = loans::table
+            .filter(loans::deleted_at.is_null())
+            .select((
+                loans::borrower_id,
+                loans::amount_cup_cents,
+                loans::carried_over_cup_cents,
+                loans::written_off_cup_cents,
+                loans::recovered_cup_cents,
+            ))
+            .load::<(i32, i64, i64, i64, i64)>(conn)?;
+        let payment_rows = payments::table
+            .filter(payments::deleted_at.is_null())
+            .select((payments::borrower_id, payments::amount_cup_cents))
+            .load::<(i32, i64)>(conn)?;
+        let penalty_rows = penalties::table
+            .filter(penalties::status.eq(PENALTY_CHARGED))
+            .filter(penalties::loan_id.ne_all(loans::table.filter(loans::deleted_at.is_not_null()).select(loans::id)))
+            .select((penalties::borrower_id, penalties::amount_cents))
+            .load::<(i32, i64)>(conn)?;
+
+        let mut expected: HashMap<i32, (Borrower, Money)> = HashMap::new();
+        for borrower in &stored_borrowers {
+            let mut interest = Money::zero(BASE_CURRENCY);
+            for recognized in borrower_interest(conn, borrower.id, now)?.recognized.values() {
+                interest = interest.checked_add(recognized)?;
+            }
+
+            let totals = Borrower {
+                total_loans: Money::zero(BASE_CURRENCY),
+                total_paid: Money::zero(BASE_CURRENCY),
+                total_penalties: Money::zero(BASE_CURRENCY),
+                written_off_total: Money::zero(BASE_CURRENCY),
+                recovered_total: Money::zero(BASE_CURRENCY),
+                ..borrower.clone()
+            };
+            expected.insert(borrower.id, (totals, interest));
+        }
+
+        for (borrower_id, amount_cup, carried_over_cup, written_off_cup, recovered_cup) in loan_rows {
+            if let Some((totals, _)) = expected.get_mut(&borrower_id) {
+                totals.total_loans = totals
+                    .total_loans
+                    .checked_add(&cup(amount_cup))?
+                    .checked_sub(&cup(carried_over_cup))?;
+                totals.written_off_total = totals.written_off_total.checked_add(&cup(written_off_cup))?;
+                totals.recovered_total = totals.recovered_total.checked_add(&cup(recovered_cup))?;
+            }
+        }
+        for (borrower_id, amount_cup) in payment_rows {
+            if let Some((totals, _)) = expected.get_mut(&borrower_id) {
+                totals.total_paid = totals.total_paid.checked_add(&cup(amount_cup))?;
+            }
+        }
+        for (borrower_id, amount) in penalty_rows {
+            if let Some((totals, _)) = expected.get_mut(&borrower_id) {
+                totals.total_penalties = totals.total_penalties.checked_add(&cup(amount))?;
+            }
+        }
+
+        let mut mismatches = Vec::new();
+        let checked = stored_borrowers.len() as i64;
+        for stored in &stored_borrowers {
+            let (totals, interest) = &expected[&stored.id];
+            let balance = expected_balance(totals, interest)?;
+
+            let fields = [
+                ("total_loans", &stored.total_loans, &totals.total_loans),
+                ("total_paid", &stored.total_paid, &totals.total_paid),
+                ("total_penalties", &stored.total_penalties, &totals.total_penalties),
+                ("written_off_total", &stored.written_off_total, &totals.written_off_total),
+                ("recovered_total", &stored.recovered_total, &totals.recovered_total),
+                ("balance", &stored.balance, &balance),
+            ];
+            for (field, stored_total, expected_total) in fields {
+                if stored_total.minor_units() != expected_total.minor_units() {
+                    mismatches.push(TotalsMismatch {
+                        borrower_id: stored.id,
+                        name: stored.name.clone(),
+                        field,
+                        stored: stored_total.clone(),
+                        expected: expected_total.clone(),
+                    });
+                }
+            }
+        }
+
+        if fix {
+            // Primero todos los pagos, que pueden pasar de un prestatario a otro, y luego los ajustes
+            for stored in &stored_borrowers {
+                sync_borrower_ledger(conn, stored.id, now)?;
+            }
+
+            for stored in &stored_borrowers {
+                let (totals, _) = &expected[&stored.id];
+                let ledger = receivable_totals(conn, stored.id)?;
+                let fields = [
+                    ("total_loans", &ledger.loans, &totals.total_loans),
+                    ("total_paid", &ledger.paid, &totals.total_paid),
+                    ("total_penalties", &ledger.penalties, &totals.total_penalties),
+                    ("written_off_total", &ledger.written_off, &totals.written_off_total),
+                    ("recovered_total", &ledger.recovered, &totals.recovered_total),
+                ];
+                for (field, ledger_total, expected_total) in fields {
+                    let delta = expected_total.checked_sub(ledger_total)?;
+                    if delta.minor_units() != 0 {
+                        post_entry(conn, &JournalPoster::totals_adjustment(stored.id, field, &delta, now)?)?;
+                    }
+                }
+
+                refresh_borrower_totals(conn, stored.id)?;
+            }
+        }
+
+        Ok(TotalsReconciliation {
+            checked,
+            mismatches,
+            fixed: fix,
+        })
+    })
+}
+
+/// Saldo = préstamos + recargos + interés − pagado − castigado + recuperado
+/// Lo recuperado ya figura en lo pagado, así que se devuelve al saldo para no descontarlo dos veces
+fn expected_balance(totals: &Borrower, interest: &Money) -> Result<Money> {
+    totals
+        .total_loans
+        .checked_add(&totals.total_penalties)?
+        .checked_add(interest)?
+        .checked_sub(&totals.total_paid)?
+        .checked_sub(&totals.written_off_total)?
+        .checked_add(&totals.recovered_total)
+}
+
+fn cup(cents: i64) -> Money {
+    Money::from_minor(cents, BASE_CURRENCY)
+}
+
+/// Reconstruye los totales y el saldo guardados de un borrower desde sus cuentas por cobrar
+/// Las columnas son solo una copia del libro mayor para listar y ordenar; se llama en la misma
+/// transacción que registra los asientos que las cambian
+pub(crate) fn refresh_borrower_totals(conn: &mut SqliteConnection, borrower_id: i32) -> Result<()> {
+    let borrower = find_borrower(conn, borrower_id)?;
+    let totals = receivable_totals(conn, borrower_id)?;
+
+    let unchanged = [
+        (&borrower.total_loans, &totals.loans),
+        (&borrower.total_paid, &totals.paid),
+        (&borrower.total_penalties, &totals.penalties),
+        (&borrower.written_off_total, &totals.written_off),
+        (&borrower.recovered_total, &totals.recovered),
+        (&borrower.balance, &totals.balance),
+    ]
+    .iter()
+    .all(|(stored, ledger)| stored.minor_units() == ledger.minor_units());
+    if unchanged {
+        return Ok(());
+    }
+
+    diesel::update(borrowers::table.find(borrower_id))
+        .set((
+            borrowers::total_loans_cents.eq(totals.loans.minor_units()),
+            borrowers::total_paid_cents.eq(totals.paid.minor_units()),
+            borrowers::total_penalties_cents.eq(totals.penalties.minor_units()),
+            borrowers::written_off_total_cents.eq(totals.written_off.minor_units()),
+            borrowers::recovered_total_cents.eq(totals.recovered.minor_units()),
+            borrowers::balance_cents.eq(totals.balance.minor_units()),
+            borrowers::updated_at.eq(chrono::Utc::now().naive_utc()),
+        ))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+/// Recalcula lo que un borrower garantiza en préstamos ajenos a partir de su saldo pendiente
+/// Se llama tras repartir los pagos del deudor y al agregar o quitar garantes
+pub(crate) fn refresh_guarantee_exposure(conn: &mut SqliteConnection, borrower_id: i32) -> Result<()> {
+    let guarantee_rows = loan_guarantors::table
+        .inner_join(loans::table)
+        .filter(loan_guarantors::borrower_id.eq(borrower_id))
+        .filter(loans::deleted_at.is_null())
+        .select((
+            LoanGuarantorModel::as_select(),
+            loans::currency,
+            loans::remaining_cents,
+            loans::exchange_rate,
+        ))
+        .load::<(LoanGuarantorModel, String, i64, f32)>(conn)?;
+
+    let mut exposure = Money::zero(BASE_CURRENCY);
+    for (model, currency, remaining_cents, exchange_rate) in guarantee_rows {
+        let guarantor = model.into_entity(&currency);
+        let remaining = Money::from_minor(remaining_cents, currency);
+        exposure = exposure.checked_add(&guarantor.exposure(&remaining, exchange_rate)?)?;
+    }
+
+    let borrower = find_borrower(conn, borrower_id)?;
+    if borrower.guarantee_exposure.minor_units() == exposure.minor_units() {
+        return Ok(());
+    }
+
+    diesel::update(borrowers::table.find(borrower_id))
+        .set((
+            borrowers::guarantee_exposure_cents.eq(exposure.minor_units()),
+            borrowers::updated_at.eq(chrono::Utc::now().naive_utc()),
+        ))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+/// Recalcula el puntaje de crédito de un borrower a partir de sus préstamos, cuotas y pagos
+/// Se llama tras repartir los pagos y en la tarea diaria, que es cuando cambian los atrasos
+pub(crate) fn refresh_credit_score(
+    conn: &mut SqliteConnection,
+    borrower_id: i32,
+    now: NaiveDateTime,
+) -> Result<()> {
+    let loan_models = loans::table
+        .filter(loans::borrower_id.eq(borrower_id))
+        .filter(loans::deleted_at.is_null())
+        .order((loans::created_at.asc(), loans::id.asc()))
+        .load::<LoanModel>(conn)?;
+
+    let mut installments: HashMap<i32, Vec<LoanInstallment>> = HashMap::new();
+    let installment_rows = loan_installments::table
+        .inner_join(loans::table)
+        .filter(loans::borrower_id.eq(borrower_id))
+        .filter(loans::deleted_at.is_null())
+        .order((loan_installments::loan_id.asc(), loan_installments::number.asc()))
+        .select((LoanInstallmentModel::as_select(), loans::currency))
+        .load::<(LoanInstallmentModel, String)>(conn)?;
+    for (model, currency) in installment_rows {
+        installments.entry(model.loan_id).or_default().push(model.into_entity(&currency));
+    }
+
+    // Fecha del último pago recibido por cada préstamo
+    let last_payments: HashMap<i32, NaiveDateTime> = payment_allocations::table
+        .inner_join(loans::table)
+        .filter(loans::borrower_id.eq(borrower_id))
+        .group_by(payment_allocations::loan_id)
+        .select((payment_allocations::loan_id, diesel::dsl::max(payment_allocations::created_at)))
+        .load::<(i32, Option<NaiveDateTime>)>(conn)?
+        .into_iter()
+        .filter_map(|(loan_id, paid_at)| paid_at.map(|paid_at| (loan_id, paid_at)))
+        .collect();
+
+    let history: Vec<LoanHistory> = loan_models
+        .into_iter()
+        .map(|model| {
+            let loan: Loan = model.into();
+            let settled_at = if loan.remaining.is_positive() {
+                None
+            } else {
+                last_payments.get(&loan.id).copied()
+            };
+            LoanHistory {
+                installments: installments.remove(&loan.id).unwrap_or_default(),
+                settled_at,
+                loan,
+            }
+        })
+        .collect();
+
+    let factors = CreditScorer::assess(&history, now)?;
+
+    diesel::update(borrowers::table.find(borrower_id))
+        .set((
+            borrowers::credit_score.eq(CreditScorer::score(&factors)),
+            borrowers::credit_on_time_installments.eq(factors.on_time_installments),
+            borrowers::credit_late_installments.eq(factors.late_installments),
+            borrowers::credit_days_past_due.eq(factors.days_past_due),
+            borrowers::credit_restructures.eq(factors.restructures),
+            borrowers::credit_write_offs.eq(factors.write_offs),
+            borrowers::credit_score_updated_at.eq(Some(now)),
+        ))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+/// Estado de cobranza según los estados guardados de sus préstamos, como lo deja la tarea diaria
+fn loan_based_status(conn: &mut SqliteConnection, borrower_id: i32) -> Result<&'static str> {
+    let loan_rows = loans::table
+        .filter(loans::borrower_id.eq(borrower_id))
+        .filter(loans::deleted_at.is_null())
+        .select((loans::status, loans::remaining_cents))
+        .load::<(String, i64)>(conn)?;
+
+    let statuses: Vec<&str> = loan_rows
+        .iter()
+        .filter_map(|(status, remaining_cents)| match status.as_str() {
+            LOAN_RESTRUCTURED => None,
+            LOAN_WRITTEN_OFF if *remaining_cents <= 0 => Some(LOAN_PAID),
+            status => Some(status),
+        })
+        .collect();
+
+    Ok(DelinquencyPolicy::borrower_status(&statuses))
+}
+
+/// Consulta de borrowers con los filtros aplicados, sin orden ni paginación
+fn filtered_borrowers(filter: &BorrowerFilter) -> borrowers::BoxedQuery<'static, Sqlite> {
+    let mut query = borrowers::table.into_boxed();
+
+    query = if filter.deleted {
+        query.filter(borrowers::deleted_at.is_not_null())
+    } else {
+        query.filter(borrowers::deleted_at.is_null())
+    };
+
+    if let Some(search) = filter.search.as_deref().map(str::trim).filter(|search| !search.is_empty()) {
+        let pattern = format!("%{}%", escape_like(search));
+        query = query.filter(
+            borrowers::name.like(pattern.clone()).escape('\\')
+                .or(borrowers::phone.like(pattern.clone()).escape('\\'))
+                .or(borrowers::location.like(pattern).escape('\\')),
+        );
+    }
+    if let Some(status) = &filter.status {
+        query = query.filter(borrowers::status.eq(status.clone()));
+    }
+    if let Some(min_balance) = &filter.min_balance {
+        query = query.filter(borrowers::balance_cents.ge(min_balance.minor_units()));
+    }
+    if let Some(max_balance) = &filter.max_balance {
+        query = query.filter(borrowers::balance_cents.le(max_balance.minor_units()));
+    }
+    if let Some(created_from) = filter.created_from {
+        query = query.filter(borrowers::created_at.ge(created_from));
+    }
+    if let Some(created_to) = filter.created_to {
+        query = query.filter(borrowers::created_at.le(created_to));
+    }
+
+    query
+}
+
+/// Escapa los comodines de LIKE para que el texto buscado se compare literalmente
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+fn find_borrower(conn: &mut SqliteConnection, borrower_id: i32) -> Result<Borrower> {
+    Ok(borrowers::table
+        .find(borrower_id)
+        .first::<BorrowerModel>(conn)
+        .optional()?
+        .ok_or_else(|| AppError::NotFound(format!(
+            "Prestatario con ID {} no encontrado",
+            borrower_id
+        )))?
+        .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::r2d2::{ConnectionManager, Pool};
+    use diesel_migrations::MigrationHarness;
+
+    use super::*;
+    use crate::server::domain::entities::loan::{InterestTerms, NewLoan};
+    use crate::server::domain::repositories::loan_repository::LoanRepository;
+    use crate::server::infrastructure::database::connection::MIGRATIONS;
+    use crate::server::infrastructure::database::repositories::diesel_loan_repository::DieselLoanRepository;
+
+    /// Base de datos en memoria con todas las migraciones; una sola conexión para no perderla
+    fn test_pool() -> DbPool {
+        let pool = Pool::builder()
+            .max_size(1)
+            .build(ConnectionManager::<SqliteConnection>::new(":memory:"))
+            .unwrap();
+        pool.get().unwrap().run_pending_migrations(MIGRATIONS).unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn delete_is_refused_while_the_borrower_has_open_loans() {
+        let pool = test_pool();
+        let borrower_repository = DieselBorrowerRepository::new(pool.clone());
+        let loan_repository = DieselLoanRepository::new(pool);
+
+        let borrower = borrower_repository
+            .create(&NewBorrower {
+                phone: "55555555".to_string(),
+                name: "Juan".to_string(),
+                location: "Habana".to_string(),
+            })
+            .await
+            .unwrap();
+        let new_loan = NewLoan::new(
+            Money::from_minor(100000, BASE_CURRENCY),
+            1.0,
+            InterestTerms::none(),
+            borrower.id,
+            chrono::Utc::now().naive_utc(),
+        )
+        .unwrap();
+        let loan = loan_repository.create(&new_loan).await.unwrap();
+
+        let result = borrower_repository.delete(borrower.id, 1).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+        assert!(borrower_repository.find_by_id(borrower.id).await.unwrap().is_some());
+
+        // Sin préstamos abiertos ni saldo ya se puede eliminar
+        loan_repository.delete(loan.id, 1).await.unwrap();
+        borrower_repository.delete(borrower.id, 1).await.unwrap();
+        assert!(borrower_repository.find_by_id(borrower.id).await.unwrap().is_none());
+    }
+}
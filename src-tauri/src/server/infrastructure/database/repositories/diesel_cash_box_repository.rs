@@ -0,0 +1,352 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use crate::server::domain::entities::cash_box::{
+    CashBalance, CashBoxLine, CashCount, CashExchange, CashExchangeFilter, CashMovement, NewCashCount,
+    NewCashExchange, NewCashMovement, CASH_OPENING, CASH_WITHDRAWAL,
+};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::ledger::cash_account;
+use crate::server::domain::repositories::cash_box_repository::CashBoxRepository;
+use crate::server::domain::services::journal_poster::JournalPoster;
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::models::cash_box::{
+    CashCountModel, CashExchangeModel, CashMovementModel, NewCashCountModel, NewCashExchangeModel,
+    NewCashMovementModel,
+};
+use crate::server::infrastructure::database::models::ledger::JournalEntryModel;
+use crate::server::infrastructure::database::repositories::diesel_ledger_repository::{cash_balance, post_entry};
+use crate::server::infrastructure::database::schema::{
+    accounts, cash_counts, cash_exchanges, cash_movements, journal_entries, journal_lines,
+};
+use crate::server::infrastructure::database::connection::DbPool;
+use crate::utils::error::{AppError, Result};
+
+/// Implementación concreta de las cajas usando Diesel con SQLite
+/// Cada movimiento manual, arqueo o cambio de moneda se guarda en la misma transacción que su asiento
+#[derive(Clone)]
+pub struct DieselCashBoxRepository {
+    pool: DbPool,
+}
+
+impl DieselCashBoxRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CashBoxRepository for DieselCashBoxRepository {
+    async fn find_balance(&self, currency: &str, at: NaiveDateTime) -> Result<CashBalance> {
+        let mut conn = self.pool.get()?;
+        cash_balance(&mut conn, currency, at)
+    }
+
+    /// El saldo de cada línea arranca en el de la caja antes de `from`
+    async fn find_lines(&self, currency: &str, from: Option<NaiveDateTime>, to: NaiveDateTime) -> Result<Vec<CashBoxLine>> {
+        let mut conn = self.pool.get()?;
+
+        let rows = journal_lines::table
+            .inner_join(journal_entries::table)
+            .inner_join(accounts::table)
+            .filter(accounts::code.eq(cash_account(currency)))
+            .filter(journal_entries::posted_at.le(to))
+            .order((journal_entries::posted_at.asc(), journal_entries::id.asc(), journal_lines::id.asc()))
+            .select((
+                JournalEntryModel::as_select(),
+                journal_lines::debit_cents,
+                journal_lines::credit_cents,
+                journal_lines::amount_cents,
+            ))
+            .load::<(JournalEntryModel, i64, i64, i64)>(&mut conn)?;
+
+        let mut balance = Money::zero(currency);
+        let mut lines = Vec::new();
+        for (entry, debit, credit, amount) in rows {
+            let amount = Money::from_minor(amount, currency);
+            balance = balance.checked_add(&amount)?;
+            if from.is_some_and(|from| entry.posted_at < from) {
+                continue;
+            }
+
+            lines.push(CashBoxLine {
+                entry_id: entry.id,
+                event: entry.event,
+                description: entry.description,
+                borrower_id: entry.borrower_id,
+                loan_id: entry.loan_id,
+                payment_id: entry.payment_id,
+                posted_at: entry.posted_at.and_utc().to_rfc3339(),
+                amount,
+                amount_cup: Money::from_minor(debit, BASE_CURRENCY)
+                    .checked_sub(&Money::from_minor(credit, BASE_CURRENCY))?,
+                balance: balance.clone(),
+            });
+        }
+
+        Ok(lines)
+    }
+
+    async fn find_opening(&self, currency: &str) -> Result<Option<CashMovement>> {
+        let mut conn = self.pool.get()?;
+
+        let movement_model = cash_movements::table
+            .filter(cash_movements::currency.eq(currency))
+            .filter(cash_movements::kind.eq(CASH_OPENING))
+            .first::<CashMovementModel>(&mut conn)
+            .optional()?;
+
+        Ok(movement_model.map(|model| model.into()))
+    }
+
+    async fn find_counts(&self, currency: &str) -> Result<Vec<CashCount>> {
+        let mut conn = self.pool.get()?;
+
+        let count_models = cash_counts::table
+            .filter(cash_counts::currency.eq(currency))
+            .order((cash_counts::counted_at.desc(), cash_counts::id.desc()))
+            .load::<CashCountModel>(&mut conn)?;
+
+        Ok(count_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn record_movement(&self, movement: &NewCashMovement) -> Result<CashMovement> {
+        let mut conn = self.pool.get()?;
+        let currency = movement.amount.currency().to_string();
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            if movement.kind == CASH_OPENING {
+                let opened = cash_movements::table
+                    .filter(cash_movements::currency.eq(&currency))
+                    .filter(cash_movements::kind.eq(CASH_OPENING))
+                    .count()
+                    .get_result::<i64>(conn)?;
+                if opened > 0 {
+                    return Err(AppError::Conflict(format!("La caja {} ya tiene saldo de apertura", currency)));
+                }
+            }
+
+            if movement.kind == CASH_WITHDRAWAL {
+                ensure_available(conn, &movement.amount, movement.moved_at)?;
+            }
+
+            let entry_id = post_entry(conn, &JournalPoster::cash_movement(movement)?)?
+                .ok_or_else(|| AppError::ValidationError(vec!["El monto debe ser de al menos 0.01".to_string()]))?;
+
+            diesel::insert_into(cash_movements::table)
+                .values(&NewCashMovementModel {
+                    currency: currency.clone(),
+                    kind: movement.kind.to_string(),
+                    amount_cents: movement.amount.minor_units(),
+                    exchange_rate: movement.exchange_rate,
+                    amount_cup_cents: movement.amount_cup.minor_units(),
+                    reason: movement.reason.clone(),
+                    entry_id,
+                    created_by: movement.created_by,
+                    moved_at: movement.moved_at,
+                })
+                .execute(conn)?;
+
+            // Dentro de la transacción el último id insertado es el nuestro
+            let movement_model = cash_movements::table
+                .order(cash_movements::id.desc())
+                .first::<CashMovementModel>(conn)?;
+
+            Ok(movement_model.into())
+        })
+    }
+
+    async fn record_count(&self, count: &NewCashCount) -> Result<CashCount> {
+        let mut conn = self.pool.get()?;
+        let currency = count.counted.currency().to_string();
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let expected = cash_balance(conn, &currency, count.counted_at)?.balance;
+            let difference = count.counted.checked_sub(&expected)?;
+            let difference_cup = difference.convert(count.exchange_rate, BASE_CURRENCY)?;
+
+            let entry_id = if difference.minor_units() == 0 {
+                None
+            } else {
+                post_entry(conn, &JournalPoster::cash_count(&difference, &difference_cup, count.counted_at)?)?
+            };
+
+            diesel::insert_into(cash_counts::table)
+                .values(&NewCashCountModel {
+                    currency: currency.clone(),
+                    expected_cents: expected.minor_units(),
+                    counted_cents: count.counted.minor_units(),
+                    difference_cents: difference.minor_units(),
+                    exchange_rate: count.exchange_rate,
+                    difference_cup_cents: difference_cup.minor_units(),
+                    notes: count.notes.clone(),
+                    entry_id,
+                    counted_by: count.counted_by,
+                    counted_at: count.counted_at,
+                })
+                .execute(conn)?;
+
+            let count_model = cash_counts::table
+                .order(cash_counts::id.desc())
+                .first::<CashCountModel>(conn)?;
+
+            Ok(count_model.into())
+        })
+    }
+
+    async fn find_exchanges(&self, filter: &CashExchangeFilter) -> Result<Vec<CashExchange>> {
+        let mut conn = self.pool.get()?;
+
+        let mut query = cash_exchanges::table
+            .order((cash_exchanges::exchanged_at.asc(), cash_exchanges::id.asc()))
+            .into_boxed();
+        if let Some(currency) = &filter.currency {
+            query = query.filter(
+                cash_exchanges::source_currency
+                    .eq(currency.clone())
+                    .or(cash_exchanges::target_currency.eq(currency.clone())),
+            );
+        }
+        if let Some(from) = filter.from {
+            query = query.filter(cash_exchanges::exchanged_at.ge(from));
+        }
+        if let Some(to) = filter.to {
+            query = query.filter(cash_exchanges::exchanged_at.le(to));
+        }
+
+        let exchange_models = query.load::<CashExchangeModel>(&mut conn)?;
+
+        Ok(exchange_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Lo entregado sale de su caja por la parte proporcional de su valor en libros
+    async fn record_exchange(&self, exchange: &NewCashExchange) -> Result<CashExchange> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            ensure_available(conn, &exchange.source, exchange.exchanged_at)?;
+
+            let available = cash_balance(conn, exchange.source.currency(), exchange.exchanged_at)?;
+            let source_cost_cup = book_cost(&available, &exchange.source)?;
+            let gain_cup = exchange.target_cup.checked_sub(&source_cost_cup)?;
+
+            let entry_id = post_entry(conn, &JournalPoster::cash_exchange(
+                &exchange.source,
+                &source_cost_cup,
+                &exchange.target,
+                &exchange.target_cup,
+                exchange.exchanged_at,
+            )?)?
+            .ok_or_else(|| AppError::ValidationError(vec!["El monto debe ser de al menos 0.01".to_string()]))?;
+
+            diesel::insert_into(cash_exchanges::table)
+                .values(&NewCashExchangeModel {
+                    source_currency: exchange.source.currency().to_string(),
+                    source_amount_cents: exchange.source.minor_units(),
+                    target_currency: exchange.target.currency().to_string(),
+                    target_amount_cents: exchange.target.minor_units(),
+                    effective_rate: exchange.effective_rate,
+                    official_rate: exchange.official_rate,
+                    spread: exchange.spread,
+                    source_cost_cup_cents: source_cost_cup.minor_units(),
+                    target_cup_cents: exchange.target_cup.minor_units(),
+                    spread_cup_cents: exchange.spread_cup.minor_units(),
+                    gain_cup_cents: gain_cup.minor_units(),
+                    notes: exchange.notes.clone(),
+                    entry_id,
+                    created_by: exchange.created_by,
+                    exchanged_at: exchange.exchanged_at,
+                })
+                .execute(conn)?;
+
+            let exchange_model = cash_exchanges::table
+                .order(cash_exchanges::id.desc())
+                .first::<CashExchangeModel>(conn)?;
+
+            Ok(exchange_model.into())
+        })
+    }
+}
+
+/// Lo que sale de una caja no puede superar lo que había en la fecha del movimiento ni lo que hay hoy
+fn ensure_available(conn: &mut SqliteConnection, amount: &Money, at: NaiveDateTime) -> Result<()> {
+    let now = chrono::Utc::now().naive_utc();
+    for at in [at, now] {
+        let available = cash_balance(conn, amount.currency(), at)?.balance;
+        if amount.minor_units() > available.minor_units() {
+            return Err(AppError::Conflict(format!(
+                "La caja {} solo tiene {:.2} disponibles",
+                amount.currency(),
+                available.to_major()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Valor en libros de `amount` según el de toda la caja: su parte proporcional, redondeada al centavo
+/// Si sale todo lo que hay, su valor es exactamente el de la caja
+fn book_cost(available: &CashBalance, amount: &Money) -> Result<Money> {
+    if available.balance.minor_units() <= 0 {
+        return Err(AppError::Conflict(format!("La caja {} está vacía", amount.currency())));
+    }
+    if amount.minor_units() == available.balance.minor_units() {
+        return Ok(available.balance_cup.clone());
+    }
+
+    let balance = i128::from(available.balance.minor_units());
+    let scaled = i128::from(amount.minor_units()) * i128::from(available.balance_cup.minor_units());
+    let cost = (2 * scaled + balance * scaled.signum()) / (2 * balance);
+    let cost = i64::try_from(cost)
+        .map_err(|_| AppError::DatabaseError("Desbordamiento al valorar la caja".to_string()))?;
+
+    Ok(Money::from_minor(cost, BASE_CURRENCY))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd_box(balance: i64, balance_cup: i64) -> CashBalance {
+        CashBalance {
+            balance: Money::from_minor(balance, "USD"),
+            balance_cup: Money::from_minor(balance_cup, BASE_CURRENCY),
+        }
+    }
+
+    fn book_cost_of(available: &CashBalance, amount: i64) -> i64 {
+        book_cost(available, &Money::from_minor(amount, "USD")).unwrap().minor_units()
+    }
+
+    #[test]
+    fn book_cost_is_the_proportional_share_rounded_half_away_from_zero() {
+        let available = usd_box(300, 100);
+
+        assert_eq!(book_cost_of(&available, 100), 33);
+        assert_eq!(book_cost_of(&available, 200), 67);
+        assert_eq!(book_cost_of(&usd_box(200, 1), 100), 1);
+        assert_eq!(book_cost_of(&usd_box(200, -1), 100), -1);
+    }
+
+    #[test]
+    fn book_cost_of_the_whole_box_is_its_exact_book_value() {
+        let available = usd_box(300, 100);
+        assert_eq!(book_cost_of(&available, 300), 100);
+
+        // Sacar la caja en partes no deja centavos huérfanos si la última se lleva lo que queda
+        let first = book_cost_of(&available, 100);
+        assert_eq!(first + book_cost_of(&usd_box(200, 100 - first), 200), 100);
+    }
+
+    #[test]
+    fn book_cost_handles_large_balances_without_overflow() {
+        let available = usd_box(i64::MAX / 2, i64::MAX / 2);
+        assert_eq!(book_cost_of(&available, 1_000_000), 1_000_000);
+    }
+
+    #[test]
+    fn book_cost_of_an_empty_box_is_a_conflict() {
+        let result = book_cost(&usd_box(0, 0), &Money::from_minor(100, "USD"));
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+}
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use crate::server::domain::entities::collateral::{
+    Collateral, NewCollateral, COLLATERAL_HELD, COLLATERAL_LIQUIDATED, COLLATERAL_RETURNED,
+};
+use crate::server::domain::entities::payment::NewPayment;
+use crate::server::domain::repositories::collateral_repository::CollateralRepository;
+use crate::server::infrastructure::database::models::collateral::{CollateralModel, NewCollateralModel};
+use crate::server::infrastructure::database::repositories::diesel_payment_repository::insert_payment;
+use crate::server::infrastructure::database::schema::loan_collaterals;
+use crate::server::infrastructure::database::connection::DbPool;
+use crate::utils::error::{AppError, Result};
+
+/// Implementación concreta del repositorio de garantías usando Diesel con SQLite
+#[derive(Clone)]
+pub struct DieselCollateralRepository {
+    pool: DbPool,
+}
+
+impl DieselCollateralRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CollateralRepository for DieselCollateralRepository {
+    /// Registrar una garantía en custodia
+    async fn create(&self, new_collateral: &NewCollateral) -> Result<Collateral> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let new_collateral_model: NewCollateralModel = new_collateral.clone().into();
+
+            diesel::insert_into(loan_collaterals::table)
+                .values(&new_collateral_model)
+                .execute(conn)?;
+
+            // Dentro de la transacción el último id insertado es el nuestro
+            let collateral_model = loan_collaterals::table
+                .order(loan_collaterals::id.desc())
+                .first::<CollateralModel>(conn)?;
+
+            Ok(collateral_model.into())
+        })
+    }
+
+    /// Obtener todas las garantías, las más recientes primero
+    async fn find_all(&self) -> Result<Vec<Collateral>> {
+        let mut conn = self.pool.get()?;
+
+        let collateral_models = loan_collaterals::table
+            .order((loan_collaterals::created_at.desc(), loan_collaterals::id.desc()))
+            .load::<CollateralModel>(&mut conn)?;
+
+        Ok(collateral_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Buscar una garantía por su ID
+    async fn find_by_id(&self, id: i32) -> Result<Option<Collateral>> {
+        let mut conn = self.pool.get()?;
+
+        let collateral_model = loan_collaterals::table
+            .find(id)
+            .first::<CollateralModel>(&mut conn)
+            .optional()?;
+
+        Ok(collateral_model.map(|model| model.into()))
+    }
+
+    /// Marcar una garantía en custodia como devuelta al prestatario
+    async fn mark_returned(&self, id: i32, returned_at: NaiveDateTime) -> Result<Collateral> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing = loan_collaterals::table.find(id).first::<CollateralModel>(conn)?;
+            if existing.status != COLLATERAL_HELD {
+                return Err(AppError::Conflict("La garantía ya no está en custodia".to_string()));
+            }
+
+            diesel::update(loan_collaterals::table.find(id))
+                .set((
+                    loan_collaterals::status.eq(COLLATERAL_RETURNED),
+                    loan_collaterals::returned_at.eq(returned_at),
+                    loan_collaterals::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            let collateral_model = loan_collaterals::table.find(id).first::<CollateralModel>(conn)?;
+            Ok(collateral_model.into())
+        })
+    }
+
+    /// Liquidar una garantía: lo obtenido entra como pago del borrower, que se reparte
+    /// de nuevo con los totales del borrower, y la garantía queda ligada a ese pago
+    async fn liquidate(&self, id: i32, proceeds: &NewPayment) -> Result<Collateral> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing = loan_collaterals::table.find(id).first::<CollateralModel>(conn)?;
+            if existing.status != COLLATERAL_HELD {
+                return Err(AppError::Conflict("La garantía ya no está en custodia".to_string()));
+            }
+
+            let payment_model = insert_payment(conn, proceeds)?;
+
+            diesel::update(loan_collaterals::table.find(id))
+                .set((
+                    loan_collaterals::status.eq(COLLATERAL_LIQUIDATED),
+                    loan_collaterals::payment_id.eq(payment_model.id),
+                    loan_collaterals::liquidated_at.eq(proceeds.created_at),
+                    loan_collaterals::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            let collateral_model = loan_collaterals::table.find(id).first::<CollateralModel>(conn)?;
+            Ok(collateral_model.into())
+        })
+    }
+}
@@ -0,0 +1,267 @@
+use async_trait::async_trait;
+use diesel::prelude::*;
+use chrono::NaiveDateTime;
+use crate::server::domain::entities::currency::{Currency, NewCurrency};
+use crate::server::domain::entities::exchange_rate::{ExchangeRate, NewExchangeRate};
+use crate::server::domain::repositories::currency_repository::CurrencyRepository;
+use crate::server::infrastructure::database::models::currency::{CurrencyModel, NewCurrencyModel};
+use crate::server::infrastructure::database::models::exchange_rate::{ExchangeRateModel, NewExchangeRateModel};
+use crate::server::infrastructure::database::schema::{currencies, exchange_rate_history, loan_products, loans, payments};
+use crate::server::infrastructure::database::connection::DbPool;
+use crate::utils::error::{AppError, Result};
+
+/// Implementación concreta del repositorio de monedas usando Diesel con SQLite
+#[derive(Clone)]
+pub struct DieselCurrencyRepository {
+    pool: DbPool,
+}
+
+impl DieselCurrencyRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CurrencyRepository for DieselCurrencyRepository {
+    /// Registrar una nueva moneda y abrir su historial de tasas
+    async fn create(&self, new_currency: &NewCurrency) -> Result<Currency> {
+        let mut conn = self.pool.get()?;
+
+        let new_currency_model: NewCurrencyModel = new_currency.clone().into();
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            diesel::insert_into(currencies::table)
+                .values(&new_currency_model)
+                .execute(conn)?;
+
+            insert_rate_history(
+                conn,
+                &new_currency_model.code,
+                new_currency_model.exchange_rate,
+                chrono::Utc::now().naive_utc(),
+            )?;
+
+            // Obtener la moneda recién insertada por su código (campo único)
+            let currency_model = currencies::table
+                .filter(currencies::code.eq(&new_currency_model.code))
+                .first::<CurrencyModel>(conn)?;
+
+            Ok(currency_model.into())
+        })
+    }
+
+    /// Obtener todas las monedas ordenadas por código
+    async fn find_all(&self) -> Result<Vec<Currency>> {
+        let mut conn = self.pool.get()?;
+
+        let currency_models = currencies::table
+            .order(currencies::code.asc())
+            .load::<CurrencyModel>(&mut conn)?;
+
+        Ok(currency_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Buscar una moneda por su ID
+    async fn find_by_id(&self, id: i32) -> Result<Option<Currency>> {
+        let mut conn = self.pool.get()?;
+
+        let currency_model = currencies::table
+            .find(id)
+            .first::<CurrencyModel>(&mut conn)
+            .optional()?;
+
+        Ok(currency_model.map(|model| model.into()))
+    }
+
+    /// Buscar una moneda por su código
+    async fn find_by_code(&self, code: &str) -> Result<Option<Currency>> {
+        let mut conn = self.pool.get()?;
+
+        let currency_model = currencies::table
+            .filter(currencies::code.eq(code))
+            .first::<CurrencyModel>(&mut conn)
+            .optional()?;
+
+        Ok(currency_model.map(|model| model.into()))
+    }
+
+    /// Actualizar una moneda existente
+    /// Un cambio de tasa se registra en el historial como vigente desde ahora
+    async fn update(&self, id: i32, currency: &Currency) -> Result<Currency> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing = currencies::table.find(id).first::<CurrencyModel>(conn)?;
+
+            if existing.code != currency.code {
+                diesel::update(
+                    exchange_rate_history::table
+                        .filter(exchange_rate_history::currency_code.eq(&existing.code)),
+                )
+                .set(exchange_rate_history::currency_code.eq(&currency.code))
+                .execute(conn)?;
+            }
+
+            diesel::update(currencies::table.find(id))
+                .set((
+                    currencies::code.eq(&currency.code),
+                    currencies::name.eq(&currency.name),
+                    currencies::exchange_rate.eq(currency.exchange_rate),
+                    currencies::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            if existing.exchange_rate != currency.exchange_rate {
+                insert_rate_history(conn, &currency.code, currency.exchange_rate, chrono::Utc::now().naive_utc())?;
+            }
+
+            let updated_currency = currencies::table
+                .find(id)
+                .first::<CurrencyModel>(conn)?;
+
+            Ok(updated_currency.into())
+        })
+    }
+
+    /// Eliminar una moneda por su ID junto con su historial de tasas
+    async fn delete(&self, id: i32) -> Result<()> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing = currencies::table.find(id).first::<CurrencyModel>(conn)?;
+
+            diesel::delete(
+                exchange_rate_history::table
+                    .filter(exchange_rate_history::currency_code.eq(&existing.code)),
+            )
+            .execute(conn)?;
+
+            diesel::delete(currencies::table.find(id))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    /// Verificar si existe otra moneda con el código dado
+    async fn exists_by_code_excluding_id(&self, code: &str, excluded_id: i32) -> Result<bool> {
+        let mut conn = self.pool.get()?;
+
+        let exists = diesel::select(diesel::dsl::exists(
+            currencies::table
+                .filter(currencies::code.eq(code))
+                .filter(currencies::id.ne(excluded_id))
+        ))
+        .get_result(&mut conn)?;
+
+        Ok(exists)
+    }
+
+    /// Verificar si algún préstamo o pago usa la moneda
+    async fn is_in_use(&self, code: &str) -> Result<bool> {
+        let mut conn = self.pool.get()?;
+
+        let used_by_loans: bool = diesel::select(diesel::dsl::exists(
+            loans::table.filter(loans::currency.eq(code))
+        ))
+        .get_result(&mut conn)?;
+
+        let used_by_payments: bool = diesel::select(diesel::dsl::exists(
+            payments::table.filter(payments::currency.eq(code))
+        ))
+        .get_result(&mut conn)?;
+
+        let used_by_products: bool = diesel::select(diesel::dsl::exists(
+            loan_products::table.filter(loan_products::currency.eq(code))
+        ))
+        .get_result(&mut conn)?;
+
+        Ok(used_by_loans || used_by_payments || used_by_products)
+    }
+
+    /// Registrar una tasa en el historial (puede ser retroactiva)
+    /// y sincronizar la tasa vigente de la moneda
+    async fn record_rate(&self, new_rate: &NewExchangeRate) -> Result<ExchangeRate> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let rate_model = insert_rate_history(
+                conn,
+                &new_rate.currency_code,
+                new_rate.exchange_rate,
+                new_rate.effective_from,
+            )?;
+
+            let current_rate = find_rate_model_at(conn, &new_rate.currency_code, chrono::Utc::now().naive_utc())?;
+            if let Some(current_rate) = current_rate {
+                diesel::update(currencies::table.filter(currencies::code.eq(&new_rate.currency_code)))
+                    .set((
+                        currencies::exchange_rate.eq(current_rate.exchange_rate),
+                        currencies::updated_at.eq(chrono::Utc::now().naive_utc()),
+                    ))
+                    .execute(conn)?;
+            }
+
+            Ok(rate_model.into())
+        })
+    }
+
+    /// Buscar la tasa vigente de una moneda en una fecha dada
+    async fn find_rate_at(&self, code: &str, at: NaiveDateTime) -> Result<Option<ExchangeRate>> {
+        let mut conn = self.pool.get()?;
+
+        let rate_model = find_rate_model_at(&mut conn, code, at)?;
+
+        Ok(rate_model.map(|model| model.into()))
+    }
+
+    /// Obtener el historial de tasas de una moneda, las más recientes primero
+    async fn find_rate_history(&self, code: &str) -> Result<Vec<ExchangeRate>> {
+        let mut conn = self.pool.get()?;
+
+        let rate_models = exchange_rate_history::table
+            .filter(exchange_rate_history::currency_code.eq(code))
+            .order((exchange_rate_history::effective_from.desc(), exchange_rate_history::id.desc()))
+            .load::<ExchangeRateModel>(&mut conn)?;
+
+        Ok(rate_models.into_iter().map(|model| model.into()).collect())
+    }
+}
+
+/// Inserta una tasa en el historial dentro de la conexión (o transacción) recibida
+fn insert_rate_history(
+    conn: &mut SqliteConnection,
+    code: &str,
+    exchange_rate: f32,
+    effective_from: NaiveDateTime,
+) -> Result<ExchangeRateModel> {
+    let new_rate_model: NewExchangeRateModel = NewExchangeRate::new(code.to_string(), exchange_rate, effective_from).into();
+
+    diesel::insert_into(exchange_rate_history::table)
+        .values(&new_rate_model)
+        .execute(conn)?;
+
+    // Dentro de la transacción el último id insertado es el nuestro
+    let rate_model = exchange_rate_history::table
+        .order(exchange_rate_history::id.desc())
+        .first::<ExchangeRateModel>(conn)?;
+
+    Ok(rate_model)
+}
+
+/// Última tasa registrada con `effective_from <= at`; ante empate gana la registrada después
+fn find_rate_model_at(
+    conn: &mut SqliteConnection,
+    code: &str,
+    at: NaiveDateTime,
+) -> Result<Option<ExchangeRateModel>> {
+    let rate_model = exchange_rate_history::table
+        .filter(exchange_rate_history::currency_code.eq(code))
+        .filter(exchange_rate_history::effective_from.le(at))
+        .order((exchange_rate_history::effective_from.desc(), exchange_rate_history::id.desc()))
+        .first::<ExchangeRateModel>(conn)
+        .optional()?;
+
+    Ok(rate_model)
+}
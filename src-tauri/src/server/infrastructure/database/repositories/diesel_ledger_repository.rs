@@ -0,0 +1,808 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use crate::server::domain::entities::cash_box::CashBalance;
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::ledger::{
+    cash_account, AccountBalance, BorrowerReceivable, JournalEntry, JournalFilter, JournalLine, NewJournalEntry,
+    NewJournalLine, PaymentSplit, ACCOUNT_ASSET, ACCOUNT_CASH, ACCOUNT_EXPENSE, ACCOUNT_INTEREST_RECEIVABLE,
+    ACCOUNT_PENALTIES_RECEIVABLE, EVENT_INTEREST, EVENT_PAYMENT, EVENT_RECOVERY, EVENT_WRITE_OFF, RECEIVABLE_ACCOUNTS,
+};
+use crate::server::domain::entities::loan::Loan;
+use crate::server::domain::entities::loan_installment::LoanInstallment;
+use crate::server::domain::entities::payment::Payment;
+use crate::server::domain::entities::penalty::{Penalty, PENALTY_CAPITALIZED, PENALTY_WAIVED};
+use crate::server::domain::repositories::ledger_repository::LedgerRepository;
+use crate::server::domain::services::journal_poster::JournalPoster;
+use crate::server::domain::services::payment_allocator::PaymentAllocator;
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::models::ledger::{
+    AccountModel, JournalEntryModel, JournalLineModel, NewAccountModel, NewJournalEntryModel, NewJournalLineModel,
+};
+use crate::server::infrastructure::database::models::loan::LoanModel;
+use crate::server::infrastructure::database::models::loan_installment::LoanInstallmentModel;
+use crate::server::infrastructure::database::models::payment::PaymentModel;
+use crate::server::infrastructure::database::models::payment_allocation::PaymentAllocationModel;
+use crate::server::infrastructure::database::models::penalty::PenaltyModel;
+use crate::server::infrastructure::database::schema::{
+    accounts, borrowers, journal_entries, journal_lines, loan_installments, loans, payment_allocations, payments,
+    penalties,
+};
+use crate::server::infrastructure::database::connection::DbPool;
+use crate::server::infrastructure::database::repositories::diesel_borrower_repository::refresh_borrower_totals;
+use crate::utils::date::parse_stored_date;
+use crate::utils::error::{AppError, Result};
+
+/// Implementación concreta del libro mayor usando Diesel con SQLite
+/// Los asientos nunca se modifican ni se borran
+#[derive(Clone)]
+pub struct DieselLedgerRepository {
+    pool: DbPool,
+}
+
+impl DieselLedgerRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerRepository for DieselLedgerRepository {
+    /// Saldos de todas las cuentas con los asientos hasta `at`, en el orden del plan de cuentas
+    async fn find_balances(&self, at: NaiveDateTime) -> Result<Vec<AccountBalance>> {
+        let mut conn = self.pool.get()?;
+
+        let account_models = accounts::table
+            .order(accounts::code.asc())
+            .load::<AccountModel>(&mut conn)?;
+
+        let line_rows = journal_lines::table
+            .inner_join(journal_entries::table)
+            .filter(journal_entries::posted_at.le(at))
+            .select((
+                journal_lines::account_id,
+                journal_lines::debit_cents,
+                journal_lines::credit_cents,
+                journal_lines::amount_cents,
+            ))
+            .load::<(i32, i64, i64, i64)>(&mut conn)?;
+
+        let mut totals: HashMap<i32, (i64, i64, i64)> = HashMap::new();
+        for (account_id, debit, credit, amount) in line_rows {
+            let total = totals.entry(account_id).or_default();
+            total.0 = total.0.checked_add(debit).ok_or_else(overflow)?;
+            total.1 = total.1.checked_add(credit).ok_or_else(overflow)?;
+            total.2 = total.2.checked_add(amount).ok_or_else(overflow)?;
+        }
+
+        let mut balances = Vec::with_capacity(account_models.len());
+        for model in account_models {
+            let (debit, credit, amount) = totals.remove(&model.id).unwrap_or_default();
+            let debit = Money::from_minor(debit, BASE_CURRENCY);
+            let credit = Money::from_minor(credit, BASE_CURRENCY);
+            let amount = Money::from_minor(amount, model.currency.as_str());
+
+            // Activos y gastos tienen saldo deudor; pasivos, patrimonio e ingresos, acreedor
+            let (balance_cup, balance) = if model.kind == ACCOUNT_ASSET || model.kind == ACCOUNT_EXPENSE {
+                (debit.checked_sub(&credit)?, amount)
+            } else {
+                (credit.checked_sub(&debit)?, amount.checked_neg()?)
+            };
+
+            balances.push(AccountBalance {
+                account: model.into(),
+                debit,
+                credit,
+                balance_cup,
+                balance,
+            });
+        }
+
+        Ok(balances)
+    }
+
+    /// Asientos en orden cronológico; con `borrower_id` los que tocan las cuentas del prestatario
+    async fn find_entries(&self, filter: &JournalFilter) -> Result<Vec<JournalEntry>> {
+        let mut conn = self.pool.get()?;
+
+        let mut query = journal_entries::table
+            .order((journal_entries::posted_at.asc(), journal_entries::id.asc()))
+            .into_boxed();
+        if let Some(borrower_id) = filter.borrower_id {
+            query = query.filter(
+                journal_entries::borrower_id.eq(borrower_id).or(journal_entries::id.eq_any(
+                    journal_lines::table
+                        .filter(journal_lines::borrower_id.eq(borrower_id))
+                        .select(journal_lines::entry_id),
+                )),
+            );
+        }
+        if let Some(loan_id) = filter.loan_id {
+            query = query.filter(journal_entries::loan_id.eq(loan_id));
+        }
+        if let Some(event) = &filter.event {
+            query = query.filter(journal_entries::event.eq(event.clone()));
+        }
+        if let Some(from) = filter.from {
+            query = query.filter(journal_entries::posted_at.ge(from));
+        }
+        if let Some(to) = filter.to {
+            query = query.filter(journal_entries::posted_at.le(to));
+        }
+
+        let entry_models = query.load::<JournalEntryModel>(&mut conn)?;
+        with_lines(&mut conn, entry_models)
+    }
+
+    /// Saldo por cobrar de cada prestatario según el libro mayor, eliminados incluidos
+    async fn find_receivables(&self) -> Result<Vec<BorrowerReceivable>> {
+        let mut conn = self.pool.get()?;
+
+        let borrower_rows = borrowers::table
+            .order(borrowers::id.asc())
+            .select((borrowers::id, borrowers::name, borrowers::balance_cents))
+            .load::<(i32, String, i64)>(&mut conn)?;
+
+        let line_rows = journal_lines::table
+            .inner_join(accounts::table)
+            .filter(accounts::code.eq_any(RECEIVABLE_ACCOUNTS))
+            .filter(journal_lines::borrower_id.is_not_null())
+            .select((journal_lines::borrower_id, journal_lines::debit_cents, journal_lines::credit_cents))
+            .load::<(Option<i32>, i64, i64)>(&mut conn)?;
+
+        let mut ledger_balances: HashMap<i32, Money> = HashMap::new();
+        for (borrower_id, debit, credit) in line_rows {
+            let Some(borrower_id) = borrower_id else { continue };
+            let balance = ledger_balances
+                .entry(borrower_id)
+                .or_insert_with(|| Money::zero(BASE_CURRENCY));
+            *balance = balance
+                .checked_add(&Money::from_minor(debit, BASE_CURRENCY))?
+                .checked_sub(&Money::from_minor(credit, BASE_CURRENCY))?;
+        }
+
+        Ok(borrower_rows
+            .into_iter()
+            .map(|(borrower_id, name, balance_cents)| BorrowerReceivable {
+                borrower_id,
+                name,
+                ledger_balance: ledger_balances
+                    .remove(&borrower_id)
+                    .unwrap_or_else(|| Money::zero(BASE_CURRENCY)),
+                stored_balance: Money::from_minor(balance_cents, BASE_CURRENCY),
+            })
+            .collect())
+    }
+
+    /// Registrar un asiento que no depende de otro registro
+    async fn post(&self, entry: &NewJournalEntry) -> Result<JournalEntry> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let entry_id = post_entry(conn, entry)?
+                .ok_or_else(|| AppError::Conflict("El asiento no tiene movimientos".to_string()))?;
+
+            let entry_model = journal_entries::table.find(entry_id).first::<JournalEntryModel>(conn)?;
+            Ok(with_lines(conn, vec![entry_model])?.remove(0))
+        })
+    }
+}
+
+/// Registra un asiento dentro de la conexión (o transacción) recibida y devuelve su ID
+/// Las partidas en cero se omiten y un asiento sin movimientos no se registra
+/// La caja de una moneda que todavía no tiene cuenta se abre al registrar su primer movimiento
+pub(crate) fn post_entry(conn: &mut SqliteConnection, entry: &NewJournalEntry) -> Result<Option<i32>> {
+    JournalPoster::ensure_balanced(entry)?;
+
+    let lines: Vec<_> = entry
+        .lines
+        .iter()
+        .filter(|line| {
+            line.debit.minor_units() != 0 || line.credit.minor_units() != 0 || line.amount.minor_units() != 0
+        })
+        .collect();
+    if lines.is_empty() {
+        return Ok(None);
+    }
+
+    diesel::insert_into(journal_entries::table)
+        .values(&NewJournalEntryModel::from(entry))
+        .execute(conn)?;
+
+    // Dentro de la transacción el último id insertado es el nuestro
+    let entry_id = journal_entries::table
+        .select(journal_entries::id)
+        .order(journal_entries::id.desc())
+        .first::<i32>(conn)?;
+
+    let mut line_models = Vec::with_capacity(lines.len());
+    for line in lines {
+        line_models.push(NewJournalLineModel {
+            entry_id,
+            account_id: find_account_id(conn, &line.account_code)?,
+            borrower_id: line.borrower_id,
+            debit_cents: line.debit.minor_units(),
+            credit_cents: line.credit.minor_units(),
+            amount_cents: line.amount.minor_units(),
+        });
+    }
+    diesel::insert_into(journal_lines::table)
+        .values(&line_models)
+        .execute(conn)?;
+
+    Ok(Some(entry_id))
+}
+
+/// Saldo de la caja de una moneda con los asientos hasta `at`; sin movimientos la caja está en cero
+pub(crate) fn cash_balance(conn: &mut SqliteConnection, currency: &str, at: NaiveDateTime) -> Result<CashBalance> {
+    let line_rows = journal_lines::table
+        .inner_join(journal_entries::table)
+        .inner_join(accounts::table)
+        .filter(accounts::code.eq(cash_account(currency)))
+        .filter(journal_entries::posted_at.le(at))
+        .select((journal_lines::debit_cents, journal_lines::credit_cents, journal_lines::amount_cents))
+        .load::<(i64, i64, i64)>(conn)?;
+
+    let mut balance = Money::zero(currency);
+    let mut balance_cup = Money::zero(BASE_CURRENCY);
+    for (debit, credit, amount) in line_rows {
+        balance = balance.checked_add(&Money::from_minor(amount, currency))?;
+        balance_cup = balance_cup
+            .checked_add(&Money::from_minor(debit, BASE_CURRENCY))?
+            .checked_sub(&Money::from_minor(credit, BASE_CURRENCY))?;
+    }
+
+    Ok(CashBalance {
+        balance,
+        balance_cup,
+    })
+}
+
+/// Registra en el libro mayor la historia de los préstamos, pagos y recargos vigentes
+/// Se usa al introducir el libro mayor; reemplaza lo que se haya registrado antes y al final
+/// reconstruye los totales de cada prestatario desde sus cuentas por cobrar
+/// Lo recuperado de un préstamo castigado se registra en la fecha del castigo
+pub(crate) fn post_ledger_history(conn: &mut SqliteConnection) -> Result<()> {
+    conn.transaction::<_, AppError, _>(|conn| {
+        diesel::delete(journal_lines::table).execute(conn)?;
+        diesel::delete(journal_entries::table).execute(conn)?;
+
+        let loans: Vec<Loan> = loans::table
+            .filter(loans::deleted_at.is_null())
+            .order((loans::created_at.asc(), loans::id.asc()))
+            .load::<LoanModel>(conn)?
+            .into_iter()
+            .map(|model| model.into())
+            .collect();
+        let loan_ids: Vec<i32> = loans.iter().map(|loan| loan.id).collect();
+        let penalties: Vec<Penalty> = penalties::table
+            .filter(penalties::loan_id.eq_any(&loan_ids))
+            .order((penalties::created_at.asc(), penalties::id.asc()))
+            .load::<PenaltyModel>(conn)?
+            .into_iter()
+            .map(|model| model.into())
+            .collect();
+
+        let mut entries = Vec::new();
+
+        for loan in &loans {
+            entries.push(origination_entry(conn, loan)?);
+
+            if let Some(write_off) = &loan.write_off {
+                let written_off_at = parse_stored_date(&write_off.written_off_at);
+                entries.push(JournalPoster::write_off(loan, &write_off.amount_cup, written_off_at)?);
+                entries.push(JournalPoster::recovery(loan.borrower_id, &write_off.recovered_cup, written_off_at)?);
+            }
+        }
+
+        for penalty in &penalties {
+            entries.push(JournalPoster::penalty(
+                penalty.borrower_id,
+                penalty.loan_id,
+                &penalty.amount,
+                parse_stored_date(&penalty.created_at),
+                format!("Recargo #{} del préstamo #{}", penalty.id, penalty.loan_id),
+            )?);
+            if penalty.status == PENALTY_WAIVED {
+                entries.push(JournalPoster::penalty(
+                    penalty.borrower_id,
+                    penalty.loan_id,
+                    &penalty.amount.checked_neg()?,
+                    parse_stored_date(penalty.waived_at.as_deref().unwrap_or(&penalty.updated_at)),
+                    format!("Condonación del recargo #{}", penalty.id),
+                )?);
+            }
+        }
+
+        entries.sort_by_key(|entry| entry.posted_at);
+        for entry in &entries {
+            post_entry(conn, entry)?;
+        }
+
+        // Los pagos, el interés reconocido y los totales de cada borrower dependen del reparto
+        let borrower_ids = borrowers::table.select(borrowers::id).load::<i32>(conn)?;
+        let now = chrono::Utc::now().naive_utc();
+        for borrower_id in borrower_ids {
+            sync_borrower_ledger(conn, borrower_id, now)?;
+        }
+
+        Ok(())
+    })
+}
+
+/// Asiento con el que un préstamo entró al saldo: su desembolso, o la reestructuración que lo originó
+pub(crate) fn origination_entry(conn: &mut SqliteConnection, loan: &Loan) -> Result<NewJournalEntry> {
+    let Some(previous_id) = loan.restructured_from_id else {
+        return JournalPoster::disbursement(loan);
+    };
+
+    let previous: Loan = loans::table.find(previous_id).first::<LoanModel>(conn)?.into();
+    let amounts = penalties::table
+        .filter(penalties::loan_id.eq(previous_id))
+        .filter(penalties::status.eq(PENALTY_CAPITALIZED))
+        .select(penalties::amount_cents)
+        .load::<i64>(conn)?;
+    let mut capitalized = Money::zero(BASE_CURRENCY);
+    for cents in amounts {
+        capitalized = capitalized.checked_add(&Money::from_minor(cents, BASE_CURRENCY))?;
+    }
+
+    let restructured_at = parse_stored_date(previous.restructured_at.as_deref().unwrap_or(&loan.created_at));
+    JournalPoster::restructure(&previous, loan, &previous.carried_over_cup, &capitalized, restructured_at)
+}
+
+/// Movimiento neto por cuenta y prestatario: en CUP, positivo al débito, y en la moneda de la cuenta
+type NetLines = BTreeMap<(String, Option<i32>), (Money, Money)>;
+
+/// Interés de los préstamos con plan de un borrower según cómo se repartieron sus pagos, en CUP
+pub(crate) struct BorrowerInterest {
+    /// Interés que cubre cada asignación de pago
+    pub by_allocation: HashMap<i32, Money>,
+    /// Interés que debe estar reconocido de cada préstamo
+    pub recognized: HashMap<i32, Money>,
+}
+
+/// Totales de un prestatario según las partidas de sus cuentas por cobrar, en CUP
+/// `balance` es lo que debe: la suma de todas sus partidas
+pub(crate) struct ReceivableTotals {
+    pub loans: Money,
+    pub paid: Money,
+    pub penalties: Money,
+    pub interest: Money,
+    pub written_off: Money,
+    pub recovered: Money,
+    pub balance: Money,
+}
+
+/// Pone al día en el libro mayor lo que depende del reparto de los pagos de un borrower y
+/// reconstruye sus totales desde sus cuentas por cobrar:
+/// - el interés reconocido de cada préstamo con plan
+/// - cada pago, repartido entre interés, recargos y principal; los eliminados se anulan
+/// - lo recuperado de sus préstamos castigados
+///
+/// Se llama tras repartir los pagos, al cambiar sus recargos y en la tarea diaria, cuando vencen cuotas
+pub(crate) fn sync_borrower_ledger(conn: &mut SqliteConnection, borrower_id: i32, now: NaiveDateTime) -> Result<()> {
+    let interest = borrower_interest(conn, borrower_id, now)?;
+    post_recognized_interest(conn, borrower_id, &interest.recognized, now)?;
+    let mut touched = post_payments(conn, borrower_id, &interest.by_allocation, now)?;
+    post_recoveries(conn, borrower_id, now)?;
+
+    touched.insert(borrower_id);
+    for touched_id in touched {
+        refresh_borrower_totals(conn, touched_id)?;
+    }
+
+    Ok(())
+}
+
+/// Separa el interés que cubre cada asignación a los préstamos no eliminados de un borrower y
+/// calcula lo que corresponde reconocer de cada uno: lo pagado y, mientras el préstamo siga
+/// abierto, lo de las cuotas vencidas a `now` que falta pagar
+/// Lo que llega a un préstamo castigado después del castigo es recuperación y no cubre interés
+pub(crate) fn borrower_interest(
+    conn: &mut SqliteConnection,
+    borrower_id: i32,
+    now: NaiveDateTime,
+) -> Result<BorrowerInterest> {
+    let loan_models = loans::table
+        .filter(loans::borrower_id.eq(borrower_id))
+        .filter(loans::deleted_at.is_null())
+        .order(loans::id.asc())
+        .load::<LoanModel>(conn)?;
+
+    let mut interest = BorrowerInterest {
+        by_allocation: HashMap::new(),
+        recognized: HashMap::new(),
+    };
+    for loan in loan_models {
+        let installment_models = loan_installments::table
+            .filter(loan_installments::loan_id.eq(loan.id))
+            .order(loan_installments::number.asc())
+            .load::<LoanInstallmentModel>(conn)?;
+        // Sin plan de cuotas el préstamo no suma interés a lo que se debe
+        if installment_models.is_empty() {
+            continue;
+        }
+
+        let allocation_models = payment_allocations::table
+            .filter(payment_allocations::loan_id.eq(loan.id))
+            .order((payment_allocations::created_at.asc(), payment_allocations::id.asc()))
+            .load::<PaymentAllocationModel>(conn)?;
+
+        let due: Vec<bool> = installment_models.iter().map(|model| model.due_date <= now).collect();
+        let installments: Vec<LoanInstallment> = installment_models
+            .into_iter()
+            .map(|model| model.into_entity(&loan.currency))
+            .collect();
+        let received: Vec<Money> = allocation_models
+            .iter()
+            .map(|model| Money::from_minor(model.amount_cents, loan.currency.as_str()))
+            .collect();
+        let split = PaymentAllocator::split_interest(&installments, &received)?;
+
+        let mut recognized = Money::zero(BASE_CURRENCY);
+        for ((allocation, amount), paid_interest) in allocation_models.iter().zip(&received).zip(&split.by_receipt) {
+            let recovery = loan.written_off_at.is_some_and(|written_off_at| allocation.created_at >= written_off_at);
+            if recovery || !paid_interest.is_positive() {
+                continue;
+            }
+
+            // En CUP es la parte proporcional de lo que la asignación aportó
+            let interest_cup = Money::from_minor(allocation.amount_cup_cents, BASE_CURRENCY)
+                .checked_scale(paid_interest.minor_units() as f64 / amount.minor_units() as f64)?;
+            recognized = recognized.checked_add(&interest_cup)?;
+            interest.by_allocation.insert(allocation.id, interest_cup);
+        }
+
+        if loan.restructured_at.is_none() && loan.written_off_at.is_none() {
+            for ((installment, paid_interest), due) in installments.iter().zip(&split.by_installment).zip(due) {
+                if due {
+                    let unpaid = installment.interest.checked_sub(paid_interest)?;
+                    recognized = recognized.checked_add(&unpaid.convert(loan.exchange_rate, BASE_CURRENCY)?)?;
+                }
+            }
+        }
+
+        interest.recognized.insert(loan.id, recognized);
+    }
+
+    Ok(interest)
+}
+
+/// Totales de un prestatario desde las partidas de sus cuentas por cobrar según su evento:
+/// lo pagado sale de los pagos en cualquiera de ellas, los recargos y el interés del resto de
+/// los eventos en sus cuentas, y de los préstamos por cobrar lo castigado, lo recuperado y,
+/// con los desembolsos y reestructuraciones, lo prestado
+pub(crate) fn receivable_totals(conn: &mut SqliteConnection, borrower_id: i32) -> Result<ReceivableTotals> {
+    let line_rows = journal_lines::table
+        .inner_join(journal_entries::table)
+        .inner_join(accounts::table)
+        .filter(accounts::code.eq_any(RECEIVABLE_ACCOUNTS))
+        .filter(journal_lines::borrower_id.eq(borrower_id))
+        .select((accounts::code, journal_entries::event, journal_lines::debit_cents, journal_lines::credit_cents))
+        .load::<(String, String, i64, i64)>(conn)?;
+
+    let zero = Money::zero(BASE_CURRENCY);
+    let mut totals = ReceivableTotals {
+        loans: zero.clone(),
+        paid: zero.clone(),
+        penalties: zero.clone(),
+        interest: zero.clone(),
+        written_off: zero.clone(),
+        recovered: zero.clone(),
+        balance: zero,
+    };
+    for (code, event, debit, credit) in line_rows {
+        let amount = cup(debit).checked_sub(&cup(credit))?;
+        totals.balance = totals.balance.checked_add(&amount)?;
+
+        match (code.as_str(), event.as_str()) {
+            (_, EVENT_PAYMENT) => totals.paid = totals.paid.checked_sub(&amount)?,
+            (ACCOUNT_PENALTIES_RECEIVABLE, _) => totals.penalties = totals.penalties.checked_add(&amount)?,
+            (ACCOUNT_INTEREST_RECEIVABLE, _) => totals.interest = totals.interest.checked_add(&amount)?,
+            (_, EVENT_WRITE_OFF) => totals.written_off = totals.written_off.checked_sub(&amount)?,
+            (_, EVENT_RECOVERY) => totals.recovered = totals.recovered.checked_add(&amount)?,
+            _ => totals.loans = totals.loans.checked_add(&amount)?,
+        }
+    }
+
+    Ok(totals)
+}
+
+/// Registra la diferencia entre el interés que corresponde reconocer de cada préstamo y lo ya
+/// reconocido al borrower, incluidos los préstamos eliminados o que pasaron a otro prestatario
+fn post_recognized_interest(
+    conn: &mut SqliteConnection,
+    borrower_id: i32,
+    recognized: &HashMap<i32, Money>,
+    now: NaiveDateTime,
+) -> Result<()> {
+    let line_rows = journal_lines::table
+        .inner_join(journal_entries::table)
+        .inner_join(accounts::table)
+        .filter(accounts::code.eq(ACCOUNT_INTEREST_RECEIVABLE))
+        .filter(journal_entries::event.eq(EVENT_INTEREST))
+        .filter(journal_lines::borrower_id.eq(borrower_id))
+        .select((journal_entries::loan_id, journal_lines::debit_cents, journal_lines::credit_cents))
+        .load::<(Option<i32>, i64, i64)>(conn)?;
+
+    let mut posted: BTreeMap<i32, Money> = recognized
+        .keys()
+        .map(|loan_id| (*loan_id, Money::zero(BASE_CURRENCY)))
+        .collect();
+    for (loan_id, debit, credit) in line_rows {
+        let Some(loan_id) = loan_id else { continue };
+        let total = posted.entry(loan_id).or_insert_with(|| Money::zero(BASE_CURRENCY));
+        *total = total.checked_add(&cup(debit))?.checked_sub(&cup(credit))?;
+    }
+
+    for (loan_id, posted_total) in posted {
+        let target = recognized.get(&loan_id).cloned().unwrap_or_else(|| Money::zero(BASE_CURRENCY));
+        let delta = target.checked_sub(&posted_total)?;
+        if delta.minor_units() != 0 {
+            post_entry(conn, &JournalPoster::interest(borrower_id, loan_id, &delta, now)?)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Compara lo registrado de cada pago del borrower con el reparto que le corresponde: lo que
+/// falta se registra, lo eliminado o con otro importe se anula y un reparto distinto se corrige
+/// Lo que sobra de cada pago tras sus asignaciones cubre, en orden, los recargos por cobrar
+/// Devuelve los prestatarios cuyas cuentas por cobrar tocaron las anulaciones y correcciones
+fn post_payments(
+    conn: &mut SqliteConnection,
+    borrower_id: i32,
+    interest_by_allocation: &HashMap<i32, Money>,
+    now: NaiveDateTime,
+) -> Result<BTreeSet<i32>> {
+    let payment_models = payments::table
+        .filter(payments::borrower_id.eq(borrower_id))
+        .order((payments::created_at.asc(), payments::id.asc()))
+        .load::<PaymentModel>(conn)?;
+    let ids: Vec<i32> = payment_models.iter().map(|model| model.id).collect();
+
+    // Lo asignado a préstamos por cada pago y el interés que cubre
+    let allocation_rows = payment_allocations::table
+        .filter(payment_allocations::payment_id.eq_any(&ids))
+        .select((payment_allocations::id, payment_allocations::payment_id, payment_allocations::amount_cup_cents))
+        .load::<(i32, i32, i64)>(conn)?;
+    let mut allocated: HashMap<i32, (Money, Money)> = HashMap::new();
+    for (allocation_id, payment_id, amount_cup) in allocation_rows {
+        let (total, interest) = allocated
+            .entry(payment_id)
+            .or_insert_with(|| (Money::zero(BASE_CURRENCY), Money::zero(BASE_CURRENCY)));
+        *total = total.checked_add(&cup(amount_cup))?;
+        if let Some(allocation_interest) = interest_by_allocation.get(&allocation_id) {
+            *interest = interest.checked_add(allocation_interest)?;
+        }
+    }
+
+    let line_rows = journal_lines::table
+        .inner_join(journal_entries::table)
+        .inner_join(accounts::table)
+        .filter(journal_entries::event.eq(EVENT_PAYMENT))
+        .filter(journal_entries::payment_id.eq_any(&ids))
+        .select((
+            journal_entries::payment_id,
+            accounts::code,
+            accounts::currency,
+            journal_lines::borrower_id,
+            journal_lines::debit_cents,
+            journal_lines::credit_cents,
+            journal_lines::amount_cents,
+        ))
+        .load::<(Option<i32>, String, String, Option<i32>, i64, i64, i64)>(conn)?;
+    let mut posted: HashMap<i32, NetLines> = HashMap::new();
+    for (payment_id, code, currency, line_borrower_id, debit, credit, amount) in line_rows {
+        let Some(payment_id) = payment_id else { continue };
+        let lines = posted.entry(payment_id).or_default();
+        add_net_line(lines, code, line_borrower_id, cup(debit).checked_sub(&cup(credit))?, Money::from_minor(amount, currency))?;
+    }
+
+    let mut open_penalties = receivable_totals(conn, borrower_id)?.penalties;
+    let mut touched = BTreeSet::new();
+    for model in payment_models {
+        let deleted = model.deleted_at.is_some();
+        let payment: Payment = model.into();
+        let registered = posted.contains_key(&payment.id);
+        let mut current = posted.remove(&payment.id).unwrap_or_default();
+        current.retain(|_, (amount_cup, amount)| amount_cup.minor_units() != 0 || amount.minor_units() != 0);
+        touched.extend(current.keys().filter_map(|(_, line_borrower_id)| *line_borrower_id));
+
+        let entry = if deleted {
+            None
+        } else {
+            let (allocated_cup, interest) = allocated
+                .remove(&payment.id)
+                .unwrap_or_else(|| (Money::zero(BASE_CURRENCY), Money::zero(BASE_CURRENCY)));
+            let unallocated = payment.amount_cup.checked_sub(&allocated_cup)?;
+            let penalties = Money::from_minor(
+                unallocated.minor_units().min(open_penalties.minor_units()).max(0),
+                BASE_CURRENCY,
+            );
+            open_penalties = open_penalties.checked_sub(&penalties)?;
+            Some(JournalPoster::payment(&payment, &PaymentSplit { interest, penalties })?)
+        };
+
+        let mut wanted = NetLines::new();
+        for line in entry.iter().flat_map(|entry| &entry.lines) {
+            let amount_cup = line.debit.checked_sub(&line.credit)?;
+            add_net_line(&mut wanted, line.account_code.clone(), line.borrower_id, amount_cup, line.amount.clone())?;
+        }
+        wanted.retain(|_, (amount_cup, amount)| amount_cup.minor_units() != 0 || amount.minor_units() != 0);
+
+        // La caja solo cambia si cambió el importe o la moneda del pago; entonces se anula todo lo registrado
+        let cash_changed = cash_lines(&current) != cash_lines(&wanted);
+        let reversed = !current.is_empty() && (entry.is_none() || cash_changed);
+        if reversed {
+            let description = if deleted {
+                format!("Anulación por eliminación del pago #{}", payment.id)
+            } else {
+                format!("Anulación por corrección del pago #{}", payment.id)
+            };
+            let mut lines = Vec::with_capacity(current.len());
+            for ((code, line_borrower_id), (amount_cup, amount)) in std::mem::take(&mut current) {
+                lines.push(NewJournalLine::signed(code, line_borrower_id, amount_cup.checked_neg()?, amount.checked_neg()?));
+            }
+            post_entry(conn, &JournalPoster::payment_correction(&payment, lines, description, now)?)?;
+        }
+
+        let Some(entry) = entry else { continue };
+        if current.is_empty() {
+            // Un pago nuevo se registra en su fecha; uno corregido o restaurado, hoy
+            let entry = if !registered {
+                entry
+            } else if reversed {
+                NewJournalEntry { posted_at: now, ..entry }
+            } else {
+                NewJournalEntry {
+                    description: format!("Restauración del pago #{}", payment.id),
+                    posted_at: now,
+                    ..entry
+                }
+            };
+            post_entry(conn, &entry)?;
+            continue;
+        }
+
+        // Mismo importe con otro reparto: solo se mueven las cuentas por cobrar
+        let keys: BTreeSet<(String, Option<i32>)> = current.keys().chain(wanted.keys()).cloned().collect();
+        let mut lines = Vec::new();
+        for key in keys {
+            let (wanted_cup, wanted_amount) = wanted.remove(&key).unwrap_or_else(|| (cup(0), cup(0)));
+            let (current_cup, current_amount) = current.remove(&key).unwrap_or_else(|| (cup(0), cup(0)));
+            let amount_cup = wanted_cup.checked_sub(&current_cup)?;
+            if amount_cup.minor_units() != 0 {
+                let amount = wanted_amount.checked_sub(&current_amount)?;
+                lines.push(NewJournalLine::signed(key.0, key.1, amount_cup, amount));
+            }
+        }
+        if !lines.is_empty() {
+            let description = format!("Reparto corregido del pago #{}", payment.id);
+            post_entry(conn, &JournalPoster::payment_correction(&payment, lines, description, now)?)?;
+        }
+    }
+
+    Ok(touched)
+}
+
+/// Registra la diferencia entre lo recuperado de los préstamos castigados del borrower y lo ya registrado
+fn post_recoveries(conn: &mut SqliteConnection, borrower_id: i32, now: NaiveDateTime) -> Result<()> {
+    let recovered_cents = loans::table
+        .filter(loans::borrower_id.eq(borrower_id))
+        .filter(loans::deleted_at.is_null())
+        .select(loans::recovered_cup_cents)
+        .load::<i64>(conn)?;
+
+    let mut recovered = Money::zero(BASE_CURRENCY);
+    for cents in recovered_cents {
+        recovered = recovered.checked_add(&cup(cents))?;
+    }
+
+    let delta = recovered.checked_sub(&receivable_totals(conn, borrower_id)?.recovered)?;
+    if delta.minor_units() != 0 {
+        post_entry(conn, &JournalPoster::recovery(borrower_id, &delta, now)?)?;
+    }
+
+    Ok(())
+}
+
+fn add_net_line(
+    lines: &mut NetLines,
+    account_code: String,
+    borrower_id: Option<i32>,
+    amount_cup: Money,
+    amount: Money,
+) -> Result<()> {
+    let currency = amount.currency().to_string();
+    let (net_cup, net_amount) = lines
+        .entry((account_code, borrower_id))
+        .or_insert_with(|| (Money::zero(BASE_CURRENCY), Money::zero(currency)));
+    *net_cup = net_cup.checked_add(&amount_cup)?;
+    *net_amount = net_amount.checked_add(&amount)?;
+    Ok(())
+}
+
+/// Partidas de caja de un movimiento neto
+fn cash_lines(lines: &NetLines) -> NetLines {
+    lines
+        .iter()
+        .filter(|((code, _), _)| code.starts_with(ACCOUNT_CASH))
+        .map(|(key, net)| (key.clone(), net.clone()))
+        .collect()
+}
+
+fn cup(cents: i64) -> Money {
+    Money::from_minor(cents, BASE_CURRENCY)
+}
+
+fn find_account_id(conn: &mut SqliteConnection, code: &str) -> Result<i32> {
+    let account_id = accounts::table
+        .filter(accounts::code.eq(code))
+        .select(accounts::id)
+        .first::<i32>(conn)
+        .optional()?;
+    if let Some(account_id) = account_id {
+        return Ok(account_id);
+    }
+
+    let currency = match code.strip_prefix(ACCOUNT_CASH).and_then(|rest| rest.strip_prefix('-')) {
+        Some(currency) => currency,
+        None => return Err(AppError::NotFound(format!("Cuenta {} no encontrada", code))),
+    };
+
+    diesel::insert_into(accounts::table)
+        .values(&NewAccountModel {
+            code: code.to_string(),
+            name: format!("Caja {}", currency),
+            kind: ACCOUNT_ASSET.to_string(),
+            currency: currency.to_string(),
+        })
+        .execute(conn)?;
+
+    Ok(accounts::table
+        .filter(accounts::code.eq(code))
+        .select(accounts::id)
+        .first::<i32>(conn)?)
+}
+
+/// Carga las partidas de los asientos en una sola consulta, conservando el orden recibido
+fn with_lines(conn: &mut SqliteConnection, entry_models: Vec<JournalEntryModel>) -> Result<Vec<JournalEntry>> {
+    let ids: Vec<i32> = entry_models.iter().map(|model| model.id).collect();
+
+    let rows = journal_lines::table
+        .inner_join(accounts::table)
+        .filter(journal_lines::entry_id.eq_any(&ids))
+        .order(journal_lines::id.asc())
+        .select((JournalLineModel::as_select(), AccountModel::as_select()))
+        .load::<(JournalLineModel, AccountModel)>(conn)?;
+
+    let mut by_entry: HashMap<i32, Vec<JournalLine>> = HashMap::new();
+    for (line, account) in rows {
+        by_entry.entry(line.entry_id).or_default().push(JournalLine {
+            id: line.id,
+            account_code: account.code,
+            account_name: account.name,
+            borrower_id: line.borrower_id,
+            debit: Money::from_minor(line.debit_cents, BASE_CURRENCY),
+            credit: Money::from_minor(line.credit_cents, BASE_CURRENCY),
+            amount: Money::from_minor(line.amount_cents, account.currency),
+        });
+    }
+
+    Ok(entry_models
+        .into_iter()
+        .map(|model| {
+            let lines = by_entry.remove(&model.id).unwrap_or_default();
+            model.into_entity(lines)
+        })
+        .collect())
+}
+
+fn overflow() -> AppError {
+    AppError::DatabaseError("Desbordamiento al sumar las partidas del libro mayor".to_string())
+}
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use diesel::prelude::*;
+use crate::server::domain::entities::loan_product::{LoanProduct, NewLoanProduct};
+use crate::server::domain::repositories::loan_product_repository::LoanProductRepository;
+use crate::server::infrastructure::database::models::loan_product::{LoanProductModel, NewLoanProductModel};
+use crate::server::infrastructure::database::schema::loan_products;
+use crate::server::infrastructure::database::connection::DbPool;
+use crate::utils::error::{AppError, Result};
+
+/// Implementación concreta del repositorio de productos de préstamo usando Diesel con SQLite
+#[derive(Clone)]
+pub struct DieselLoanProductRepository {
+    pool: DbPool,
+}
+
+impl DieselLoanProductRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoanProductRepository for DieselLoanProductRepository {
+    /// Registrar un nuevo producto
+    async fn create(&self, new_product: &NewLoanProduct) -> Result<LoanProduct> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let new_product_model: NewLoanProductModel = new_product.clone().into();
+
+            diesel::insert_into(loan_products::table)
+                .values(&new_product_model)
+                .execute(conn)?;
+
+            // Dentro de la transacción el último id insertado es el nuestro
+            let product_model = loan_products::table
+                .order(loan_products::id.desc())
+                .first::<LoanProductModel>(conn)?;
+
+            Ok(product_model.into())
+        })
+    }
+
+    /// Obtener todos los productos, activos o no, por nombre
+    async fn find_all(&self) -> Result<Vec<LoanProduct>> {
+        let mut conn = self.pool.get()?;
+
+        let product_models = loan_products::table
+            .order(loan_products::name.asc())
+            .load::<LoanProductModel>(&mut conn)?;
+
+        Ok(product_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Buscar un producto por su ID
+    async fn find_by_id(&self, id: i32) -> Result<Option<LoanProduct>> {
+        let mut conn = self.pool.get()?;
+
+        let product_model = loan_products::table
+            .find(id)
+            .first::<LoanProductModel>(&mut conn)
+            .optional()?;
+
+        Ok(product_model.map(|model| model.into()))
+    }
+
+    /// Buscar un producto por su nombre
+    async fn find_by_name(&self, name: &str) -> Result<Option<LoanProduct>> {
+        let mut conn = self.pool.get()?;
+
+        let product_model = loan_products::table
+            .filter(loan_products::name.eq(name))
+            .first::<LoanProductModel>(&mut conn)
+            .optional()?;
+
+        Ok(product_model.map(|model| model.into()))
+    }
+
+    /// Reemplazar las condiciones de un producto; los préstamos ya creados no cambian
+    async fn update(&self, id: i32, product: &NewLoanProduct) -> Result<LoanProduct> {
+        let mut conn = self.pool.get()?;
+
+        let changes: NewLoanProductModel = product.clone().into();
+
+        diesel::update(loan_products::table.find(id))
+            .set((
+                loan_products::name.eq(&changes.name),
+                loan_products::currency.eq(&changes.currency),
+                loan_products::interest_rate.eq(changes.interest_rate),
+                loan_products::rate_period.eq(&changes.rate_period),
+                loan_products::interest_method.eq(&changes.interest_method),
+                loan_products::installment_frequency.eq(&changes.installment_frequency),
+                loan_products::installment_count.eq(changes.installment_count),
+                loan_products::term_days.eq(changes.term_days),
+                loan_products::penalty_rule_id.eq(changes.penalty_rule_id),
+                loan_products::min_amount_cents.eq(changes.min_amount_cents),
+                loan_products::max_amount_cents.eq(changes.max_amount_cents),
+                loan_products::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        let product_model = loan_products::table
+            .find(id)
+            .first::<LoanProductModel>(&mut conn)?;
+
+        Ok(product_model.into())
+    }
+
+    /// Activar o desactivar un producto
+    async fn set_active(&self, id: i32, active: bool) -> Result<LoanProduct> {
+        let mut conn = self.pool.get()?;
+
+        diesel::update(loan_products::table.find(id))
+            .set((
+                loan_products::active.eq(active),
+                loan_products::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        let product_model = loan_products::table
+            .find(id)
+            .first::<LoanProductModel>(&mut conn)?;
+
+        Ok(product_model.into())
+    }
+}
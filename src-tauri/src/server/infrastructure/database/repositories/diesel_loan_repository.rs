@@ -0,0 +1,722 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use crate::server::domain::entities::collateral::{Collateral, COLLATERAL_HELD};
+use crate::server::domain::entities::loan::{
+    Loan, LoanRestructuring, NewLoan, NewLoanWriteOff, LOAN_RESTRUCTURED, LOAN_WRITTEN_OFF,
+};
+use crate::server::domain::entities::loan_guarantor::{LoanGuarantor, NewLoanGuarantor};
+use crate::server::domain::entities::loan_installment::{
+    LoanInstallment, NewLoanInstallment, INSTALLMENT_CLOSED, INSTALLMENT_PAID,
+};
+use crate::server::domain::entities::penalty::{PENALTY_CAPITALIZED, PENALTY_CHARGED};
+use crate::server::domain::entities::payment_allocation::PaymentAllocation;
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::ledger::NewJournalEntry;
+use crate::server::domain::value_objects::money::Money;
+use crate::server::domain::repositories::loan_repository::LoanRepository;
+use crate::server::domain::services::journal_poster::JournalPoster;
+use crate::server::infrastructure::database::models::collateral::CollateralModel;
+use crate::server::infrastructure::database::models::loan::{LoanModel, NewLoanModel};
+use crate::server::infrastructure::database::models::loan_guarantor::{LoanGuarantorModel, NewLoanGuarantorModel};
+use crate::server::infrastructure::database::models::loan_installment::{LoanInstallmentModel, NewLoanInstallmentModel};
+use crate::server::infrastructure::database::models::payment_allocation::PaymentAllocationModel;
+use crate::server::infrastructure::database::repositories::diesel_borrower_repository::refresh_guarantee_exposure;
+use crate::server::infrastructure::database::repositories::diesel_ledger_repository::{
+    borrower_interest, origination_entry, post_entry,
+};
+use crate::server::infrastructure::database::repositories::diesel_payment_repository::reallocate_payments;
+use crate::server::infrastructure::database::repositories::diesel_penalty_repository::{
+    charged_total, transfer_loan_penalties,
+};
+use crate::server::infrastructure::database::schema::{
+    loan_collaterals, loan_guarantors, loan_installments, loans, payment_allocations, payments, penalties,
+};
+use crate::server::infrastructure::database::connection::DbPool;
+use crate::utils::error::{AppError, Result};
+
+/// Implementación concreta del repositorio de préstamos usando Diesel con SQLite
+/// Mantiene sincronizados los totales del borrower en cada operación
+#[derive(Clone)]
+pub struct DieselLoanRepository {
+    pool: DbPool,
+}
+
+impl DieselLoanRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoanRepository for DieselLoanRepository {
+    /// Registrar un préstamo y sumarlo a los totales del borrower
+    async fn create(&self, new_loan: &NewLoan) -> Result<Loan> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let new_loan_model: NewLoanModel = new_loan.clone().into();
+
+            diesel::insert_into(loans::table)
+                .values(&new_loan_model)
+                .execute(conn)?;
+
+            // Dentro de la transacción el último id insertado es el nuestro
+            let loan_model = loans::table
+                .order(loans::id.desc())
+                .first::<LoanModel>(conn)?;
+
+            let loan: Loan = loan_model.into();
+            post_entry(conn, &JournalPoster::disbursement(&loan)?)?;
+
+            insert_installments(conn, loan.id, &new_loan.installments)?;
+            insert_guarantors(conn, loan.id, &new_loan.guarantors)?;
+            reallocate_payments(conn, loan.borrower_id)?;
+
+            // Releer para devolver el saldo pendiente ya calculado
+            let loan_model = loans::table.find(loan.id).first::<LoanModel>(conn)?;
+            Ok(loan_model.into())
+        })
+    }
+
+    /// Obtener todos los préstamos no eliminados, los más recientes primero
+    async fn find_all(&self) -> Result<Vec<Loan>> {
+        let mut conn = self.pool.get()?;
+
+        let loan_models = loans::table
+            .filter(loans::deleted_at.is_null())
+            .order(loans::created_at.desc())
+            .load::<LoanModel>(&mut conn)?;
+
+        Ok(loan_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Buscar un préstamo no eliminado por su ID
+    async fn find_by_id(&self, id: i32) -> Result<Option<Loan>> {
+        let mut conn = self.pool.get()?;
+
+        let loan_model = loans::table
+            .find(id)
+            .filter(loans::deleted_at.is_null())
+            .first::<LoanModel>(&mut conn)
+            .optional()?;
+
+        Ok(loan_model.map(|model| model.into()))
+    }
+
+    /// Obtener los préstamos no eliminados de un borrower
+    async fn find_by_borrower(&self, borrower_id: i32) -> Result<Vec<Loan>> {
+        let mut conn = self.pool.get()?;
+
+        let loan_models = loans::table
+            .filter(loans::borrower_id.eq(borrower_id))
+            .filter(loans::deleted_at.is_null())
+            .order(loans::created_at.desc())
+            .load::<LoanModel>(&mut conn)?;
+
+        Ok(loan_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Actualizar un préstamo, revirtiendo su importe anterior y aplicando el nuevo
+    async fn update(&self, id: i32, loan: &NewLoan) -> Result<Loan> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing: Loan = loans::table.find(id).first::<LoanModel>(conn)?.into();
+
+            // El asiento original se anula y se registra de nuevo con los datos corregidos
+            let now = chrono::Utc::now().naive_utc();
+            let reversal = JournalPoster::reversal(
+                origination_entry(conn, &existing)?,
+                format!("Anulación por corrección del préstamo #{}", id),
+                now,
+            )?;
+            post_entry(conn, &reversal)?;
+
+            let changes: NewLoanModel = loan.clone().into();
+
+            diesel::update(loans::table.find(id))
+                .set((
+                    loans::amount_cents.eq(changes.amount_cents),
+                    loans::currency.eq(&changes.currency),
+                    loans::exchange_rate.eq(changes.exchange_rate),
+                    loans::amount_cup_cents.eq(changes.amount_cup_cents),
+                    loans::interest_rate.eq(changes.interest_rate),
+                    loans::rate_period.eq(&changes.rate_period),
+                    loans::interest_method.eq(&changes.interest_method),
+                    loans::installment_frequency.eq(&changes.installment_frequency),
+                    loans::installment_count.eq(changes.installment_count),
+                    loans::first_due_date.eq(changes.first_due_date),
+                    loans::due_date.eq(changes.due_date),
+                    loans::borrower_id.eq(changes.borrower_id),
+                    loans::created_at.eq(changes.created_at),
+                    loans::credit_limit_override_by.eq(changes.credit_limit_override_by),
+                    loans::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            let updated: Loan = loans::table.find(id).first::<LoanModel>(conn)?.into();
+            let origination = origination_entry(conn, &updated)?;
+            post_entry(conn, &NewJournalEntry { posted_at: now, ..origination })?;
+
+            // El plan se regenera completo porque el importe o las condiciones pudieron cambiar
+            diesel::delete(loan_installments::table.filter(loan_installments::loan_id.eq(id)))
+                .execute(conn)?;
+            insert_installments(conn, id, &loan.installments)?;
+
+            // Los recargos pasan antes de repartir para que los pagos de cada uno cubran los suyos
+            if loan.borrower_id != existing.borrower_id {
+                transfer_loan_penalties(conn, id, existing.borrower_id, loan.borrower_id)?;
+            }
+            reallocate_payments(conn, existing.borrower_id)?;
+            if loan.borrower_id != existing.borrower_id {
+                reallocate_payments(conn, loan.borrower_id)?;
+            }
+
+            let updated_loan = loans::table.find(id).first::<LoanModel>(conn)?;
+            Ok(updated_loan.into())
+        })
+    }
+
+    /// Eliminar un préstamo de forma lógica y restarlo de los totales del borrower
+    /// Sus cuotas, garantes y recargos se conservan para poder restaurarlo
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<()> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing: Loan = loans::table.find(id).first::<LoanModel>(conn)?.into();
+
+            // Las garantías son bienes físicos y su historial no se pierde con el préstamo
+            let collateral_count = loan_collaterals::table
+                .filter(loan_collaterals::loan_id.eq(id))
+                .count()
+                .get_result::<i64>(conn)?;
+            if collateral_count > 0 {
+                return Err(AppError::Conflict(
+                    "El préstamo tiene garantías registradas y no se puede eliminar".to_string()
+                ));
+            }
+
+            let now = chrono::Utc::now().naive_utc();
+            diesel::update(loans::table.find(id))
+                .set((
+                    loans::deleted_at.eq(now),
+                    loans::deleted_by.eq(deleted_by),
+                ))
+                .execute(conn)?;
+
+            let penalties_total = charged_total(conn, id)?;
+            let reversal = JournalPoster::reversal(
+                origination_entry(conn, &existing)?,
+                format!("Anulación por eliminación del préstamo #{}", id),
+                now,
+            )?;
+            post_entry(conn, &reversal)?;
+            post_entry(conn, &JournalPoster::penalty(
+                existing.borrower_id,
+                id,
+                &penalties_total.checked_neg()?,
+                now,
+                format!("Recargos del préstamo #{} eliminado", id),
+            )?)?;
+            if let Some(loan_write_off) = &existing.write_off {
+                post_entry(conn, &JournalPoster::reversal(
+                    JournalPoster::write_off(&existing, &loan_write_off.amount_cup, now)?,
+                    format!("Castigo del préstamo #{} eliminado", id),
+                    now,
+                )?)?;
+            }
+
+            // Los pagos que cubrían este préstamo se reparten de nuevo entre los demás
+            reallocate_payments(conn, existing.borrower_id)?;
+            refresh_loan_guarantors(conn, id)?;
+
+            Ok(())
+        })
+    }
+
+    /// Restaurar un préstamo eliminado y volver a sumarlo a los totales del borrower
+    async fn restore(&self, id: i32) -> Result<Loan> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing: Loan = loans::table.find(id).first::<LoanModel>(conn)?.into();
+
+            diesel::update(loans::table.find(id))
+                .set((
+                    loans::deleted_at.eq(None::<NaiveDateTime>),
+                    loans::deleted_by.eq(None::<i32>),
+                ))
+                .execute(conn)?;
+
+            let penalties_total = charged_total(conn, id)?;
+            let now = chrono::Utc::now().naive_utc();
+            let origination = origination_entry(conn, &existing)?;
+            post_entry(conn, &NewJournalEntry {
+                description: format!("Restauración del préstamo #{}", id),
+                posted_at: now,
+                ..origination
+            })?;
+            post_entry(conn, &JournalPoster::penalty(
+                existing.borrower_id,
+                id,
+                &penalties_total,
+                now,
+                format!("Recargos del préstamo #{} restaurado", id),
+            )?)?;
+            if let Some(loan_write_off) = &existing.write_off {
+                post_entry(conn, &NewJournalEntry {
+                    description: format!("Castigo del préstamo #{} restaurado", id),
+                    ..JournalPoster::write_off(&existing, &loan_write_off.amount_cup, now)?
+                })?;
+            }
+
+            reallocate_payments(conn, existing.borrower_id)?;
+            refresh_loan_guarantors(conn, id)?;
+
+            let loan_model = loans::table.find(id).first::<LoanModel>(conn)?;
+            Ok(loan_model.into())
+        })
+    }
+
+    /// Borrar definitivamente un préstamo eliminado junto con su plan y sus garantes
+    /// Se rechaza mientras tenga recargos, garantías, pagos dirigidos o reestructuraciones
+    async fn purge(&self, id: i32) -> Result<()> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let penalty_count = penalties::table
+                .filter(penalties::loan_id.eq(id))
+                .count()
+                .get_result::<i64>(conn)?;
+            let collateral_count = loan_collaterals::table
+                .filter(loan_collaterals::loan_id.eq(id))
+                .count()
+                .get_result::<i64>(conn)?;
+            let payment_count = payments::table
+                .filter(payments::loan_id.eq(id))
+                .count()
+                .get_result::<i64>(conn)?;
+            let restructure_count = loans::table
+                .filter(loans::restructured_from_id.eq(id))
+                .count()
+                .get_result::<i64>(conn)?;
+            if penalty_count + collateral_count + payment_count + restructure_count > 0 {
+                return Err(AppError::Conflict(
+                    "El préstamo tiene recargos, garantías, pagos o reestructuraciones asociados y no se puede purgar".to_string()
+                ));
+            }
+
+            diesel::delete(loan_installments::table.filter(loan_installments::loan_id.eq(id)))
+                .execute(conn)?;
+            diesel::delete(payment_allocations::table.filter(payment_allocations::loan_id.eq(id)))
+                .execute(conn)?;
+            diesel::delete(loan_guarantors::table.filter(loan_guarantors::loan_id.eq(id)))
+                .execute(conn)?;
+            diesel::delete(loans::table.find(id)).execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    /// Obtener los préstamos eliminados, los más recientes primero
+    async fn find_deleted(&self) -> Result<Vec<Loan>> {
+        let mut conn = self.pool.get()?;
+
+        let loan_models = loans::table
+            .filter(loans::deleted_at.is_not_null())
+            .order(loans::deleted_at.desc())
+            .load::<LoanModel>(&mut conn)?;
+
+        Ok(loan_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Buscar un préstamo eliminado por su ID
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<Loan>> {
+        let mut conn = self.pool.get()?;
+
+        let loan_model = loans::table
+            .find(id)
+            .filter(loans::deleted_at.is_not_null())
+            .first::<LoanModel>(&mut conn)
+            .optional()?;
+
+        Ok(loan_model.map(|model| model.into()))
+    }
+
+    /// Obtener el plan de cuotas de un préstamo, en orden
+    async fn find_installments(&self, loan_id: i32) -> Result<Vec<LoanInstallment>> {
+        let mut conn = self.pool.get()?;
+
+        let currency = loans::table
+            .find(loan_id)
+            .select(loans::currency)
+            .first::<String>(&mut conn)?;
+
+        let installment_models = loan_installments::table
+            .filter(loan_installments::loan_id.eq(loan_id))
+            .order(loan_installments::number.asc())
+            .load::<LoanInstallmentModel>(&mut conn)?;
+
+        Ok(installment_models
+            .into_iter()
+            .map(|model| model.into_entity(&currency))
+            .collect())
+    }
+
+    /// Cambiar el estado de cobranza de un préstamo
+    async fn update_status(&self, id: i32, status: &str) -> Result<()> {
+        let mut conn = self.pool.get()?;
+
+        diesel::update(loans::table.find(id))
+            .set((
+                loans::status.eq(status),
+                loans::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    /// Total de recargos cobrados y sin condonar de un préstamo
+    async fn find_charged_penalties(&self, loan_id: i32) -> Result<Money> {
+        let mut conn = self.pool.get()?;
+
+        charged_total(&mut conn, loan_id)
+    }
+
+    /// Cerrar un préstamo y crear el que lo reemplaza con las condiciones nuevas
+    /// El principal que pasa al préstamo nuevo se descuenta de los totales del borrower
+    /// y los recargos capitalizados dejan de contarse como recargos
+    async fn restructure(&self, id: i32, restructuring: &LoanRestructuring) -> Result<Loan> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing: Loan = loans::table.find(id).first::<LoanModel>(conn)?.into();
+            if existing.status == LOAN_RESTRUCTURED {
+                return Err(AppError::Conflict("El préstamo ya fue reestructurado".to_string()));
+            }
+
+            // Los recargos se releen dentro de la transacción para capitalizar exactamente lo calculado
+            if restructuring.capitalized_penalties.is_positive()
+                && charged_total(conn, id)?.minor_units() != restructuring.capitalized_penalties.minor_units()
+            {
+                return Err(AppError::Conflict(
+                    "Los recargos del préstamo cambiaron durante la reestructuración; intente de nuevo".to_string()
+                ));
+            }
+
+            let now = chrono::Utc::now().naive_utc();
+            diesel::update(loans::table.find(id))
+                .set((
+                    loans::status.eq(LOAN_RESTRUCTURED),
+                    loans::restructured_at.eq(restructuring.restructured_at),
+                    loans::carried_over_cup_cents.eq(restructuring.carried_principal_cup.minor_units()),
+                    loans::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            // El plan anterior se conserva como historial; lo que quedó sin pagar se cierra
+            diesel::update(
+                loan_installments::table
+                    .filter(loan_installments::loan_id.eq(id))
+                    .filter(loan_installments::status.ne(INSTALLMENT_PAID)),
+            )
+            .set((
+                loan_installments::status.eq(INSTALLMENT_CLOSED),
+                loan_installments::updated_at.eq(now),
+            ))
+            .execute(conn)?;
+
+            let new_loan_model: NewLoanModel = restructuring.new_loan.clone().into();
+            diesel::insert_into(loans::table)
+                .values(&new_loan_model)
+                .execute(conn)?;
+
+            let loan: Loan = loans::table
+                .order(loans::id.desc())
+                .first::<LoanModel>(conn)?
+                .into();
+
+            if restructuring.capitalized_penalties.is_positive() {
+                diesel::update(
+                    penalties::table
+                        .filter(penalties::loan_id.eq(id))
+                        .filter(penalties::status.eq(PENALTY_CHARGED)),
+                )
+                .set((
+                    penalties::status.eq(PENALTY_CAPITALIZED),
+                    penalties::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+            }
+            post_entry(conn, &JournalPoster::restructure(
+                &existing,
+                &loan,
+                &restructuring.carried_principal_cup,
+                &restructuring.capitalized_penalties,
+                restructuring.restructured_at,
+            )?)?;
+
+            insert_installments(conn, loan.id, &restructuring.new_loan.installments)?;
+
+            // Los garantes del original pasan a responder por el préstamo nuevo
+            let guarantor_models: Vec<NewLoanGuarantorModel> = loan_guarantors::table
+                .filter(loan_guarantors::loan_id.eq(id))
+                .order(loan_guarantors::id.asc())
+                .load::<LoanGuarantorModel>(conn)?
+                .into_iter()
+                .map(|model| NewLoanGuarantorModel {
+                    loan_id: loan.id,
+                    borrower_id: model.borrower_id,
+                    contact_name: model.contact_name,
+                    contact_phone: model.contact_phone,
+                    guaranteed_amount_cents: model.guaranteed_amount_cents,
+                })
+                .collect();
+            diesel::insert_into(loan_guarantors::table)
+                .values(&guarantor_models)
+                .execute(conn)?;
+
+            // Las garantías que siguen en custodia respaldan ahora al préstamo nuevo
+            diesel::update(
+                loan_collaterals::table
+                    .filter(loan_collaterals::loan_id.eq(id))
+                    .filter(loan_collaterals::status.eq(COLLATERAL_HELD)),
+            )
+            .set((
+                loan_collaterals::loan_id.eq(loan.id),
+                loan_collaterals::updated_at.eq(now),
+            ))
+            .execute(conn)?;
+
+            reallocate_payments(conn, loan.borrower_id)?;
+
+            let loan_model = loans::table.find(loan.id).first::<LoanModel>(conn)?;
+            Ok(loan_model.into())
+        })
+    }
+
+    /// Castigar un préstamo: lo pendiente a la fecha del castigo sale del saldo del borrower
+    /// Solo sale el principal sin cubrir en CUP, que es lo que el préstamo aportó a ese saldo
+    async fn write_off(&self, id: i32, write_off: &NewLoanWriteOff) -> Result<Loan> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing: Loan = loans::table.find(id).first::<LoanModel>(conn)?.into();
+            if existing.status == LOAN_WRITTEN_OFF {
+                return Err(AppError::Conflict("El préstamo ya fue castigado".to_string()));
+            }
+
+            let currency = existing.amount.currency().to_string();
+            let allocation_models = payment_allocations::table
+                .filter(payment_allocations::loan_id.eq(id))
+                .load::<PaymentAllocationModel>(conn)?;
+
+            // Lo asignado después de la fecha del castigo se devuelve a lo castigado
+            // para que cuente como recuperación y no como pago previo
+            // De lo asignado antes solo el principal reduce lo que el préstamo aportó al saldo
+            let interest = borrower_interest(conn, existing.borrower_id, chrono::Utc::now().naive_utc())?;
+            let mut amount = existing.remaining.clone();
+            let mut covered_cup = Money::zero(BASE_CURRENCY);
+            for model in allocation_models {
+                let paid_at = model.created_at;
+                let allocation_interest = interest
+                    .by_allocation
+                    .get(&model.id)
+                    .cloned()
+                    .unwrap_or_else(|| Money::zero(BASE_CURRENCY));
+                let allocation = model.into_entity(&currency);
+                if paid_at >= write_off.written_off_at {
+                    amount = amount.checked_add(&allocation.amount)?;
+                } else {
+                    covered_cup = covered_cup
+                        .checked_add(&allocation.amount_cup)?
+                        .checked_sub(&allocation_interest)?;
+                }
+            }
+            if !amount.is_positive() {
+                return Err(AppError::Conflict("No se puede castigar un préstamo saldado".to_string()));
+            }
+
+            let amount_cup = existing.amount_cup.checked_sub(&covered_cup)?;
+            let amount_cup = if amount_cup.is_positive() {
+                amount_cup
+            } else {
+                Money::zero(BASE_CURRENCY)
+            };
+
+            diesel::update(loans::table.find(id))
+                .set((
+                    loans::status.eq(LOAN_WRITTEN_OFF),
+                    loans::written_off_at.eq(write_off.written_off_at),
+                    loans::written_off_cents.eq(amount.minor_units()),
+                    loans::written_off_cup_cents.eq(amount_cup.minor_units()),
+                    loans::write_off_reason.eq(&write_off.reason),
+                    loans::written_off_by.eq(write_off.written_off_by),
+                    loans::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+            post_entry(conn, &JournalPoster::write_off(&existing, &amount_cup, write_off.written_off_at)?)?;
+
+            // Los pagos posteriores a la fecha del castigo pasan a contar como recuperaciones
+            reallocate_payments(conn, existing.borrower_id)?;
+
+            let loan_model = loans::table.find(id).first::<LoanModel>(conn)?;
+            Ok(loan_model.into())
+        })
+    }
+
+    /// Obtener las partes de pagos asignadas a un préstamo, en orden cronológico
+    async fn find_allocations(&self, loan_id: i32) -> Result<Vec<PaymentAllocation>> {
+        let mut conn = self.pool.get()?;
+
+        let currency = loans::table
+            .find(loan_id)
+            .select(loans::currency)
+            .first::<String>(&mut conn)?;
+
+        let allocation_models = payment_allocations::table
+            .filter(payment_allocations::loan_id.eq(loan_id))
+            .order((payment_allocations::created_at.asc(), payment_allocations::id.asc()))
+            .load::<PaymentAllocationModel>(&mut conn)?;
+
+        Ok(allocation_models
+            .into_iter()
+            .map(|model| model.into_entity(&currency))
+            .collect())
+    }
+
+    /// Obtener los garantes de un préstamo, en el orden en que se agregaron
+    async fn find_guarantors(&self, loan_id: i32) -> Result<Vec<LoanGuarantor>> {
+        let mut conn = self.pool.get()?;
+
+        let currency = loans::table
+            .find(loan_id)
+            .select(loans::currency)
+            .first::<String>(&mut conn)?;
+
+        let guarantor_models = loan_guarantors::table
+            .filter(loan_guarantors::loan_id.eq(loan_id))
+            .order(loan_guarantors::id.asc())
+            .load::<LoanGuarantorModel>(&mut conn)?;
+
+        Ok(guarantor_models
+            .into_iter()
+            .map(|model| model.into_entity(&currency))
+            .collect())
+    }
+
+    /// Agregar un garante a un préstamo y sumar lo garantizado a su exposición
+    async fn add_guarantor(&self, loan_id: i32, guarantor: &NewLoanGuarantor) -> Result<LoanGuarantor> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let currency = loans::table
+                .find(loan_id)
+                .select(loans::currency)
+                .first::<String>(conn)?;
+
+            insert_guarantors(conn, loan_id, std::slice::from_ref(guarantor))?;
+            let guarantor_model = loan_guarantors::table
+                .order(loan_guarantors::id.desc())
+                .first::<LoanGuarantorModel>(conn)?;
+
+            if let Some(borrower_id) = guarantor_model.borrower_id {
+                refresh_guarantee_exposure(conn, borrower_id)?;
+            }
+
+            Ok(guarantor_model.into_entity(&currency))
+        })
+    }
+
+    /// Quitar un garante de un préstamo y liberar lo que garantizaba
+    async fn remove_guarantor(&self, loan_id: i32, guarantor_id: i32) -> Result<()> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let guarantor_model = loan_guarantors::table
+                .find(guarantor_id)
+                .filter(loan_guarantors::loan_id.eq(loan_id))
+                .first::<LoanGuarantorModel>(conn)
+                .optional()?
+                .ok_or_else(|| AppError::NotFound(format!(
+                    "Garante con ID {} no encontrado en el préstamo {}",
+                    guarantor_id, loan_id
+                )))?;
+
+            diesel::delete(loan_guarantors::table.find(guarantor_id)).execute(conn)?;
+            if let Some(borrower_id) = guarantor_model.borrower_id {
+                refresh_guarantee_exposure(conn, borrower_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Obtener las garantías de un préstamo, en el orden en que se registraron
+    async fn find_collateral(&self, loan_id: i32) -> Result<Vec<Collateral>> {
+        let mut conn = self.pool.get()?;
+
+        let collateral_models = loan_collaterals::table
+            .filter(loan_collaterals::loan_id.eq(loan_id))
+            .order(loan_collaterals::id.asc())
+            .load::<CollateralModel>(&mut conn)?;
+
+        Ok(collateral_models.into_iter().map(|model| model.into()).collect())
+    }
+}
+
+fn insert_guarantors(
+    conn: &mut SqliteConnection,
+    loan_id: i32,
+    guarantors: &[NewLoanGuarantor],
+) -> Result<()> {
+    if guarantors.is_empty() {
+        return Ok(());
+    }
+
+    let models: Vec<NewLoanGuarantorModel> = guarantors
+        .iter()
+        .map(|guarantor| NewLoanGuarantorModel::from_entity(loan_id, guarantor))
+        .collect();
+
+    diesel::insert_into(loan_guarantors::table)
+        .values(&models)
+        .execute(conn)?;
+
+    Ok(())
+}
+
+fn insert_installments(
+    conn: &mut SqliteConnection,
+    loan_id: i32,
+    installments: &[NewLoanInstallment],
+) -> Result<()> {
+    if installments.is_empty() {
+        return Ok(());
+    }
+
+    let models: Vec<NewLoanInstallmentModel> = installments
+        .iter()
+        .map(|installment| NewLoanInstallmentModel::from_entity(loan_id, installment))
+        .collect();
+
+    diesel::insert_into(loan_installments::table)
+        .values(&models)
+        .execute(conn)?;
+
+    Ok(())
+}
+
+/// Recalcula lo que garantiza cada prestatario garante del préstamo
+fn refresh_loan_guarantors(conn: &mut SqliteConnection, loan_id: i32) -> Result<()> {
+    let guarantor_ids = loan_guarantors::table
+        .filter(loan_guarantors::loan_id.eq(loan_id))
+        .select(loan_guarantors::borrower_id)
+        .load::<Option<i32>>(conn)?;
+    for guarantor_id in guarantor_ids.into_iter().flatten() {
+        refresh_guarantee_exposure(conn, guarantor_id)?;
+    }
+    Ok(())
+}
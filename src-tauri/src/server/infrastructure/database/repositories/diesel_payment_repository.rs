@@ -0,0 +1,540 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use crate::server::domain::entities::payment::{NewPayment, Payment};
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::loan::{
+    Loan, LOAN_ACTIVE, LOAN_PAID, LOAN_RESTRUCTURED, LOAN_WRITTEN_OFF,
+};
+use crate::server::domain::entities::loan_installment::{
+    LoanInstallment, INSTALLMENT_CLOSED, INSTALLMENT_PAID, INSTALLMENT_PARTIAL, INSTALLMENT_PENDING,
+};
+use crate::server::domain::services::payment_allocator::{OpenLoan, PaymentAllocator};
+use crate::server::domain::value_objects::money::Money;
+use crate::server::domain::repositories::payment_repository::PaymentRepository;
+use crate::server::infrastructure::database::models::loan::LoanModel;
+use crate::server::infrastructure::database::models::loan_installment::LoanInstallmentModel;
+use crate::server::infrastructure::database::models::payment::{NewPaymentModel, PaymentModel};
+use crate::server::infrastructure::database::models::payment_allocation::{NewPaymentAllocationModel, PaymentAllocationModel};
+use crate::server::infrastructure::database::repositories::diesel_borrower_repository::{
+    refresh_credit_score, refresh_guarantee_exposure,
+};
+use crate::server::infrastructure::database::repositories::diesel_ledger_repository::sync_borrower_ledger;
+use crate::server::infrastructure::database::schema::{
+    loan_collaterals, loan_guarantors, loan_installments, loans, payment_allocations, payments,
+};
+use crate::server::infrastructure::database::connection::DbPool;
+use crate::utils::date::parse_stored_date;
+use crate::utils::error::{AppError, Result};
+
+/// Implementación concreta del repositorio de pagos usando Diesel con SQLite
+/// Mantiene sincronizados los totales del borrower y el reparto de sus pagos en cada operación
+#[derive(Clone)]
+pub struct DieselPaymentRepository {
+    pool: DbPool,
+}
+
+impl DieselPaymentRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PaymentRepository for DieselPaymentRepository {
+    /// Registrar un pago convirtiéndolo a CUP y sumarlo a lo pagado por el borrower
+    async fn create(&self, new_payment: &NewPayment) -> Result<Payment> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let payment_model = insert_payment(conn, new_payment)?;
+            Ok(with_allocations(conn, vec![payment_model])?.remove(0))
+        })
+    }
+
+    /// Obtener todos los pagos no eliminados, los más recientes primero
+    async fn find_all(&self) -> Result<Vec<Payment>> {
+        let mut conn = self.pool.get()?;
+
+        let payment_models = payments::table
+            .filter(payments::deleted_at.is_null())
+            .order(payments::created_at.desc())
+            .load::<PaymentModel>(&mut conn)?;
+
+        with_allocations(&mut conn, payment_models)
+    }
+
+    /// Buscar un pago no eliminado por su ID
+    async fn find_by_id(&self, id: i32) -> Result<Option<Payment>> {
+        let mut conn = self.pool.get()?;
+
+        let payment_model = payments::table
+            .find(id)
+            .filter(payments::deleted_at.is_null())
+            .first::<PaymentModel>(&mut conn)
+            .optional()?;
+
+        match payment_model {
+            Some(model) => Ok(with_allocations(&mut conn, vec![model])?.pop()),
+            None => Ok(None),
+        }
+    }
+
+    /// Obtener los pagos no eliminados de un borrower
+    async fn find_by_borrower(&self, borrower_id: i32) -> Result<Vec<Payment>> {
+        let mut conn = self.pool.get()?;
+
+        let payment_models = payments::table
+            .filter(payments::borrower_id.eq(borrower_id))
+            .filter(payments::deleted_at.is_null())
+            .order(payments::created_at.desc())
+            .load::<PaymentModel>(&mut conn)?;
+
+        with_allocations(&mut conn, payment_models)
+    }
+
+    /// Actualizar un pago; su asiento se corrige al repartir de nuevo
+    async fn update(&self, id: i32, payment: &NewPayment) -> Result<Payment> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing: Payment = payments::table.find(id).first::<PaymentModel>(conn)?.into();
+            ensure_not_liquidation(conn, id)?;
+
+            let now = chrono::Utc::now().naive_utc();
+            let changes: NewPaymentModel = payment.clone().into();
+
+            diesel::update(payments::table.find(id))
+                .set((
+                    payments::amount_cents.eq(changes.amount_cents),
+                    payments::currency.eq(&changes.currency),
+                    payments::exchange_rate.eq(changes.exchange_rate),
+                    payments::amount_cup_cents.eq(changes.amount_cup_cents),
+                    payments::allocation_strategy.eq(&changes.allocation_strategy),
+                    payments::borrower_id.eq(changes.borrower_id),
+                    payments::created_at.eq(changes.created_at),
+                    payments::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            // Al repartir de nuevo se anula el asiento original y se registra el corregido
+            reallocate_payments(conn, existing.borrower_id)?;
+            if payment.borrower_id != existing.borrower_id {
+                reallocate_payments(conn, payment.borrower_id)?;
+            }
+
+            let updated_payment = payments::table.find(id).first::<PaymentModel>(conn)?;
+            Ok(with_allocations(conn, vec![updated_payment])?.remove(0))
+        })
+    }
+
+    /// Eliminar un pago de forma lógica; su asiento se anula al repartir de nuevo
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<()> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing: Payment = payments::table.find(id).first::<PaymentModel>(conn)?.into();
+            ensure_not_liquidation(conn, id)?;
+
+            let now = chrono::Utc::now().naive_utc();
+            diesel::update(payments::table.find(id))
+                .set((
+                    payments::deleted_at.eq(now),
+                    payments::deleted_by.eq(deleted_by),
+                ))
+                .execute(conn)?;
+            reallocate_payments(conn, existing.borrower_id)?;
+
+            Ok(())
+        })
+    }
+
+    /// Restaurar un pago eliminado; su asiento se registra de nuevo al repartir
+    async fn restore(&self, id: i32) -> Result<Payment> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing: Payment = payments::table.find(id).first::<PaymentModel>(conn)?.into();
+
+            diesel::update(payments::table.find(id))
+                .set((
+                    payments::deleted_at.eq(None::<NaiveDateTime>),
+                    payments::deleted_by.eq(None::<i32>),
+                ))
+                .execute(conn)?;
+            reallocate_payments(conn, existing.borrower_id)?;
+
+            let payment_model = payments::table.find(id).first::<PaymentModel>(conn)?;
+            Ok(with_allocations(conn, vec![payment_model])?.remove(0))
+        })
+    }
+
+    /// Borrar definitivamente un pago eliminado
+    async fn purge(&self, id: i32) -> Result<()> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            ensure_not_liquidation(conn, id)?;
+
+            diesel::delete(payment_allocations::table.filter(payment_allocations::payment_id.eq(id)))
+                .execute(conn)?;
+            diesel::delete(payments::table.find(id)).execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    /// Obtener los pagos eliminados, los más recientes primero
+    async fn find_deleted(&self) -> Result<Vec<Payment>> {
+        let mut conn = self.pool.get()?;
+
+        let payment_models = payments::table
+            .filter(payments::deleted_at.is_not_null())
+            .order(payments::deleted_at.desc())
+            .load::<PaymentModel>(&mut conn)?;
+
+        with_allocations(&mut conn, payment_models)
+    }
+
+    /// Buscar un pago eliminado por su ID
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<Payment>> {
+        let mut conn = self.pool.get()?;
+
+        let payment_model = payments::table
+            .find(id)
+            .filter(payments::deleted_at.is_not_null())
+            .first::<PaymentModel>(&mut conn)
+            .optional()?;
+
+        match payment_model {
+            Some(model) => Ok(with_allocations(&mut conn, vec![model])?.pop()),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Inserta un pago y vuelve a repartir los pagos del borrower, lo que registra su asiento
+pub(crate) fn insert_payment(conn: &mut SqliteConnection, new_payment: &NewPayment) -> Result<PaymentModel> {
+    let new_payment_model: NewPaymentModel = new_payment.clone().into();
+
+    diesel::insert_into(payments::table)
+        .values(&new_payment_model)
+        .execute(conn)?;
+
+    // Dentro de la transacción el último id insertado es el nuestro
+    let payment_model = payments::table
+        .order(payments::id.desc())
+        .first::<PaymentModel>(conn)?;
+
+    reallocate_payments(conn, payment_model.borrower_id)?;
+
+    Ok(payment_model)
+}
+
+/// El pago de una liquidación queda ligado a la garantía vendida y no se modifica por separado
+fn ensure_not_liquidation(conn: &mut SqliteConnection, payment_id: i32) -> Result<()> {
+    let collateral_id = loan_collaterals::table
+        .filter(loan_collaterals::payment_id.eq(payment_id))
+        .select(loan_collaterals::id)
+        .first::<i32>(conn)
+        .optional()?;
+
+    match collateral_id {
+        Some(collateral_id) => Err(AppError::Conflict(format!(
+            "El pago proviene de la liquidación de la garantía {}",
+            collateral_id
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Reparte los pagos de todos los borrowers con préstamos; se usa al introducir las asignaciones
+pub(crate) fn reallocate_all_payments(conn: &mut SqliteConnection) -> Result<()> {
+    let borrower_ids = loans::table
+        .select(loans::borrower_id)
+        .distinct()
+        .load::<i32>(conn)?;
+
+    conn.transaction::<_, AppError, _>(|conn| {
+        for borrower_id in borrower_ids {
+            reallocate_payments(conn, borrower_id)?;
+        }
+        Ok(())
+    })
+}
+
+/// Carga las asignaciones de los pagos en una sola consulta, conservando el orden recibido
+fn with_allocations(conn: &mut SqliteConnection, payment_models: Vec<PaymentModel>) -> Result<Vec<Payment>> {
+    let ids: Vec<i32> = payment_models.iter().map(|model| model.id).collect();
+
+    let rows = payment_allocations::table
+        .inner_join(loans::table)
+        .filter(payment_allocations::payment_id.eq_any(&ids))
+        .order(payment_allocations::id.asc())
+        .select((PaymentAllocationModel::as_select(), loans::currency))
+        .load::<(PaymentAllocationModel, String)>(conn)?;
+
+    let mut by_payment: HashMap<i32, Vec<_>> = HashMap::new();
+    for (model, currency) in rows {
+        by_payment
+            .entry(model.payment_id)
+            .or_default()
+            .push(model.into_entity(&currency));
+    }
+
+    Ok(payment_models
+        .into_iter()
+        .map(|model| {
+            let mut payment: Payment = model.into();
+            payment.allocations = by_payment.remove(&payment.id).unwrap_or_default();
+            payment
+        })
+        .collect())
+}
+
+/// Recalcula desde cero el reparto de los pagos del borrower entre sus préstamos
+/// Los pagos se aplican en orden cronológico, cada uno con su estrategia y primero a su
+/// préstamo si lo indica, y solo cubren préstamos que ya existían cuando se hizo el pago
+/// Después se actualizan el saldo pendiente y el estado de cada préstamo y lo pagado
+/// de cada cuota de su plan
+/// El saldo de un préstamo con plan incluye el interés de sus cuotas
+/// Con el reparto nuevo se ponen al día los asientos de los pagos del borrower y sus totales,
+/// y lo que garantizan otros prestatarios sigue al saldo pendiente de cada préstamo
+/// Los préstamos y pagos eliminados no participan en el reparto
+/// Al final se recalcula el puntaje de crédito del borrower
+pub(crate) fn reallocate_payments(conn: &mut SqliteConnection, borrower_id: i32) -> Result<()> {
+    let borrower_loan_ids = loans::table
+        .filter(loans::borrower_id.eq(borrower_id))
+        .select(loans::id);
+    let borrower_payment_ids = payments::table
+        .filter(payments::borrower_id.eq(borrower_id))
+        .select(payments::id);
+
+    diesel::delete(
+        payment_allocations::table.filter(
+            payment_allocations::loan_id
+                .eq_any(borrower_loan_ids)
+                .or(payment_allocations::payment_id.eq_any(borrower_payment_ids)),
+        ),
+    )
+    .execute(conn)?;
+
+    let loan_models = loans::table
+        .filter(loans::borrower_id.eq(borrower_id))
+        .filter(loans::deleted_at.is_null())
+        .order((loans::created_at.asc(), loans::id.asc()))
+        .load::<LoanModel>(conn)?;
+
+    if loan_models.is_empty() {
+        let now = chrono::Utc::now().naive_utc();
+        sync_borrower_ledger(conn, borrower_id, now)?;
+        return refresh_credit_score(conn, borrower_id, now);
+    }
+
+    let mut installments: HashMap<i32, Vec<LoanInstallment>> = HashMap::new();
+    let installment_rows = loan_installments::table
+        .inner_join(loans::table)
+        .filter(loans::borrower_id.eq(borrower_id))
+        .filter(loans::deleted_at.is_null())
+        .order((loan_installments::loan_id.asc(), loan_installments::number.asc()))
+        .select((LoanInstallmentModel::as_select(), loans::currency))
+        .load::<(LoanInstallmentModel, String)>(conn)?;
+    for (model, currency) in installment_rows {
+        let mut installment = model.into_entity(&currency);
+        installment.paid = Money::zero(currency);
+        installment.paid_at = None;
+        installments.entry(installment.loan_id).or_default().push(installment);
+    }
+
+    let mut open_loans = Vec::with_capacity(loan_models.len());
+    let mut statuses: HashMap<i32, String> = HashMap::new();
+    let mut closed_at: HashMap<i32, NaiveDateTime> = HashMap::new();
+    let mut written_off_at: HashMap<i32, NaiveDateTime> = HashMap::new();
+    for model in loan_models {
+        let created_at = model.created_at;
+        statuses.insert(model.id, model.status.clone());
+        if let Some(restructured_at) = model.restructured_at {
+            closed_at.insert(model.id, restructured_at);
+        }
+        if let Some(written_off) = model.written_off_at {
+            written_off_at.insert(model.id, written_off);
+        }
+        let loan: Loan = model.into();
+
+        let mut debt = loan.amount.clone();
+        for installment in installments.get(&loan.id).into_iter().flatten() {
+            debt = debt.checked_add(&installment.interest)?;
+        }
+
+        open_loans.push(OpenLoan {
+            loan_id: loan.id,
+            remaining: debt,
+            exchange_rate: loan.exchange_rate,
+            interest: loan.interest,
+            created_at,
+        });
+    }
+
+    let borrower_payments = payments::table
+        .filter(payments::borrower_id.eq(borrower_id))
+        .filter(payments::deleted_at.is_null())
+        .order((payments::created_at.asc(), payments::id.asc()))
+        .load::<PaymentModel>(conn)?;
+
+    let mut received: HashMap<i32, Vec<(Money, NaiveDateTime)>> = HashMap::new();
+    let mut recovered: HashMap<i32, Money> = HashMap::new();
+    for payment_model in borrower_payments {
+        let paid_at = payment_model.created_at;
+        let payment: Payment = payment_model.into();
+
+        // Un préstamo reestructurado solo recibe los pagos anteriores a su cierre
+        let eligible: Vec<OpenLoan> = open_loans
+            .iter()
+            .filter(|loan| loan.created_at <= paid_at)
+            .filter(|loan| closed_at.get(&loan.loan_id).is_none_or(|closed| paid_at < *closed))
+            .cloned()
+            .collect();
+        let allocations = match payment.loan_id {
+            Some(loan_id) => PaymentAllocator::allocate_to_loan_first(
+                &payment.amount_cup,
+                payment.allocation_strategy,
+                &eligible,
+                loan_id,
+            )?,
+            None => PaymentAllocator::allocate(&payment.amount_cup, payment.allocation_strategy, &eligible)?,
+        };
+        if allocations.is_empty() {
+            continue;
+        }
+
+        for allocation in &allocations {
+            if let Some(loan) = open_loans.iter_mut().find(|loan| loan.loan_id == allocation.loan_id) {
+                loan.remaining = loan.remaining.checked_sub(&allocation.amount)?;
+            }
+            received
+                .entry(allocation.loan_id)
+                .or_default()
+                .push((allocation.amount.clone(), paid_at));
+
+            // Lo que llega a un préstamo castigado después del castigo es una recuperación
+            if written_off_at.get(&allocation.loan_id).is_some_and(|written_off| paid_at >= *written_off) {
+                let loan_recovered = recovered
+                    .entry(allocation.loan_id)
+                    .or_insert_with(|| Money::zero(BASE_CURRENCY));
+                *loan_recovered = loan_recovered.checked_add(&allocation.amount_cup)?;
+            }
+        }
+
+        let models: Vec<NewPaymentAllocationModel> = allocations
+            .iter()
+            .map(|allocation| NewPaymentAllocationModel::from_allocation(payment.id, paid_at, allocation))
+            .collect();
+        diesel::insert_into(payment_allocations::table)
+            .values(&models)
+            .execute(conn)?;
+    }
+
+    for mut loan in open_loans {
+        // El saldo de un préstamo reestructurado vive en el préstamo nuevo
+        let closed = closed_at.contains_key(&loan.loan_id);
+        if closed {
+            loan.remaining = Money::zero(loan.remaining.currency());
+        }
+
+        // Un préstamo con saldo conserva su estado de atraso; lo recalcula la tarea diaria
+        let status = match statuses.remove(&loan.loan_id) {
+            _ if closed => LOAN_RESTRUCTURED.to_string(),
+            _ if written_off_at.contains_key(&loan.loan_id) => LOAN_WRITTEN_OFF.to_string(),
+            _ if !loan.remaining.is_positive() => LOAN_PAID.to_string(),
+            Some(status) if status == LOAN_PAID => LOAN_ACTIVE.to_string(),
+            Some(status) => status,
+            None => LOAN_ACTIVE.to_string(),
+        };
+        let loan_recovered = recovered
+            .remove(&loan.loan_id)
+            .unwrap_or_else(|| Money::zero(BASE_CURRENCY));
+
+        diesel::update(loans::table.find(loan.loan_id))
+            .set((
+                loans::remaining_cents.eq(loan.remaining.minor_units()),
+                loans::status.eq(status),
+                loans::recovered_cup_cents.eq(loan_recovered.minor_units()),
+            ))
+            .execute(conn)?;
+
+        if let Some(loan_installments) = installments.remove(&loan.loan_id) {
+            let loan_received = received.remove(&loan.loan_id).unwrap_or_default();
+            settle_installments(conn, loan_installments, &loan_received, closed)?;
+        }
+    }
+
+    sync_borrower_ledger(conn, borrower_id, chrono::Utc::now().naive_utc())?;
+
+    let guarantor_ids = loan_guarantors::table
+        .inner_join(loans::table)
+        .filter(loans::borrower_id.eq(borrower_id))
+        .select(loan_guarantors::borrower_id)
+        .distinct()
+        .load::<Option<i32>>(conn)?;
+    for guarantor_id in guarantor_ids.into_iter().flatten() {
+        refresh_guarantee_exposure(conn, guarantor_id)?;
+    }
+
+    refresh_credit_score(conn, borrower_id, chrono::Utc::now().naive_utc())
+}
+
+/// Aplica lo recibido por un préstamo a sus cuotas, la más antigua primero
+/// Si el préstamo se cerró por reestructuración, las cuotas sin cubrir quedan cerradas
+fn settle_installments(
+    conn: &mut SqliteConnection,
+    mut installments: Vec<LoanInstallment>,
+    received: &[(Money, NaiveDateTime)],
+    closed: bool,
+) -> Result<()> {
+    for (amount, paid_at) in received {
+        let mut left = amount.clone();
+
+        for installment in installments.iter_mut() {
+            if !left.is_positive() {
+                break;
+            }
+
+            let outstanding = installment.outstanding()?;
+            if !outstanding.is_positive() {
+                continue;
+            }
+
+            let covered = if left.minor_units() < outstanding.minor_units() { left.clone() } else { outstanding };
+            left = left.checked_sub(&covered)?;
+            installment.paid = installment.paid.checked_add(&covered)?;
+            installment.paid_at = Some(paid_at.and_utc().to_rfc3339());
+        }
+    }
+
+    let now = chrono::Utc::now().naive_utc();
+    for installment in installments {
+        let outstanding = installment.outstanding()?;
+        let (status, paid_at) = if !outstanding.is_positive() {
+            (INSTALLMENT_PAID, installment.paid_at.as_deref().map(parse_stored_date))
+        } else if closed {
+            (INSTALLMENT_CLOSED, None)
+        } else if installment.paid.is_positive() {
+            (INSTALLMENT_PARTIAL, None)
+        } else {
+            (INSTALLMENT_PENDING, None)
+        };
+
+        diesel::update(loan_installments::table.find(installment.id))
+            .set((
+                loan_installments::paid_cents.eq(installment.paid.minor_units()),
+                loan_installments::status.eq(status),
+                loan_installments::paid_at.eq(paid_at),
+                loan_installments::updated_at.eq(now),
+            ))
+            .execute(conn)?;
+    }
+
+    Ok(())
+}
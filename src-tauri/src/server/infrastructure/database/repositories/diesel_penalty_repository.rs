@@ -0,0 +1,244 @@
+use async_trait::async_trait;
+use diesel::prelude::*;
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::penalty::{NewPenalty, NewPenaltyRule, Penalty, PenaltyRule, PENALTY_CHARGED, PENALTY_WAIVED};
+use crate::server::domain::repositories::penalty_repository::PenaltyRepository;
+use crate::server::domain::services::journal_poster::JournalPoster;
+use crate::server::domain::value_objects::money::Money;
+use crate::server::infrastructure::database::models::penalty::{
+    NewPenaltyModel, NewPenaltyRuleModel, PenaltyModel, PenaltyRuleModel,
+};
+use crate::server::infrastructure::database::repositories::diesel_ledger_repository::{post_entry, sync_borrower_ledger};
+use crate::server::infrastructure::database::schema::{loans, penalties, penalty_rules};
+use crate::server::infrastructure::database::connection::DbPool;
+use crate::utils::date::parse_stored_date;
+use crate::utils::error::{AppError, Result};
+
+/// Implementación concreta del repositorio de recargos usando Diesel con SQLite
+/// Mantiene sincronizados los recargos y el saldo del borrower en cada operación
+#[derive(Clone)]
+pub struct DieselPenaltyRepository {
+    pool: DbPool,
+}
+
+impl DieselPenaltyRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PenaltyRepository for DieselPenaltyRepository {
+    /// Registrar una nueva regla de recargo
+    async fn create_rule(&self, new_rule: &NewPenaltyRule) -> Result<PenaltyRule> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let new_rule_model: NewPenaltyRuleModel = new_rule.clone().into();
+
+            diesel::insert_into(penalty_rules::table)
+                .values(&new_rule_model)
+                .execute(conn)?;
+
+            // Dentro de la transacción el último id insertado es el nuestro
+            let rule_model = penalty_rules::table
+                .order(penalty_rules::id.desc())
+                .first::<PenaltyRuleModel>(conn)?;
+
+            Ok(rule_model.into())
+        })
+    }
+
+    /// Obtener todas las reglas, activas o no
+    async fn find_rules(&self) -> Result<Vec<PenaltyRule>> {
+        let mut conn = self.pool.get()?;
+
+        let rule_models = penalty_rules::table
+            .order(penalty_rules::id.asc())
+            .load::<PenaltyRuleModel>(&mut conn)?;
+
+        Ok(rule_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Buscar una regla por su ID
+    async fn find_rule_by_id(&self, id: i32) -> Result<Option<PenaltyRule>> {
+        let mut conn = self.pool.get()?;
+
+        let rule_model = penalty_rules::table
+            .find(id)
+            .first::<PenaltyRuleModel>(&mut conn)
+            .optional()?;
+
+        Ok(rule_model.map(|model| model.into()))
+    }
+
+    /// Activar o desactivar una regla; los recargos ya cobrados no cambian
+    async fn set_rule_active(&self, id: i32, active: bool) -> Result<PenaltyRule> {
+        let mut conn = self.pool.get()?;
+
+        diesel::update(penalty_rules::table.find(id))
+            .set((
+                penalty_rules::active.eq(active),
+                penalty_rules::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        let rule_model = penalty_rules::table
+            .find(id)
+            .first::<PenaltyRuleModel>(&mut conn)?;
+
+        Ok(rule_model.into())
+    }
+
+    /// Registrar un recargo y sumarlo al saldo del borrower
+    async fn create(&self, new_penalty: &NewPenalty) -> Result<Penalty> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let new_penalty_model: NewPenaltyModel = new_penalty.clone().into();
+
+            diesel::insert_into(penalties::table)
+                .values(&new_penalty_model)
+                .execute(conn)?;
+
+            let penalty_model = penalties::table
+                .order(penalties::id.desc())
+                .first::<PenaltyModel>(conn)?;
+
+            let penalty: Penalty = penalty_model.into();
+            post_entry(conn, &JournalPoster::penalty(
+                penalty.borrower_id,
+                penalty.loan_id,
+                &penalty.amount,
+                parse_stored_date(&penalty.created_at),
+                format!("Recargo #{} del préstamo #{}", penalty.id, penalty.loan_id),
+            )?)?;
+            // Lo que sobró de pagos anteriores pasa a cubrir el recargo nuevo
+            sync_borrower_ledger(conn, penalty.borrower_id, chrono::Utc::now().naive_utc())?;
+
+            Ok(penalty)
+        })
+    }
+
+    /// Obtener los recargos, los más recientes primero, opcionalmente de un solo borrower
+    /// Los recargos de préstamos eliminados no se incluyen
+    async fn find_all(&self, borrower_id: Option<i32>) -> Result<Vec<Penalty>> {
+        let mut conn = self.pool.get()?;
+
+        let mut query = penalties::table
+            .filter(penalties::loan_id.ne_all(deleted_loan_ids()))
+            .order((penalties::created_at.desc(), penalties::id.desc()))
+            .into_boxed();
+        if let Some(borrower_id) = borrower_id {
+            query = query.filter(penalties::borrower_id.eq(borrower_id));
+        }
+
+        let penalty_models = query.load::<PenaltyModel>(&mut conn)?;
+
+        Ok(penalty_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Buscar un recargo por su ID
+    async fn find_by_id(&self, id: i32) -> Result<Option<Penalty>> {
+        let mut conn = self.pool.get()?;
+
+        let penalty_model = penalties::table
+            .find(id)
+            .filter(penalties::loan_id.ne_all(deleted_loan_ids()))
+            .first::<PenaltyModel>(&mut conn)
+            .optional()?;
+
+        Ok(penalty_model.map(|model| model.into()))
+    }
+
+    /// Obtener los recargos de un préstamo en orden cronológico
+    async fn find_by_loan(&self, loan_id: i32) -> Result<Vec<Penalty>> {
+        let mut conn = self.pool.get()?;
+
+        let penalty_models = penalties::table
+            .filter(penalties::loan_id.eq(loan_id))
+            .order((penalties::created_at.asc(), penalties::id.asc()))
+            .load::<PenaltyModel>(&mut conn)?;
+
+        Ok(penalty_models.into_iter().map(|model| model.into()).collect())
+    }
+
+    /// Condonar un recargo y restarlo del saldo del borrower
+    async fn waive(&self, id: i32, reason: &str, waived_by: i32) -> Result<Penalty> {
+        let mut conn = self.pool.get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing: Penalty = penalties::table.find(id).first::<PenaltyModel>(conn)?.into();
+            if existing.status != PENALTY_CHARGED {
+                return Err(AppError::Conflict("El recargo ya fue condonado".to_string()));
+            }
+
+            let now = chrono::Utc::now().naive_utc();
+            diesel::update(penalties::table.find(id))
+                .set((
+                    penalties::status.eq(PENALTY_WAIVED),
+                    penalties::waived_reason.eq(reason),
+                    penalties::waived_by.eq(waived_by),
+                    penalties::waived_at.eq(now),
+                    penalties::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            post_entry(conn, &JournalPoster::penalty(
+                existing.borrower_id,
+                existing.loan_id,
+                &existing.amount.checked_neg()?,
+                now,
+                format!("Condonación del recargo #{}", id),
+            )?)?;
+            sync_borrower_ledger(conn, existing.borrower_id, now)?;
+
+            let penalty_model = penalties::table.find(id).first::<PenaltyModel>(conn)?;
+            Ok(penalty_model.into())
+        })
+    }
+}
+
+/// IDs de los préstamos eliminados, cuyos recargos quedan ocultos hasta que se restauren
+fn deleted_loan_ids() -> diesel::dsl::Select<diesel::dsl::Filter<loans::table, diesel::dsl::IsNotNull<loans::deleted_at>>, loans::id> {
+    loans::table.filter(loans::deleted_at.is_not_null()).select(loans::id)
+}
+
+/// Total cobrado y sin condonar de un préstamo, en CUP
+pub(crate) fn charged_total(conn: &mut SqliteConnection, loan_id: i32) -> Result<Money> {
+    let amounts = penalties::table
+        .filter(penalties::loan_id.eq(loan_id))
+        .filter(penalties::status.eq(PENALTY_CHARGED))
+        .select(penalties::amount_cents)
+        .load::<i64>(conn)?;
+
+    let mut total = Money::zero(BASE_CURRENCY);
+    for cents in amounts {
+        total = total.checked_add(&Money::from_minor(cents, BASE_CURRENCY))?;
+    }
+    Ok(total)
+}
+
+/// Pasa los recargos de un préstamo a otro borrower junto con sus recargos por cobrar
+/// Los totales de ambos se rehacen al repartir de nuevo sus pagos
+pub(crate) fn transfer_loan_penalties(
+    conn: &mut SqliteConnection,
+    loan_id: i32,
+    from_borrower_id: i32,
+    to_borrower_id: i32,
+) -> Result<()> {
+    let total = charged_total(conn, loan_id)?;
+
+    diesel::update(penalties::table.filter(penalties::loan_id.eq(loan_id)))
+        .set(penalties::borrower_id.eq(to_borrower_id))
+        .execute(conn)?;
+    post_entry(conn, &JournalPoster::penalty_transfer(
+        loan_id,
+        from_borrower_id,
+        to_borrower_id,
+        &total,
+        chrono::Utc::now().naive_utc(),
+    )?)?;
+
+    Ok(())
+}
@@ -1,2 +1,10 @@
 pub mod diesel_user_repository;
-pub mod diesel_borrower_repository;
\ No newline at end of file
+pub mod diesel_borrower_repository;
+pub mod diesel_currency_repository;
+pub mod diesel_loan_repository;
+pub mod diesel_payment_repository;
+pub mod diesel_penalty_repository;
+pub mod diesel_loan_product_repository;
+pub mod diesel_collateral_repository;
+pub mod diesel_ledger_repository;
+pub mod diesel_cash_box_repository;
\ No newline at end of file
@@ -1,17 +1,101 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    accounts (id) {
+        id -> Integer,
+        code -> Text,
+        name -> Text,
+        kind -> Text,
+        currency -> Text,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     borrowers (id) {
         id -> Integer,
         phone -> Text,
         name -> Text,
         location -> Text,
-        total_loans -> Double,
-        total_paid -> Double,
-        balance -> Double,
+        total_loans_cents -> BigInt,
+        total_paid_cents -> BigInt,
+        balance_cents -> BigInt,
         status -> Text,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        total_penalties_cents -> BigInt,
+        written_off_total_cents -> BigInt,
+        recovered_total_cents -> BigInt,
+        guarantee_exposure_cents -> BigInt,
+        credit_limit_cents -> Nullable<BigInt>,
+        risk_tier -> Text,
+        credit_score -> Integer,
+        credit_on_time_installments -> Integer,
+        credit_late_installments -> Integer,
+        credit_days_past_due -> Integer,
+        credit_restructures -> Integer,
+        credit_write_offs -> Integer,
+        credit_score_updated_at -> Nullable<Timestamp>,
+        blocked_reason -> Nullable<Text>,
+        blocked_at -> Nullable<Timestamp>,
+        blocked_by -> Nullable<Integer>,
+        deleted_at -> Nullable<Timestamp>,
+        deleted_by -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    cash_counts (id) {
+        id -> Integer,
+        currency -> Text,
+        expected_cents -> BigInt,
+        counted_cents -> BigInt,
+        difference_cents -> BigInt,
+        exchange_rate -> Float,
+        difference_cup_cents -> BigInt,
+        notes -> Nullable<Text>,
+        entry_id -> Nullable<Integer>,
+        counted_by -> Integer,
+        counted_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    cash_exchanges (id) {
+        id -> Integer,
+        source_currency -> Text,
+        source_amount_cents -> BigInt,
+        target_currency -> Text,
+        target_amount_cents -> BigInt,
+        effective_rate -> Double,
+        official_rate -> Double,
+        spread -> Double,
+        source_cost_cup_cents -> BigInt,
+        target_cup_cents -> BigInt,
+        spread_cup_cents -> BigInt,
+        gain_cup_cents -> BigInt,
+        notes -> Nullable<Text>,
+        entry_id -> Integer,
+        created_by -> Integer,
+        exchanged_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    cash_movements (id) {
+        id -> Integer,
+        currency -> Text,
+        kind -> Text,
+        amount_cents -> BigInt,
+        exchange_rate -> Float,
+        amount_cup_cents -> BigInt,
+        reason -> Text,
+        entry_id -> Integer,
+        created_by -> Integer,
+        moved_at -> Timestamp,
+        created_at -> Timestamp,
     }
 }
 
@@ -25,28 +109,200 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    exchange_rate_history (id) {
+        id -> Integer,
+        currency_code -> Text,
+        exchange_rate -> Float,
+        effective_from -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    journal_entries (id) {
+        id -> Integer,
+        event -> Text,
+        description -> Text,
+        borrower_id -> Nullable<Integer>,
+        loan_id -> Nullable<Integer>,
+        payment_id -> Nullable<Integer>,
+        posted_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    journal_lines (id) {
+        id -> Integer,
+        entry_id -> Integer,
+        account_id -> Integer,
+        borrower_id -> Nullable<Integer>,
+        debit_cents -> BigInt,
+        credit_cents -> BigInt,
+        amount_cents -> BigInt,
+    }
+}
+
+diesel::table! {
+    loan_collaterals (id) {
+        id -> Integer,
+        loan_id -> Integer,
+        description -> Text,
+        estimated_value_cents -> BigInt,
+        currency -> Text,
+        storage_location -> Text,
+        status -> Text,
+        payment_id -> Nullable<Integer>,
+        returned_at -> Nullable<Timestamp>,
+        liquidated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    loan_guarantors (id) {
+        id -> Integer,
+        loan_id -> Integer,
+        borrower_id -> Nullable<Integer>,
+        contact_name -> Nullable<Text>,
+        contact_phone -> Nullable<Text>,
+        guaranteed_amount_cents -> BigInt,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    loan_installments (id) {
+        id -> Integer,
+        loan_id -> Integer,
+        number -> Integer,
+        due_date -> Timestamp,
+        principal_cents -> BigInt,
+        interest_cents -> BigInt,
+        paid_cents -> BigInt,
+        status -> Text,
+        paid_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    loan_products (id) {
+        id -> Integer,
+        name -> Text,
+        currency -> Text,
+        interest_rate -> Double,
+        rate_period -> Text,
+        interest_method -> Text,
+        installment_frequency -> Nullable<Text>,
+        installment_count -> Nullable<Integer>,
+        term_days -> Nullable<Integer>,
+        penalty_rule_id -> Nullable<Integer>,
+        min_amount_cents -> Nullable<BigInt>,
+        max_amount_cents -> Nullable<BigInt>,
+        active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     loans (id) {
         id -> Integer,
-        amount -> Float,
+        amount_cents -> BigInt,
         currency -> Text,
         status -> Text,
         borrower_id -> Integer,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        exchange_rate -> Float,
+        amount_cup_cents -> BigInt,
+        interest_rate -> Double,
+        rate_period -> Text,
+        interest_method -> Text,
+        installment_frequency -> Nullable<Text>,
+        installment_count -> Nullable<Integer>,
+        first_due_date -> Nullable<Timestamp>,
+        remaining_cents -> BigInt,
+        due_date -> Nullable<Timestamp>,
+        restructured_from_id -> Nullable<Integer>,
+        restructured_at -> Nullable<Timestamp>,
+        carried_over_cup_cents -> BigInt,
+        written_off_at -> Nullable<Timestamp>,
+        written_off_cents -> BigInt,
+        written_off_cup_cents -> BigInt,
+        recovered_cup_cents -> BigInt,
+        write_off_reason -> Nullable<Text>,
+        written_off_by -> Nullable<Integer>,
+        product_id -> Nullable<Integer>,
+        penalty_rule_id -> Nullable<Integer>,
+        credit_limit_override_by -> Nullable<Integer>,
+        deleted_at -> Nullable<Timestamp>,
+        deleted_by -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    payment_allocations (id) {
+        id -> Integer,
+        payment_id -> Integer,
+        loan_id -> Integer,
+        amount_cents -> BigInt,
+        amount_cup_cents -> BigInt,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    penalties (id) {
+        id -> Integer,
+        loan_id -> Integer,
+        borrower_id -> Integer,
+        rule_id -> Integer,
+        amount_cents -> BigInt,
+        days_past_due -> Integer,
+        days_charged -> Integer,
+        status -> Text,
+        waived_reason -> Nullable<Text>,
+        waived_by -> Nullable<Integer>,
+        waived_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    penalty_rules (id) {
+        id -> Integer,
+        name -> Text,
+        kind -> Text,
+        amount_cents -> BigInt,
+        rate -> Double,
+        grace_days -> Integer,
+        cap_cents -> Nullable<BigInt>,
+        active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
 diesel::table! {
     payments (id) {
         id -> Integer,
-        amount -> Float,
+        amount_cents -> BigInt,
         currency -> Text,
         exchange_rate -> Float,
-        amount_cup -> Float,
+        amount_cup_cents -> BigInt,
         borrower_id -> Integer,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        allocation_strategy -> Text,
+        loan_id -> Nullable<Integer>,
+        deleted_at -> Nullable<Timestamp>,
+        deleted_by -> Nullable<Integer>,
     }
 }
 
@@ -62,7 +318,43 @@ diesel::table! {
     }
 }
 
+diesel::joinable!(cash_counts -> journal_entries (entry_id));
+diesel::joinable!(cash_exchanges -> journal_entries (entry_id));
+diesel::joinable!(cash_movements -> journal_entries (entry_id));
+diesel::joinable!(journal_lines -> accounts (account_id));
+diesel::joinable!(journal_lines -> journal_entries (entry_id));
+diesel::joinable!(loan_collaterals -> loans (loan_id));
+diesel::joinable!(loan_collaterals -> payments (payment_id));
+diesel::joinable!(loan_guarantors -> borrowers (borrower_id));
+diesel::joinable!(loan_guarantors -> loans (loan_id));
+diesel::joinable!(loan_installments -> loans (loan_id));
+diesel::joinable!(loan_products -> penalty_rules (penalty_rule_id));
 diesel::joinable!(loans -> borrowers (borrower_id));
+diesel::joinable!(payment_allocations -> loans (loan_id));
+diesel::joinable!(payment_allocations -> payments (payment_id));
 diesel::joinable!(payments -> borrowers (borrower_id));
+diesel::joinable!(penalties -> borrowers (borrower_id));
+diesel::joinable!(penalties -> loans (loan_id));
+diesel::joinable!(penalties -> penalty_rules (rule_id));
 
-diesel::allow_tables_to_appear_in_same_query!(borrowers, currencies, loans, payments, users,);
+diesel::allow_tables_to_appear_in_same_query!(
+    accounts,
+    borrowers,
+    cash_counts,
+    cash_exchanges,
+    cash_movements,
+    currencies,
+    exchange_rate_history,
+    journal_entries,
+    journal_lines,
+    loan_collaterals,
+    loan_guarantors,
+    loan_installments,
+    loan_products,
+    loans,
+    payment_allocations,
+    payments,
+    penalties,
+    penalty_rules,
+    users,
+);
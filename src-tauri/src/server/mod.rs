@@ -4,6 +4,7 @@ pub mod domain;
 pub mod infrastructure;
 pub mod presentation;
 pub mod routes;
+pub mod tasks;
 pub use routes::configure_routes;
 
 use axum::Router;
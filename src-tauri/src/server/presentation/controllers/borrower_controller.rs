@@ -1,8 +1,8 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use serde_json::json;
 use validator::Validate;
@@ -10,17 +10,31 @@ use validator::Validate;
 use crate::{
     server::{
         application::{
-            requests::borrower_model_requests::{CreateBorrowerRequest, UpdateBorrowerRequest},
+            requests::borrower_model_requests::{
+                BlockBorrowerRequest, CreateBorrowerRequest, SearchBorrowersRequest, UpdateBorrowerRequest,
+            },
             use_cases::borrower_use_cases::BorrowerUseCases,
         },
-        domain::repositories::borrower_repository::BorrowerRepository,
+        domain::{
+            entities::{
+                borrower::{BorrowerSortField, RiskTier, SortDirection},
+                user::UserPayload,
+            },
+            repositories::borrower_repository::BorrowerRepository,
+        },
     },
     utils::error::{AppError, Result},
 };
 
 use super::super::dtos::{
-    requests::borrower_request_dto::{CreateBorrowerRequestDto, UpdateBorrowerRequestDto},
-    responses::{api_response::ApiResponse, borrower_responses::BorrowerResponseDto},
+    requests::borrower_request_dto::{
+        BlockBorrowerRequestDto, BorrowerQueryDto, CreateBorrowerRequestDto, ReconcileQueryDto,
+        UpdateBorrowerRequestDto,
+    },
+    responses::{
+        api_response::ApiResponse,
+        borrower_responses::{BorrowerPageResponseDto, BorrowerResponseDto, TotalsReconciliationResponseDto},
+    },
 };
 
 /// Crear un nuevo prestatario
@@ -44,25 +58,38 @@ where
     let response = ApiResponse::created(format!(
         "Prestatario {} registrado exitosamente",
         borrower_dto.name
-    ));
+    ))
+    .with_warnings(borrower_dto.warnings);
     Ok((StatusCode::CREATED, Json(json!(response))))
 }
 
-/// Obtener todos los prestatarios
+/// Buscar prestatarios con filtros, orden y paginación
 pub async fn get_all_borrowers<T>(
     State(borrower_use_cases): State<BorrowerUseCases<T>>,
-) -> Result<Json<Vec<BorrowerResponseDto>>>
+    Query(query): Query<BorrowerQueryDto>,
+) -> Result<Json<BorrowerPageResponseDto>>
 where
     T: BorrowerRepository,
 {
-    let borrower_dtos = borrower_use_cases.get_all_borrowers().await?;
+    query.validate().map_err(AppError::from)?;
+
+    let search_request = SearchBorrowersRequest {
+        search: query.search,
+        status: query.status,
+        min_balance: query.min_balance,
+        max_balance: query.max_balance,
+        created_from: query.created_from,
+        created_to: query.created_to,
+        deleted: query.deleted,
+        sort: query.sort.as_deref().and_then(BorrowerSortField::parse),
+        direction: query.direction.as_deref().and_then(SortDirection::parse),
+        page: query.page,
+        page_size: query.page_size,
+    };
 
-    let response_dtos: Vec<BorrowerResponseDto> = borrower_dtos
-        .into_iter()
-        .map(BorrowerResponseDto::from)
-        .collect();
+    let page_dto = borrower_use_cases.get_all_borrowers(search_request).await?;
 
-    Ok(Json(response_dtos))
+    Ok(Json(BorrowerPageResponseDto::from(page_dto)))
 }
 
 /// Obtener un prestatario por ID
@@ -80,20 +107,28 @@ where
 /// Actualizar un prestatario
 pub async fn update_borrower<T>(
     State(borrower_use_cases): State<BorrowerUseCases<T>>,
+    Extension(user): Extension<UserPayload>,
     Path(id): Path<i32>,
     Json(payload): Json<UpdateBorrowerRequestDto>,
 ) -> Result<Json<BorrowerResponseDto>>
 where
     T: BorrowerRepository,
 {
-    if payload.phone.is_some() || payload.name.is_some() || payload.location.is_some() {
-        payload.validate().map_err(AppError::from)?;
+    payload.validate().map_err(AppError::from)?;
+
+    // El límite y la categoría deciden cuánto se le presta, así que solo los cambia un administrador
+    if (payload.credit_limit.is_some() || payload.risk_tier.is_some()) && user.role != "admin" {
+        return Err(AppError::Forbidden(
+            "Solo un administrador puede cambiar el límite de crédito o la categoría de riesgo".to_string(),
+        ));
     }
 
     let update_request = UpdateBorrowerRequest {
         phone: payload.phone,
         name: payload.name,
         location: payload.location,
+        credit_limit: payload.credit_limit,
+        risk_tier: payload.risk_tier.as_deref().and_then(RiskTier::parse),
     };
 
     let updated_borrower_dto = borrower_use_cases
@@ -106,13 +141,87 @@ where
 /// Eliminar un prestatario
 pub async fn delete_borrower<T>(
     State(borrower_use_cases): State<BorrowerUseCases<T>>,
+    Extension(user): Extension<UserPayload>,
     Path(id): Path<i32>,
 ) -> Result<impl IntoResponse>
 where
     T: BorrowerRepository,
 {
-    borrower_use_cases.delete_borrower(id).await?;
+    borrower_use_cases.delete_borrower(id, user.id).await?;
 
     let response = ApiResponse::ok("Prestatario eliminado exitosamente".to_string());
     Ok((StatusCode::OK, Json(json!(response))))
 }
+
+/// Restaurar un prestatario eliminado
+pub async fn restore_borrower<T>(
+    State(borrower_use_cases): State<BorrowerUseCases<T>>,
+    Path(id): Path<i32>,
+) -> Result<Json<BorrowerResponseDto>>
+where
+    T: BorrowerRepository,
+{
+    let borrower_dto = borrower_use_cases.restore_borrower(id).await?;
+
+    Ok(Json(BorrowerResponseDto::from(borrower_dto)))
+}
+
+/// Borrar definitivamente un prestatario eliminado (solo administradores)
+pub async fn purge_borrower<T>(
+    State(borrower_use_cases): State<BorrowerUseCases<T>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse>
+where
+    T: BorrowerRepository,
+{
+    borrower_use_cases.purge_borrower(id).await?;
+
+    let response = ApiResponse::ok("Prestatario purgado exitosamente".to_string());
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+/// Conciliar los totales de los prestatarios con sus registros (solo administradores)
+pub async fn reconcile_borrower_totals<T>(
+    State(borrower_use_cases): State<BorrowerUseCases<T>>,
+    Query(query): Query<ReconcileQueryDto>,
+) -> Result<Json<TotalsReconciliationResponseDto>>
+where
+    T: BorrowerRepository,
+{
+    let reconciliation = borrower_use_cases.reconcile_totals(query.fix).await?;
+    Ok(Json(TotalsReconciliationResponseDto::from(reconciliation)))
+}
+
+/// Bloquear un prestatario
+pub async fn block_borrower<T>(
+    State(borrower_use_cases): State<BorrowerUseCases<T>>,
+    Extension(user): Extension<UserPayload>,
+    Path(id): Path<i32>,
+    Json(payload): Json<BlockBorrowerRequestDto>,
+) -> Result<Json<BorrowerResponseDto>>
+where
+    T: BorrowerRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let block_request = BlockBorrowerRequest {
+        reason: payload.reason,
+    };
+
+    let borrower_dto = borrower_use_cases.block_borrower(id, block_request, user.id).await?;
+
+    Ok(Json(BorrowerResponseDto::from(borrower_dto)))
+}
+
+/// Desbloquear un prestatario (solo administradores)
+pub async fn unblock_borrower<T>(
+    State(borrower_use_cases): State<BorrowerUseCases<T>>,
+    Path(id): Path<i32>,
+) -> Result<Json<BorrowerResponseDto>>
+where
+    T: BorrowerRepository,
+{
+    let borrower_dto = borrower_use_cases.unblock_borrower(id).await?;
+
+    Ok(Json(BorrowerResponseDto::from(borrower_dto)))
+}
@@ -0,0 +1,227 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use validator::Validate;
+
+use crate::{
+    server::{
+        application::{
+            requests::cash_box_model_requests::{
+                CashCountRequest, CashExchangeRequest, CashMovementRequest, SearchCashExchangesRequest,
+            },
+            use_cases::cash_box_use_cases::CashBoxUseCases,
+        },
+        domain::{
+            entities::user::UserPayload,
+            repositories::{cash_box_repository::CashBoxRepository, currency_repository::CurrencyRepository},
+        },
+    },
+    utils::error::{AppError, Result},
+};
+
+use super::super::dtos::{
+    requests::cash_box_request_dto::{
+        CashCountRequestDto, CashExchangeQueryDto, CashExchangeRequestDto, CashMovementRequestDto,
+        CashStatementQueryDto,
+    },
+    responses::cash_box_responses::{
+        CashBoxResponseDto, CashCountResponseDto, CashExchangeReportResponseDto, CashExchangeResponseDto,
+        CashMovementResponseDto, CashStatementResponseDto,
+    },
+};
+
+/// Cajas de todas las monedas con su saldo actual
+pub async fn get_cash_boxes<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+) -> Result<Json<Vec<CashBoxResponseDto>>>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    let cash_boxes = cash_box_use_cases.get_cash_boxes().await?;
+    Ok(Json(cash_boxes.into_iter().map(CashBoxResponseDto::from).collect()))
+}
+
+/// Caja de una moneda con su saldo actual y el último arqueo
+pub async fn get_cash_box<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+    Path(currency): Path<String>,
+) -> Result<Json<CashBoxResponseDto>>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    let cash_box = cash_box_use_cases.get_cash_box(&currency).await?;
+    Ok(Json(CashBoxResponseDto::from(cash_box)))
+}
+
+/// Registrar el saldo de apertura de una caja (solo administradores)
+pub async fn open_cash_box<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+    Extension(user): Extension<UserPayload>,
+    Path(currency): Path<String>,
+    Json(payload): Json<CashMovementRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let movement_dto = cash_box_use_cases
+        .open_cash_box(&currency, movement_request(payload, user.id))
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(CashMovementResponseDto::from(movement_dto))))
+}
+
+/// Depositar fondos en una caja indicando el motivo
+pub async fn deposit_cash<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+    Extension(user): Extension<UserPayload>,
+    Path(currency): Path<String>,
+    Json(payload): Json<CashMovementRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let movement_dto = cash_box_use_cases
+        .deposit(&currency, movement_request(payload, user.id))
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(CashMovementResponseDto::from(movement_dto))))
+}
+
+/// Retirar fondos de una caja indicando el motivo
+pub async fn withdraw_cash<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+    Extension(user): Extension<UserPayload>,
+    Path(currency): Path<String>,
+    Json(payload): Json<CashMovementRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let movement_dto = cash_box_use_cases
+        .withdraw(&currency, movement_request(payload, user.id))
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(CashMovementResponseDto::from(movement_dto))))
+}
+
+/// Entradas y salidas de una caja en un período con el saldo después de cada una
+pub async fn get_cash_statement<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+    Path(currency): Path<String>,
+    Query(query): Query<CashStatementQueryDto>,
+) -> Result<Json<CashStatementResponseDto>>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    let statement = cash_box_use_cases.get_statement(&currency, query.from, query.to).await?;
+    Ok(Json(CashStatementResponseDto::from(statement)))
+}
+
+/// Registrar el arqueo de una caja; la diferencia con el saldo esperado queda ajustada
+pub async fn count_cash<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+    Extension(user): Extension<UserPayload>,
+    Path(currency): Path<String>,
+    Json(payload): Json<CashCountRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let count_request = CashCountRequest {
+        counted: payload.counted,
+        notes: payload.notes,
+        counted_by: user.id,
+        date: payload.date,
+    };
+
+    let count_dto = cash_box_use_cases.count_cash(&currency, count_request).await?;
+
+    Ok((StatusCode::CREATED, Json(CashCountResponseDto::from(count_dto))))
+}
+
+/// Arqueos de una caja, los más recientes primero
+pub async fn get_cash_counts<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+    Path(currency): Path<String>,
+) -> Result<Json<Vec<CashCountResponseDto>>>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    let counts = cash_box_use_cases.get_counts(&currency).await?;
+    Ok(Json(counts.into_iter().map(CashCountResponseDto::from).collect()))
+}
+
+/// Cambiar moneda de una caja a otra; ambas cajas se mueven en el mismo asiento
+pub async fn exchange_cash<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+    Extension(user): Extension<UserPayload>,
+    Json(payload): Json<CashExchangeRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let exchange_request = CashExchangeRequest {
+        source_currency: payload.source_currency,
+        source_amount: payload.source_amount,
+        target_currency: payload.target_currency,
+        target_amount: payload.target_amount,
+        rate: payload.rate,
+        notes: payload.notes,
+        created_by: user.id,
+        date: payload.date,
+    };
+
+    let exchange_dto = cash_box_use_cases.exchange(exchange_request).await?;
+
+    Ok((StatusCode::CREATED, Json(CashExchangeResponseDto::from(exchange_dto))))
+}
+
+/// Cambios de moneda del período con la ganancia o pérdida cambiaria realizada
+pub async fn get_cash_exchanges<X, C>(
+    State(cash_box_use_cases): State<CashBoxUseCases<X, C>>,
+    Query(query): Query<CashExchangeQueryDto>,
+) -> Result<Json<CashExchangeReportResponseDto>>
+where
+    X: CashBoxRepository,
+    C: CurrencyRepository,
+{
+    let search_request = SearchCashExchangesRequest {
+        currency: query.currency,
+        from: query.from,
+        to: query.to,
+    };
+
+    let report = cash_box_use_cases.get_exchange_report(search_request).await?;
+    Ok(Json(CashExchangeReportResponseDto::from(report)))
+}
+
+fn movement_request(payload: CashMovementRequestDto, created_by: i32) -> CashMovementRequest {
+    CashMovementRequest {
+        amount: payload.amount,
+        reason: payload.reason,
+        created_by,
+        date: payload.date,
+    }
+}
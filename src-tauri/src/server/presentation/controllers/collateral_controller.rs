@@ -0,0 +1,130 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use validator::Validate;
+
+use crate::{
+    server::{
+        application::{
+            requests::collateral_model_requests::{LiquidateCollateralRequest, RegisterCollateralRequest},
+            use_cases::collateral_use_cases::CollateralUseCases,
+        },
+        domain::repositories::{
+            collateral_repository::CollateralRepository, currency_repository::CurrencyRepository,
+            loan_repository::LoanRepository,
+        },
+    },
+    utils::error::{AppError, Result},
+};
+
+use super::super::dtos::{
+    requests::collateral_request_dto::{
+        CollateralQueryDto, LiquidateCollateralRequestDto, RegisterCollateralRequestDto,
+        ReturnCollateralRequestDto,
+    },
+    responses::collateral_responses::CollateralResponseDto,
+};
+
+/// Obtener las garantías, opcionalmente filtradas por préstamo
+pub async fn get_collateral<K, L, C>(
+    State(collateral_use_cases): State<CollateralUseCases<K, L, C>>,
+    Query(query): Query<CollateralQueryDto>,
+) -> Result<Json<Vec<CollateralResponseDto>>>
+where
+    K: CollateralRepository,
+    L: LoanRepository,
+    C: CurrencyRepository,
+{
+    let collateral_dtos = collateral_use_cases.get_collateral(query.loan_id).await?;
+
+    let response_dtos: Vec<CollateralResponseDto> = collateral_dtos
+        .into_iter()
+        .map(CollateralResponseDto::from)
+        .collect();
+
+    Ok(Json(response_dtos))
+}
+
+/// Obtener una garantía por ID
+pub async fn get_collateral_by_id<K, L, C>(
+    State(collateral_use_cases): State<CollateralUseCases<K, L, C>>,
+    Path(id): Path<i32>,
+) -> Result<Json<CollateralResponseDto>>
+where
+    K: CollateralRepository,
+    L: LoanRepository,
+    C: CurrencyRepository,
+{
+    let collateral_dto = collateral_use_cases.get_collateral_by_id(id).await?;
+
+    Ok(Json(CollateralResponseDto::from(collateral_dto)))
+}
+
+/// Registrar una garantía para un préstamo
+pub async fn register_collateral<K, L, C>(
+    State(collateral_use_cases): State<CollateralUseCases<K, L, C>>,
+    Json(payload): Json<RegisterCollateralRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    K: CollateralRepository,
+    L: LoanRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let register_request = RegisterCollateralRequest {
+        loan_id: payload.loan_id,
+        description: payload.description,
+        estimated_value: payload.estimated_value,
+        currency: payload.currency,
+        storage_location: payload.storage_location,
+        date: payload.date,
+    };
+
+    let collateral_dto = collateral_use_cases.register_collateral(register_request).await?;
+
+    Ok((StatusCode::CREATED, Json(CollateralResponseDto::from(collateral_dto))))
+}
+
+/// Devolver una garantía al prestatario
+pub async fn return_collateral<K, L, C>(
+    State(collateral_use_cases): State<CollateralUseCases<K, L, C>>,
+    Path(id): Path<i32>,
+    Json(payload): Json<ReturnCollateralRequestDto>,
+) -> Result<Json<CollateralResponseDto>>
+where
+    K: CollateralRepository,
+    L: LoanRepository,
+    C: CurrencyRepository,
+{
+    let collateral_dto = collateral_use_cases.return_collateral(id, payload.date).await?;
+
+    Ok(Json(CollateralResponseDto::from(collateral_dto)))
+}
+
+/// Liquidar una garantía registrando lo obtenido como pago (solo administradores)
+pub async fn liquidate_collateral<K, L, C>(
+    State(collateral_use_cases): State<CollateralUseCases<K, L, C>>,
+    Path(id): Path<i32>,
+    Json(payload): Json<LiquidateCollateralRequestDto>,
+) -> Result<Json<CollateralResponseDto>>
+where
+    K: CollateralRepository,
+    L: LoanRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let liquidate_request = LiquidateCollateralRequest {
+        amount: payload.amount,
+        currency: payload.currency,
+        date: payload.date,
+    };
+
+    let collateral_dto = collateral_use_cases.liquidate_collateral(id, liquidate_request).await?;
+
+    Ok(Json(CollateralResponseDto::from(collateral_dto)))
+}
@@ -0,0 +1,150 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    server::{
+        application::{
+            requests::currency_model_requests::{
+                CreateCurrencyRequest, RecordExchangeRateRequest, UpdateCurrencyRequest,
+            },
+            use_cases::currency_use_cases::CurrencyUseCases,
+        },
+        domain::repositories::currency_repository::CurrencyRepository,
+    },
+    utils::error::{AppError, Result},
+};
+
+use super::super::dtos::{
+    requests::currency_request_dto::{
+        CreateCurrencyRequestDto, RecordExchangeRateRequestDto, UpdateCurrencyRequestDto,
+    },
+    responses::{
+        api_response::ApiResponse,
+        currency_responses::{CurrencyResponseDto, ExchangeRateResponseDto},
+    },
+};
+
+/// Obtener todas las monedas
+pub async fn get_all_currencies<T>(
+    State(currency_use_cases): State<CurrencyUseCases<T>>,
+) -> Result<Json<Vec<CurrencyResponseDto>>>
+where
+    T: CurrencyRepository,
+{
+    let currency_dtos = currency_use_cases.get_all_currencies().await?;
+
+    let response_dtos: Vec<CurrencyResponseDto> = currency_dtos
+        .into_iter()
+        .map(CurrencyResponseDto::from)
+        .collect();
+
+    Ok(Json(response_dtos))
+}
+
+/// Registrar una nueva moneda (solo administradores)
+pub async fn create_currency<T>(
+    State(currency_use_cases): State<CurrencyUseCases<T>>,
+    Json(payload): Json<CreateCurrencyRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    T: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let create_request = CreateCurrencyRequest {
+        code: payload.code,
+        name: payload.name,
+        exchange_rate: payload.exchange_rate,
+    };
+
+    let currency_dto = currency_use_cases.create_currency(create_request).await?;
+
+    let response = ApiResponse::created(format!(
+        "Moneda {} registrada exitosamente",
+        currency_dto.code
+    ));
+    Ok((StatusCode::CREATED, Json(json!(response))))
+}
+
+/// Actualizar una moneda o su tasa de cambio (solo administradores)
+pub async fn update_currency<T>(
+    State(currency_use_cases): State<CurrencyUseCases<T>>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateCurrencyRequestDto>,
+) -> Result<Json<CurrencyResponseDto>>
+where
+    T: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let update_request = UpdateCurrencyRequest {
+        code: payload.code,
+        name: payload.name,
+        exchange_rate: payload.exchange_rate,
+    };
+
+    let updated_currency_dto = currency_use_cases
+        .update_currency(id, update_request)
+        .await?;
+
+    Ok(Json(CurrencyResponseDto::from(updated_currency_dto)))
+}
+
+/// Eliminar una moneda (solo administradores)
+pub async fn delete_currency<T>(
+    State(currency_use_cases): State<CurrencyUseCases<T>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse>
+where
+    T: CurrencyRepository,
+{
+    currency_use_cases.delete_currency(id).await?;
+
+    let response = ApiResponse::ok("Moneda eliminada exitosamente".to_string());
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+/// Obtener el historial de tasas de una moneda
+pub async fn get_rate_history<T>(
+    State(currency_use_cases): State<CurrencyUseCases<T>>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<ExchangeRateResponseDto>>>
+where
+    T: CurrencyRepository,
+{
+    let rate_dtos = currency_use_cases.get_rate_history(id).await?;
+
+    let response_dtos: Vec<ExchangeRateResponseDto> = rate_dtos
+        .into_iter()
+        .map(ExchangeRateResponseDto::from)
+        .collect();
+
+    Ok(Json(response_dtos))
+}
+
+/// Registrar una tasa, actual o histórica (solo administradores)
+pub async fn record_rate<T>(
+    State(currency_use_cases): State<CurrencyUseCases<T>>,
+    Path(id): Path<i32>,
+    Json(payload): Json<RecordExchangeRateRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    T: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let record_request = RecordExchangeRateRequest {
+        exchange_rate: payload.exchange_rate,
+        effective_from: payload.effective_from,
+    };
+
+    let rate_dto = currency_use_cases.record_rate(id, record_request).await?;
+
+    Ok((StatusCode::CREATED, Json(ExchangeRateResponseDto::from(rate_dto))))
+}
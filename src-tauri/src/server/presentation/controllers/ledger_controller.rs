@@ -0,0 +1,81 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use validator::Validate;
+
+use crate::{
+    server::{
+        application::{
+            requests::ledger_model_requests::SearchJournalRequest, use_cases::ledger_use_cases::LedgerUseCases,
+        },
+        domain::repositories::{currency_repository::CurrencyRepository, ledger_repository::LedgerRepository},
+    },
+    utils::error::{AppError, Result},
+};
+
+use super::super::dtos::{
+    requests::ledger_request_dto::{JournalQueryDto, LedgerQueryDto},
+    responses::ledger_responses::{JournalEntryResponseDto, ReceivablesResponseDto, TrialBalanceResponseDto},
+};
+
+/// Balance de comprobación a una fecha
+pub async fn get_trial_balance<G, C>(
+    State(ledger_use_cases): State<LedgerUseCases<G, C>>,
+    Query(query): Query<LedgerQueryDto>,
+) -> Result<Json<TrialBalanceResponseDto>>
+where
+    G: LedgerRepository,
+    C: CurrencyRepository,
+{
+    let trial_balance = ledger_use_cases.get_trial_balance(query.at).await?;
+    Ok(Json(TrialBalanceResponseDto::from(trial_balance)))
+}
+
+/// Asientos del libro diario con filtros por prestatario, préstamo, evento y fechas
+pub async fn get_journal_entries<G, C>(
+    State(ledger_use_cases): State<LedgerUseCases<G, C>>,
+    Query(query): Query<JournalQueryDto>,
+) -> Result<Json<Vec<JournalEntryResponseDto>>>
+where
+    G: LedgerRepository,
+    C: CurrencyRepository,
+{
+    query.validate().map_err(AppError::from)?;
+
+    let search_request = SearchJournalRequest {
+        borrower_id: query.borrower_id,
+        loan_id: query.loan_id,
+        event: query.event,
+        from: query.from,
+        to: query.to,
+    };
+
+    let entries = ledger_use_cases.get_journal(search_request).await?;
+    Ok(Json(entries.into_iter().map(JournalEntryResponseDto::from).collect()))
+}
+
+/// Cuentas por cobrar de cada prestatario según el libro mayor frente a su saldo guardado
+pub async fn get_receivables<G, C>(
+    State(ledger_use_cases): State<LedgerUseCases<G, C>>,
+) -> Result<Json<ReceivablesResponseDto>>
+where
+    G: LedgerRepository,
+    C: CurrencyRepository,
+{
+    let receivables = ledger_use_cases.get_receivables().await?;
+    Ok(Json(ReceivablesResponseDto::from(receivables)))
+}
+
+/// Revaluar las cajas en moneda extranjera (solo administradores)
+pub async fn revalue_cash<G, C>(
+    State(ledger_use_cases): State<LedgerUseCases<G, C>>,
+    Query(query): Query<LedgerQueryDto>,
+) -> Result<Json<Vec<JournalEntryResponseDto>>>
+where
+    G: LedgerRepository,
+    C: CurrencyRepository,
+{
+    let entries = ledger_use_cases.revalue_cash(query.at).await?;
+    Ok(Json(entries.into_iter().map(JournalEntryResponseDto::from).collect()))
+}
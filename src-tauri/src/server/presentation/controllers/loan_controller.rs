@@ -0,0 +1,399 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    server::{
+        application::{
+            requests::loan_model_requests::{
+                CreateLoanRequest, GuarantorRequest, RestructureLoanRequest, UpdateLoanRequest,
+                WriteOffLoanRequest,
+            },
+            use_cases::loan_use_cases::LoanUseCases,
+        },
+        domain::{
+            entities::{
+                loan::{InstallmentFrequency, InterestMethod, RatePeriod, ScheduleTerms},
+                user::UserPayload,
+            },
+            repositories::{
+                borrower_repository::BorrowerRepository, cash_box_repository::CashBoxRepository,
+                currency_repository::CurrencyRepository, loan_product_repository::LoanProductRepository,
+                loan_repository::LoanRepository,
+            },
+        },
+    },
+    utils::error::{AppError, Result},
+};
+
+use super::super::dtos::{
+    requests::loan_requests_dto::{
+        CreateLoanRequestDto, GuarantorRequestDto, LoanInterestQueryDto, LoanQueryDto, RestructureLoanRequestDto,
+        ScheduleRequestDto, UpdateLoanRequestDto, WriteOffLoanRequestDto,
+    },
+    responses::{
+        api_response::ApiResponse,
+        loan_responses::{
+            LoanGuarantorResponseDto, LoanInterestResponseDto, LoanResponseDto, LoanRestructureResponseDto,
+            LoanScheduleResponseDto,
+        },
+    },
+};
+
+/// Crear un nuevo préstamo
+pub async fn create_loan<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Extension(user): Extension<UserPayload>,
+    Json(payload): Json<CreateLoanRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let credit_limit_override_by = credit_limit_override(payload.override_credit_limit, &user)?;
+
+    let create_request = CreateLoanRequest {
+        amount: payload.amount,
+        currency: payload.currency,
+        interest_rate: payload.interest_rate,
+        rate_period: payload.rate_period.as_deref().and_then(RatePeriod::parse),
+        interest_method: payload.interest_method.as_deref().and_then(InterestMethod::parse),
+        schedule: payload.schedule.as_ref().and_then(schedule_terms),
+        due_date: payload.due_date,
+        borrower_id: payload.borrower_id,
+        product_id: payload.product_id,
+        guarantors: payload
+            .guarantors
+            .unwrap_or_default()
+            .into_iter()
+            .map(guarantor_request)
+            .collect(),
+        credit_limit_override_by,
+        date: payload.date,
+    };
+
+    let loan_dto = loan_use_cases.create_loan(create_request).await?;
+
+    let response = ApiResponse::created(format!(
+        "Préstamo registrado exitosamente para {}",
+        loan_dto.borrower.name
+    ))
+    .with_warnings(loan_dto.warnings);
+    Ok((StatusCode::CREATED, Json(json!(response))))
+}
+
+/// Obtener todos los préstamos
+pub async fn get_all_loans<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Query(query): Query<LoanQueryDto>,
+) -> Result<Json<Vec<LoanResponseDto>>>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    let loan_dtos = loan_use_cases.get_all_loans(query.deleted).await?;
+
+    let response_dtos: Vec<LoanResponseDto> = loan_dtos
+        .into_iter()
+        .map(LoanResponseDto::from)
+        .collect();
+
+    Ok(Json(response_dtos))
+}
+
+/// Obtener un préstamo por ID
+pub async fn get_loan_by_id<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Path(id): Path<i32>,
+) -> Result<Json<LoanResponseDto>>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    let loan_dto = loan_use_cases.get_loan_by_id(id).await?;
+    Ok(Json(LoanResponseDto::from(loan_dto)))
+}
+
+/// Actualizar un préstamo
+pub async fn update_loan<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Extension(user): Extension<UserPayload>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateLoanRequestDto>,
+) -> Result<Json<LoanResponseDto>>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let update_request = UpdateLoanRequest {
+        amount: payload.amount,
+        currency: payload.currency,
+        interest_rate: payload.interest_rate,
+        rate_period: payload.rate_period.as_deref().and_then(RatePeriod::parse),
+        interest_method: payload.interest_method.as_deref().and_then(InterestMethod::parse),
+        schedule: payload.schedule.as_ref().and_then(schedule_terms),
+        due_date: payload.due_date,
+        borrower_id: payload.borrower_id,
+        credit_limit_override_by: credit_limit_override(payload.override_credit_limit, &user)?,
+        date: payload.date,
+    };
+
+    let updated_loan_dto = loan_use_cases.update_loan(id, update_request).await?;
+
+    Ok(Json(LoanResponseDto::from(updated_loan_dto)))
+}
+
+/// Interés devengado y total adeudado de un préstamo a una fecha
+pub async fn get_loan_interest<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Path(id): Path<i32>,
+    Query(query): Query<LoanInterestQueryDto>,
+) -> Result<Json<LoanInterestResponseDto>>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    let interest_dto = loan_use_cases.get_loan_interest(id, query.at).await?;
+    Ok(Json(LoanInterestResponseDto::from(interest_dto)))
+}
+
+/// Plan de cuotas de un préstamo
+pub async fn get_loan_schedule<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Path(id): Path<i32>,
+) -> Result<Json<LoanScheduleResponseDto>>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    let schedule_dto = loan_use_cases.get_loan_schedule(id).await?;
+    Ok(Json(LoanScheduleResponseDto::from(schedule_dto)))
+}
+
+/// Reestructurar un préstamo: se cierra y se crea otro con las condiciones nuevas
+pub async fn restructure_loan<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Path(id): Path<i32>,
+    Json(payload): Json<RestructureLoanRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let restructure_request = RestructureLoanRequest {
+        interest_rate: payload.interest_rate,
+        rate_period: payload.rate_period.as_deref().and_then(RatePeriod::parse),
+        interest_method: payload.interest_method.as_deref().and_then(InterestMethod::parse),
+        schedule: payload.schedule.as_ref().and_then(schedule_terms),
+        due_date: payload.due_date,
+        capitalize_interest: payload.capitalize_interest,
+        capitalize_penalties: payload.capitalize_penalties,
+        date: payload.date,
+    };
+
+    let restructure_dto = loan_use_cases.restructure_loan(id, restructure_request).await?;
+
+    Ok((StatusCode::CREATED, Json(LoanRestructureResponseDto::from(restructure_dto))))
+}
+
+/// Castigar un préstamo incobrable (solo administradores)
+pub async fn write_off_loan<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Extension(user): Extension<UserPayload>,
+    Path(id): Path<i32>,
+    Json(payload): Json<WriteOffLoanRequestDto>,
+) -> Result<Json<LoanResponseDto>>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let write_off_request = WriteOffLoanRequest {
+        reason: payload.reason,
+        date: payload.date,
+    };
+
+    let loan_dto = loan_use_cases.write_off_loan(id, write_off_request, user.id).await?;
+
+    Ok(Json(LoanResponseDto::from(loan_dto)))
+}
+
+/// Eliminar un préstamo
+pub async fn delete_loan<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Extension(user): Extension<UserPayload>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    loan_use_cases.delete_loan(id, user.id).await?;
+
+    let response = ApiResponse::ok("Préstamo eliminado exitosamente".to_string());
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+/// Restaurar un préstamo eliminado
+pub async fn restore_loan<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Path(id): Path<i32>,
+) -> Result<Json<LoanResponseDto>>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    let loan_dto = loan_use_cases.restore_loan(id).await?;
+
+    Ok(Json(LoanResponseDto::from(loan_dto)))
+}
+
+/// Borrar definitivamente un préstamo eliminado (solo administradores)
+pub async fn purge_loan<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    loan_use_cases.purge_loan(id).await?;
+
+    let response = ApiResponse::ok("Préstamo purgado exitosamente".to_string());
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+/// Obtener los garantes de un préstamo
+pub async fn get_loan_guarantors<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<LoanGuarantorResponseDto>>>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    let guarantor_dtos = loan_use_cases.get_loan_guarantors(id).await?;
+
+    let response_dtos: Vec<LoanGuarantorResponseDto> = guarantor_dtos
+        .into_iter()
+        .map(LoanGuarantorResponseDto::from)
+        .collect();
+
+    Ok(Json(response_dtos))
+}
+
+/// Agregar un garante a un préstamo
+pub async fn add_loan_guarantor<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Path(id): Path<i32>,
+    Json(payload): Json<GuarantorRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let guarantor_dto = loan_use_cases
+        .add_loan_guarantor(id, guarantor_request(payload))
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(LoanGuarantorResponseDto::from(guarantor_dto))))
+}
+
+/// Quitar un garante de un préstamo
+pub async fn remove_loan_guarantor<T, U, C, R, X>(
+    State(loan_use_cases): State<LoanUseCases<T, U, C, R, X>>,
+    Path((id, guarantor_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse>
+where
+    T: LoanRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+    R: LoanProductRepository,
+    X: CashBoxRepository,
+{
+    loan_use_cases.remove_loan_guarantor(id, guarantor_id).await?;
+
+    let response = ApiResponse::ok("Garante quitado exitosamente".to_string());
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+/// Solo un administrador puede autorizar que el préstamo supere el límite de crédito
+fn credit_limit_override(override_credit_limit: bool, user: &UserPayload) -> Result<Option<i32>> {
+    match override_credit_limit {
+        true if user.role != "admin" => Err(AppError::Forbidden(
+            "Solo un administrador puede autorizar un préstamo por encima del límite de crédito".to_string(),
+        )),
+        true => Ok(Some(user.id)),
+        false => Ok(None),
+    }
+}
+
+fn guarantor_request(dto: GuarantorRequestDto) -> GuarantorRequest {
+    GuarantorRequest {
+        borrower_id: dto.borrower_id,
+        contact_name: dto.contact_name,
+        contact_phone: dto.contact_phone,
+        amount: dto.amount,
+    }
+}
+
+fn schedule_terms(dto: &ScheduleRequestDto) -> Option<ScheduleTerms> {
+    Some(ScheduleTerms {
+        frequency: InstallmentFrequency::parse(&dto.frequency)?,
+        periods: dto.periods,
+        first_due_date: dto.first_due_date.naive_utc(),
+    })
+}
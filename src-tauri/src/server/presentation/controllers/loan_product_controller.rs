@@ -0,0 +1,147 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    server::{
+        application::{
+            requests::loan_product_model_requests::{CreateLoanProductRequest, UpdateLoanProductRequest},
+            use_cases::loan_product_use_cases::LoanProductUseCases,
+        },
+        domain::{
+            entities::loan::{InstallmentFrequency, InterestMethod, RatePeriod},
+            repositories::{
+                currency_repository::CurrencyRepository,
+                loan_product_repository::LoanProductRepository,
+                penalty_repository::PenaltyRepository,
+            },
+        },
+    },
+    utils::error::{AppError, Result},
+};
+
+use super::super::dtos::{
+    requests::loan_product_request_dto::{CreateLoanProductRequestDto, UpdateLoanProductRequestDto},
+    responses::{api_response::ApiResponse, loan_product_responses::LoanProductResponseDto},
+};
+
+/// Obtener todos los productos de préstamo
+pub async fn get_loan_products<R, N, C>(
+    State(product_use_cases): State<LoanProductUseCases<R, N, C>>,
+) -> Result<Json<Vec<LoanProductResponseDto>>>
+where
+    R: LoanProductRepository,
+    N: PenaltyRepository,
+    C: CurrencyRepository,
+{
+    let product_dtos = product_use_cases.get_products().await?;
+
+    let response_dtos: Vec<LoanProductResponseDto> = product_dtos
+        .into_iter()
+        .map(LoanProductResponseDto::from)
+        .collect();
+
+    Ok(Json(response_dtos))
+}
+
+/// Obtener un producto de préstamo por ID
+pub async fn get_loan_product_by_id<R, N, C>(
+    State(product_use_cases): State<LoanProductUseCases<R, N, C>>,
+    Path(id): Path<i32>,
+) -> Result<Json<LoanProductResponseDto>>
+where
+    R: LoanProductRepository,
+    N: PenaltyRepository,
+    C: CurrencyRepository,
+{
+    let product_dto = product_use_cases.get_product(id).await?;
+
+    Ok(Json(LoanProductResponseDto::from(product_dto)))
+}
+
+/// Registrar un producto de préstamo (solo administradores)
+pub async fn create_loan_product<R, N, C>(
+    State(product_use_cases): State<LoanProductUseCases<R, N, C>>,
+    Json(payload): Json<CreateLoanProductRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    R: LoanProductRepository,
+    N: PenaltyRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let create_request = CreateLoanProductRequest {
+        name: payload.name,
+        currency: payload.currency,
+        interest_rate: payload.interest_rate,
+        rate_period: payload.rate_period.as_deref().and_then(RatePeriod::parse),
+        interest_method: payload.interest_method.as_deref().and_then(InterestMethod::parse),
+        installment_frequency: payload.installment_frequency.as_deref().and_then(InstallmentFrequency::parse),
+        installment_count: payload.installment_count,
+        term_days: payload.term_days,
+        penalty_rule_id: payload.penalty_rule_id,
+        min_amount: payload.min_amount,
+        max_amount: payload.max_amount,
+    };
+
+    let product_dto = product_use_cases.create_product(create_request).await?;
+
+    Ok((StatusCode::CREATED, Json(LoanProductResponseDto::from(product_dto))))
+}
+
+/// Actualizar un producto de préstamo (solo administradores)
+pub async fn update_loan_product<R, N, C>(
+    State(product_use_cases): State<LoanProductUseCases<R, N, C>>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateLoanProductRequestDto>,
+) -> Result<Json<LoanProductResponseDto>>
+where
+    R: LoanProductRepository,
+    N: PenaltyRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let update_request = UpdateLoanProductRequest {
+        name: payload.name,
+        currency: payload.currency,
+        interest_rate: payload.interest_rate,
+        rate_period: payload.rate_period.as_deref().and_then(RatePeriod::parse),
+        interest_method: payload.interest_method.as_deref().and_then(InterestMethod::parse),
+        installment_frequency: payload.installment_frequency.as_deref().and_then(InstallmentFrequency::parse),
+        installment_count: payload.installment_count,
+        term_days: payload.term_days,
+        penalty_rule_id: payload.penalty_rule_id,
+        min_amount: payload.min_amount,
+        max_amount: payload.max_amount,
+    };
+
+    let product_dto = product_use_cases.update_product(id, update_request).await?;
+
+    Ok(Json(LoanProductResponseDto::from(product_dto)))
+}
+
+/// Desactivar un producto de préstamo (solo administradores)
+pub async fn deactivate_loan_product<R, N, C>(
+    State(product_use_cases): State<LoanProductUseCases<R, N, C>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse>
+where
+    R: LoanProductRepository,
+    N: PenaltyRepository,
+    C: CurrencyRepository,
+{
+    let product_dto = product_use_cases.deactivate_product(id).await?;
+
+    let response = ApiResponse::ok(format!(
+        "Producto {} desactivado exitosamente",
+        product_dto.name
+    ));
+    Ok((StatusCode::OK, Json(json!(response))))
+}
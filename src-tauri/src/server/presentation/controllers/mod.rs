@@ -1,3 +1,13 @@
 pub mod  auth_controller;
 pub mod user_controller;
-pub mod  borrower_controller;
\ No newline at end of file
+pub mod  borrower_controller;
+pub mod loan_controller;
+pub mod payment_controller;
+pub mod currency_controller;
+pub mod report_controller;
+pub mod penalty_controller;
+pub mod loan_product_controller;
+pub mod collateral_controller;
+pub mod statement_controller;
+pub mod ledger_controller;
+pub mod cash_box_controller;
\ No newline at end of file
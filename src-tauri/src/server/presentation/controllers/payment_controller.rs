@@ -0,0 +1,167 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    server::{
+        application::{
+            requests::payment_model_requests::{CreatePaymentRequest, UpdatePaymentRequest},
+            use_cases::payment_use_cases::PaymentUseCases,
+        },
+        domain::{
+            entities::{payment_allocation::AllocationStrategy, user::UserPayload},
+            repositories::{
+                borrower_repository::BorrowerRepository, currency_repository::CurrencyRepository,
+                payment_repository::PaymentRepository,
+            },
+        },
+    },
+    utils::error::{AppError, Result},
+};
+
+use super::super::dtos::{
+    requests::payment_request_dto::{CreatePaymentRequestDto, PaymentQueryDto, UpdatePaymentRequestDto},
+    responses::{api_response::ApiResponse, payment_responses::PaymentResponseDto},
+};
+
+/// Crear un nuevo pago
+pub async fn create_payment<T, U, C>(
+    State(payment_use_cases): State<PaymentUseCases<T, U, C>>,
+    Json(payload): Json<CreatePaymentRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    T: PaymentRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let create_request = CreatePaymentRequest {
+        amount: payload.amount,
+        currency: payload.currency,
+        allocation_strategy: payload.allocation_strategy.as_deref().and_then(AllocationStrategy::parse),
+        borrower_id: payload.borrower_id,
+        date: payload.date,
+    };
+
+    let payment_dto = payment_use_cases.create_payment(create_request).await?;
+
+    let response = ApiResponse::created(format!(
+        "Pago de {} registrado exitosamente",
+        payment_dto.borrower.name
+    ));
+    Ok((StatusCode::CREATED, Json(json!(response))))
+}
+
+/// Obtener todos los pagos
+pub async fn get_all_payments<T, U, C>(
+    State(payment_use_cases): State<PaymentUseCases<T, U, C>>,
+    Query(query): Query<PaymentQueryDto>,
+) -> Result<Json<Vec<PaymentResponseDto>>>
+where
+    T: PaymentRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+{
+    let payment_dtos = payment_use_cases.get_all_payments(query.deleted).await?;
+
+    let response_dtos: Vec<PaymentResponseDto> = payment_dtos
+        .into_iter()
+        .map(PaymentResponseDto::from)
+        .collect();
+
+    Ok(Json(response_dtos))
+}
+
+/// Obtener un pago por ID
+pub async fn get_payment_by_id<T, U, C>(
+    State(payment_use_cases): State<PaymentUseCases<T, U, C>>,
+    Path(id): Path<i32>,
+) -> Result<Json<PaymentResponseDto>>
+where
+    T: PaymentRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+{
+    let payment_dto = payment_use_cases.get_payment_by_id(id).await?;
+    Ok(Json(PaymentResponseDto::from(payment_dto)))
+}
+
+/// Actualizar un pago
+pub async fn update_payment<T, U, C>(
+    State(payment_use_cases): State<PaymentUseCases<T, U, C>>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdatePaymentRequestDto>,
+) -> Result<Json<PaymentResponseDto>>
+where
+    T: PaymentRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let update_request = UpdatePaymentRequest {
+        amount: payload.amount,
+        currency: payload.currency,
+        allocation_strategy: payload.allocation_strategy.as_deref().and_then(AllocationStrategy::parse),
+        borrower_id: payload.borrower_id,
+        date: payload.date,
+    };
+
+    let updated_payment_dto = payment_use_cases.update_payment(id, update_request).await?;
+
+    Ok(Json(PaymentResponseDto::from(updated_payment_dto)))
+}
+
+/// Eliminar un pago
+pub async fn delete_payment<T, U, C>(
+    State(payment_use_cases): State<PaymentUseCases<T, U, C>>,
+    Extension(user): Extension<UserPayload>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse>
+where
+    T: PaymentRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+{
+    payment_use_cases.delete_payment(id, user.id).await?;
+
+    let response = ApiResponse::ok("Pago eliminado exitosamente".to_string());
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+/// Restaurar un pago eliminado
+pub async fn restore_payment<T, U, C>(
+    State(payment_use_cases): State<PaymentUseCases<T, U, C>>,
+    Path(id): Path<i32>,
+) -> Result<Json<PaymentResponseDto>>
+where
+    T: PaymentRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+{
+    let payment_dto = payment_use_cases.restore_payment(id).await?;
+
+    Ok(Json(PaymentResponseDto::from(payment_dto)))
+}
+
+/// Borrar definitivamente un pago eliminado (solo administradores)
+pub async fn purge_payment<T, U, C>(
+    State(payment_use_cases): State<PaymentUseCases<T, U, C>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse>
+where
+    T: PaymentRepository,
+    U: BorrowerRepository,
+    C: CurrencyRepository,
+{
+    payment_use_cases.purge_payment(id).await?;
+
+    let response = ApiResponse::ok("Pago purgado exitosamente".to_string());
+    Ok((StatusCode::OK, Json(json!(response))))
+}
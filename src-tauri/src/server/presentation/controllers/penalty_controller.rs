@@ -0,0 +1,130 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    server::{
+        application::{
+            requests::penalty_model_requests::CreatePenaltyRuleRequest,
+            use_cases::penalty_use_cases::PenaltyUseCases,
+        },
+        domain::{
+            entities::user::UserPayload,
+            repositories::{loan_repository::LoanRepository, penalty_repository::PenaltyRepository},
+        },
+    },
+    utils::error::{AppError, Result},
+};
+
+use super::super::dtos::{
+    requests::penalty_request_dto::{CreatePenaltyRuleRequestDto, PenaltyQueryDto, WaivePenaltyRequestDto},
+    responses::{
+        api_response::ApiResponse,
+        penalty_responses::{PenaltyResponseDto, PenaltyRuleResponseDto},
+    },
+};
+
+/// Obtener los recargos, opcionalmente filtrados por prestatario
+pub async fn get_penalties<P, L>(
+    State(penalty_use_cases): State<PenaltyUseCases<P, L>>,
+    Query(query): Query<PenaltyQueryDto>,
+) -> Result<Json<Vec<PenaltyResponseDto>>>
+where
+    P: PenaltyRepository,
+    L: LoanRepository,
+{
+    let penalty_dtos = penalty_use_cases.get_penalties(query.borrower_id).await?;
+
+    let response_dtos: Vec<PenaltyResponseDto> = penalty_dtos
+        .into_iter()
+        .map(PenaltyResponseDto::from)
+        .collect();
+
+    Ok(Json(response_dtos))
+}
+
+/// Condonar un recargo indicando el motivo (solo administradores)
+pub async fn waive_penalty<P, L>(
+    State(penalty_use_cases): State<PenaltyUseCases<P, L>>,
+    Extension(user): Extension<UserPayload>,
+    Path(id): Path<i32>,
+    Json(payload): Json<WaivePenaltyRequestDto>,
+) -> Result<Json<PenaltyResponseDto>>
+where
+    P: PenaltyRepository,
+    L: LoanRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let penalty_dto = penalty_use_cases
+        .waive_penalty(id, payload.reason, user.id)
+        .await?;
+
+    Ok(Json(PenaltyResponseDto::from(penalty_dto)))
+}
+
+/// Obtener todas las reglas de recargo
+pub async fn get_penalty_rules<P, L>(
+    State(penalty_use_cases): State<PenaltyUseCases<P, L>>,
+) -> Result<Json<Vec<PenaltyRuleResponseDto>>>
+where
+    P: PenaltyRepository,
+    L: LoanRepository,
+{
+    let rule_dtos = penalty_use_cases.get_rules().await?;
+
+    let response_dtos: Vec<PenaltyRuleResponseDto> = rule_dtos
+        .into_iter()
+        .map(PenaltyRuleResponseDto::from)
+        .collect();
+
+    Ok(Json(response_dtos))
+}
+
+/// Registrar una regla de recargo (solo administradores)
+pub async fn create_penalty_rule<P, L>(
+    State(penalty_use_cases): State<PenaltyUseCases<P, L>>,
+    Json(payload): Json<CreatePenaltyRuleRequestDto>,
+) -> Result<impl IntoResponse>
+where
+    P: PenaltyRepository,
+    L: LoanRepository,
+{
+    payload.validate().map_err(AppError::from)?;
+
+    let create_request = CreatePenaltyRuleRequest {
+        name: payload.name,
+        kind: payload.kind,
+        amount: payload.amount,
+        rate: payload.rate,
+        grace_days: payload.grace_days,
+        cap: payload.cap,
+    };
+
+    let rule_dto = penalty_use_cases.create_rule(create_request).await?;
+
+    Ok((StatusCode::CREATED, Json(PenaltyRuleResponseDto::from(rule_dto))))
+}
+
+/// Desactivar una regla de recargo (solo administradores)
+pub async fn deactivate_penalty_rule<P, L>(
+    State(penalty_use_cases): State<PenaltyUseCases<P, L>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse>
+where
+    P: PenaltyRepository,
+    L: LoanRepository,
+{
+    let rule_dto = penalty_use_cases.deactivate_rule(id).await?;
+
+    let response = ApiResponse::ok(format!(
+        "Regla de recargo {} desactivada exitosamente",
+        rule_dto.name
+    ));
+    Ok((StatusCode::OK, Json(json!(response))))
+}
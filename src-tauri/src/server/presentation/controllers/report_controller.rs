@@ -0,0 +1,35 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+
+use crate::{
+    server::{
+        application::use_cases::report_use_cases::ReportUseCases,
+        domain::repositories::{
+            borrower_repository::BorrowerRepository, currency_repository::CurrencyRepository,
+            loan_repository::LoanRepository, payment_repository::PaymentRepository,
+        },
+    },
+    utils::error::Result,
+};
+
+use super::super::dtos::{
+    requests::report_requests_dto::RevaluationQueryDto,
+    responses::report_responses::BalanceRevaluationResponseDto,
+};
+
+/// Revaluar los saldos de los prestatarios a las tasas de una fecha
+pub async fn get_balance_revaluation<L, P, B, C>(
+    State(report_use_cases): State<ReportUseCases<L, P, B, C>>,
+    Query(query): Query<RevaluationQueryDto>,
+) -> Result<Json<BalanceRevaluationResponseDto>>
+where
+    L: LoanRepository,
+    P: PaymentRepository,
+    B: BorrowerRepository,
+    C: CurrencyRepository,
+{
+    let revaluation = report_use_cases.revalue_balances(query.at).await?;
+    Ok(Json(BalanceRevaluationResponseDto::from(revaluation)))
+}
@@ -0,0 +1,38 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+
+use crate::{
+    server::{
+        application::use_cases::statement_use_cases::StatementUseCases,
+        domain::repositories::{
+            borrower_repository::BorrowerRepository, loan_repository::LoanRepository,
+            ledger_repository::LedgerRepository, payment_repository::PaymentRepository,
+            penalty_repository::PenaltyRepository,
+        },
+    },
+    utils::error::Result,
+};
+
+use super::super::dtos::{
+    requests::statement_request_dto::StatementQueryDto,
+    responses::statement_responses::BorrowerStatementResponseDto,
+};
+
+/// Estado de cuenta de un prestatario en un período
+pub async fn get_borrower_statement<B, L, P, N, G>(
+    State(statement_use_cases): State<StatementUseCases<B, L, P, N, G>>,
+    Path(id): Path<i32>,
+    Query(query): Query<StatementQueryDto>,
+) -> Result<Json<BorrowerStatementResponseDto>>
+where
+    B: BorrowerRepository,
+    L: LoanRepository,
+    P: PaymentRepository,
+    N: PenaltyRepository,
+    G: LedgerRepository,
+{
+    let statement = statement_use_cases.get_statement(id, query.from, query.to).await?;
+    Ok(Json(BorrowerStatementResponseDto::from(statement)))
+}
@@ -1,5 +1,11 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+use crate::server::domain::entities::borrower::{
+    BorrowerSortField, RiskTier, SortDirection, BORROWER_BLOCKED, BORROWER_CURRENT, BORROWER_DELINQUENT,
+    BORROWER_LATE, BORROWER_PAID_OFF,
+};
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreateBorrowerRequestDto {
@@ -41,4 +47,119 @@ pub struct UpdateBorrowerRequestDto {
         message = "La ubicación no puede estar vacía"
     ))]
     pub location: Option<String>,
+
+    /// Límite de crédito en CUP; 0 quita el límite (solo administradores)
+    #[validate(range(
+        min = 0.0,
+        max = 100000000.0,
+        message = "El límite de crédito no puede ser negativo ni exceder 100,000,000"
+    ))]
+    pub credit_limit: Option<f64>,
+
+    /// Categoría de riesgo (solo administradores)
+    #[validate(custom(
+        function = "validate_risk_tier",
+        message = "La categoría de riesgo debe ser low, medium o high"
+    ))]
+    pub risk_tier: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct BlockBorrowerRequestDto {
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "El motivo es requerido y debe tener menos de 500 caracteres"
+    ))]
+    pub reason: String,
+}
+
+/// Parámetros de consulta del listado de prestatarios
+#[derive(Debug, Deserialize, Validate)]
+pub struct BorrowerQueryDto {
+    /// Texto a buscar en el nombre, el teléfono y la ubicación
+    pub search: Option<String>,
+
+    #[validate(custom(
+        function = "validate_borrower_status",
+        message = "El estado debe ser current, late, delinquent, paid_off o blocked"
+    ))]
+    pub status: Option<String>,
+
+    /// Rango del saldo en CUP
+    #[serde(rename = "minBalance")]
+    pub min_balance: Option<f64>,
+
+    #[serde(rename = "maxBalance")]
+    pub max_balance: Option<f64>,
+
+    /// Rango de la fecha de alta
+    #[serde(rename = "createdFrom")]
+    pub created_from: Option<DateTime<Utc>>,
+
+    #[serde(rename = "createdTo")]
+    pub created_to: Option<DateTime<Utc>>,
+
+    /// Listar los eliminados en lugar de los vigentes
+    #[serde(default)]
+    pub deleted: bool,
+
+    #[validate(custom(
+        function = "validate_sort_field",
+        message = "El orden debe ser name, created_at, balance, total_loans o credit_score"
+    ))]
+    pub sort: Option<String>,
+
+    #[validate(custom(
+        function = "validate_sort_direction",
+        message = "La dirección debe ser asc o desc"
+    ))]
+    pub direction: Option<String>,
+
+    #[validate(range(
+        min = 1,
+        message = "La página debe ser al menos 1"
+    ))]
+    pub page: Option<i64>,
+
+    /// Sin tamaño de página se devuelven todas las coincidencias
+    #[validate(range(
+        min = 1,
+        max = 100,
+        message = "El tamaño de página debe estar entre 1 y 100"
+    ))]
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+}
+
+/// Parámetros de la conciliación de totales; sin `fix` solo se informan las diferencias
+#[derive(Debug, Deserialize)]
+pub struct ReconcileQueryDto {
+    #[serde(default)]
+    pub fix: bool,
+}
+
+fn validate_risk_tier(value: &str) -> Result<(), ValidationError> {
+    RiskTier::parse(value)
+        .map(|_| ())
+        .ok_or_else(|| ValidationError::new("risk_tier"))
+}
+
+fn validate_borrower_status(value: &str) -> Result<(), ValidationError> {
+    match value {
+        BORROWER_CURRENT | BORROWER_LATE | BORROWER_DELINQUENT | BORROWER_PAID_OFF | BORROWER_BLOCKED => Ok(()),
+        _ => Err(ValidationError::new("status")),
+    }
+}
+
+fn validate_sort_field(value: &str) -> Result<(), ValidationError> {
+    BorrowerSortField::parse(value)
+        .map(|_| ())
+        .ok_or_else(|| ValidationError::new("sort"))
+}
+
+fn validate_sort_direction(value: &str) -> Result<(), ValidationError> {
+    SortDirection::parse(value)
+        .map(|_| ())
+        .ok_or_else(|| ValidationError::new("direction"))
 }
\ No newline at end of file
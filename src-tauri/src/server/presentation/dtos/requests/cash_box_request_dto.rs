@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::Validate;
+
+/// Saldo de apertura, depósito o retiro de la caja, en su moneda
+#[derive(Debug, Deserialize, Validate)]
+pub struct CashMovementRequestDto {
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub amount: f64,
+
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "El motivo es requerido y debe tener menos de 500 caracteres"
+    ))]
+    pub reason: String,
+
+    /// Fecha del registro, permite cargar movimientos con fecha anterior
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Arqueo de la caja: el efectivo contado, en su moneda
+#[derive(Debug, Deserialize, Validate)]
+pub struct CashCountRequestDto {
+    #[validate(range(
+        min = 0.0,
+        max = 100000000.0,
+        message = "Lo contado no puede ser negativo ni exceder 100,000,000"
+    ))]
+    pub counted: f64,
+
+    #[validate(length(
+        max = 500,
+        message = "Las notas deben tener menos de 500 caracteres"
+    ))]
+    pub notes: Option<String>,
+
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Período de los movimientos de la caja; sin `from` abarca todo el historial y sin `to` llega a hoy
+#[derive(Debug, Deserialize)]
+pub struct CashStatementQueryDto {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Cambio de moneda entre cajas; se indica lo recibido o la tasa aplicada, no ambos
+#[derive(Debug, Deserialize, Validate)]
+pub struct CashExchangeRequestDto {
+    #[validate(length(equal = 3, message = "El código de moneda debe tener 3 letras"))]
+    pub source_currency: String,
+
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto entregado debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub source_amount: f64,
+
+    #[validate(length(equal = 3, message = "El código de moneda debe tener 3 letras"))]
+    pub target_currency: String,
+
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto recibido debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub target_amount: Option<f64>,
+
+    /// Lo recibido por cada unidad entregada
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 1000000.0,
+        message = "La tasa debe ser positiva y no puede exceder 1,000,000"
+    ))]
+    pub rate: Option<f64>,
+
+    #[validate(length(
+        max = 500,
+        message = "Las notas deben tener menos de 500 caracteres"
+    ))]
+    pub notes: Option<String>,
+
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Período del reporte de cambios; con `currency` solo los que la entregan o la reciben
+#[derive(Debug, Deserialize)]
+pub struct CashExchangeQueryDto {
+    pub currency: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct RegisterCollateralRequestDto {
+    #[serde(rename = "loanId")]
+    #[validate(range(
+        min = 1,
+        message = "Debe seleccionar un préstamo válido"
+    ))]
+    pub loan_id: i32,
+
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "La descripción es requerida y debe tener menos de 500 caracteres"
+    ))]
+    pub description: String,
+
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El valor estimado debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub estimated_value: f64,
+
+    /// Moneda de la tasación; por defecto la del préstamo
+    #[validate(length(
+        min = 1,
+        message = "La moneda no puede estar vacía"
+    ))]
+    pub currency: Option<String>,
+
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "El lugar de custodia es requerido y debe tener menos de 200 caracteres"
+    ))]
+    pub storage_location: String,
+
+    /// Fecha en que se recibió la garantía
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ReturnCollateralRequestDto {
+    /// Fecha de la devolución; por defecto la actual
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct LiquidateCollateralRequestDto {
+    /// Lo obtenido al vender la garantía
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub amount: f64,
+
+    /// Moneda en que se cobró; por defecto la de la tasación
+    #[validate(length(
+        min = 1,
+        message = "La moneda no puede estar vacía"
+    ))]
+    pub currency: Option<String>,
+
+    /// Fecha de la venta, que es la fecha del pago registrado
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Parámetros de consulta del listado de garantías
+#[derive(Debug, Deserialize)]
+pub struct CollateralQueryDto {
+    #[serde(rename = "loanId")]
+    pub loan_id: Option<i32>,
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateCurrencyRequestDto {
+    #[validate(custom(
+        function = "validate_currency_code",
+        message = "El código debe tener 3 letras mayúsculas"
+    ))]
+    pub code: String,
+
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "El nombre es requerido y debe tener menos de 50 caracteres"
+    ))]
+    pub name: String,
+
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 1000000.0,
+        message = "La tasa debe ser positiva y no puede ser mayor a 1,000,000"
+    ))]
+    pub exchange_rate: f32,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateCurrencyRequestDto {
+    #[validate(custom(
+        function = "validate_currency_code",
+        message = "El código debe tener 3 letras mayúsculas"
+    ))]
+    pub code: Option<String>,
+
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "El nombre es requerido y debe tener menos de 50 caracteres"
+    ))]
+    pub name: Option<String>,
+
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 1000000.0,
+        message = "La tasa debe ser positiva y no puede ser mayor a 1,000,000"
+    ))]
+    pub exchange_rate: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct RecordExchangeRateRequestDto {
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 1000000.0,
+        message = "La tasa debe ser positiva y no puede ser mayor a 1,000,000"
+    ))]
+    pub exchange_rate: f32,
+
+    /// Fecha desde la que rige la tasa, permite cargar tasas históricas
+    pub effective_from: Option<DateTime<Utc>>,
+}
+
+/// Códigos ISO 4217: tres letras mayúsculas (CUP, USD, EUR, MLC...)
+fn validate_currency_code(code: &str) -> Result<(), ValidationError> {
+    if code.len() == 3 && code.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("currency_code"))
+    }
+}
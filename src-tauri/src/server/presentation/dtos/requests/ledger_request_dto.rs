@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+use crate::server::domain::entities::ledger::{
+    EVENT_CASH_COUNT, EVENT_CASH_DEPOSIT, EVENT_CASH_EXCHANGE, EVENT_CASH_WITHDRAWAL, EVENT_DISBURSEMENT,
+    EVENT_FX_REVALUATION, EVENT_INTEREST, EVENT_OPENING, EVENT_PAYMENT, EVENT_PENALTY, EVENT_RECOVERY,
+    EVENT_WRITE_OFF,
+};
+
+/// Fecha de corte de los saldos o de la revaluación; por defecto la actual
+#[derive(Debug, Deserialize)]
+pub struct LedgerQueryDto {
+    pub at: Option<DateTime<Utc>>,
+}
+
+/// Parámetros de consulta del libro diario; las fechas incluyen ambos extremos
+#[derive(Debug, Deserialize, Validate)]
+pub struct JournalQueryDto {
+    #[serde(rename = "borrowerId")]
+    pub borrower_id: Option<i32>,
+
+    #[serde(rename = "loanId")]
+    pub loan_id: Option<i32>,
+
+    #[validate(custom(
+        function = "validate_event",
+        message = "El evento debe ser opening, disbursement, payment, interest, penalty, write_off, recovery, fx_revaluation, cash_deposit, cash_withdrawal, cash_count o cash_exchange"
+    ))]
+    pub event: Option<String>,
+
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+fn validate_event(value: &str) -> Result<(), ValidationError> {
+    match value {
+        EVENT_OPENING | EVENT_DISBURSEMENT | EVENT_PAYMENT | EVENT_INTEREST | EVENT_PENALTY | EVENT_WRITE_OFF
+        | EVENT_RECOVERY | EVENT_FX_REVALUATION | EVENT_CASH_DEPOSIT | EVENT_CASH_WITHDRAWAL | EVENT_CASH_COUNT
+        | EVENT_CASH_EXCHANGE => Ok(()),
+        _ => Err(ValidationError::new("event")),
+    }
+}
@@ -0,0 +1,162 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use super::loan_requests_dto::{
+    validate_installment_frequency, validate_interest_method, validate_rate_period,
+};
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateLoanProductRequestDto {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "El nombre es requerido y debe tener menos de 100 caracteres"
+    ))]
+    pub name: String,
+
+    #[validate(length(
+        min = 1,
+        message = "La moneda no puede estar vacía"
+    ))]
+    pub currency: Option<String>,
+
+    #[validate(range(
+        min = 0.0,
+        max = 100.0,
+        message = "La tasa de interés debe estar entre 0 y 100"
+    ))]
+    pub interest_rate: Option<f64>,
+
+    #[validate(custom(
+        function = "validate_rate_period",
+        message = "El período debe ser daily, weekly, monthly o yearly"
+    ))]
+    pub rate_period: Option<String>,
+
+    #[validate(custom(
+        function = "validate_interest_method",
+        message = "El método debe ser flat, declining_balance o simple_daily"
+    ))]
+    pub interest_method: Option<String>,
+
+    /// Frecuencia y número de cuotas del plan; sin plan se usa `term_days`
+    #[validate(custom(
+        function = "validate_installment_frequency",
+        message = "La frecuencia debe ser weekly, biweekly o monthly"
+    ))]
+    pub installment_frequency: Option<String>,
+
+    #[validate(range(
+        min = 1,
+        max = 520,
+        message = "El número de cuotas debe estar entre 1 y 520"
+    ))]
+    pub installment_count: Option<i32>,
+
+    /// Días desde la fecha del préstamo hasta su vencimiento
+    #[validate(range(
+        min = 1,
+        max = 3650,
+        message = "El plazo debe estar entre 1 y 3650 días"
+    ))]
+    pub term_days: Option<i32>,
+
+    #[validate(range(
+        min = 1,
+        message = "Debe seleccionar una regla de recargo válida"
+    ))]
+    pub penalty_rule_id: Option<i32>,
+
+    /// Límites del monto en la moneda del producto
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto mínimo debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub min_amount: Option<f64>,
+
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto máximo debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub max_amount: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateLoanProductRequestDto {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "El nombre debe tener entre 1 y 100 caracteres"
+    ))]
+    pub name: Option<String>,
+
+    #[validate(length(
+        min = 1,
+        message = "La moneda no puede estar vacía"
+    ))]
+    pub currency: Option<String>,
+
+    #[validate(range(
+        min = 0.0,
+        max = 100.0,
+        message = "La tasa de interés debe estar entre 0 y 100"
+    ))]
+    pub interest_rate: Option<f64>,
+
+    #[validate(custom(
+        function = "validate_rate_period",
+        message = "El período debe ser daily, weekly, monthly o yearly"
+    ))]
+    pub rate_period: Option<String>,
+
+    #[validate(custom(
+        function = "validate_interest_method",
+        message = "El método debe ser flat, declining_balance o simple_daily"
+    ))]
+    pub interest_method: Option<String>,
+
+    /// Frecuencia y número de cuotas del plan; sin plan se usa `term_days`
+    #[validate(custom(
+        function = "validate_installment_frequency",
+        message = "La frecuencia debe ser weekly, biweekly o monthly"
+    ))]
+    pub installment_frequency: Option<String>,
+
+    #[validate(range(
+        min = 1,
+        max = 520,
+        message = "El número de cuotas debe estar entre 1 y 520"
+    ))]
+    pub installment_count: Option<i32>,
+
+    /// Días desde la fecha del préstamo hasta su vencimiento
+    #[validate(range(
+        min = 1,
+        max = 3650,
+        message = "El plazo debe estar entre 1 y 3650 días"
+    ))]
+    pub term_days: Option<i32>,
+
+    #[validate(range(
+        min = 1,
+        message = "Debe seleccionar una regla de recargo válida"
+    ))]
+    pub penalty_rule_id: Option<i32>,
+
+    /// Límites del monto en la moneda del producto
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto mínimo debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub min_amount: Option<f64>,
+
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto máximo debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub max_amount: Option<f64>,
+}
@@ -0,0 +1,260 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+use crate::server::domain::entities::loan::{InstallmentFrequency, InterestMethod, RatePeriod};
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateLoanRequestDto {
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub amount: f64,
+
+    #[validate(length(
+        min = 1,
+        message = "La moneda no puede estar vacía"
+    ))]
+    pub currency: Option<String>,
+
+    #[validate(range(
+        min = 0.0,
+        max = 100.0,
+        message = "La tasa de interés debe estar entre 0 y 100"
+    ))]
+    pub interest_rate: Option<f64>,
+
+    #[validate(custom(
+        function = "validate_rate_period",
+        message = "El período debe ser daily, weekly, monthly o yearly"
+    ))]
+    pub rate_period: Option<String>,
+
+    #[validate(custom(
+        function = "validate_interest_method",
+        message = "El método debe ser flat, declining_balance o simple_daily"
+    ))]
+    pub interest_method: Option<String>,
+
+    /// Plan de cuotas opcional; sin él el préstamo se paga de una vez
+    #[validate(nested)]
+    pub schedule: Option<ScheduleRequestDto>,
+
+    /// Vencimiento del préstamo sin plan; con plan se toma el de la última cuota
+    pub due_date: Option<DateTime<Utc>>,
+
+    #[serde(rename = "borrowerId")]
+    #[validate(range(
+        min = 1,
+        message = "Debe seleccionar un prestatario válido"
+    ))]
+    pub borrower_id: i32,
+
+    /// Producto del que se toman las condiciones que no vengan en la solicitud
+    #[serde(rename = "productId")]
+    #[validate(range(
+        min = 1,
+        message = "Debe seleccionar un producto válido"
+    ))]
+    pub product_id: Option<i32>,
+
+    /// Prestatarios o contactos que garantizan el préstamo
+    #[validate(nested)]
+    pub guarantors: Option<Vec<GuarantorRequestDto>>,
+
+    /// Autoriza superar el límite de crédito del prestatario (solo administradores)
+    #[serde(default)]
+    pub override_credit_limit: bool,
+
+    /// Fecha del registro, permite cargar movimientos con fecha anterior
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateLoanRequestDto {
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub amount: Option<f64>,
+
+    #[validate(length(
+        min = 1,
+        message = "La moneda no puede estar vacía"
+    ))]
+    pub currency: Option<String>,
+
+    #[validate(range(
+        min = 0.0,
+        max = 100.0,
+        message = "La tasa de interés debe estar entre 0 y 100"
+    ))]
+    pub interest_rate: Option<f64>,
+
+    #[validate(custom(
+        function = "validate_rate_period",
+        message = "El período debe ser daily, weekly, monthly o yearly"
+    ))]
+    pub rate_period: Option<String>,
+
+    #[validate(custom(
+        function = "validate_interest_method",
+        message = "El método debe ser flat, declining_balance o simple_daily"
+    ))]
+    pub interest_method: Option<String>,
+
+    /// Plan de cuotas opcional; sin él el préstamo se paga de una vez
+    #[validate(nested)]
+    pub schedule: Option<ScheduleRequestDto>,
+
+    pub due_date: Option<DateTime<Utc>>,
+
+    #[serde(rename = "borrowerId")]
+    #[validate(range(
+        min = 1,
+        message = "Debe seleccionar un prestatario válido"
+    ))]
+    pub borrower_id: Option<i32>,
+
+    /// Autoriza superar el límite de crédito del prestatario (solo administradores)
+    #[serde(default)]
+    pub override_credit_limit: bool,
+
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Condiciones del préstamo nuevo; las que falten se toman del original
+#[derive(Debug, Deserialize, Validate)]
+pub struct RestructureLoanRequestDto {
+    #[validate(range(
+        min = 0.0,
+        max = 100.0,
+        message = "La tasa de interés debe estar entre 0 y 100"
+    ))]
+    pub interest_rate: Option<f64>,
+
+    #[validate(custom(
+        function = "validate_rate_period",
+        message = "El período debe ser daily, weekly, monthly o yearly"
+    ))]
+    pub rate_period: Option<String>,
+
+    #[validate(custom(
+        function = "validate_interest_method",
+        message = "El método debe ser flat, declining_balance o simple_daily"
+    ))]
+    pub interest_method: Option<String>,
+
+    #[validate(nested)]
+    pub schedule: Option<ScheduleRequestDto>,
+
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Sumar el interés pendiente al principal nuevo; si no, se condona
+    #[serde(default)]
+    pub capitalize_interest: bool,
+
+    /// Sumar los recargos sin condonar al principal nuevo; si no, se siguen cobrando aparte
+    #[serde(default)]
+    pub capitalize_penalties: bool,
+
+    /// Fecha de la reestructuración; por defecto la actual
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct WriteOffLoanRequestDto {
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "El motivo es requerido y debe tener menos de 500 caracteres"
+    ))]
+    pub reason: String,
+
+    /// Fecha del castigo; por defecto la actual
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ScheduleRequestDto {
+    #[validate(custom(
+        function = "validate_installment_frequency",
+        message = "La frecuencia debe ser weekly, biweekly o monthly"
+    ))]
+    pub frequency: String,
+
+    #[validate(range(
+        min = 1,
+        max = 520,
+        message = "El número de cuotas debe estar entre 1 y 520"
+    ))]
+    pub periods: i32,
+
+    pub first_due_date: DateTime<Utc>,
+}
+
+/// Garante registrado (`borrowerId`) o contacto sin ficha (`contact_name`)
+#[derive(Debug, Deserialize, Validate)]
+pub struct GuarantorRequestDto {
+    #[serde(rename = "borrowerId")]
+    #[validate(range(
+        min = 1,
+        message = "Debe seleccionar un prestatario válido"
+    ))]
+    pub borrower_id: Option<i32>,
+
+    #[validate(length(
+        max = 100,
+        message = "El nombre del contacto debe tener menos de 100 caracteres"
+    ))]
+    pub contact_name: Option<String>,
+
+    #[validate(length(
+        min = 8,
+        message = "El teléfono debe tener mínimo 8 dígitos"
+    ))]
+    pub contact_phone: Option<String>,
+
+    /// Lo garantizado, en la moneda del préstamo
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "Lo garantizado debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub amount: f64,
+}
+
+/// Fecha a la que se calcula el interés; por defecto la actual
+#[derive(Debug, Deserialize)]
+pub struct LoanInterestQueryDto {
+    pub at: Option<DateTime<Utc>>,
+}
+
+/// Parámetros de consulta del listado de préstamos
+#[derive(Debug, Deserialize)]
+pub struct LoanQueryDto {
+    /// Listar los eliminados en lugar de los vigentes
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+pub(crate) fn validate_rate_period(value: &str) -> Result<(), ValidationError> {
+    RatePeriod::parse(value)
+        .map(|_| ())
+        .ok_or_else(|| ValidationError::new("rate_period"))
+}
+
+pub(crate) fn validate_interest_method(value: &str) -> Result<(), ValidationError> {
+    InterestMethod::parse(value)
+        .map(|_| ())
+        .ok_or_else(|| ValidationError::new("interest_method"))
+}
+
+pub(crate) fn validate_installment_frequency(value: &str) -> Result<(), ValidationError> {
+    InstallmentFrequency::parse(value)
+        .map(|_| ())
+        .ok_or_else(|| ValidationError::new("installment_frequency"))
+}
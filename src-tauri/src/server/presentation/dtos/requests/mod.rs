@@ -1,3 +1,13 @@
 pub mod auth_requests_dto;
 pub mod user_requests_dto;
-pub mod borrower_request_dto;
\ No newline at end of file
+pub mod borrower_request_dto;
+pub mod loan_requests_dto;
+pub mod payment_request_dto;
+pub mod currency_request_dto;
+pub mod report_requests_dto;
+pub mod penalty_request_dto;
+pub mod loan_product_request_dto;
+pub mod collateral_request_dto;
+pub mod statement_request_dto;
+pub mod ledger_request_dto;
+pub mod cash_box_request_dto;
\ No newline at end of file
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+use crate::server::domain::entities::payment_allocation::AllocationStrategy;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreatePaymentRequestDto {
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub amount: f64,
+
+    #[validate(length(
+        min = 1,
+        message = "La moneda es requerida"
+    ))]
+    pub currency: String,
+
+    /// Cómo repartir el pago entre los préstamos abiertos; por defecto la configurada
+    #[validate(custom(
+        function = "validate_allocation_strategy",
+        message = "La estrategia debe ser oldest_first, highest_interest o pro_rata"
+    ))]
+    pub allocation_strategy: Option<String>,
+
+    #[serde(rename = "borrowerId")]
+    #[validate(range(
+        min = 1,
+        message = "Debe seleccionar un prestatario válido"
+    ))]
+    pub borrower_id: i32,
+
+    /// Fecha del registro, permite cargar movimientos con fecha anterior
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdatePaymentRequestDto {
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub amount: Option<f64>,
+
+    #[validate(length(
+        min = 1,
+        message = "La moneda es requerida"
+    ))]
+    pub currency: Option<String>,
+
+    #[validate(custom(
+        function = "validate_allocation_strategy",
+        message = "La estrategia debe ser oldest_first, highest_interest o pro_rata"
+    ))]
+    pub allocation_strategy: Option<String>,
+
+    #[serde(rename = "borrowerId")]
+    #[validate(range(
+        min = 1,
+        message = "Debe seleccionar un prestatario válido"
+    ))]
+    pub borrower_id: Option<i32>,
+
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// Parámetros de consulta del listado de pagos
+#[derive(Debug, Deserialize)]
+pub struct PaymentQueryDto {
+    /// Listar los eliminados en lugar de los vigentes
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+fn validate_allocation_strategy(value: &str) -> Result<(), ValidationError> {
+    AllocationStrategy::parse(value)
+        .map(|_| ())
+        .ok_or_else(|| ValidationError::new("allocation_strategy"))
+}
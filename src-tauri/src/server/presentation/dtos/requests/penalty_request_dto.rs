@@ -0,0 +1,75 @@
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+use crate::server::domain::entities::penalty::PenaltyKind;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreatePenaltyRuleRequestDto {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "El nombre es requerido y debe tener menos de 100 caracteres"
+    ))]
+    pub name: String,
+
+    #[validate(custom(
+        function = "validate_penalty_kind",
+        message = "El tipo debe ser fixed, percentage o daily"
+    ))]
+    pub kind: String,
+
+    /// Monto en CUP de un recargo fijo
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El monto debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub amount: Option<f64>,
+
+    /// Porcentaje de lo vencido; en los recargos diarios es por día
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100.0,
+        message = "La tasa debe ser positiva y no puede ser mayor a 100"
+    ))]
+    pub rate: Option<f64>,
+
+    #[serde(default)]
+    #[validate(range(
+        min = 0,
+        max = 3650,
+        message = "Los días de gracia deben estar entre 0 y 3650"
+    ))]
+    pub grace_days: i32,
+
+    /// Máximo en CUP que la regla puede cobrar a un mismo préstamo
+    #[validate(range(
+        exclusive_min = 0.0,
+        max = 100000000.0,
+        message = "El tope debe ser positivo y no puede exceder 100,000,000"
+    ))]
+    pub cap: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct WaivePenaltyRequestDto {
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "El motivo es requerido y debe tener menos de 500 caracteres"
+    ))]
+    pub reason: String,
+}
+
+/// Parámetros de consulta del listado de recargos
+#[derive(Debug, Deserialize)]
+pub struct PenaltyQueryDto {
+    #[serde(rename = "borrowerId")]
+    pub borrower_id: Option<i32>,
+}
+
+fn validate_penalty_kind(value: &str) -> Result<(), ValidationError> {
+    PenaltyKind::parse(value)
+        .map(|_| ())
+        .ok_or_else(|| ValidationError::new("penalty_kind"))
+}
@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Parámetros de consulta de la revaluación de saldos
+#[derive(Debug, Deserialize)]
+pub struct RevaluationQueryDto {
+    /// Fecha a la que se revalúan los saldos; por defecto la actual
+    pub at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Parámetros de consulta del estado de cuenta; sin fechas abarca todo el historial
+#[derive(Debug, Deserialize)]
+pub struct StatementQueryDto {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
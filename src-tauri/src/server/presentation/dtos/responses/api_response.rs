@@ -5,6 +5,8 @@ pub struct ApiResponse {
     pub status_code: u16,
     pub message: String,
     pub error: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 
@@ -15,6 +17,7 @@ impl ApiResponse {
             status_code: 201,
             message,
             error: "created".to_string(),
+            warnings: Vec::new(),
         }
     }
     
@@ -23,6 +26,13 @@ impl ApiResponse {
             status_code: 200,
             message,
             error: "ok".to_string(),
+            warnings: Vec::new(),
         }
     }
+
+    /// Advertencias que no impidieron completar la operación
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
 }
\ No newline at end of file
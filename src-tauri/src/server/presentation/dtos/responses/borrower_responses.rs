@@ -1,6 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use crate::server::application::requests::borrower_model_requests::BorrowerModelResponse;
+use crate::server::application::requests::borrower_model_requests::{
+    BorrowerBlockModelResponse, BorrowerModelResponse, BorrowerPageModelResponse,
+    CreditScoreFactorModelResponse, CreditScoreModelResponse, TotalsMismatchModelResponse,
+    TotalsReconciliationModelResponse,
+};
 
 #[derive(Debug, Serialize)]
 pub struct BorrowerResponseDto {
@@ -10,10 +14,20 @@ pub struct BorrowerResponseDto {
     pub location: String,
     pub total_loans: f64,
     pub total_paid: f64,
+    pub total_penalties: f64,
+    pub written_off_total: f64,
+    pub recovered_total: f64,
+    pub guarantee_exposure: f64,
+    pub credit_limit: Option<f64>,
+    pub risk_tier: String,
+    pub credit_score: CreditScoreResponseDto,
     pub balance: f64,
     pub status: String,
+    pub block: Option<BorrowerBlockResponseDto>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<i32>,
 }
 
 impl From<BorrowerModelResponse> for BorrowerResponseDto {
@@ -25,10 +39,136 @@ impl From<BorrowerModelResponse> for BorrowerResponseDto {
             location: dto.location,
             total_loans: dto.total_loans,
             total_paid: dto.total_paid,
+            total_penalties: dto.total_penalties,
+            written_off_total: dto.written_off_total,
+            recovered_total: dto.recovered_total,
+            guarantee_exposure: dto.guarantee_exposure,
+            credit_limit: dto.credit_limit,
+            risk_tier: dto.risk_tier,
+            credit_score: CreditScoreResponseDto::from(dto.credit_score),
             balance: dto.balance,
             status: dto.status,
+            block: dto.block.map(BorrowerBlockResponseDto::from),
             created_at: dto.created_at,
             updated_at: dto.updated_at,
+            deleted_at: dto.deleted_at,
+            deleted_by: dto.deleted_by,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BorrowerPageResponseDto {
+    pub items: Vec<BorrowerResponseDto>,
+    pub total: i64,
+    pub page: i64,
+    pub page_size: Option<i64>,
+}
+
+impl From<BorrowerPageModelResponse> for BorrowerPageResponseDto {
+    fn from(dto: BorrowerPageModelResponse) -> Self {
+        Self {
+            items: dto.items.into_iter().map(BorrowerResponseDto::from).collect(),
+            total: dto.total,
+            page: dto.page,
+            page_size: dto.page_size,
+        }
+    }
+}
+
+/// `blocked_by` es el usuario que bloqueó al prestatario
+#[derive(Debug, Serialize)]
+pub struct BorrowerBlockResponseDto {
+    pub reason: String,
+    pub blocked_by: i32,
+    pub blocked_at: DateTime<Utc>,
+}
+
+impl From<BorrowerBlockModelResponse> for BorrowerBlockResponseDto {
+    fn from(dto: BorrowerBlockModelResponse) -> Self {
+        Self {
+            reason: dto.reason,
+            blocked_by: dto.blocked_by,
+            blocked_at: dto.blocked_at,
+        }
+    }
+}
+
+/// `factors` desglosa cuánto suma o resta cada factor al puntaje base de 600
+#[derive(Debug, Serialize)]
+pub struct CreditScoreResponseDto {
+    pub score: i32,
+    pub factors: Vec<CreditScoreFactorResponseDto>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<CreditScoreModelResponse> for CreditScoreResponseDto {
+    fn from(dto: CreditScoreModelResponse) -> Self {
+        Self {
+            score: dto.score,
+            factors: dto
+                .factors
+                .into_iter()
+                .map(CreditScoreFactorResponseDto::from)
+                .collect(),
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreditScoreFactorResponseDto {
+    pub factor: String,
+    pub value: i32,
+    pub points: i32,
+}
+
+impl From<CreditScoreFactorModelResponse> for CreditScoreFactorResponseDto {
+    fn from(dto: CreditScoreFactorModelResponse) -> Self {
+        Self {
+            factor: dto.factor,
+            value: dto.value,
+            points: dto.points,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TotalsMismatchResponseDto {
+    pub borrower_id: i32,
+    pub name: String,
+    pub field: String,
+    pub stored: f64,
+    pub expected: f64,
+    pub difference: f64,
+}
+
+impl From<TotalsMismatchModelResponse> for TotalsMismatchResponseDto {
+    fn from(dto: TotalsMismatchModelResponse) -> Self {
+        Self {
+            borrower_id: dto.borrower_id,
+            name: dto.name,
+            field: dto.field,
+            stored: dto.stored,
+            expected: dto.expected,
+            difference: dto.difference,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TotalsReconciliationResponseDto {
+    pub checked: i64,
+    pub mismatches: Vec<TotalsMismatchResponseDto>,
+    pub fixed: bool,
+}
+
+impl From<TotalsReconciliationModelResponse> for TotalsReconciliationResponseDto {
+    fn from(dto: TotalsReconciliationModelResponse) -> Self {
+        Self {
+            checked: dto.checked,
+            mismatches: dto.mismatches.into_iter().map(TotalsMismatchResponseDto::from).collect(),
+            fixed: dto.fixed,
         }
     }
 }
\ No newline at end of file
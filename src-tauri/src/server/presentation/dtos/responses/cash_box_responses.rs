@@ -0,0 +1,259 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::cash_box_model_requests::{
+    CashBoxLineModelResponse, CashBoxModelResponse, CashCountModelResponse, CashExchangeModelResponse,
+    CashExchangePairModelResponse, CashExchangeReportModelResponse, CashMovementModelResponse,
+    CashStatementModelResponse,
+};
+
+#[derive(Debug, Serialize)]
+pub struct CashBoxResponseDto {
+    pub currency: String,
+    pub name: String,
+    pub opening: Option<CashMovementResponseDto>,
+    pub balance: f64,
+    pub balance_cup: f64,
+    pub last_count: Option<CashCountResponseDto>,
+}
+
+impl From<CashBoxModelResponse> for CashBoxResponseDto {
+    fn from(dto: CashBoxModelResponse) -> Self {
+        Self {
+            currency: dto.currency,
+            name: dto.name,
+            opening: dto.opening.map(CashMovementResponseDto::from),
+            balance: dto.balance,
+            balance_cup: dto.balance_cup,
+            last_count: dto.last_count.map(CashCountResponseDto::from),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashMovementResponseDto {
+    pub id: i32,
+    pub currency: String,
+    pub kind: String,
+    pub amount: f64,
+    pub exchange_rate: f32,
+    pub amount_cup: f64,
+    pub reason: String,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub moved_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CashMovementModelResponse> for CashMovementResponseDto {
+    fn from(dto: CashMovementModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            currency: dto.currency,
+            kind: dto.kind,
+            amount: dto.amount,
+            exchange_rate: dto.exchange_rate,
+            amount_cup: dto.amount_cup,
+            reason: dto.reason,
+            entry_id: dto.entry_id,
+            created_by: dto.created_by,
+            moved_at: dto.moved_at,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashCountResponseDto {
+    pub id: i32,
+    pub currency: String,
+    pub expected: f64,
+    pub counted: f64,
+    pub difference: f64,
+    pub exchange_rate: f32,
+    pub difference_cup: f64,
+    pub notes: Option<String>,
+    pub entry_id: Option<i32>,
+    pub counted_by: i32,
+    pub counted_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CashCountModelResponse> for CashCountResponseDto {
+    fn from(dto: CashCountModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            currency: dto.currency,
+            expected: dto.expected,
+            counted: dto.counted,
+            difference: dto.difference,
+            exchange_rate: dto.exchange_rate,
+            difference_cup: dto.difference_cup,
+            notes: dto.notes,
+            entry_id: dto.entry_id,
+            counted_by: dto.counted_by,
+            counted_at: dto.counted_at,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashBoxLineResponseDto {
+    pub entry_id: i32,
+    pub event: String,
+    pub description: String,
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub payment_id: Option<i32>,
+    pub posted_at: DateTime<Utc>,
+    pub amount: f64,
+    pub amount_cup: f64,
+    pub balance: f64,
+}
+
+impl From<CashBoxLineModelResponse> for CashBoxLineResponseDto {
+    fn from(dto: CashBoxLineModelResponse) -> Self {
+        Self {
+            entry_id: dto.entry_id,
+            event: dto.event,
+            description: dto.description,
+            borrower_id: dto.borrower_id,
+            loan_id: dto.loan_id,
+            payment_id: dto.payment_id,
+            posted_at: dto.posted_at,
+            amount: dto.amount,
+            amount_cup: dto.amount_cup,
+            balance: dto.balance,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashStatementResponseDto {
+    pub currency: String,
+    pub from: Option<DateTime<Utc>>,
+    pub to: DateTime<Utc>,
+    pub opening_balance: f64,
+    pub total_in: f64,
+    pub total_out: f64,
+    pub closing_balance: f64,
+    pub lines: Vec<CashBoxLineResponseDto>,
+}
+
+impl From<CashStatementModelResponse> for CashStatementResponseDto {
+    fn from(dto: CashStatementModelResponse) -> Self {
+        Self {
+            currency: dto.currency,
+            from: dto.from,
+            to: dto.to,
+            opening_balance: dto.opening_balance,
+            total_in: dto.total_in,
+            total_out: dto.total_out,
+            closing_balance: dto.closing_balance,
+            lines: dto.lines.into_iter().map(CashBoxLineResponseDto::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashExchangeResponseDto {
+    pub id: i32,
+    pub source_currency: String,
+    pub source_amount: f64,
+    pub target_currency: String,
+    pub target_amount: f64,
+    pub effective_rate: f64,
+    pub official_rate: f64,
+    pub spread: f64,
+    pub source_cost_cup: f64,
+    pub target_cup: f64,
+    pub spread_cup: f64,
+    pub gain_cup: f64,
+    pub notes: Option<String>,
+    pub entry_id: i32,
+    pub created_by: i32,
+    pub exchanged_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CashExchangeModelResponse> for CashExchangeResponseDto {
+    fn from(dto: CashExchangeModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            source_currency: dto.source_currency,
+            source_amount: dto.source_amount,
+            target_currency: dto.target_currency,
+            target_amount: dto.target_amount,
+            effective_rate: dto.effective_rate,
+            official_rate: dto.official_rate,
+            spread: dto.spread,
+            source_cost_cup: dto.source_cost_cup,
+            target_cup: dto.target_cup,
+            spread_cup: dto.spread_cup,
+            gain_cup: dto.gain_cup,
+            notes: dto.notes,
+            entry_id: dto.entry_id,
+            created_by: dto.created_by,
+            exchanged_at: dto.exchanged_at,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashExchangePairResponseDto {
+    pub source_currency: String,
+    pub target_currency: String,
+    pub count: usize,
+    pub source_amount: f64,
+    pub target_amount: f64,
+    pub average_rate: f64,
+    pub spread_cup: f64,
+    pub gain_cup: f64,
+}
+
+impl From<CashExchangePairModelResponse> for CashExchangePairResponseDto {
+    fn from(dto: CashExchangePairModelResponse) -> Self {
+        Self {
+            source_currency: dto.source_currency,
+            target_currency: dto.target_currency,
+            count: dto.count,
+            source_amount: dto.source_amount,
+            target_amount: dto.target_amount,
+            average_rate: dto.average_rate,
+            spread_cup: dto.spread_cup,
+            gain_cup: dto.gain_cup,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashExchangeReportResponseDto {
+    pub currency: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub count: usize,
+    pub realized_gain: f64,
+    pub realized_loss: f64,
+    pub net_gain: f64,
+    pub spread_cup: f64,
+    pub pairs: Vec<CashExchangePairResponseDto>,
+    pub exchanges: Vec<CashExchangeResponseDto>,
+}
+
+impl From<CashExchangeReportModelResponse> for CashExchangeReportResponseDto {
+    fn from(dto: CashExchangeReportModelResponse) -> Self {
+        Self {
+            currency: dto.currency,
+            from: dto.from,
+            to: dto.to,
+            count: dto.count,
+            realized_gain: dto.realized_gain,
+            realized_loss: dto.realized_loss,
+            net_gain: dto.net_gain,
+            spread_cup: dto.spread_cup,
+            pairs: dto.pairs.into_iter().map(CashExchangePairResponseDto::from).collect(),
+            exchanges: dto.exchanges.into_iter().map(CashExchangeResponseDto::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::collateral_model_requests::CollateralModelResponse;
+
+#[derive(Debug, Serialize)]
+pub struct CollateralResponseDto {
+    pub id: i32,
+    pub loan_id: i32,
+    pub description: String,
+    pub estimated_value: f64,
+    pub currency: String,
+    pub storage_location: String,
+    pub status: String,
+    pub payment_id: Option<i32>,
+    pub returned_at: Option<DateTime<Utc>>,
+    pub liquidated_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<CollateralModelResponse> for CollateralResponseDto {
+    fn from(dto: CollateralModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            loan_id: dto.loan_id,
+            description: dto.description,
+            estimated_value: dto.estimated_value,
+            currency: dto.currency,
+            storage_location: dto.storage_location,
+            status: dto.status,
+            payment_id: dto.payment_id,
+            returned_at: dto.returned_at,
+            liquidated_at: dto.liquidated_at,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
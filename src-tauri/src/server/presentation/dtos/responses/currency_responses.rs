@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::currency_model_requests::{CurrencyModelResponse, ExchangeRateModelResponse};
+
+#[derive(Debug, Serialize)]
+pub struct CurrencyResponseDto {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub exchange_rate: f32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<CurrencyModelResponse> for CurrencyResponseDto {
+    fn from(dto: CurrencyModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            code: dto.code,
+            name: dto.name,
+            exchange_rate: dto.exchange_rate,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+
+#[derive(Debug, Serialize)]
+pub struct ExchangeRateResponseDto {
+    pub id: i32,
+    pub currency_code: String,
+    pub exchange_rate: f32,
+    pub effective_from: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ExchangeRateModelResponse> for ExchangeRateResponseDto {
+    fn from(dto: ExchangeRateModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            currency_code: dto.currency_code,
+            exchange_rate: dto.exchange_rate,
+            effective_from: dto.effective_from,
+            created_at: dto.created_at,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::ledger_model_requests::{
+    AccountBalanceModelResponse, BorrowerReceivableModelResponse, JournalEntryModelResponse, JournalLineModelResponse,
+    ReceivablesModelResponse, TrialBalanceModelResponse,
+};
+
+#[derive(Debug, Serialize)]
+pub struct AccountBalanceResponseDto {
+    pub code: String,
+    pub name: String,
+    pub kind: String,
+    pub currency: String,
+    pub debit: f64,
+    pub credit: f64,
+    pub balance_cup: f64,
+    pub balance: f64,
+}
+
+impl From<AccountBalanceModelResponse> for AccountBalanceResponseDto {
+    fn from(dto: AccountBalanceModelResponse) -> Self {
+        Self {
+            code: dto.code,
+            name: dto.name,
+            kind: dto.kind,
+            currency: dto.currency,
+            debit: dto.debit,
+            credit: dto.credit,
+            balance_cup: dto.balance_cup,
+            balance: dto.balance,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrialBalanceResponseDto {
+    pub at: DateTime<Utc>,
+    pub accounts: Vec<AccountBalanceResponseDto>,
+    pub total_debit: f64,
+    pub total_credit: f64,
+    pub balanced: bool,
+}
+
+impl From<TrialBalanceModelResponse> for TrialBalanceResponseDto {
+    fn from(dto: TrialBalanceModelResponse) -> Self {
+        Self {
+            at: dto.at,
+            accounts: dto.accounts.into_iter().map(AccountBalanceResponseDto::from).collect(),
+            total_debit: dto.total_debit,
+            total_credit: dto.total_credit,
+            balanced: dto.balanced,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct JournalLineResponseDto {
+    pub account_code: String,
+    pub account_name: String,
+    pub borrower_id: Option<i32>,
+    pub debit: f64,
+    pub credit: f64,
+    pub currency: String,
+    pub amount: f64,
+}
+
+impl From<JournalLineModelResponse> for JournalLineResponseDto {
+    fn from(dto: JournalLineModelResponse) -> Self {
+        Self {
+            account_code: dto.account_code,
+            account_name: dto.account_name,
+            borrower_id: dto.borrower_id,
+            debit: dto.debit,
+            credit: dto.credit,
+            currency: dto.currency,
+            amount: dto.amount,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct JournalEntryResponseDto {
+    pub id: i32,
+    pub event: String,
+    pub description: String,
+    pub borrower_id: Option<i32>,
+    pub loan_id: Option<i32>,
+    pub payment_id: Option<i32>,
+    pub posted_at: DateTime<Utc>,
+    pub lines: Vec<JournalLineResponseDto>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<JournalEntryModelResponse> for JournalEntryResponseDto {
+    fn from(dto: JournalEntryModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            event: dto.event,
+            description: dto.description,
+            borrower_id: dto.borrower_id,
+            loan_id: dto.loan_id,
+            payment_id: dto.payment_id,
+            posted_at: dto.posted_at,
+            lines: dto.lines.into_iter().map(JournalLineResponseDto::from).collect(),
+            created_at: dto.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BorrowerReceivableResponseDto {
+    pub borrower_id: i32,
+    pub name: String,
+    pub ledger_balance: f64,
+    pub stored_balance: f64,
+    pub difference: f64,
+}
+
+impl From<BorrowerReceivableModelResponse> for BorrowerReceivableResponseDto {
+    fn from(dto: BorrowerReceivableModelResponse) -> Self {
+        Self {
+            borrower_id: dto.borrower_id,
+            name: dto.name,
+            ledger_balance: dto.ledger_balance,
+            stored_balance: dto.stored_balance,
+            difference: dto.difference,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReceivablesResponseDto {
+    pub borrowers: Vec<BorrowerReceivableResponseDto>,
+    pub total_ledger_balance: f64,
+    pub total_stored_balance: f64,
+    pub balanced: bool,
+}
+
+impl From<ReceivablesModelResponse> for ReceivablesResponseDto {
+    fn from(dto: ReceivablesModelResponse) -> Self {
+        Self {
+            borrowers: dto.borrowers.into_iter().map(BorrowerReceivableResponseDto::from).collect(),
+            total_ledger_balance: dto.total_ledger_balance,
+            total_stored_balance: dto.total_stored_balance,
+            balanced: dto.balanced,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::loan_product_model_requests::LoanProductModelResponse;
+
+#[derive(Debug, Serialize)]
+pub struct LoanProductResponseDto {
+    pub id: i32,
+    pub name: String,
+    pub currency: String,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub installment_frequency: Option<String>,
+    pub installment_count: Option<i32>,
+    pub term_days: Option<i32>,
+    pub penalty_rule_id: Option<i32>,
+    pub min_amount: Option<f64>,
+    pub max_amount: Option<f64>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<LoanProductModelResponse> for LoanProductResponseDto {
+    fn from(dto: LoanProductModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            currency: dto.currency,
+            interest_rate: dto.interest_rate,
+            rate_period: dto.rate_period,
+            interest_method: dto.interest_method,
+            installment_frequency: dto.installment_frequency,
+            installment_count: dto.installment_count,
+            term_days: dto.term_days,
+            penalty_rule_id: dto.penalty_rule_id,
+            min_amount: dto.min_amount,
+            max_amount: dto.max_amount,
+            active: dto.active,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::loan_model_requests::{
+    LoanGuarantorModelResponse, LoanInstallmentModelResponse, LoanInterestModelResponse, LoanModelResponse,
+    LoanRestructureModelResponse, LoanScheduleModelResponse, LoanWriteOffModelResponse,
+};
+use crate::server::presentation::dtos::responses::borrower_responses::BorrowerResponseDto;
+
+#[derive(Debug, Serialize)]
+pub struct LoanResponseDto {
+    pub id: i32,
+    pub amount: f64,
+    pub currency: String,
+    pub exchange_rate: f32,
+    pub amount_cup: f64,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub installment_frequency: Option<String>,
+    pub installment_count: Option<i32>,
+    pub first_due_date: Option<DateTime<Utc>>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub remaining: f64,
+    pub status: String,
+    pub borrower: BorrowerResponseDto,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<i32>,
+    pub restructured_from_id: Option<i32>,
+    pub restructured_at: Option<DateTime<Utc>>,
+    pub write_off: Option<LoanWriteOffResponseDto>,
+    pub product_id: Option<i32>,
+    pub credit_limit_override_by: Option<i32>,
+    pub collateral_value: Option<f64>,
+    pub loan_to_value: Option<f64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl From<LoanModelResponse> for LoanResponseDto {
+    fn from(dto: LoanModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            amount: dto.amount,
+            currency: dto.currency,
+            exchange_rate: dto.exchange_rate,
+            amount_cup: dto.amount_cup,
+            interest_rate: dto.interest_rate,
+            rate_period: dto.rate_period,
+            interest_method: dto.interest_method,
+            installment_frequency: dto.installment_frequency,
+            installment_count: dto.installment_count,
+            first_due_date: dto.first_due_date,
+            due_date: dto.due_date,
+            remaining: dto.remaining,
+            status: dto.status,
+            borrower: BorrowerResponseDto::from(dto.borrower),
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+            deleted_at: dto.deleted_at,
+            deleted_by: dto.deleted_by,
+            restructured_from_id: dto.restructured_from_id,
+            restructured_at: dto.restructured_at,
+            write_off: dto.write_off.map(LoanWriteOffResponseDto::from),
+            product_id: dto.product_id,
+            credit_limit_override_by: dto.credit_limit_override_by,
+            collateral_value: dto.collateral_value,
+            loan_to_value: dto.loan_to_value,
+            warnings: dto.warnings,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoanWriteOffResponseDto {
+    pub amount: f64,
+    pub amount_cup: f64,
+    pub recovered: f64,
+    pub reason: String,
+    pub written_off_by: i32,
+    pub written_off_at: DateTime<Utc>,
+}
+
+impl From<LoanWriteOffModelResponse> for LoanWriteOffResponseDto {
+    fn from(dto: LoanWriteOffModelResponse) -> Self {
+        Self {
+            amount: dto.amount,
+            amount_cup: dto.amount_cup,
+            recovered: dto.recovered,
+            reason: dto.reason,
+            written_off_by: dto.written_off_by,
+            written_off_at: dto.written_off_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoanRestructureResponseDto {
+    pub previous_loan: LoanResponseDto,
+    pub loan: LoanResponseDto,
+    pub carried_principal: f64,
+    pub capitalized_interest: f64,
+    pub capitalized_penalties: f64,
+}
+
+impl From<LoanRestructureModelResponse> for LoanRestructureResponseDto {
+    fn from(dto: LoanRestructureModelResponse) -> Self {
+        Self {
+            previous_loan: LoanResponseDto::from(dto.previous_loan),
+            loan: LoanResponseDto::from(dto.loan),
+            carried_principal: dto.carried_principal,
+            capitalized_interest: dto.capitalized_interest,
+            capitalized_penalties: dto.capitalized_penalties,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoanInterestResponseDto {
+    pub loan_id: i32,
+    pub currency: String,
+    pub as_of: DateTime<Utc>,
+    pub interest_rate: f64,
+    pub rate_period: String,
+    pub interest_method: String,
+    pub principal: f64,
+    pub accrued_interest: f64,
+    pub interest_paid: f64,
+    pub principal_paid: f64,
+    pub outstanding_principal: f64,
+    pub outstanding_interest: f64,
+    pub total_due: f64,
+}
+
+impl From<LoanInterestModelResponse> for LoanInterestResponseDto {
+    fn from(dto: LoanInterestModelResponse) -> Self {
+        Self {
+            loan_id: dto.loan_id,
+            currency: dto.currency,
+            as_of: dto.as_of,
+            interest_rate: dto.interest_rate,
+            rate_period: dto.rate_period,
+            interest_method: dto.interest_method,
+            principal: dto.principal,
+            accrued_interest: dto.accrued_interest,
+            interest_paid: dto.interest_paid,
+            principal_paid: dto.principal_paid,
+            outstanding_principal: dto.outstanding_principal,
+            outstanding_interest: dto.outstanding_interest,
+            total_due: dto.total_due,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoanInstallmentResponseDto {
+    pub id: i32,
+    pub number: i32,
+    pub due_date: DateTime<Utc>,
+    pub principal: f64,
+    pub interest: f64,
+    pub total: f64,
+    pub paid: f64,
+    pub status: String,
+    pub paid_at: Option<DateTime<Utc>>,
+}
+
+impl From<LoanInstallmentModelResponse> for LoanInstallmentResponseDto {
+    fn from(dto: LoanInstallmentModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            number: dto.number,
+            due_date: dto.due_date,
+            principal: dto.principal,
+            interest: dto.interest,
+            total: dto.total,
+            paid: dto.paid,
+            status: dto.status,
+            paid_at: dto.paid_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoanScheduleResponseDto {
+    pub loan_id: i32,
+    pub currency: String,
+    pub frequency: String,
+    pub installments: Vec<LoanInstallmentResponseDto>,
+    pub total_principal: f64,
+    pub total_interest: f64,
+    pub total_paid: f64,
+    pub total_outstanding: f64,
+}
+
+impl From<LoanScheduleModelResponse> for LoanScheduleResponseDto {
+    fn from(dto: LoanScheduleModelResponse) -> Self {
+        Self {
+            loan_id: dto.loan_id,
+            currency: dto.currency,
+            frequency: dto.frequency,
+            installments: dto
+                .installments
+                .into_iter()
+                .map(LoanInstallmentResponseDto::from)
+                .collect(),
+            total_principal: dto.total_principal,
+            total_interest: dto.total_interest,
+            total_paid: dto.total_paid,
+            total_outstanding: dto.total_outstanding,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoanGuarantorResponseDto {
+    pub id: i32,
+    pub loan_id: i32,
+    pub borrower_id: Option<i32>,
+    pub contact_name: Option<String>,
+    pub contact_phone: Option<String>,
+    pub amount: f64,
+    pub currency: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl From<LoanGuarantorModelResponse> for LoanGuarantorResponseDto {
+    fn from(dto: LoanGuarantorModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            loan_id: dto.loan_id,
+            borrower_id: dto.borrower_id,
+            contact_name: dto.contact_name,
+            contact_phone: dto.contact_phone,
+            amount: dto.amount,
+            currency: dto.currency,
+            created_at: dto.created_at,
+            warnings: dto.warnings,
+        }
+    }
+}
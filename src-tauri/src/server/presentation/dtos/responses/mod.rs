@@ -2,3 +2,14 @@ pub mod api_response;
 pub mod auth_responses;
 pub mod borrower_responses;
 pub mod user_responses;
+pub mod loan_responses;
+pub mod payment_responses;
+pub mod currency_responses;
+pub mod report_responses;
+
+pub mod penalty_responses;
+pub mod loan_product_responses;
+pub mod collateral_responses;
+pub mod statement_responses;
+pub mod ledger_responses;
+pub mod cash_box_responses;
\ No newline at end of file
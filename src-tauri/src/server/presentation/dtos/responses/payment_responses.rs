@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::payment_model_requests::{PaymentAllocationModelResponse, PaymentModelResponse};
+use crate::server::presentation::dtos::responses::borrower_responses::BorrowerResponseDto;
+
+#[derive(Debug, Serialize)]
+pub struct PaymentResponseDto {
+    pub id: i32,
+    pub amount: f64,
+    pub currency: String,
+    pub exchange_rate: f32,
+    pub amount_cup: f64,
+    pub allocation_strategy: String,
+    pub allocations: Vec<PaymentAllocationResponseDto>,
+    pub loan_id: Option<i32>,
+    pub borrower: BorrowerResponseDto,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<i32>,
+}
+
+impl From<PaymentModelResponse> for PaymentResponseDto {
+    fn from(dto: PaymentModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            amount: dto.amount,
+            currency: dto.currency,
+            exchange_rate: dto.exchange_rate,
+            amount_cup: dto.amount_cup,
+            allocation_strategy: dto.allocation_strategy,
+            allocations: dto
+                .allocations
+                .into_iter()
+                .map(PaymentAllocationResponseDto::from)
+                .collect(),
+            loan_id: dto.loan_id,
+            borrower: BorrowerResponseDto::from(dto.borrower),
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+            deleted_at: dto.deleted_at,
+            deleted_by: dto.deleted_by,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaymentAllocationResponseDto {
+    pub loan_id: i32,
+    pub amount: f64,
+    pub currency: String,
+    pub amount_cup: f64,
+}
+
+impl From<PaymentAllocationModelResponse> for PaymentAllocationResponseDto {
+    fn from(dto: PaymentAllocationModelResponse) -> Self {
+        Self {
+            loan_id: dto.loan_id,
+            amount: dto.amount,
+            currency: dto.currency,
+            amount_cup: dto.amount_cup,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::penalty_model_requests::{
+    PenaltyModelResponse, PenaltyRuleModelResponse,
+};
+
+#[derive(Debug, Serialize)]
+pub struct PenaltyRuleResponseDto {
+    pub id: i32,
+    pub name: String,
+    pub kind: String,
+    pub amount: f64,
+    pub rate: f64,
+    pub grace_days: i32,
+    pub cap: Option<f64>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<PenaltyRuleModelResponse> for PenaltyRuleResponseDto {
+    fn from(dto: PenaltyRuleModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            kind: dto.kind,
+            amount: dto.amount,
+            rate: dto.rate,
+            grace_days: dto.grace_days,
+            cap: dto.cap,
+            active: dto.active,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PenaltyResponseDto {
+    pub id: i32,
+    pub loan_id: i32,
+    pub borrower_id: i32,
+    pub rule_id: i32,
+    pub amount: f64,
+    pub days_past_due: i32,
+    pub days_charged: i32,
+    pub status: String,
+    pub waived_reason: Option<String>,
+    pub waived_by: Option<i32>,
+    pub waived_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PenaltyModelResponse> for PenaltyResponseDto {
+    fn from(dto: PenaltyModelResponse) -> Self {
+        Self {
+            id: dto.id,
+            loan_id: dto.loan_id,
+            borrower_id: dto.borrower_id,
+            rule_id: dto.rule_id,
+            amount: dto.amount,
+            days_past_due: dto.days_past_due,
+            days_charged: dto.days_charged,
+            status: dto.status,
+            waived_reason: dto.waived_reason,
+            waived_by: dto.waived_by,
+            waived_at: dto.waived_at,
+            created_at: dto.created_at,
+        }
+    }
+}
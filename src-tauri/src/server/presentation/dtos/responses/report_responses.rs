@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::report_model_requests::{BalanceRevaluationModelResponse, BorrowerRevaluationModelResponse};
+
+#[derive(Debug, Serialize)]
+pub struct BorrowerRevaluationResponseDto {
+    pub borrower_id: i32,
+    pub name: String,
+    pub booked_balance_cup: f64,
+    pub revalued_balance_cup: f64,
+    pub fx_difference_cup: f64,
+}
+
+impl From<BorrowerRevaluationModelResponse> for BorrowerRevaluationResponseDto {
+    fn from(dto: BorrowerRevaluationModelResponse) -> Self {
+        Self {
+            borrower_id: dto.borrower_id,
+            name: dto.name,
+            booked_balance_cup: dto.booked_balance_cup,
+            revalued_balance_cup: dto.revalued_balance_cup,
+            fx_difference_cup: dto.fx_difference_cup,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BalanceRevaluationResponseDto {
+    pub at: DateTime<Utc>,
+    pub borrowers: Vec<BorrowerRevaluationResponseDto>,
+    pub total_booked_cup: f64,
+    pub total_revalued_cup: f64,
+    pub total_fx_difference_cup: f64,
+}
+
+impl From<BalanceRevaluationModelResponse> for BalanceRevaluationResponseDto {
+    fn from(dto: BalanceRevaluationModelResponse) -> Self {
+        Self {
+            at: dto.at,
+            borrowers: dto.borrowers.into_iter().map(BorrowerRevaluationResponseDto::from).collect(),
+            total_booked_cup: dto.total_booked_cup,
+            total_revalued_cup: dto.total_revalued_cup,
+            total_fx_difference_cup: dto.total_fx_difference_cup,
+        }
+    }
+}
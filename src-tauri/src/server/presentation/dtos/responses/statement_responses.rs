@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::server::application::requests::statement_model_requests::{
+    BorrowerStatementModelResponse, CurrencyBalanceModelResponse, StatementEntryModelResponse,
+};
+
+#[derive(Debug, Serialize)]
+pub struct CurrencyBalanceResponseDto {
+    pub currency: String,
+    pub amount: f64,
+}
+
+impl From<CurrencyBalanceModelResponse> for CurrencyBalanceResponseDto {
+    fn from(dto: CurrencyBalanceModelResponse) -> Self {
+        Self {
+            currency: dto.currency,
+            amount: dto.amount,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatementEntryResponseDto {
+    pub date: DateTime<Utc>,
+    pub kind: String,
+    pub reference_id: i32,
+    pub loan_id: Option<i32>,
+    pub description: String,
+    pub currency: String,
+    pub amount: f64,
+    pub amount_cup: f64,
+    pub balance: f64,
+    pub balance_cup: f64,
+}
+
+impl From<StatementEntryModelResponse> for StatementEntryResponseDto {
+    fn from(dto: StatementEntryModelResponse) -> Self {
+        Self {
+            date: dto.date,
+            kind: dto.kind,
+            reference_id: dto.reference_id,
+            loan_id: dto.loan_id,
+            description: dto.description,
+            currency: dto.currency,
+            amount: dto.amount,
+            amount_cup: dto.amount_cup,
+            balance: dto.balance,
+            balance_cup: dto.balance_cup,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BorrowerStatementResponseDto {
+    pub borrower_id: i32,
+    pub name: String,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub opening_balance_cup: f64,
+    pub opening_balances: Vec<CurrencyBalanceResponseDto>,
+    pub entries: Vec<StatementEntryResponseDto>,
+    pub closing_balance_cup: f64,
+    pub closing_balances: Vec<CurrencyBalanceResponseDto>,
+}
+
+impl From<BorrowerStatementModelResponse> for BorrowerStatementResponseDto {
+    fn from(dto: BorrowerStatementModelResponse) -> Self {
+        Self {
+            borrower_id: dto.borrower_id,
+            name: dto.name,
+            from: dto.from,
+            to: dto.to,
+            opening_balance_cup: dto.opening_balance_cup,
+            opening_balances: dto.opening_balances.into_iter().map(CurrencyBalanceResponseDto::from).collect(),
+            entries: dto.entries.into_iter().map(StatementEntryResponseDto::from).collect(),
+            closing_balance_cup: dto.closing_balance_cup,
+            closing_balances: dto.closing_balances.into_iter().map(CurrencyBalanceResponseDto::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::server::domain::entities::user::UserPayload;
+use crate::utils::error::AppError;
+use axum::{extract::Request, middleware::Next, response::Response};
+
+/// Restringe una ruta a usuarios con rol `admin`
+/// Debe ejecutarse después de `jwt_middleware`, que es quien inserta el `UserPayload`
+pub async fn admin_middleware(request: Request, next: Next) -> Result<Response, AppError> {
+    let user_payload = request
+        .extensions()
+        .get::<UserPayload>()
+        .ok_or_else(|| AppError::AuthError("Usuario no autorizado".to_string()))?;
+
+    if user_payload.role != "admin" {
+        return Err(AppError::Forbidden(
+            "Solo un administrador puede realizar esta operación".to_string(),
+        ));
+    }
+
+    Ok(next.run(request).await)
+}
@@ -1,5 +1,6 @@
 pub mod timing;
 pub mod jwt_middleware;
 pub  mod rate_limit;
+pub mod admin_middleware;
 
 pub use timing::timing_middleware;
\ No newline at end of file
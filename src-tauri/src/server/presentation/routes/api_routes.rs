@@ -2,29 +2,88 @@ use axum::{middleware, Router};
 use crate::server::presentation::routes::{
     auth_routes::auth_routes, 
     user_routes::user_routes, 
-    borrower_routes::borrower_routes
+    borrower_routes::borrower_routes,
+    loan_routes::loan_routes,
+    payment_routes::payment_routes,
+    currency_routes::currency_routes,
+    report_routes::report_routes,
+    penalty_routes::penalty_routes,
+    loan_product_routes::loan_product_routes,
+    collateral_routes::collateral_routes,
+    statement_routes::statement_routes,
+    ledger_routes::ledger_routes,
+    cash_box_routes::cash_box_routes
 };
 use crate::server::presentation::middleware::jwt_middleware::jwt_middleware;
 use crate::server::application::use_cases::{
     user_use_cases::UserUseCases,
-    borrower_use_cases::BorrowerUseCases
+    borrower_use_cases::BorrowerUseCases,
+    loan_use_cases::LoanUseCases,
+    payment_use_cases::PaymentUseCases,
+    currency_use_cases::CurrencyUseCases,
+    report_use_cases::ReportUseCases,
+    penalty_use_cases::PenaltyUseCases,
+    loan_product_use_cases::LoanProductUseCases,
+    collateral_use_cases::CollateralUseCases,
+    statement_use_cases::StatementUseCases,
+    ledger_use_cases::LedgerUseCases,
+    cash_box_use_cases::CashBoxUseCases
 };
 use crate::server::domain::repositories::{
     user_repository::UserRepository,
-    borrower_repository::BorrowerRepository
+    borrower_repository::BorrowerRepository,
+    loan_repository::LoanRepository,
+    payment_repository::PaymentRepository,
+    currency_repository::CurrencyRepository,
+    penalty_repository::PenaltyRepository,
+    loan_product_repository::LoanProductRepository,
+    collateral_repository::CollateralRepository,
+    ledger_repository::LedgerRepository,
+    cash_box_repository::CashBoxRepository
 };
 
-pub fn api_routes<T, U>(user_use_cases: UserUseCases<T>, borrower_use_cases: BorrowerUseCases<U>) -> Router 
+#[allow(clippy::too_many_arguments)]
+pub fn api_routes<T, U, L, P, C, N, R, K, G, X>(
+    user_use_cases: UserUseCases<T>,
+    borrower_use_cases: BorrowerUseCases<U>,
+    loan_use_cases: LoanUseCases<L, U, C, R, X>,
+    payment_use_cases: PaymentUseCases<P, U, C>,
+    currency_use_cases: CurrencyUseCases<C>,
+    report_use_cases: ReportUseCases<L, P, U, C>,
+    penalty_use_cases: PenaltyUseCases<N, L>,
+    loan_product_use_cases: LoanProductUseCases<R, N, C>,
+    collateral_use_cases: CollateralUseCases<K, L, C>,
+    statement_use_cases: StatementUseCases<U, L, P, N, G>,
+    ledger_use_cases: LedgerUseCases<G, C>,
+    cash_box_use_cases: CashBoxUseCases<X, C>,
+) -> Router 
 where 
     T: UserRepository + Clone + Send + Sync + 'static,
     U: BorrowerRepository + Clone + Send + Sync + 'static,
+    L: LoanRepository + Clone + Send + Sync + 'static,
+    P: PaymentRepository + Clone + Send + Sync + 'static,
+    C: CurrencyRepository + Clone + Send + Sync + 'static,
+    N: PenaltyRepository + Clone + Send + Sync + 'static,
+    R: LoanProductRepository + Clone + Send + Sync + 'static,
+    K: CollateralRepository + Clone + Send + Sync + 'static,
+    G: LedgerRepository + Clone + Send + Sync + 'static,
+    X: CashBoxRepository + Clone + Send + Sync + 'static,
 {
     Router::new()
         .nest("/auth", auth_routes(user_use_cases.clone()))
         .nest("/users", user_routes(user_use_cases.clone()))
-        .nest("/borrowers", borrower_routes(borrower_use_cases)) 
+        .nest("/borrowers", borrower_routes(borrower_use_cases).merge(statement_routes(statement_use_cases)))
+        .nest("/loans", loan_routes(loan_use_cases))
+        .nest("/payments", payment_routes(payment_use_cases))
+        .nest("/currencies", currency_routes(currency_use_cases))
+        .nest("/reports", report_routes(report_use_cases))
+        .nest("/penalties", penalty_routes(penalty_use_cases))
+        .nest("/loan-products", loan_product_routes(loan_product_use_cases))
+        .nest("/collateral", collateral_routes(collateral_use_cases))
+        .nest("/ledger", ledger_routes(ledger_use_cases))
+        .nest("/cash", cash_box_routes(cash_box_use_cases))
         .layer(middleware::from_fn_with_state(
             user_use_cases, 
             jwt_middleware
         ))
-}
\ No newline at end of file
+}
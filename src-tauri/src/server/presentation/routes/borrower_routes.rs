@@ -1,9 +1,12 @@
-use crate::server::presentation::middleware::rate_limit::auth_rate_limit;
+use crate::server::presentation::middleware::{
+    admin_middleware::admin_middleware, rate_limit::auth_rate_limit,
+};
 use crate::server::{
     application::use_cases::borrower_use_cases::BorrowerUseCases,
     domain::repositories::borrower_repository::BorrowerRepository,
     presentation::controllers::borrower_controller::{
-        create_borrower, delete_borrower, get_all_borrowers, get_borrower_by_id, update_borrower,
+        block_borrower, create_borrower, delete_borrower, get_all_borrowers, get_borrower_by_id,
+        purge_borrower, reconcile_borrower_totals, restore_borrower, unblock_borrower, update_borrower,
     },
 };
 use axum::{
@@ -16,12 +19,22 @@ pub fn borrower_routes<T>(borrower_use_cases: BorrowerUseCases<T>) -> Router
 where
     T: BorrowerRepository + Clone + Send + Sync + 'static,
 {
+    // Levantar un bloqueo, borrar definitivamente y conciliar totales quedan reservados a administradores
+    let admin_routes = Router::new()
+        .route("/reconcile", post(reconcile_borrower_totals))
+        .route("/:id/unblock", post(unblock_borrower))
+        .route("/:id/purge", delete(purge_borrower))
+        .layer(middleware::from_fn(admin_middleware));
+
     Router::new()
         .route("/", post(create_borrower))
         .route("/", get(get_all_borrowers))
         .route("/:id", get(get_borrower_by_id))
         .route("/:id", patch(update_borrower))
         .route("/:id", delete(delete_borrower))
+        .route("/:id/restore", post(restore_borrower))
+        .route("/:id/block", post(block_borrower))
+        .merge(admin_routes)
         .layer(middleware::from_fn(auth_rate_limit))
         .with_state(borrower_use_cases)
 }
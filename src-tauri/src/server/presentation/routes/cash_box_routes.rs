@@ -0,0 +1,39 @@
+use crate::server::presentation::middleware::{
+    admin_middleware::admin_middleware, rate_limit::normal_rate_limit,
+};
+use crate::server::{
+    application::use_cases::cash_box_use_cases::CashBoxUseCases,
+    domain::repositories::{cash_box_repository::CashBoxRepository, currency_repository::CurrencyRepository},
+    presentation::controllers::cash_box_controller::{
+        count_cash, deposit_cash, exchange_cash, get_cash_box, get_cash_boxes, get_cash_counts, get_cash_exchanges,
+        get_cash_statement, open_cash_box, withdraw_cash,
+    },
+};
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+
+pub fn cash_box_routes<X, C>(cash_box_use_cases: CashBoxUseCases<X, C>) -> Router
+where
+    X: CashBoxRepository + Clone + Send + Sync + 'static,
+    C: CurrencyRepository + Clone + Send + Sync + 'static,
+{
+    // El saldo de apertura de cada caja lo fija un administrador
+    let admin_routes = Router::new()
+        .route("/:currency/opening", post(open_cash_box))
+        .layer(middleware::from_fn(admin_middleware));
+
+    Router::new()
+        .route("/", get(get_cash_boxes))
+        .route("/exchanges", get(get_cash_exchanges).post(exchange_cash))
+        .route("/:currency", get(get_cash_box))
+        .route("/:currency/deposits", post(deposit_cash))
+        .route("/:currency/withdrawals", post(withdraw_cash))
+        .route("/:currency/movements", get(get_cash_statement))
+        .route("/:currency/counts", get(get_cash_counts).post(count_cash))
+        .merge(admin_routes)
+        .layer(middleware::from_fn(normal_rate_limit))
+        .with_state(cash_box_use_cases)
+}
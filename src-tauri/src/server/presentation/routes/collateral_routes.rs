@@ -0,0 +1,39 @@
+use crate::server::presentation::middleware::{
+    admin_middleware::admin_middleware, rate_limit::normal_rate_limit,
+};
+use crate::server::{
+    application::use_cases::collateral_use_cases::CollateralUseCases,
+    domain::repositories::{
+        collateral_repository::CollateralRepository, currency_repository::CurrencyRepository,
+        loan_repository::LoanRepository,
+    },
+    presentation::controllers::collateral_controller::{
+        get_collateral, get_collateral_by_id, liquidate_collateral, register_collateral,
+        return_collateral,
+    },
+};
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+
+pub fn collateral_routes<K, L, C>(collateral_use_cases: CollateralUseCases<K, L, C>) -> Router
+where
+    K: CollateralRepository + Clone + Send + Sync + 'static,
+    L: LoanRepository + Clone + Send + Sync + 'static,
+    C: CurrencyRepository + Clone + Send + Sync + 'static,
+{
+    // Liquidar una garantía registra un pago, así que queda reservado a administradores
+    let admin_routes = Router::new()
+        .route("/:id/liquidate", post(liquidate_collateral))
+        .layer(middleware::from_fn(admin_middleware));
+
+    Router::new()
+        .route("/", get(get_collateral).post(register_collateral))
+        .route("/:id", get(get_collateral_by_id))
+        .route("/:id/return", post(return_collateral))
+        .merge(admin_routes)
+        .layer(middleware::from_fn(normal_rate_limit))
+        .with_state(collateral_use_cases)
+}
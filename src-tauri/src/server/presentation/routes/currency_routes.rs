@@ -0,0 +1,36 @@
+use crate::server::presentation::middleware::{
+    admin_middleware::admin_middleware, rate_limit::normal_rate_limit,
+};
+use crate::server::{
+    application::use_cases::currency_use_cases::CurrencyUseCases,
+    domain::repositories::currency_repository::CurrencyRepository,
+    presentation::controllers::currency_controller::{
+        create_currency, delete_currency, get_all_currencies, get_rate_history, record_rate,
+        update_currency,
+    },
+};
+use axum::{
+    middleware,
+    routing::{delete, get, patch, post},
+    Router,
+};
+
+pub fn currency_routes<T>(currency_use_cases: CurrencyUseCases<T>) -> Router
+where
+    T: CurrencyRepository + Clone + Send + Sync + 'static,
+{
+    // Crear, modificar tasas o eliminar monedas queda reservado a administradores
+    let admin_routes = Router::new()
+        .route("/", post(create_currency))
+        .route("/:id", patch(update_currency))
+        .route("/:id", delete(delete_currency))
+        .route("/:id/rates", post(record_rate))
+        .layer(middleware::from_fn(admin_middleware));
+
+    Router::new()
+        .route("/", get(get_all_currencies))
+        .route("/:id/rates", get(get_rate_history))
+        .merge(admin_routes)
+        .layer(middleware::from_fn(normal_rate_limit))
+        .with_state(currency_use_cases)
+}
@@ -0,0 +1,34 @@
+use crate::server::presentation::middleware::{
+    admin_middleware::admin_middleware, rate_limit::normal_rate_limit,
+};
+use crate::server::{
+    application::use_cases::ledger_use_cases::LedgerUseCases,
+    domain::repositories::{currency_repository::CurrencyRepository, ledger_repository::LedgerRepository},
+    presentation::controllers::ledger_controller::{
+        get_journal_entries, get_receivables, get_trial_balance, revalue_cash,
+    },
+};
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+
+pub fn ledger_routes<G, C>(ledger_use_cases: LedgerUseCases<G, C>) -> Router
+where
+    G: LedgerRepository + Clone + Send + Sync + 'static,
+    C: CurrencyRepository + Clone + Send + Sync + 'static,
+{
+    // Registrar la revaluación cambiaria queda reservado a administradores
+    let admin_routes = Router::new()
+        .route("/revaluations", post(revalue_cash))
+        .layer(middleware::from_fn(admin_middleware));
+
+    Router::new()
+        .route("/accounts", get(get_trial_balance))
+        .route("/entries", get(get_journal_entries))
+        .route("/receivables", get(get_receivables))
+        .merge(admin_routes)
+        .layer(middleware::from_fn(normal_rate_limit))
+        .with_state(ledger_use_cases)
+}
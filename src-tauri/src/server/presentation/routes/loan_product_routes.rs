@@ -0,0 +1,39 @@
+use crate::server::presentation::middleware::{
+    admin_middleware::admin_middleware, rate_limit::normal_rate_limit,
+};
+use crate::server::{
+    application::use_cases::loan_product_use_cases::LoanProductUseCases,
+    domain::repositories::{
+        currency_repository::CurrencyRepository, loan_product_repository::LoanProductRepository,
+        penalty_repository::PenaltyRepository,
+    },
+    presentation::controllers::loan_product_controller::{
+        create_loan_product, deactivate_loan_product, get_loan_product_by_id, get_loan_products,
+        update_loan_product,
+    },
+};
+use axum::{
+    middleware,
+    routing::{get, patch, post},
+    Router,
+};
+
+pub fn loan_product_routes<R, N, C>(product_use_cases: LoanProductUseCases<R, N, C>) -> Router
+where
+    R: LoanProductRepository + Clone + Send + Sync + 'static,
+    N: PenaltyRepository + Clone + Send + Sync + 'static,
+    C: CurrencyRepository + Clone + Send + Sync + 'static,
+{
+    // Definir y modificar productos queda reservado a administradores
+    let admin_routes = Router::new()
+        .route("/", post(create_loan_product))
+        .route("/:id", patch(update_loan_product).delete(deactivate_loan_product))
+        .layer(middleware::from_fn(admin_middleware));
+
+    Router::new()
+        .route("/", get(get_loan_products))
+        .route("/:id", get(get_loan_product_by_id))
+        .merge(admin_routes)
+        .layer(middleware::from_fn(normal_rate_limit))
+        .with_state(product_use_cases)
+}
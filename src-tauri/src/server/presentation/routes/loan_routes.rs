@@ -0,0 +1,53 @@
+use crate::server::presentation::middleware::{
+    admin_middleware::admin_middleware, rate_limit::normal_rate_limit,
+};
+use crate::server::{
+    application::use_cases::loan_use_cases::LoanUseCases,
+    domain::repositories::{
+        borrower_repository::BorrowerRepository, cash_box_repository::CashBoxRepository,
+        currency_repository::CurrencyRepository, loan_product_repository::LoanProductRepository,
+        loan_repository::LoanRepository,
+    },
+    presentation::controllers::loan_controller::{
+        add_loan_guarantor, create_loan, delete_loan, get_all_loans, get_loan_by_id, get_loan_guarantors,
+        get_loan_interest, get_loan_schedule, purge_loan, remove_loan_guarantor, restore_loan,
+        restructure_loan, update_loan, write_off_loan,
+    },
+};
+use axum::{
+    middleware,
+    routing::{delete, get, patch, post},
+    Router,
+};
+
+pub fn loan_routes<T, U, C, R, X>(loan_use_cases: LoanUseCases<T, U, C, R, X>) -> Router
+where
+    T: LoanRepository + Clone + Send + Sync + 'static,
+    U: BorrowerRepository + Clone + Send + Sync + 'static,
+    C: CurrencyRepository + Clone + Send + Sync + 'static,
+    R: LoanProductRepository + Clone + Send + Sync + 'static,
+    X: CashBoxRepository + Clone + Send + Sync + 'static,
+{
+    // Castigar y borrar definitivamente un préstamo queda reservado a administradores
+    let admin_routes = Router::new()
+        .route("/:id/write-off", post(write_off_loan))
+        .route("/:id/purge", delete(purge_loan))
+        .layer(middleware::from_fn(admin_middleware));
+
+    Router::new()
+        .route("/", post(create_loan))
+        .route("/", get(get_all_loans))
+        .route("/:id", get(get_loan_by_id))
+        .route("/:id", patch(update_loan))
+        .route("/:id", delete(delete_loan))
+        .route("/:id/restore", post(restore_loan))
+        .route("/:id/interest", get(get_loan_interest))
+        .route("/:id/schedule", get(get_loan_schedule))
+        .route("/:id/restructure", post(restructure_loan))
+        .route("/:id/guarantors", get(get_loan_guarantors))
+        .route("/:id/guarantors", post(add_loan_guarantor))
+        .route("/:id/guarantors/:guarantor_id", delete(remove_loan_guarantor))
+        .merge(admin_routes)
+        .layer(middleware::from_fn(normal_rate_limit))
+        .with_state(loan_use_cases)
+}
@@ -1,4 +1,14 @@
 pub mod auth_routes;
 pub mod user_routes;
 pub mod borrower_routes;
-pub mod api_routes;
\ No newline at end of file
+pub mod loan_routes;
+pub mod payment_routes;
+pub mod currency_routes;
+pub mod report_routes;
+pub mod penalty_routes;
+pub mod loan_product_routes;
+pub mod collateral_routes;
+pub mod statement_routes;
+pub mod api_routes;
+pub mod ledger_routes;
+pub mod cash_box_routes;
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::server::presentation::middleware::{
+    admin_middleware::admin_middleware, rate_limit::normal_rate_limit,
+};
+use crate::server::{
+    application::use_cases::payment_use_cases::PaymentUseCases,
+    domain::repositories::{
+        borrower_repository::BorrowerRepository, currency_repository::CurrencyRepository,
+        payment_repository::PaymentRepository,
+    },
+    presentation::controllers::payment_controller::{
+        create_payment, delete_payment, get_all_payments, get_payment_by_id, purge_payment,
+        restore_payment, update_payment,
+    },
+};
+use axum::{
+    middleware,
+    routing::{delete, get, patch, post},
+    Router,
+};
+
+pub fn payment_routes<T, U, C>(payment_use_cases: PaymentUseCases<T, U, C>) -> Router
+where
+    T: PaymentRepository + Clone + Send + Sync + 'static,
+    U: BorrowerRepository + Clone + Send + Sync + 'static,
+    C: CurrencyRepository + Clone + Send + Sync + 'static,
+{
+    // Borrar definitivamente queda reservado a administradores
+    let admin_routes = Router::new()
+        .route("/:id/purge", delete(purge_payment))
+        .layer(middleware::from_fn(admin_middleware));
+
+    Router::new()
+        .route("/", post(create_payment))
+        .route("/", get(get_all_payments))
+        .route("/:id", get(get_payment_by_id))
+        .route("/:id", patch(update_payment))
+        .route("/:id", delete(delete_payment))
+        .route("/:id/restore", post(restore_payment))
+        .merge(admin_routes)
+        .layer(middleware::from_fn(normal_rate_limit))
+        .with_state(payment_use_cases)
+}
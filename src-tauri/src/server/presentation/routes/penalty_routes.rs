@@ -0,0 +1,36 @@
+use crate::server::presentation::middleware::{
+    admin_middleware::admin_middleware, rate_limit::normal_rate_limit,
+};
+use crate::server::{
+    application::use_cases::penalty_use_cases::PenaltyUseCases,
+    domain::repositories::{loan_repository::LoanRepository, penalty_repository::PenaltyRepository},
+    presentation::controllers::penalty_controller::{
+        create_penalty_rule, deactivate_penalty_rule, get_penalties, get_penalty_rules,
+        waive_penalty,
+    },
+};
+use axum::{
+    middleware,
+    routing::{delete, get, post},
+    Router,
+};
+
+pub fn penalty_routes<P, L>(penalty_use_cases: PenaltyUseCases<P, L>) -> Router
+where
+    P: PenaltyRepository + Clone + Send + Sync + 'static,
+    L: LoanRepository + Clone + Send + Sync + 'static,
+{
+    // Definir reglas y condonar recargos queda reservado a administradores
+    let admin_routes = Router::new()
+        .route("/rules", post(create_penalty_rule))
+        .route("/rules/:id", delete(deactivate_penalty_rule))
+        .route("/:id/waive", post(waive_penalty))
+        .layer(middleware::from_fn(admin_middleware));
+
+    Router::new()
+        .route("/", get(get_penalties))
+        .route("/rules", get(get_penalty_rules))
+        .merge(admin_routes)
+        .layer(middleware::from_fn(normal_rate_limit))
+        .with_state(penalty_use_cases)
+}
@@ -0,0 +1,23 @@
+use crate::server::presentation::middleware::rate_limit::normal_rate_limit;
+use crate::server::{
+    application::use_cases::report_use_cases::ReportUseCases,
+    domain::repositories::{
+        borrower_repository::BorrowerRepository, currency_repository::CurrencyRepository,
+        loan_repository::LoanRepository, payment_repository::PaymentRepository,
+    },
+    presentation::controllers::report_controller::get_balance_revaluation,
+};
+use axum::{middleware, routing::get, Router};
+
+pub fn report_routes<L, P, B, C>(report_use_cases: ReportUseCases<L, P, B, C>) -> Router
+where
+    L: LoanRepository + Clone + Send + Sync + 'static,
+    P: PaymentRepository + Clone + Send + Sync + 'static,
+    B: BorrowerRepository + Clone + Send + Sync + 'static,
+    C: CurrencyRepository + Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/balances", get(get_balance_revaluation))
+        .layer(middleware::from_fn(normal_rate_limit))
+        .with_state(report_use_cases)
+}
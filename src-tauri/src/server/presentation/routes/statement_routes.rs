@@ -0,0 +1,25 @@
+use crate::server::presentation::middleware::rate_limit::auth_rate_limit;
+use crate::server::{
+    application::use_cases::statement_use_cases::StatementUseCases,
+    domain::repositories::{
+        borrower_repository::BorrowerRepository, loan_repository::LoanRepository,
+        ledger_repository::LedgerRepository, payment_repository::PaymentRepository,
+        penalty_repository::PenaltyRepository,
+    },
+    presentation::controllers::statement_controller::get_borrower_statement,
+};
+use axum::{middleware, routing::get, Router};
+
+pub fn statement_routes<B, L, P, N, G>(statement_use_cases: StatementUseCases<B, L, P, N, G>) -> Router
+where
+    B: BorrowerRepository + Clone + Send + Sync + 'static,
+    L: LoanRepository + Clone + Send + Sync + 'static,
+    P: PaymentRepository + Clone + Send + Sync + 'static,
+    N: PenaltyRepository + Clone + Send + Sync + 'static,
+    G: LedgerRepository + Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/:id/statement", get(get_borrower_statement))
+        .layer(middleware::from_fn(auth_rate_limit))
+        .with_state(statement_use_cases)
+}
@@ -17,6 +17,16 @@ use crate::{server::presentation::{
 pub fn configure_routes() -> Router {
      let user_use_cases = ServiceFactory::create_user_use_cases();
      let borrower_use_cases = ServiceFactory::create_borrower_use_cases();
+     let loan_use_cases = ServiceFactory::create_loan_use_cases();
+     let payment_use_cases = ServiceFactory::create_payment_use_cases();
+     let currency_use_cases = ServiceFactory::create_currency_use_cases();
+     let report_use_cases = ServiceFactory::create_report_use_cases();
+     let penalty_use_cases = ServiceFactory::create_penalty_use_cases();
+     let loan_product_use_cases = ServiceFactory::create_loan_product_use_cases();
+     let collateral_use_cases = ServiceFactory::create_collateral_use_cases();
+     let statement_use_cases = ServiceFactory::create_statement_use_cases();
+     let ledger_use_cases = ServiceFactory::create_ledger_use_cases();
+     let cash_box_use_cases = ServiceFactory::create_cash_box_use_cases();
     Router::new()
     
     .route("/health", get(health_check)
@@ -26,7 +36,20 @@ pub fn configure_routes() -> Router {
     .route("/api/hello", get(hello_world)
         .layer(middleware::from_fn(normal_rate_limit)))  
     
-    .nest("/api", api_routes(user_use_cases, borrower_use_cases))
+    .nest("/api", api_routes(
+        user_use_cases,
+        borrower_use_cases,
+        loan_use_cases,
+        payment_use_cases,
+        currency_use_cases,
+        report_use_cases,
+        penalty_use_cases,
+        loan_product_use_cases,
+        collateral_use_cases,
+        statement_use_cases,
+        ledger_use_cases,
+        cash_box_use_cases,
+    ))
     .layer(middleware::from_fn(timing_middleware))
 }
 
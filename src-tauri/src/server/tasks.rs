@@ -0,0 +1,46 @@
+use colored::Colorize;
+use std::time::Duration;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::get_global_app_state;
+use crate::utils::ServiceFactory;
+
+/// Recalcula los estados de cobranza y cobra los recargos por atraso al iniciar y luego cada `check_interval_hours`
+/// Corre en segundo plano junto al servidor Axum; un error se registra y se reintenta en el siguiente ciclo
+pub async fn run_loan_status_task() {
+    let app_state = get_global_app_state();
+    let hours = app_state.config.collections.check_interval_hours.max(1);
+    let loan_status_use_cases = ServiceFactory::create_loan_status_use_cases();
+    let penalty_use_cases = ServiceFactory::create_penalty_use_cases();
+
+    let mut ticker = interval(Duration::from_secs(hours * 60 * 60));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        let now = chrono::Utc::now().naive_utc();
+
+        match loan_status_use_cases.refresh_statuses(now).await {
+            Ok(summary) => println!(
+                "{} {} préstamo(s) y {} prestatario(s) actualizados ({} vencidos, {} en mora)",
+                "Estados de cobranza:".blue(),
+                summary.loans_updated,
+                summary.borrowers_updated,
+                summary.overdue_loans,
+                summary.defaulted_loans
+            ),
+            Err(e) => println!("{} {}", "❌ Error al actualizar los estados de cobranza:".red(), e),
+        }
+
+        match penalty_use_cases.assess_penalties(now).await {
+            Ok(summary) => println!(
+                "{} {} recargo(s) cobrados por {:.2} CUP",
+                "Recargos por atraso:".blue(),
+                summary.penalties_charged,
+                summary.total_charged
+            ),
+            Err(e) => println!("{} {}", "❌ Error al cobrar los recargos por atraso:".red(), e),
+        }
+    }
+}
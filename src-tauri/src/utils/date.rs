@@ -0,0 +1,22 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::utils::error::{AppError, Result};
+
+/// Fecha efectiva de un registro financiero: la recibida (sin admitir fechas futuras) o la actual
+pub fn resolve_record_date(date: Option<DateTime<Utc>>) -> Result<NaiveDateTime> {
+    let now = Utc::now();
+    match date {
+        Some(date) if date > now => Err(AppError::ValidationError(vec![
+            "La fecha no puede estar en el futuro".to_string(),
+        ])),
+        Some(date) => Ok(date.naive_utc()),
+        None => Ok(now.naive_utc()),
+    }
+}
+
+/// Convierte una fecha RFC3339 guardada en una entidad del dominio a UTC naive
+pub fn parse_stored_date(date: &str) -> NaiveDateTime {
+    DateTime::parse_from_rfc3339(date)
+        .map(|dt| dt.naive_utc())
+        .unwrap_or_else(|_| Utc::now().naive_utc())
+}
@@ -33,17 +33,26 @@ pub enum AppError {
 
 fn extract_validation_messages(error: &validator::ValidationErrors) -> Vec<String> {
     error
-        .field_errors()
+        .errors()
         .iter()
-        .flat_map(|(field, errors)| {
-            errors.iter().map(move |error| {
-                // Solo devolvemos el mensaje sin el nombre del campo
-                if let Some(message) = &error.message {
-                    message.to_string()
-                } else {
-                    format!("Validation failed for {}", field)
-                }
-            })
+        .flat_map(|(field, kind)| match kind {
+            validator::ValidationErrorsKind::Field(errors) => errors
+                .iter()
+                .map(|error| {
+                    // Solo devolvemos el mensaje sin el nombre del campo
+                    if let Some(message) = &error.message {
+                        message.to_string()
+                    } else {
+                        format!("Validation failed for {}", field)
+                    }
+                })
+                .collect::<Vec<_>>(),
+            // Los objetos anidados aportan sus propios mensajes
+            validator::ValidationErrorsKind::Struct(nested) => extract_validation_messages(nested),
+            validator::ValidationErrorsKind::List(items) => items
+                .values()
+                .flat_map(|nested| extract_validation_messages(nested))
+                .collect(),
         })
         .collect()
 }
@@ -1,5 +1,6 @@
 pub mod error;
 pub  mod service_factory;
+pub mod date;
 
 pub use error::*;
 pub use service_factory::*;
\ No newline at end of file
@@ -1,6 +1,30 @@
 use crate::get_global_app_state;
 use crate::server::application::use_cases::borrower_use_cases::BorrowerUseCases;
+use crate::server::application::use_cases::loan_use_cases::LoanUseCases;
+use crate::server::application::use_cases::payment_use_cases::PaymentUseCases;
+use crate::server::application::use_cases::currency_use_cases::CurrencyUseCases;
+use crate::server::application::use_cases::report_use_cases::ReportUseCases;
+use crate::server::application::use_cases::loan_status_use_cases::LoanStatusUseCases;
+use crate::server::application::use_cases::penalty_use_cases::PenaltyUseCases;
+use crate::server::application::use_cases::loan_product_use_cases::LoanProductUseCases;
+use crate::server::application::use_cases::collateral_use_cases::CollateralUseCases;
+use crate::server::application::use_cases::statement_use_cases::StatementUseCases;
+use crate::server::application::use_cases::ledger_use_cases::LedgerUseCases;
+use crate::server::application::use_cases::cash_box_use_cases::CashBoxUseCases;
+use crate::server::domain::entities::currency::BASE_CURRENCY;
+use crate::server::domain::entities::loan_guarantor::GuarantorExposureLimit;
+use crate::server::domain::entities::payment_allocation::AllocationStrategy;
+use crate::server::domain::services::delinquency_policy::DelinquencyThresholds;
+use crate::server::domain::value_objects::money::Money;
 use crate::server::infrastructure::database::repositories::diesel_borrower_repository::DieselBorrowerRepository;
+use crate::server::infrastructure::database::repositories::diesel_loan_repository::DieselLoanRepository;
+use crate::server::infrastructure::database::repositories::diesel_payment_repository::DieselPaymentRepository;
+use crate::server::infrastructure::database::repositories::diesel_currency_repository::DieselCurrencyRepository;
+use crate::server::infrastructure::database::repositories::diesel_penalty_repository::DieselPenaltyRepository;
+use crate::server::infrastructure::database::repositories::diesel_loan_product_repository::DieselLoanProductRepository;
+use crate::server::infrastructure::database::repositories::diesel_collateral_repository::DieselCollateralRepository;
+use crate::server::infrastructure::database::repositories::diesel_ledger_repository::DieselLedgerRepository;
+use crate::server::infrastructure::database::repositories::diesel_cash_box_repository::DieselCashBoxRepository;
 use crate::server::infrastructure::database::repositories::diesel_user_repository::DieselUserRepository;
 use crate::server::application::use_cases::user_use_cases::UserUseCases;
 
@@ -25,5 +49,125 @@ impl ServiceFactory {
         
         BorrowerUseCases::new(borrower_repository)
     }
-    
-}
\ No newline at end of file
+    pub fn create_loan_use_cases() -> LoanUseCases<DieselLoanRepository, DieselBorrowerRepository, DieselCurrencyRepository, DieselLoanProductRepository, DieselCashBoxRepository> {
+        let app_state = get_global_app_state();
+
+        let loan_repository = DieselLoanRepository::new(app_state.db.clone());
+        let borrower_repository = DieselBorrowerRepository::new(app_state.db.clone());
+        let currency_repository = DieselCurrencyRepository::new(app_state.db.clone());
+        let product_repository = DieselLoanProductRepository::new(app_state.db.clone());
+        let cash_box_repository = DieselCashBoxRepository::new(app_state.db.clone());
+
+        // Un tope en cero o inválido deja a los garantes sin límite de exposición
+        let guarantor_limit = GuarantorExposureLimit {
+            limit: Money::from_major(app_state.config.guarantors.exposure_limit, BASE_CURRENCY)
+                .ok()
+                .filter(|limit| limit.is_positive()),
+            refuse: app_state.config.guarantors.limit_action == "refuse",
+        };
+
+        LoanUseCases::new(
+            loan_repository,
+            borrower_repository,
+            currency_repository,
+            product_repository,
+            cash_box_repository,
+            guarantor_limit,
+        )
+    }
+    pub fn create_payment_use_cases() -> PaymentUseCases<DieselPaymentRepository, DieselBorrowerRepository, DieselCurrencyRepository> {
+        let app_state = get_global_app_state();
+
+        let payment_repository = DieselPaymentRepository::new(app_state.db.clone());
+        let borrower_repository = DieselBorrowerRepository::new(app_state.db.clone());
+        let currency_repository = DieselCurrencyRepository::new(app_state.db.clone());
+
+        // Un valor desconocido en la configuración cae en la estrategia más antigua primero
+        let default_strategy = AllocationStrategy::parse(&app_state.config.app.allocation_strategy)
+            .unwrap_or(AllocationStrategy::OldestFirst);
+
+        PaymentUseCases::new(payment_repository, borrower_repository, currency_repository, default_strategy)
+    }
+    pub fn create_currency_use_cases() -> CurrencyUseCases<DieselCurrencyRepository> {
+        let app_state = get_global_app_state();
+
+        let currency_repository = DieselCurrencyRepository::new(app_state.db.clone());
+
+        CurrencyUseCases::new(currency_repository)
+    }
+    pub fn create_report_use_cases() -> ReportUseCases<DieselLoanRepository, DieselPaymentRepository, DieselBorrowerRepository, DieselCurrencyRepository> {
+        let app_state = get_global_app_state();
+
+        let loan_repository = DieselLoanRepository::new(app_state.db.clone());
+        let payment_repository = DieselPaymentRepository::new(app_state.db.clone());
+        let borrower_repository = DieselBorrowerRepository::new(app_state.db.clone());
+        let currency_repository = DieselCurrencyRepository::new(app_state.db.clone());
+
+        ReportUseCases::new(loan_repository, payment_repository, borrower_repository, currency_repository)
+    }
+    pub fn create_loan_status_use_cases() -> LoanStatusUseCases<DieselLoanRepository, DieselBorrowerRepository> {
+        let app_state = get_global_app_state();
+
+        let loan_repository = DieselLoanRepository::new(app_state.db.clone());
+        let borrower_repository = DieselBorrowerRepository::new(app_state.db.clone());
+        let thresholds = DelinquencyThresholds {
+            overdue_after_days: app_state.config.collections.overdue_after_days,
+            default_after_days: app_state.config.collections.default_after_days,
+        };
+
+        LoanStatusUseCases::new(loan_repository, borrower_repository, thresholds)
+    }
+    pub fn create_penalty_use_cases() -> PenaltyUseCases<DieselPenaltyRepository, DieselLoanRepository> {
+        let app_state = get_global_app_state();
+
+        let penalty_repository = DieselPenaltyRepository::new(app_state.db.clone());
+        let loan_repository = DieselLoanRepository::new(app_state.db.clone());
+
+        PenaltyUseCases::new(penalty_repository, loan_repository)
+    }
+    pub fn create_loan_product_use_cases() -> LoanProductUseCases<DieselLoanProductRepository, DieselPenaltyRepository, DieselCurrencyRepository> {
+        let app_state = get_global_app_state();
+
+        let product_repository = DieselLoanProductRepository::new(app_state.db.clone());
+        let penalty_repository = DieselPenaltyRepository::new(app_state.db.clone());
+        let currency_repository = DieselCurrencyRepository::new(app_state.db.clone());
+
+        LoanProductUseCases::new(product_repository, penalty_repository, currency_repository)
+    }
+    pub fn create_collateral_use_cases() -> CollateralUseCases<DieselCollateralRepository, DieselLoanRepository, DieselCurrencyRepository> {
+        let app_state = get_global_app_state();
+
+        let collateral_repository = DieselCollateralRepository::new(app_state.db.clone());
+        let loan_repository = DieselLoanRepository::new(app_state.db.clone());
+        let currency_repository = DieselCurrencyRepository::new(app_state.db.clone());
+
+        CollateralUseCases::new(collateral_repository, loan_repository, currency_repository)
+    }
+    pub fn create_statement_use_cases() -> StatementUseCases<DieselBorrowerRepository, DieselLoanRepository, DieselPaymentRepository, DieselPenaltyRepository, DieselLedgerRepository> {
+        let app_state = get_global_app_state();
+
+        let borrower_repository = DieselBorrowerRepository::new(app_state.db.clone());
+        let loan_repository = DieselLoanRepository::new(app_state.db.clone());
+        let payment_repository = DieselPaymentRepository::new(app_state.db.clone());
+        let penalty_repository = DieselPenaltyRepository::new(app_state.db.clone());
+        let ledger_repository = DieselLedgerRepository::new(app_state.db.clone());
+
+        StatementUseCases::new(borrower_repository, loan_repository, payment_repository, penalty_repository, ledger_repository)
+    }
+    pub fn create_ledger_use_cases() -> LedgerUseCases<DieselLedgerRepository, DieselCurrencyRepository> {
+        let app_state = get_global_app_state();
+
+        let ledger_repository = DieselLedgerRepository::new(app_state.db.clone());
+        let currency_repository = DieselCurrencyRepository::new(app_state.db.clone());
+
+        LedgerUseCases::new(ledger_repository, currency_repository)
+    }
+    pub fn create_cash_box_use_cases() -> CashBoxUseCases<DieselCashBoxRepository, DieselCurrencyRepository> {
+        let app_state = get_global_app_state();
+
+        let cash_box_repository = DieselCashBoxRepository::new(app_state.db.clone());
+        let currency_repository = DieselCurrencyRepository::new(app_state.db.clone());
+
+        CashBoxUseCases::new(cash_box_repository, currency_repository)
+    }
+}
@@ -28,6 +28,13 @@ export type BorrowerResponse = {
   updated_at: Date;
 };
 
+export type BorrowerPageResponse = {
+  items: BorrowerResponse[];
+  total: number;
+  page: number;
+  page_size: number | null;
+};
+
 export type BorrowerErrorResponse = {
   status_code: number;
   message: string | string[];
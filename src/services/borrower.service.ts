@@ -4,6 +4,7 @@ import {
   BorrowerForm,
   BorrowerResponse,
   BorrowerErrorResponse,
+  BorrowerPageResponse,
   BorrowerSuccessResponse,
 } from "../schemas/borrower.schema";
 import { isApiError } from "../utils/isApiError";
@@ -11,9 +12,9 @@ import { isApiError } from "../utils/isApiError";
 export const borrowerService = {
   async getAll(): Promise<BorrowerResponse[] | BorrowerErrorResponse> {
     try {
-      const response = await api.get<BorrowerResponse[]>("/borrowers");
+      const response = await api.get<BorrowerPageResponse>("/borrowers");
       console.log(response)
-      return response.data;
+      return response.data.items;
     } catch (error) {
       if (isApiError<BorrowerErrorResponse>(error)) {
         return (